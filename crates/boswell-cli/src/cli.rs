@@ -1,6 +1,7 @@
 //! CLI command definitions and argument parsing.
 
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 /// Boswell CLI - Interact with the Boswell cognitive memory system.
 #[derive(Debug, Parser)]
@@ -59,9 +60,24 @@ pub enum Command {
     /// Semantic search for claims
     Search(SearchArgs),
 
+    /// Export a namespace subtree as JSON Lines or RDF
+    Export(ExportArgs),
+
+    /// Import a JSON Lines export
+    Import(ImportArgs),
+
+    /// List, inspect and move namespaces
+    Ns(NsArgs),
+
+    /// Merge, unmerge and list entity aliases
+    Alias(AliasArgs),
+
     /// Manage configuration profiles
     Profile(ProfileArgs),
 
+    /// Administer a local instance database
+    Admin(AdminArgs),
+
     /// Enter interactive REPL mode
     Repl,
 }
@@ -114,7 +130,7 @@ pub struct AssertArgs {
 /// Arguments for the query command.
 #[derive(Debug, Parser)]
 pub struct QueryArgs {
-    /// Filter by subject (format: namespace:value or namespace:*)
+    /// Filter by subject (format: kind:value, or kind:* for every entity of a kind)
     #[arg(short, long)]
     pub subject: Option<String>,
 
@@ -122,7 +138,7 @@ pub struct QueryArgs {
     #[arg(short, long)]
     pub predicate: Option<String>,
 
-    /// Filter by object (format: namespace:value or namespace:*)
+    /// Filter by object (format: kind:value, or kind:* for every entity of a kind)
     #[arg(short, long)]
     pub object: Option<String>,
 
@@ -134,6 +150,18 @@ pub struct QueryArgs {
     #[arg(long)]
     pub min_confidence: Option<f64>,
 
+    /// Only literal objects after this one (e.g. date:2025, number:100)
+    #[arg(long)]
+    pub after: Option<String>,
+
+    /// Only literal objects before this one (e.g. date:2025-06-01)
+    #[arg(long)]
+    pub before: Option<String>,
+
+    /// Match subject and object through entity aliases
+    #[arg(long)]
+    pub expand_aliases: bool,
+
     /// Maximum number of results
     #[arg(short, long)]
     pub limit: Option<usize>,
@@ -189,6 +217,150 @@ pub struct SearchArgs {
     pub threshold: f64,
 }
 
+/// Arguments for the export command.
+#[derive(Debug, Parser)]
+pub struct ExportArgs {
+    /// Namespace to export, including its descendants
+    pub namespace: String,
+
+    /// Write to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Write RDF in this syntax instead of JSON Lines
+    #[arg(long, value_enum)]
+    pub rdf: Option<RdfSyntax>,
+
+    /// How RDF output attaches claim metadata (default: star, or
+    /// statements for JSON-LD)
+    #[arg(long, value_enum, requires = "rdf")]
+    pub reification: Option<ReificationArg>,
+}
+
+/// RDF syntaxes for export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RdfSyntax {
+    /// N-Triples
+    NTriples,
+    /// Turtle
+    Turtle,
+    /// JSON-LD
+    JsonLd,
+}
+
+/// RDF reification styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReificationArg {
+    /// RDF-star quoted triples
+    Star,
+    /// rdf:Statement nodes
+    Statements,
+}
+
+/// Arguments for the import command.
+#[derive(Debug, Parser)]
+pub struct ImportArgs {
+    /// Export file to read ("-" for stdin)
+    pub file: PathBuf,
+
+    /// Validate each claim with the instance's gatekeeper
+    #[arg(short, long)]
+    pub gatekeeper: bool,
+
+    /// Claims sent per request
+    #[arg(long, default_value = "500")]
+    pub batch_size: usize,
+}
+
+/// Arguments for namespace management.
+#[derive(Debug, Parser)]
+pub struct NsArgs {
+    #[command(subcommand)]
+    pub action: NsAction,
+}
+
+/// Namespace management actions.
+///
+/// Counts include every namespace below the one shown.
+#[derive(Debug, Subcommand)]
+pub enum NsAction {
+    /// List the namespaces directly below a namespace (top-level by default)
+    Ls {
+        /// Namespace whose children to list
+        root: Option<String>,
+    },
+
+    /// Show a namespace and everything below it as a tree
+    Tree {
+        /// Namespace at the top of the tree (every namespace by default)
+        root: Option<String>,
+    },
+
+    /// Show claim counts per tier, confidence and contradictions for a namespace
+    Stats {
+        /// Namespace to summarize, including its descendants
+        namespace: String,
+    },
+
+    /// Move a namespace and everything below it to a new name
+    Mv {
+        /// Namespace to move
+        from: String,
+        /// New name; must not hold any claims yet
+        to: String,
+    },
+}
+
+/// Arguments for entity alias management.
+#[derive(Debug, Parser)]
+pub struct AliasArgs {
+    #[command(subcommand)]
+    pub action: AliasAction,
+}
+
+/// Entity alias actions.
+///
+/// Merges are recorded, never destroyed: unmerging keeps the alias as retracted.
+#[derive(Debug, Subcommand)]
+pub enum AliasAction {
+    /// Record that one entity is the same as another
+    Merge {
+        /// Entity to merge (e.g. person:j_doe)
+        alias: String,
+        /// Entity it is the same as (e.g. person:john_doe)
+        canonical: String,
+        /// Why the entities are the same
+        #[arg(short, long, default_value = "")]
+        reason: String,
+    },
+
+    /// Undo the merge of an alias
+    Unmerge {
+        /// Alias whose merge to undo
+        alias: String,
+    },
+
+    /// List aliases
+    List {
+        /// Include retracted aliases
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Suggest merges between similarly named entities
+    Suggest {
+        /// Only consider claims in this namespace subtree
+        #[arg(short, long)]
+        namespace: Option<String>,
+        /// Lowest score to suggest (the instance default when omitted)
+        #[arg(long)]
+        min_score: Option<f64>,
+        /// Maximum number of suggestions
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+    },
+}
+
 /// Arguments for profile management.
 #[derive(Debug, Parser)]
 pub struct ProfileArgs {
@@ -233,6 +405,46 @@ pub enum ProfileAction {
     },
 }
 
+/// Arguments for instance administration.
+#[derive(Debug, Parser)]
+pub struct AdminArgs {
+    #[command(subcommand)]
+    pub action: AdminAction,
+}
+
+/// Instance administration actions.
+///
+/// These work on the database file directly rather than through the router.
+#[derive(Debug, Subcommand)]
+pub enum AdminAction {
+    /// Back up a database while the instance keeps serving
+    Backup {
+        /// Directory to write the backup to (created if missing, must be empty)
+        output: PathBuf,
+        /// Database file of the instance
+        #[arg(short, long, default_value = "boswell.db")]
+        database: PathBuf,
+        /// Embedding dimension of the instance; include a vector snapshot of this dimension
+        #[arg(short, long)]
+        embedding_dimension: Option<usize>,
+    },
+
+    /// Restore a backup to a database file
+    Restore {
+        /// Backup directory written by `admin backup`
+        backup: PathBuf,
+        /// Database file to create
+        #[arg(short, long, default_value = "boswell.db")]
+        database: PathBuf,
+        /// Embedding dimension of the instance; the backup's embeddings must match it
+        #[arg(short, long)]
+        embedding_dimension: Option<usize>,
+        /// Replace the database file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+}
+
 /// Tier argument.
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 pub enum TierArg {
@@ -267,6 +479,17 @@ impl From<TierArg> for boswell_domain::Tier {
     }
 }
 
+impl From<boswell_domain::Tier> for TierArg {
+    fn from(tier: boswell_domain::Tier) -> Self {
+        match tier {
+            boswell_domain::Tier::Ephemeral => TierArg::Ephemeral,
+            boswell_domain::Tier::Task => TierArg::Task,
+            boswell_domain::Tier::Project => TierArg::Project,
+            boswell_domain::Tier::Permanent => TierArg::Permanent,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,6 +515,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ns_command() {
+        let cli = Cli::parse_from(["boswell", "ns", "mv", "acme/eng", "acme/engineering"]);
+        match cli.command {
+            Some(Command::Ns(NsArgs { action: NsAction::Mv { from, to } })) => {
+                assert_eq!((from.as_str(), to.as_str()), ("acme/eng", "acme/engineering"));
+            }
+            _ => panic!("Expected Ns Mv command"),
+        }
+    }
+
+    #[test]
+    fn test_alias_command() {
+        let cli = Cli::parse_from(["boswell", "alias", "merge", "person:j_doe", "person:john_doe", "-r", "same person"]);
+        match cli.command {
+            Some(Command::Alias(AliasArgs { action: AliasAction::Merge { alias, canonical, reason } })) => {
+                assert_eq!((alias.as_str(), canonical.as_str()), ("person:j_doe", "person:john_doe"));
+                assert_eq!(reason, "same person");
+            }
+            _ => panic!("Expected Alias Merge command"),
+        }
+    }
+
+    #[test]
+    fn test_query_literal_bounds() {
+        let cli = Cli::parse_from(["boswell", "query", "--object", "date:*", "--after", "date:2025"]);
+        match cli.command {
+            Some(Command::Query(args)) => {
+                assert_eq!(args.object.as_deref(), Some("date:*"));
+                assert_eq!(args.after.as_deref(), Some("date:2025"));
+                assert!(args.before.is_none());
+            }
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_import_command() {
+        let cli = Cli::parse_from(["boswell", "import", "acme.jsonl", "--gatekeeper"]);
+        match cli.command {
+            Some(Command::Import(args)) => {
+                assert_eq!(args.file, PathBuf::from("acme.jsonl"));
+                assert!(args.gatekeeper);
+                assert_eq!(args.batch_size, 500);
+            }
+            _ => panic!("Expected Import command"),
+        }
+    }
+
+    #[test]
+    fn test_export_rdf_command() {
+        let cli = Cli::parse_from(["boswell", "export", "acme", "--rdf", "json-ld", "--reification", "statements"]);
+        match cli.command {
+            Some(Command::Export(args)) => {
+                assert_eq!(args.namespace, "acme");
+                assert_eq!(args.rdf, Some(RdfSyntax::JsonLd));
+                assert_eq!(args.reification, Some(ReificationArg::Statements));
+            }
+            _ => panic!("Expected Export command"),
+        }
+
+        assert!(Cli::try_parse_from(["boswell", "export", "acme", "--reification", "star"]).is_err());
+    }
+
+    #[test]
+    fn test_admin_restore_command() {
+        let cli = Cli::parse_from([
+            "boswell",
+            "admin",
+            "restore",
+            "backups/monday",
+            "--database",
+            "restored.db",
+            "--embedding-dimension",
+            "384",
+        ]);
+        match cli.command {
+            Some(Command::Admin(AdminArgs {
+                action: AdminAction::Restore { backup, database, embedding_dimension, force },
+            })) => {
+                assert_eq!(backup, PathBuf::from("backups/monday"));
+                assert_eq!(database, PathBuf::from("restored.db"));
+                assert_eq!(embedding_dimension, Some(384));
+                assert!(!force);
+            }
+            _ => panic!("Expected Admin Restore command"),
+        }
+    }
+
     #[test]
     fn test_tier_conversion() {
         let tier: boswell_domain::Tier = TierArg::Task.into();
@@ -0,0 +1,219 @@
+//! Admin command implementation.
+
+use crate::cli::{AdminAction, AdminArgs};
+use crate::error::{CliError, Result};
+use crate::output::Formatter;
+use boswell_store::{BackupManifest, SqliteStore};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Execute the admin command.
+pub async fn execute_admin(args: AdminArgs, formatter: &Formatter) -> Result<()> {
+    match args.action {
+        AdminAction::Backup {
+            output,
+            database,
+            embedding_dimension,
+        } => backup(&database, &output, embedding_dimension, formatter),
+        AdminAction::Restore {
+            backup,
+            database,
+            embedding_dimension,
+            force,
+        } => restore(&backup, &database, embedding_dimension, force, formatter),
+    }
+}
+
+/// Back up a database file with SQLite's online backup API.
+///
+/// The database is opened as it is, without migrating it. With an embedding
+/// dimension, the claims are embedded again so the backup carries a vector
+/// snapshot; otherwise it holds the database only.
+fn backup(
+    database: &Path,
+    output: &Path,
+    embedding_dimension: Option<usize>,
+    formatter: &Formatter,
+) -> Result<()> {
+    if !database.is_file() {
+        return Err(CliError::InvalidInput(format!(
+            "Database '{}' does not exist",
+            database.display()
+        )));
+    }
+
+    let store = SqliteStore::open_existing(
+        database,
+        embedding_dimension.is_some(),
+        embedding_dimension.unwrap_or(0),
+    )?;
+    let manifest = store.backup(output)?;
+
+    println!(
+        "{}",
+        formatter.success(&format!(
+            "Backed up {} claims to {}",
+            manifest.claim_count,
+            output.display()
+        ))
+    );
+    print_manifest(&manifest);
+    Ok(())
+}
+
+/// Restore a backup to a database file.
+///
+/// The backup is restored to a file beside the database and renamed over it
+/// only once it has been checked and written, so a bad backup leaves an
+/// existing database untouched.
+fn restore(
+    backup: &Path,
+    database: &Path,
+    embedding_dimension: Option<usize>,
+    force: bool,
+    formatter: &Formatter,
+) -> Result<()> {
+    if database.exists() && !force {
+        return Err(CliError::NotPermitted(format!(
+            "Database '{}' already exists (use --force to replace it)",
+            database.display()
+        )));
+    }
+
+    let manifest = boswell_store::read_manifest(backup)?;
+
+    let staging = staging_path(database);
+    remove_database_files(&staging)?;
+    let restored = SqliteStore::restore(
+        backup,
+        &staging,
+        embedding_dimension.is_some(),
+        embedding_dimension.unwrap_or(0),
+    );
+    match restored {
+        // Closing the store checkpoints its WAL into the staging file
+        Ok(store) => drop(store),
+        Err(e) => {
+            remove_database_files(&staging)?;
+            return Err(e.into());
+        }
+    }
+
+    fs::rename(&staging, database)?;
+    // WAL files of the replaced database must not be applied to the restored one
+    for path in &database_files(database)[1..] {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+
+    println!(
+        "{}",
+        formatter.success(&format!(
+            "Restored {} claims to {}",
+            manifest.claim_count,
+            database.display()
+        ))
+    );
+    print_manifest(&manifest);
+    Ok(())
+}
+
+/// Path beside `database` that a backup is restored to before replacing it.
+fn staging_path(database: &Path) -> PathBuf {
+    let mut path = database.as_os_str().to_owned();
+    path.push(".restoring");
+    PathBuf::from(path)
+}
+
+/// Remove a database file and its WAL files, where they exist.
+fn remove_database_files(database: &Path) -> Result<()> {
+    for path in database_files(database) {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// The database file and the WAL files SQLite keeps beside it.
+fn database_files(database: &Path) -> [PathBuf; 3] {
+    let with_suffix = |suffix: &str| {
+        let mut path = database.as_os_str().to_owned();
+        path.push(suffix);
+        PathBuf::from(path)
+    };
+    [database.to_path_buf(), with_suffix("-wal"), with_suffix("-shm")]
+}
+
+/// Print the details recorded in a backup manifest.
+fn print_manifest(manifest: &BackupManifest) {
+    println!("  Schema version: {}", manifest.schema_version);
+    match &manifest.embeddings {
+        Some(embeddings) => println!(
+            "  Embeddings: {} from {} ({} dimensions)",
+            embeddings.count, embeddings.model, embeddings.dimension
+        ),
+        None => println!("  Embeddings: none"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::OutputFormat;
+    use boswell_domain::traits::ClaimStore;
+    use boswell_domain::{Claim, ClaimId, Tier};
+
+    fn claim() -> Claim {
+        Claim::new(
+            ClaimId::new(),
+            "acme".to_string(),
+            "user:alice".to_string(),
+            "knows".to_string(),
+            "user:bob".to_string(),
+            (0.8, 0.9),
+            Tier::Project,
+            1000,
+        )
+    }
+
+    fn formatter() -> Formatter {
+        Formatter::new(OutputFormat::Quiet, false)
+    }
+
+    #[test]
+    fn test_bad_backup_leaves_database_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let database = dir.path().join("boswell.db");
+        let id = SqliteStore::new(&database, false, 0).unwrap().assert_claim(claim()).unwrap().claim_id();
+
+        let backup = dir.path().join("backup");
+        fs::create_dir(&backup).unwrap();
+        fs::write(backup.join("manifest.json"), "not a manifest").unwrap();
+
+        assert!(restore(&backup, &database, None, true, &formatter()).is_err());
+
+        let store = SqliteStore::new(&database, false, 0).unwrap();
+        assert!(store.get_claim(id).unwrap().is_some());
+        assert!(!staging_path(&database).exists());
+    }
+
+    #[test]
+    fn test_restore_replaces_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.db");
+        let kept = SqliteStore::new(&source, false, 0).unwrap().assert_claim(claim()).unwrap().claim_id();
+        let backup_dir = dir.path().join("backup");
+        backup(&source, &backup_dir, None, &formatter()).unwrap();
+
+        let database = dir.path().join("boswell.db");
+        let replaced = SqliteStore::new(&database, false, 0).unwrap().assert_claim(claim()).unwrap().claim_id();
+
+        restore(&backup_dir, &database, None, true, &formatter()).unwrap();
+
+        let store = SqliteStore::new(&database, false, 0).unwrap();
+        assert!(store.get_claim(kept).unwrap().is_some());
+        assert!(store.get_claim(replaced).unwrap().is_none());
+    }
+}
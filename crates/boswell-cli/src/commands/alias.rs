@@ -0,0 +1,37 @@
+//! Entity alias command implementation.
+
+use crate::cli::{AliasAction, AliasArgs};
+use crate::error::Result;
+use crate::output::Formatter;
+use boswell_sdk::BoswellClient;
+
+/// Execute an entity alias command.
+pub async fn execute_alias(
+    args: AliasArgs,
+    client: &mut BoswellClient,
+    formatter: &Formatter,
+) -> Result<()> {
+    match args.action {
+        AliasAction::Merge { alias, canonical, reason } => {
+            let merged = client.merge_entities(&alias, &canonical, &reason).await?;
+            println!(
+                "{}",
+                formatter.success(&format!("Merged '{}' into '{}'", merged.alias, merged.canonical))
+            );
+        }
+        AliasAction::Unmerge { alias } => {
+            client.unmerge_entity(&alias).await?;
+            println!("{}", formatter.success(&format!("Unmerged '{}'", alias)));
+        }
+        AliasAction::List { all } => {
+            let aliases = client.list_aliases(all).await?;
+            println!("{}", formatter.format_aliases(&aliases)?);
+        }
+        AliasAction::Suggest { namespace, min_score, limit } => {
+            let proposals = client.propose_merges(namespace.as_deref(), min_score, limit).await?;
+            println!("{}", formatter.format_merge_proposals(&proposals)?);
+        }
+    }
+
+    Ok(())
+}
@@ -3,6 +3,7 @@
 use crate::cli::AssertArgs;
 use crate::error::{CliError, Result};
 use crate::output::Formatter;
+use boswell_domain::ConfidenceInterval;
 use boswell_sdk::BoswellClient;
 
 /// Execute the assert command.
@@ -17,16 +18,8 @@ pub async fn execute_assert(
     let (object_ns, object_val) = parse_entity_parts(&args.object)?;
 
     // Validate confidence
-    if args.confidence_lower > args.confidence_upper {
-        return Err(CliError::InvalidInput(
-            "Lower confidence must be <= upper confidence".to_string(),
-        ));
-    }
-    if args.confidence_lower < 0.0 || args.confidence_upper > 1.0 {
-        return Err(CliError::InvalidInput(
-            "Confidence values must be between 0.0 and 1.0".to_string(),
-        ));
-    }
+    ConfidenceInterval::try_new(args.confidence_lower, args.confidence_upper)
+        .map_err(|e| CliError::InvalidInput(format!("Invalid confidence: {}", e)))?;
 
     // Use the subject namespace as the overall namespace
     let namespace = &subject_ns;
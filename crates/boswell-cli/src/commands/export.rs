@@ -0,0 +1,57 @@
+//! Export command implementation.
+
+use crate::cli::{ExportArgs, RdfSyntax, ReificationArg};
+use crate::error::Result;
+use crate::output::Formatter;
+use boswell_sdk::{BoswellClient, ExportSummary, RdfFormat, Reification};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+/// Execute the export command.
+pub async fn execute_export(
+    args: ExportArgs,
+    client: &mut BoswellClient,
+    formatter: &Formatter,
+) -> Result<()> {
+    let summary = match &args.output {
+        Some(path) => write_export(&args, client, BufWriter::new(File::create(path)?)).await?,
+        None => write_export(&args, client, io::stdout().lock()).await?,
+    };
+
+    // Keep stdout clean when it carries the export
+    eprintln!(
+        "{}",
+        formatter.success(&format!(
+            "Exported {} claims and {} relationships from '{}'",
+            summary.claims, summary.relationships, args.namespace
+        ))
+    );
+
+    Ok(())
+}
+
+/// Write the export in the requested format.
+async fn write_export<W: Write>(
+    args: &ExportArgs,
+    client: &mut BoswellClient,
+    writer: W,
+) -> Result<ExportSummary> {
+    let Some(syntax) = args.rdf else {
+        return Ok(client.export(&args.namespace, writer).await?);
+    };
+
+    let format = match syntax {
+        RdfSyntax::NTriples => RdfFormat::NTriples,
+        RdfSyntax::Turtle => RdfFormat::Turtle,
+        RdfSyntax::JsonLd => RdfFormat::JsonLd,
+    };
+    let reification = match args.reification {
+        Some(ReificationArg::Star) => Reification::Star,
+        Some(ReificationArg::Statements) => Reification::Statements,
+        None => format.default_reification(),
+    };
+
+    Ok(client
+        .export_rdf(&args.namespace, format, reification, writer)
+        .await?)
+}
@@ -0,0 +1,56 @@
+//! Import command implementation.
+
+use crate::cli::ImportArgs;
+use crate::error::{CliError, Result};
+use crate::output::Formatter;
+use boswell_sdk::{BoswellClient, ImportOptions};
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+
+/// Execute the import command.
+pub async fn execute_import(
+    args: ImportArgs,
+    client: &mut BoswellClient,
+    formatter: &Formatter,
+) -> Result<()> {
+    if args.batch_size == 0 {
+        return Err(CliError::InvalidInput("Batch size must be at least 1".to_string()));
+    }
+
+    let options = ImportOptions {
+        use_gatekeeper: args.gatekeeper,
+        batch_size: args.batch_size,
+    };
+
+    let report = if args.file == Path::new("-") {
+        client.import(io::stdin().lock(), options).await?
+    } else {
+        let file = BufReader::new(File::open(&args.file)?);
+        client.import(file, options).await?
+    };
+
+    println!(
+        "{}",
+        formatter.success(&format!(
+            "Imported {} claims and {} relationships",
+            report.inserted + report.existing + report.corroborated + report.remapped,
+            report.relationships
+        ))
+    );
+    println!("  New: {}", report.inserted);
+    println!("  Already present: {}", report.existing);
+    println!("  Corroborated existing claims: {}", report.corroborated);
+    println!("  Given new IDs: {}", report.remapped);
+    if report.rejected > 0 {
+        println!("{}", formatter.warning(&format!("Rejected {} claims", report.rejected)));
+    }
+    for (original, stored) in &report.mappings {
+        println!("  {} -> {}", original, stored);
+    }
+    for error in &report.errors {
+        println!("{}", formatter.error(error));
+    }
+
+    Ok(())
+}
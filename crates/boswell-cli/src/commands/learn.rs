@@ -60,7 +60,7 @@ struct ClaimDefinition {
     #[serde(default = "default_confidence")]
     confidence: ConfidenceDef,
     #[serde(default)]
-    tier: Option<String>,
+    tier: Option<boswell_domain::Tier>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -73,16 +73,12 @@ struct ConfidenceDef {
 
 impl ClaimDefinition {
     fn to_claim(self, default_tier: boswell_domain::Tier) -> Result<boswell_domain::Claim> {
+        boswell_domain::ConfidenceInterval::try_new(self.confidence.lower, self.confidence.upper)
+            .map_err(|e| CliError::InvalidInput(format!("Invalid confidence for '{}': {}", self.subject, e)))?;
         let (subject_ns, subject_val) = parse_entity(&self.subject)?;
         let (predicate_ns, predicate_val) = parse_entity(&self.predicate)?;
         let (object_ns, object_val) = parse_entity(&self.object)?;
 
-        let tier_str = if let Some(tier_str) = self.tier {
-            tier_str
-        } else {
-            default_tier.as_str().to_string()
-        };
-
         let created_at = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -100,7 +96,7 @@ impl ClaimDefinition {
             predicate,
             object,
             confidence: (self.confidence.lower, self.confidence.upper),
-            tier: tier_str,
+            tier: self.tier.unwrap_or(default_tier),
             created_at,
             stale_at: None,
         })
@@ -160,4 +156,34 @@ mod tests {
         assert_eq!(def.confidence.lower, 0.5);
         assert_eq!(def.confidence.upper, 1.0);
     }
+
+    #[test]
+    fn test_claim_definition_tier() {
+        let json = r#"{"subject": "user:alice", "predicate": "likes:coffee", "object": "beverage:espresso", "tier": "Project"}"#;
+        let def: ClaimDefinition = serde_json::from_str(json).unwrap();
+        assert_eq!(def.to_claim(boswell_domain::Tier::Task).unwrap().tier, boswell_domain::Tier::Project);
+
+        let json = r#"{"subject": "user:alice", "predicate": "likes:coffee", "object": "beverage:espresso"}"#;
+        let def: ClaimDefinition = serde_json::from_str(json).unwrap();
+        assert_eq!(def.to_claim(boswell_domain::Tier::Task).unwrap().tier, boswell_domain::Tier::Task);
+
+        let json = r#"{"subject": "user:alice", "predicate": "likes:coffee", "object": "beverage:espresso", "tier": "session"}"#;
+        assert!(serde_json::from_str::<ClaimDefinition>(json).is_err());
+    }
+
+    #[test]
+    fn test_claim_definition_rejects_invalid_confidence() {
+        let json = r#"
+        {
+            "subject": "user:alice",
+            "predicate": "likes:coffee",
+            "object": "beverage:espresso",
+            "confidence": {"lower": 0.9, "upper": 0.4}
+        }
+        "#;
+
+        let def: ClaimDefinition = serde_json::from_str(json).unwrap();
+        let err = def.to_claim(boswell_domain::Tier::Task).unwrap_err();
+        assert!(matches!(err, CliError::InvalidInput(msg) if msg.contains("lower bound")));
+    }
 }
@@ -1,17 +1,27 @@
 //! Command implementations.
 
+pub mod admin;
+pub mod alias;
 pub mod assert;
 pub mod connect;
+pub mod export;
 pub mod forget;
+pub mod import;
 pub mod learn;
+pub mod ns;
 pub mod profile;
 pub mod query;
 pub mod search;
 
+pub use self::admin::execute_admin;
+pub use self::alias::execute_alias;
 pub use self::assert::execute_assert;
 pub use self::connect::execute_connect;
+pub use self::export::execute_export;
 pub use self::forget::execute_forget;
+pub use self::import::execute_import;
 pub use self::learn::execute_learn;
+pub use self::ns::execute_ns;
 pub use self::profile::execute_profile;
 pub use self::query::execute_query;
 pub use self::search::execute_search;
@@ -0,0 +1,84 @@
+//! Namespace management command implementation.
+
+use crate::cli::{NsAction, NsArgs};
+use crate::error::Result;
+use crate::output::Formatter;
+use boswell_domain::namespace::SEPARATOR;
+use boswell_domain::NamespaceStats;
+use boswell_sdk::BoswellClient;
+
+/// Execute a namespace management command.
+pub async fn execute_ns(
+    args: NsArgs,
+    client: &mut BoswellClient,
+    formatter: &Formatter,
+) -> Result<()> {
+    match args.action {
+        NsAction::Ls { root } => {
+            let root = root.unwrap_or_default();
+            let totals = subtree_totals(client, &root).await?;
+            println!("{}", formatter.format_namespaces(&children(&totals, &root))?);
+        }
+        NsAction::Tree { root } => {
+            let root = root.unwrap_or_default();
+            let totals = subtree_totals(client, &root).await?;
+            println!("{}", formatter.format_namespace_tree(&totals, &root)?);
+        }
+        NsAction::Stats { namespace } => {
+            let totals = subtree_totals(client, &namespace).await?;
+            let stats = totals
+                .into_iter()
+                .find(|stats| stats.namespace == namespace.trim_end_matches(SEPARATOR))
+                .unwrap_or_else(|| NamespaceStats::new(namespace));
+            println!("{}", formatter.format_namespace_stats(&stats)?);
+        }
+        NsAction::Mv { from, to } => {
+            let moved = client.move_namespace(&from, &to).await?;
+            println!(
+                "{}",
+                formatter.success(&format!("Moved {} claim(s) from '{}' to '{}'", moved, from, to))
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Totals for `root` and every namespace below it.
+async fn subtree_totals(client: &mut BoswellClient, root: &str) -> Result<Vec<NamespaceStats>> {
+    let namespaces = client.list_namespaces(root).await?;
+    Ok(NamespaceStats::rollup(&namespaces, root))
+}
+
+/// The entries exactly one level below `root` (top-level namespaces for an empty root).
+fn children(totals: &[NamespaceStats], root: &str) -> Vec<NamespaceStats> {
+    let root = root.trim_end_matches(SEPARATOR);
+    totals
+        .iter()
+        .filter(|stats| match stats.namespace.rsplit_once(SEPARATOR) {
+            Some((parent, _)) => parent == root,
+            None => root.is_empty(),
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_children() {
+        let totals: Vec<NamespaceStats> = ["acme", "acme/eng", "acme/eng/db", "acme/ops", "beta"]
+            .into_iter()
+            .map(NamespaceStats::new)
+            .collect();
+        let names = |root: &str| -> Vec<String> {
+            children(&totals, root).into_iter().map(|stats| stats.namespace).collect()
+        };
+
+        assert_eq!(names(""), vec!["acme", "beta"]);
+        assert_eq!(names("acme/"), vec!["acme/eng", "acme/ops"]);
+        assert!(names("acme/eng/db").is_empty());
+    }
+}
@@ -3,6 +3,7 @@
 use crate::cli::QueryArgs;
 use crate::error::{CliError, Result};
 use crate::output::Formatter;
+use boswell_domain::Entity;
 use boswell_sdk::{BoswellClient, QueryFilter};
 
 /// Execute the query command.
@@ -43,6 +44,14 @@ pub async fn execute_query(
         filter.min_confidence = Some(min_conf);
     }
 
+    // Apply literal bounds
+    for bound in [&args.after, &args.before].into_iter().flatten() {
+        Entity::parse_literal(bound).map_err(CliError::InvalidInput)?;
+    }
+    filter.object_after = args.after;
+    filter.object_before = args.before;
+    filter.expand_aliases = args.expand_aliases;
+
     // Execute query
     let claims = client.query(filter).await?;
 
@@ -20,6 +20,10 @@ pub enum CliError {
     #[error("SDK error: {0}")]
     Sdk(#[from] boswell_sdk::SdkError),
 
+    /// Store error
+    #[error("Store error: {0}")]
+    Store(#[from] boswell_store::StoreError),
+
     /// I/O error
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
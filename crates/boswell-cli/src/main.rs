@@ -53,6 +53,9 @@ async fn run() -> boswell_cli::Result<()> {
         Some(Command::Profile(args)) => {
             commands::execute_profile(args, &mut config, &formatter).await?;
         }
+        Some(Command::Admin(args)) => {
+            commands::execute_admin(args, &formatter).await?;
+        }
         Some(cmd) => {
             // Commands that require a connection
             let profile = config.get_active_profile()?;
@@ -75,6 +78,18 @@ async fn run() -> boswell_cli::Result<()> {
                 Command::Search(args) => {
                     commands::execute_search(args, &mut client, &formatter).await?;
                 }
+                Command::Export(args) => {
+                    commands::execute_export(args, &mut client, &formatter).await?;
+                }
+                Command::Import(args) => {
+                    commands::execute_import(args, &mut client, &formatter).await?;
+                }
+                Command::Ns(args) => {
+                    commands::execute_ns(args, &mut client, &formatter).await?;
+                }
+                Command::Alias(args) => {
+                    commands::execute_alias(args, &mut client, &formatter).await?;
+                }
                 _ => unreachable!(),
             }
         }
@@ -2,7 +2,8 @@
 
 use crate::config::OutputFormat;
 use crate::error::Result;
-use boswell_domain::{Claim, ClaimId, Tier};
+use boswell_domain::namespace::SEPARATOR;
+use boswell_domain::{Claim, ClaimId, EntityAlias, MergeProposal, NamespaceStats, Tier};
 use colored::*;
 use serde_json;
 use tabled::{
@@ -39,30 +40,9 @@ impl Formatter {
         self.format_claims(&[claim.clone()])
     }
 
-    /// Format claims as JSON.
+    /// Format claims as JSON, in the domain JSON representation.
     fn format_claims_json(&self, claims: &[Claim]) -> Result<String> {
-        // Create a serializable representation
-        let json_claims: Vec<serde_json::Value> = claims
-            .iter()
-            .map(|c| {
-                serde_json::json!({
-                    "id": c.id.to_string(),
-                    "namespace": c.namespace,
-                    "subject": c.subject,
-                    "predicate": c.predicate,
-                    "object": c.object,
-                    "confidence": {
-                        "lower": c.confidence.0,
-                        "upper": c.confidence.1
-                    },
-                    "tier": c.tier,
-                    "created_at": c.created_at,
-                    "stale_at": c.stale_at
-                })
-            })
-            .collect();
-
-        Ok(serde_json::to_string_pretty(&json_claims)?)
+        Ok(serde_json::to_string_pretty(claims)?)
     }
 
     /// Format claims as a table.
@@ -86,7 +66,7 @@ impl Formatter {
                 &claim.predicate,
                 &claim.object,
                 &confidence,
-                &claim.tier,
+                claim.tier.as_str(),
             ]);
         }
 
@@ -104,6 +84,189 @@ impl Formatter {
         Ok(ids.join("\n"))
     }
 
+    /// Format namespace statistics, one row per namespace.
+    pub fn format_namespaces(&self, namespaces: &[NamespaceStats]) -> Result<String> {
+        match self.format {
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(
+                &namespaces.iter().map(namespace_json).collect::<Vec<_>>(),
+            )?),
+            OutputFormat::Table => Ok(self.format_namespaces_table(namespaces)),
+            OutputFormat::Quiet => Ok(namespace_names(namespaces)),
+        }
+    }
+
+    /// Format namespaces as an indented tree below `root`.
+    ///
+    /// Expects subtree totals sorted parents first, as returned by
+    /// [`NamespaceStats::rollup`]. JSON and quiet output match
+    /// [`format_namespaces`](Self::format_namespaces).
+    pub fn format_namespace_tree(&self, namespaces: &[NamespaceStats], root: &str) -> Result<String> {
+        if !matches!(self.format, OutputFormat::Table) {
+            return self.format_namespaces(namespaces);
+        }
+        if namespaces.is_empty() {
+            return Ok(self.colorize("No namespaces found.", "yellow"));
+        }
+
+        let base_depth = root.split(SEPARATOR).filter(|segment| !segment.is_empty()).count();
+        let lines: Vec<String> = namespaces
+            .iter()
+            .map(|stats| {
+                let depth = stats.namespace.split(SEPARATOR).count();
+                let name = if depth <= base_depth.max(1) {
+                    stats.namespace.as_str()
+                } else {
+                    stats.namespace.rsplit(SEPARATOR).next().unwrap_or_default()
+                };
+                format!(
+                    "{}{} {}",
+                    "  ".repeat(depth.saturating_sub(base_depth.max(1))),
+                    name,
+                    self.colorize(&format!("({} claims)", stats.claims), "cyan")
+                )
+            })
+            .collect();
+
+        Ok(lines.join("\n"))
+    }
+
+    /// Format the statistics of one namespace subtree, broken down by tier.
+    pub fn format_namespace_stats(&self, stats: &NamespaceStats) -> Result<String> {
+        match self.format {
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(&namespace_json(stats))?),
+            OutputFormat::Quiet => Ok(stats.claims.to_string()),
+            OutputFormat::Table => {
+                let mut builder = Builder::default();
+                builder.push_record(["Namespace", &stats.namespace]);
+                builder.push_record(["Claims", &stats.claims.to_string()]);
+                for tier in Tier::ALL {
+                    builder.push_record([tier.as_str(), &stats.tier_count(tier).to_string()]);
+                }
+                builder.push_record(["Average confidence", &format_average(stats)]);
+                builder.push_record(["Contradictions", &stats.contradictions.to_string()]);
+                builder.push_record(["Last write", &stats.last_write.to_string()]);
+
+                let mut table = builder.build();
+                table.with(Style::rounded());
+                Ok(table.to_string())
+            }
+        }
+    }
+
+    /// Format namespace statistics as a table.
+    fn format_namespaces_table(&self, namespaces: &[NamespaceStats]) -> String {
+        if namespaces.is_empty() {
+            return self.colorize("No namespaces found.", "yellow");
+        }
+
+        let mut builder = Builder::default();
+        builder.push_record(["Namespace", "Claims", "Avg confidence", "Contradictions", "Last write"]);
+
+        for stats in namespaces {
+            builder.push_record([
+                stats.namespace.clone(),
+                stats.claims.to_string(),
+                format_average(stats),
+                stats.contradictions.to_string(),
+                stats.last_write.to_string(),
+            ]);
+        }
+
+        let mut table = builder.build();
+        table
+            .with(Style::rounded())
+            .with(Modify::new(Rows::first()).with(Alignment::center()));
+
+        table.to_string()
+    }
+
+    /// Format entity aliases, retracted ones marked with when they were undone.
+    pub fn format_aliases(&self, aliases: &[EntityAlias]) -> Result<String> {
+        match self.format {
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(
+                &aliases.iter().map(alias_json).collect::<Vec<_>>(),
+            )?),
+            OutputFormat::Quiet => Ok(aliases
+                .iter()
+                .map(|alias| format!("{} {}", alias.alias, alias.canonical))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            OutputFormat::Table => {
+                if aliases.is_empty() {
+                    return Ok(self.colorize("No aliases found.", "yellow"));
+                }
+
+                let mut builder = Builder::default();
+                builder.push_record(["Alias", "Canonical", "Reason", "Created", "Retracted"]);
+                for alias in aliases {
+                    builder.push_record([
+                        alias.alias.clone(),
+                        alias.canonical.clone(),
+                        alias.reason.clone(),
+                        alias.created_at.to_string(),
+                        alias.retracted_at.map_or_else(|| "-".to_string(), |at| at.to_string()),
+                    ]);
+                }
+
+                let mut table = builder.build();
+                table
+                    .with(Style::rounded())
+                    .with(Modify::new(Rows::first()).with(Alignment::center()));
+                Ok(table.to_string())
+            }
+        }
+    }
+
+    /// Format suggested entity merges, best first.
+    pub fn format_merge_proposals(&self, proposals: &[MergeProposal]) -> Result<String> {
+        match self.format {
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(
+                &proposals
+                    .iter()
+                    .map(|proposal| {
+                        serde_json::json!({
+                            "alias": proposal.alias,
+                            "canonical": proposal.canonical,
+                            "name_similarity": proposal.name_similarity,
+                            "embedding_similarity": proposal.embedding_similarity,
+                            "score": proposal.score
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            )?),
+            OutputFormat::Quiet => Ok(proposals
+                .iter()
+                .map(|proposal| format!("{} {}", proposal.alias, proposal.canonical))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            OutputFormat::Table => {
+                if proposals.is_empty() {
+                    return Ok(self.colorize("No merges to suggest.", "yellow"));
+                }
+
+                let mut builder = Builder::default();
+                builder.push_record(["Alias", "Canonical", "Score", "Name", "Embedding"]);
+                for proposal in proposals {
+                    builder.push_record([
+                        proposal.alias.clone(),
+                        proposal.canonical.clone(),
+                        format!("{:.2}", proposal.score),
+                        format!("{:.2}", proposal.name_similarity),
+                        proposal
+                            .embedding_similarity
+                            .map_or_else(|| "-".to_string(), |similarity| format!("{:.2}", similarity)),
+                    ]);
+                }
+
+                let mut table = builder.build();
+                table
+                    .with(Style::rounded())
+                    .with(Modify::new(Rows::first()).with(Alignment::center()));
+                Ok(table.to_string())
+            }
+        }
+    }
+
     /// Format a success message.
     pub fn success(&self, message: &str) -> String {
         self.colorize(&format!("✓ {}", message), "green")
@@ -161,15 +324,45 @@ impl Formatter {
     }
 }
 
-/// Format a tier enum value for display.
-pub fn format_tier(tier: &str) -> Option<Tier> {
-    match tier.to_lowercase().as_str() {
-        "ephemeral" => Some(Tier::Ephemeral),
-        "task" => Some(Tier::Task),
-        "project" => Some(Tier::Project),
-        "permanent" => Some(Tier::Permanent),
-        _ => None,
-    }
+/// JSON representation of namespace statistics.
+fn namespace_json(stats: &NamespaceStats) -> serde_json::Value {
+    let tiers: serde_json::Map<String, serde_json::Value> = Tier::ALL
+        .iter()
+        .map(|&tier| (tier.as_str().to_string(), stats.tier_count(tier).into()))
+        .collect();
+
+    serde_json::json!({
+        "namespace": stats.namespace,
+        "claims": stats.claims,
+        "tiers": tiers,
+        "average_confidence": stats.average_confidence(),
+        "contradictions": stats.contradictions,
+        "last_write": stats.last_write
+    })
+}
+
+/// JSON representation of an entity alias.
+fn alias_json(alias: &EntityAlias) -> serde_json::Value {
+    serde_json::json!({
+        "alias": alias.alias,
+        "canonical": alias.canonical,
+        "reason": alias.reason,
+        "created_at": alias.created_at,
+        "retracted_at": alias.retracted_at
+    })
+}
+
+/// Namespace names, one per line.
+fn namespace_names(namespaces: &[NamespaceStats]) -> String {
+    let names: Vec<&str> = namespaces.iter().map(|stats| stats.namespace.as_str()).collect();
+    names.join("\n")
+}
+
+/// Average confidence to two decimals, or "-" without claims.
+fn format_average(stats: &NamespaceStats) -> String {
+    stats
+        .average_confidence()
+        .map_or_else(|| "-".to_string(), |average| format!("{:.2}", average))
 }
 
 #[cfg(test)]
@@ -185,7 +378,7 @@ mod tests {
             predicate: "likes:coffee".to_string(),
             object: "beverage:espresso".to_string(),
             confidence: (0.8, 0.9),
-            tier: "task".to_string(),
+            tier: Tier::Task,
             created_at: 12345678,
             stale_at: None,
         }
@@ -198,6 +391,8 @@ mod tests {
         let output = formatter.format_claims(&claims).unwrap();
         assert!(output.contains("subject"));
         assert!(output.contains("predicate"));
+        let parsed: Vec<Claim> = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed, claims);
     }
 
     #[test]
@@ -234,11 +429,18 @@ mod tests {
     }
 
     #[test]
-    fn test_tier_parsing() {
-        assert!(matches!(format_tier("ephemeral"), Some(Tier::Ephemeral)));
-        assert!(matches!(format_tier("Task"), Some(Tier::Task)));
-        assert!(matches!(format_tier("Project"), Some(Tier::Project)));
-        assert!(matches!(format_tier("PERMANENT"), Some(Tier::Permanent)));
-        assert!(format_tier("invalid").is_none());
+    fn test_namespace_tree() {
+        let formatter = Formatter::new(OutputFormat::Table, false);
+        let namespaces = vec![
+            NamespaceStats { claims: 3, ..NamespaceStats::new("acme") },
+            NamespaceStats { claims: 2, ..NamespaceStats::new("acme/eng") },
+            NamespaceStats { claims: 1, ..NamespaceStats::new("acme/eng/db") },
+        ];
+
+        let output = formatter.format_namespace_tree(&namespaces, "").unwrap();
+        assert_eq!(output, "acme (3 claims)\n  eng (2 claims)\n    db (1 claims)");
+
+        let output = formatter.format_namespace_tree(&namespaces[1..], "acme/eng").unwrap();
+        assert_eq!(output, "acme/eng (2 claims)\n  db (1 claims)");
     }
 }
@@ -1,6 +1,9 @@
 //! Interactive REPL (Read-Eval-Print Loop) mode.
 
-use crate::cli::{AssertArgs, Command, ConnectArgs, ForgetArgs, LearnArgs, ProfileAction, ProfileArgs, QueryArgs, SearchArgs, TierArg};
+use crate::cli::{
+    AliasAction, AliasArgs, AssertArgs, Command, ConnectArgs, ForgetArgs, LearnArgs, NsAction, NsArgs, ProfileAction,
+    ProfileArgs, QueryArgs, SearchArgs, TierArg,
+};
 use crate::commands;
 use crate::config::Config;
 use crate::error::{CliError, Result};
@@ -107,6 +110,8 @@ fn parse_repl_command(line: &str) -> Result<ReplCommand> {
         "forget" => parse_forget_command(&parts[1..]),
         "search" => parse_search_command(&parts[1..]),
         "profile" => parse_profile_command(&parts[1..]),
+        "ns" => parse_ns_command(&parts[1..]),
+        "alias" => parse_alias_command(&parts[1..]),
         _ => Err(CliError::InvalidInput(format!(
             "Unknown command: {}. Type 'help' for available commands.",
             parts[0]
@@ -129,6 +134,9 @@ async fn execute_repl_command(
         Command::Profile(args) => {
             commands::execute_profile(args, config, formatter).await?;
         }
+        Command::Admin(args) => {
+            commands::execute_admin(args, formatter).await?;
+        }
         _ => {
             let client_ref = client.as_mut().ok_or(CliError::NotConnected)?;
             
@@ -148,6 +156,18 @@ async fn execute_repl_command(
                 Command::Search(args) => {
                     commands::execute_search(args, client_ref, formatter).await?;
                 }
+                Command::Export(args) => {
+                    commands::execute_export(args, client_ref, formatter).await?;
+                }
+                Command::Import(args) => {
+                    commands::execute_import(args, client_ref, formatter).await?;
+                }
+                Command::Ns(args) => {
+                    commands::execute_ns(args, client_ref, formatter).await?;
+                }
+                Command::Alias(args) => {
+                    commands::execute_alias(args, client_ref, formatter).await?;
+                }
                 _ => unreachable!(),
             }
         }
@@ -202,6 +222,9 @@ fn parse_query_command(args: &[&str]) -> Result<ReplCommand> {
         object: None,
         tier: None,
         min_confidence: None,
+        after: None,
+        before: None,
+        expand_aliases: false,
         limit: Some(20),
     })))
 }
@@ -267,14 +290,58 @@ fn parse_profile_command(args: &[&str]) -> Result<ReplCommand> {
     Ok(ReplCommand::Command(Command::Profile(ProfileArgs { action })))
 }
 
+fn parse_ns_command(args: &[&str]) -> Result<ReplCommand> {
+    let root = args.get(1).map(|root| root.to_string());
+    let action = match (args.first().copied(), args.len()) {
+        (None | Some("ls"), _) => NsAction::Ls { root },
+        (Some("tree"), _) => NsAction::Tree { root },
+        (Some("stats"), 2) => NsAction::Stats {
+            namespace: args[1].to_string(),
+        },
+        (Some("mv"), 3) => NsAction::Mv {
+            from: args[1].to_string(),
+            to: args[2].to_string(),
+        },
+        _ => {
+            return Err(CliError::InvalidInput(
+                "Usage: ns [ls|tree] [root] | ns stats <namespace> | ns mv <from> <to>".to_string(),
+            ))
+        }
+    };
+
+    Ok(ReplCommand::Command(Command::Ns(NsArgs { action })))
+}
+
+fn parse_alias_command(args: &[&str]) -> Result<ReplCommand> {
+    let action = match (args.first().copied(), args.len()) {
+        (None | Some("list"), _) => AliasAction::List {
+            all: args.get(1) == Some(&"all"),
+        },
+        (Some("merge"), n) if n >= 3 => AliasAction::Merge {
+            alias: args[1].to_string(),
+            canonical: args[2].to_string(),
+            reason: args[3..].join(" "),
+        },
+        (Some("unmerge"), 2) => AliasAction::Unmerge {
+            alias: args[1].to_string(),
+        },
+        (Some("suggest"), _) => AliasAction::Suggest {
+            namespace: args.get(1).map(|namespace| namespace.to_string()),
+            min_score: None,
+            limit: 20,
+        },
+        _ => {
+            return Err(CliError::InvalidInput(
+                "Usage: alias [list [all]] | alias merge <alias> <canonical> [reason] | alias unmerge <alias> | alias suggest [namespace]".to_string(),
+            ))
+        }
+    };
+
+    Ok(ReplCommand::Command(Command::Alias(AliasArgs { action })))
+}
+
 fn parse_tier_arg(s: &&str) -> Option<TierArg> {
-    match s.to_lowercase().as_str() {
-        "ephemeral" => Some(TierArg::Ephemeral),
-        "task" => Some(TierArg::Task),
-        "project" => Some(TierArg::Project),
-        "permanent" => Some(TierArg::Permanent),
-        _ => None,
-    }
+    boswell_domain::Tier::parse(s).map(TierArg::from)
 }
 
 fn get_history_path() -> Result<PathBuf> {
@@ -295,10 +362,17 @@ fn print_help(formatter: &Formatter) {
     println!("    l: confidence lower (default: 0.5)");
     println!("    u: confidence upper (default: 1.0)");
     println!("    t: tier (ephemeral|task|project|permanent, default: task)");
-    println!("  query [subject]                - Query claims");
+    println!("  query [subject]                - Query claims (kind:* for every entity of a kind)");
     println!("  learn <file>                   - Learn claims from JSON file");
     println!("  forget <id> [id2] [id3]        - Delete claims by ID");
     println!("  search <query>                 - Semantic search (not yet implemented)");
+    println!("  ns [ls|tree] [root]            - List namespaces with claim counts");
+    println!("  ns stats <namespace>           - Show statistics for a namespace");
+    println!("  ns mv <from> <to>              - Move a namespace subtree");
+    println!("  alias [list [all]]             - List entity aliases");
+    println!("  alias merge <a> <c> [reason]   - Record that entity a is the same as c");
+    println!("  alias unmerge <a>              - Undo the merge of an alias");
+    println!("  alias suggest [namespace]      - Suggest entity merges");
     println!("  profile [list|show|switch]     - Manage profiles");
     println!("  help, ?                        - Show this help");
     println!("  exit, quit, q                  - Exit REPL");
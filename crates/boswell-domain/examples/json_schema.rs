@@ -0,0 +1,10 @@
+//! Print the JSON Schema of the domain types' JSON representation
+//!
+//! ```text
+//! cargo run -p boswell-domain --features schema --example json_schema > crates/boswell-domain/schema/boswell-domain.v1.schema.json
+//! ```
+
+fn main() {
+    let schema = boswell_domain::json::json_schema();
+    println!("{}", serde_json::to_string_pretty(&schema).expect("a schema serializes to JSON"));
+}
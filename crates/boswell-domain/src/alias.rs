@@ -0,0 +1,351 @@
+//! Alias module - one entity known by several identifiers
+//!
+//! Extraction can name one person `person:john_doe`, `person:john` and
+//! `person:j_doe`. An [`EntityAlias`] records that an alias names the same
+//! entity as a canonical identifier, in the spirit of `owl:sameAs`. Stores
+//! never delete alias records: undoing a merge stamps `retracted_at`, so every
+//! merge and unmerge stays auditable.
+//!
+//! Active aliases form a forest. Each alias points at one canonical entity,
+//! which may itself be an alias of another; cycles are refused. [`AliasGraph`]
+//! resolves an entity to the root of its tree and expands it to every
+//! equivalent identifier, which is what query-time alias expansion runs on.
+//! [`name_similarity`] scores how likely two identifiers of one kind are to
+//! name the same thing; resolvers turn such scores into [`MergeProposal`]s.
+
+use crate::Entity;
+use std::collections::{BTreeSet, HashMap};
+
+/// A record that `alias` names the same entity as `canonical`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityAlias {
+    /// The merged entity, normalized (`person:john`)
+    pub alias: String,
+
+    /// The entity it is the same as, normalized (`person:john_doe`)
+    pub canonical: String,
+
+    /// Why the entities were merged (a person, a resolver run)
+    pub reason: String,
+
+    /// When the merge was recorded (Unix seconds)
+    pub created_at: u64,
+
+    /// When the merge was undone, if it has been
+    pub retracted_at: Option<u64>,
+}
+
+impl EntityAlias {
+    /// Record that `alias` names the same entity as `canonical`
+    ///
+    /// Both are stored in their normalized [`Entity`] form.
+    ///
+    /// # Errors
+    /// Returns a description of the problem if either does not parse, they
+    /// are the same entity, their kinds differ, or they are literals
+    pub fn new(alias: &str, canonical: &str, reason: impl Into<String>, created_at: u64) -> Result<Self, String> {
+        let alias_entity = Entity::parse(alias)?;
+        let canonical_entity = Entity::parse(canonical)?;
+
+        if alias_entity.is_literal() || canonical_entity.is_literal() {
+            return Err(format!("'{}' and '{}' are literals, which cannot be aliased", alias, canonical));
+        }
+        if alias_entity.kind != canonical_entity.kind {
+            return Err(format!(
+                "'{}' and '{}' are different kinds of entity ({} and {})",
+                alias, canonical, alias_entity.kind, canonical_entity.kind
+            ));
+        }
+        if alias_entity == canonical_entity {
+            return Err(format!("'{}' and '{}' are the same entity", alias, canonical));
+        }
+
+        Ok(Self {
+            alias: alias_entity.to_string(),
+            canonical: canonical_entity.to_string(),
+            reason: reason.into(),
+            created_at,
+            retracted_at: None,
+        })
+    }
+
+    /// Whether the merge is still in effect
+    pub fn is_active(&self) -> bool {
+        self.retracted_at.is_none()
+    }
+}
+
+/// The normalized form of a subject or object, for comparing against aliases
+///
+/// Values that do not parse as an [`Entity`] are only trimmed.
+pub fn normalize(value: &str) -> String {
+    Entity::parse(value).map_or_else(|_| value.trim().to_string(), |entity| entity.to_string())
+}
+
+/// The active aliases of a store, for resolving entities
+#[derive(Debug, Clone, Default)]
+pub struct AliasGraph {
+    /// Alias to the entity it points at
+    canonical_of: HashMap<String, String>,
+
+    /// Entity to the aliases pointing at it
+    aliases_of: HashMap<String, Vec<String>>,
+}
+
+impl AliasGraph {
+    /// Graph of the active records in `aliases`; retracted records are ignored
+    pub fn new(aliases: &[EntityAlias]) -> Self {
+        let mut graph = Self::default();
+        for alias in aliases.iter().filter(|alias| alias.is_active()) {
+            graph.insert(alias);
+        }
+        graph
+    }
+
+    /// Whether the graph holds no aliases
+    pub fn is_empty(&self) -> bool {
+        self.canonical_of.is_empty()
+    }
+
+    /// The canonical entity `entity` resolves to, normalized
+    ///
+    /// Follows aliases of aliases to the root; an entity without an alias
+    /// resolves to itself.
+    pub fn resolve(&self, entity: &str) -> String {
+        let mut current = normalize(entity);
+        while let Some(next) = self.canonical_of.get(&current) {
+            current = next.clone();
+        }
+        current
+    }
+
+    /// Every identifier of the entity `entity` names, itself included, sorted
+    pub fn equivalents(&self, entity: &str) -> Vec<String> {
+        let root = self.resolve(entity);
+        let mut found = BTreeSet::new();
+        let mut pending = vec![root];
+        while let Some(current) = pending.pop() {
+            if let Some(aliases) = self.aliases_of.get(&current) {
+                pending.extend(aliases.iter().cloned());
+            }
+            found.insert(current);
+        }
+        found.into_iter().collect()
+    }
+
+    /// Whether `a` and `b` name the same entity
+    pub fn same_entity(&self, a: &str, b: &str) -> bool {
+        self.resolve(a) == self.resolve(b)
+    }
+
+    /// Check that `alias` can be added to the active aliases
+    ///
+    /// # Errors
+    /// Returns a description of the problem if the alias already points at
+    /// an entity or the merge would make the canonical entity an alias of
+    /// itself
+    pub fn check_add(&self, alias: &EntityAlias) -> Result<(), String> {
+        if let Some(existing) = self.canonical_of.get(&alias.alias) {
+            return Err(format!("'{}' is already an alias of '{}'", alias.alias, existing));
+        }
+        if self.resolve(&alias.canonical) == alias.alias {
+            return Err(format!("'{}' is already an alias of '{}'", alias.canonical, alias.alias));
+        }
+        Ok(())
+    }
+
+    /// Add an active alias that passed [`check_add`](Self::check_add)
+    pub fn insert(&mut self, alias: &EntityAlias) {
+        self.canonical_of.insert(alias.alias.clone(), alias.canonical.clone());
+        self.aliases_of.entry(alias.canonical.clone()).or_default().push(alias.alias.clone());
+    }
+}
+
+/// A suggested merge found by a resolver
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeProposal {
+    /// Entity to merge
+    pub alias: String,
+
+    /// Entity to merge it into
+    pub canonical: String,
+
+    /// Similarity of the identifiers (see [`name_similarity`])
+    pub name_similarity: f64,
+
+    /// Cosine similarity of the identifiers' embeddings, when a model was available
+    pub embedding_similarity: Option<f64>,
+
+    /// Combined score in [0, 1] the proposal was ranked by
+    pub score: f64,
+}
+
+/// How likely two identifiers are to name the same entity, in [0, 1]
+///
+/// Entities of different kinds, and literals, score 0. Otherwise this is the
+/// larger of the Jaro-Winkler similarity of the identifiers and their token
+/// overlap, where identifiers are split on `_`, `-` and `.` and a one-letter
+/// token matches a token it is the initial of (`j_doe` and `john_doe`).
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let (Ok(a), Ok(b)) = (Entity::parse(a), Entity::parse(b)) else {
+        return 0.0;
+    };
+    if a.kind != b.kind || a.is_literal() || b.is_literal() {
+        return 0.0;
+    }
+    if a.identifier == b.identifier {
+        return 1.0;
+    }
+
+    jaro_winkler(&a.identifier, &b.identifier).max(token_overlap(&a.identifier, &b.identifier))
+}
+
+/// Weight of a one-letter token matching the initial of a longer token
+const INITIAL_MATCH: f64 = 0.8;
+
+/// Dice coefficient over tokens, counting initials as partial matches
+fn token_overlap(a: &str, b: &str) -> f64 {
+    let split = |value: &str| -> Vec<String> {
+        value.split(['_', '-', '.']).filter(|token| !token.is_empty()).map(str::to_string).collect()
+    };
+    let (a, mut b) = (split(a), split(b));
+    let total = a.len() + b.len();
+    if total == 0 {
+        return 0.0;
+    }
+
+    let mut matched = 0.0;
+    for token in &a {
+        let best = b.iter().enumerate()
+            .map(|(index, other)| (index, token_match(token, other)))
+            .filter(|(_, weight)| *weight > 0.0)
+            .max_by(|x, y| x.1.total_cmp(&y.1));
+        if let Some((index, weight)) = best {
+            matched += weight;
+            b.remove(index);
+        }
+    }
+
+    2.0 * matched / total as f64
+}
+
+fn token_match(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let initial_of = |short: &str, long: &str| short.chars().count() == 1 && long.starts_with(short);
+    if initial_of(a, b) || initial_of(b, a) {
+        INITIAL_MATCH
+    } else {
+        0.0
+    }
+}
+
+/// Jaro-Winkler similarity with the usual prefix scale of 0.1 over at most four characters
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0usize;
+    for (i, c) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *c {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_order = a.iter().zip(&a_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let b_order = b.iter().zip(&b_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let transpositions = a_order.zip(b_order).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+    let prefix = a.iter().zip(&b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alias(alias: &str, canonical: &str) -> EntityAlias {
+        EntityAlias::new(alias, canonical, "test", 1).unwrap()
+    }
+
+    #[test]
+    fn test_new_normalizes_and_validates() {
+        let merged = alias("Person:John", "person:John Doe");
+        assert_eq!(merged.alias, "person:john");
+        assert_eq!(merged.canonical, "person:john_doe");
+        assert!(merged.is_active());
+
+        assert!(EntityAlias::new("person:john", "company:john", "", 1).is_err());
+        assert!(EntityAlias::new("person:john", "Person:John", "", 1).is_err());
+        assert!(EntityAlias::new("date:2025", "date:2025-01", "", 1).is_err());
+        assert!(EntityAlias::new("john", "person:john", "", 1).is_err());
+    }
+
+    #[test]
+    fn test_resolve_and_expand() {
+        let mut retracted = alias("person:jd", "person:john_doe");
+        retracted.retracted_at = Some(2);
+        let graph = AliasGraph::new(&[
+            alias("person:john", "person:john_doe"),
+            alias("person:j_doe", "person:john"),
+            retracted,
+        ]);
+
+        assert_eq!(graph.resolve("Person:J Doe"), "person:john_doe");
+        assert_eq!(graph.resolve("person:jd"), "person:jd");
+        assert_eq!(graph.resolve("person:alice"), "person:alice");
+        assert_eq!(
+            graph.equivalents("person:john"),
+            vec!["person:j_doe", "person:john", "person:john_doe"]
+        );
+        assert_eq!(graph.equivalents("person:alice"), vec!["person:alice"]);
+        assert!(graph.same_entity("person:j_doe", "person:john_doe"));
+        assert!(!graph.same_entity("person:jd", "person:john_doe"));
+    }
+
+    #[test]
+    fn test_check_add_refuses_reassignment_and_cycles() {
+        let graph = AliasGraph::new(&[
+            alias("person:john", "person:john_doe"),
+            alias("person:j_doe", "person:john"),
+        ]);
+
+        assert!(graph.check_add(&alias("person:john", "person:jd")).is_err());
+        assert!(graph.check_add(&alias("person:john_doe", "person:j_doe")).is_err());
+        assert!(graph.check_add(&alias("person:jd", "person:j_doe")).is_ok());
+        assert!(graph.check_add(&alias("person:john_doe", "person:jonathan_doe")).is_ok());
+    }
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(name_similarity("person:john_doe", "Person:John Doe"), 1.0);
+        assert_eq!(name_similarity("person:john_doe", "company:john_doe"), 0.0);
+        assert_eq!(name_similarity("number:1", "number:1.0"), 0.0);
+
+        let initials = name_similarity("person:j_doe", "person:john_doe");
+        let prefix = name_similarity("person:john", "person:john_doe");
+        let unrelated = name_similarity("person:alice", "person:john_doe");
+        assert!(initials > 0.85, "{}", initials);
+        assert!(prefix > 0.85, "{}", prefix);
+        assert!(unrelated < 0.6, "{}", unrelated);
+    }
+}
@@ -1,5 +1,6 @@
 //! Claim module - the fundamental unit of Boswell's memory system
 
+use crate::{Entity, Tier};
 use std::fmt;
 
 /// Unique identifier for a claim based on UUIDv7 (per ADR-011)
@@ -76,11 +77,44 @@ impl fmt::Display for ClaimId {
     }
 }
 
+/// Serialized as its hyphenated UUID string, as written by `Display`
+#[cfg(feature = "serde")]
+impl serde::Serialize for ClaimId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ClaimId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Self::from_string(&id).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for ClaimId {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ClaimId".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Claim identifier, a UUIDv7 string",
+            "type": "string",
+            "format": "uuid",
+        })
+    }
+}
+
 /// A claim - the fundamental unit of knowledge in Boswell
 ///
 /// Per ADR-001, everything is a claim with confidence, not a fact.
 /// Claims are immutable once created; updates create new claims.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Claim {
     /// Unique identifier
     pub id: ClaimId,
@@ -98,15 +132,18 @@ pub struct Claim {
     pub object: String,
     
     /// Confidence interval [lower, upper] (per ADR-003)
+    #[cfg_attr(feature = "serde", serde(with = "crate::confidence::serde_pair"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::ConfidenceInterval"))]
     pub confidence: (f64, f64),
     
-    /// Current tier (ephemeral, task, project, permanent)
-    pub tier: String,
+    /// Current tier
+    pub tier: Tier,
     
-    /// When this claim was created (timestamp)
+    /// When this claim was created (Unix seconds)
     pub created_at: u64,
     
-    /// When this claim should be considered stale
+    /// When this claim should be considered stale (Unix seconds)
+    #[cfg_attr(feature = "serde", serde(default))]
     pub stale_at: Option<u64>,
 }
 
@@ -120,7 +157,7 @@ impl Claim {
         predicate: String,
         object: String,
         confidence: (f64, f64),
-        tier: String,
+        tier: Tier,
         created_at: u64,
     ) -> Self {
         Self {
@@ -135,6 +172,88 @@ impl Claim {
             stale_at: None,
         }
     }
+
+    /// Normalized content hash of this claim's (namespace, subject, predicate, object)
+    ///
+    /// Two claims with the same hash are exact duplicates of each other.
+    /// See [`Claim::hash_content`] for the normalization rules.
+    pub fn content_hash(&self) -> String {
+        Self::hash_content(&self.namespace, &self.subject, &self.predicate, &self.object)
+    }
+
+    /// Compute the normalized content hash of a (namespace, subject, predicate, object) tuple
+    ///
+    /// Each component is trimmed, has internal whitespace collapsed to a single
+    /// space and is lowercased before hashing, so `"Alice  Smith"` and
+    /// `"alice smith"` hash identically. The hash is 128-bit FNV-1a rendered as
+    /// 32 lowercase hex characters; it is stable across platforms and releases
+    /// and is safe to persist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boswell_domain::Claim;
+    ///
+    /// let a = Claim::hash_content("project", "person:Alice", "knows", "person:Bob");
+    /// let b = Claim::hash_content("project", " person:alice ", "KNOWS", "person:bob");
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.len(), 32);
+    /// ```
+    pub fn hash_content(namespace: &str, subject: &str, predicate: &str, object: &str) -> String {
+        const FNV_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+        const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+        // ASCII unit separator keeps ("a b", "c") distinct from ("a", "b c")
+        const SEPARATOR: char = '\u{1f}';
+
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut feed = |c: char| {
+            let mut buf = [0u8; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                hash ^= byte as u128;
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        };
+
+        for (i, component) in [namespace, subject, predicate, object].iter().enumerate() {
+            if i > 0 {
+                feed(SEPARATOR);
+            }
+            for (j, word) in component.split_whitespace().enumerate() {
+                if j > 0 {
+                    feed(' ');
+                }
+                word.chars().flat_map(char::to_lowercase).for_each(&mut feed);
+            }
+        }
+
+        format!("{:032x}", hash)
+    }
+
+    /// Whether `other` states the same fact as this claim, up to wording
+    ///
+    /// Namespaces must be equal, predicates equal ignoring case and
+    /// surrounding whitespace, and subjects and objects equal once
+    /// normalized: entities in canonical form, anything else lowercased with
+    /// whitespace collapsed. Literal objects match when their values do, so
+    /// `number:3` and `number:3.0` agree. Semantic deduplication uses this to
+    /// keep embeddings that are merely close from merging different facts.
+    pub fn restates(&self, other: &Claim) -> bool {
+        self.namespace == other.namespace
+            && self.predicate.trim().eq_ignore_ascii_case(other.predicate.trim())
+            && normalize_term(&self.subject) == normalize_term(&other.subject)
+            && match (Entity::parse_literal(&self.object), Entity::parse_literal(&other.object)) {
+                (Ok(a), Ok(b)) => a == b,
+                _ => normalize_term(&self.object) == normalize_term(&other.object),
+            }
+    }
+}
+
+/// Canonical form of an entity, or `value` lowercased with whitespace collapsed
+fn normalize_term(value: &str) -> String {
+    Entity::parse(value).map_or_else(
+        |_| value.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase(),
+        |entity| entity.to_string(),
+    )
 }
 
 #[cfg(test)]
@@ -174,6 +293,36 @@ mod tests {
         assert_eq!(id, parsed);
     }
 
+    #[test]
+    fn test_content_hash_normalization() {
+        let hash = Claim::hash_content("ns", "person:Alice  Smith", "knows", "person:bob");
+
+        assert_eq!(hash, Claim::hash_content(" ns", "person:alice smith", "KNOWS", "person:Bob\t"));
+        assert_ne!(hash, Claim::hash_content("other", "person:alice smith", "knows", "person:bob"));
+        assert_ne!(hash, Claim::hash_content("ns", "person:bob", "knows", "person:alice smith"));
+
+        // Component boundaries are significant
+        assert_ne!(
+            Claim::hash_content("ns", "a b", "c", "d"),
+            Claim::hash_content("ns", "a", "b c", "d")
+        );
+    }
+
+    #[test]
+    fn test_restates() {
+        let claim = |subject: &str, predicate: &str, object: &str| {
+            Claim::new(ClaimId::new(), "ns".to_string(), subject.to_string(), predicate.to_string(), object.to_string(), (0.8, 0.9), Tier::Task, 0)
+        };
+        let fact = claim("person:Alice Smith", "weighs", "measurement:70kg");
+
+        assert!(fact.restates(&claim("person:alice_smith", " WEIGHS", "measurement:70.0kg")));
+        assert!(claim("Alice  Smith", "knows", "Bob").restates(&claim("alice smith", "knows", "bob")));
+        assert!(!fact.restates(&claim("person:bob", "weighs", "measurement:70kg")));
+        assert!(!fact.restates(&claim("person:alice_smith", "weighs", "measurement:71kg")));
+        assert!(!fact.restates(&claim("person:alice_smith", "height", "measurement:70kg")));
+        assert!(!fact.restates(&Claim { namespace: "other".to_string(), ..fact.clone() }));
+    }
+
     #[test]
     fn test_claim_id_invalid_string() {
         assert!(ClaimId::from_string("not-a-valid-uuid").is_err());
@@ -1,29 +1,101 @@
 //! Confidence interval module (per ADR-003)
 
+use std::fmt;
+
+/// Why a confidence interval or other unit value was refused
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConfidenceError {
+    /// The value is NaN or infinite
+    NotFinite {
+        /// What the value is (`lower bound`, `strength`)
+        name: &'static str,
+        /// The value given
+        value: f64,
+    },
+
+    /// The value lies outside [0, 1]
+    OutOfRange {
+        /// What the value is (`lower bound`, `strength`)
+        name: &'static str,
+        /// The value given
+        value: f64,
+    },
+
+    /// The lower bound exceeds the upper bound
+    Inverted {
+        /// Lower bound given
+        lower: f64,
+        /// Upper bound given
+        upper: f64,
+    },
+}
+
+impl fmt::Display for ConfidenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFinite { name, value } => write!(f, "{} must be a finite number, got {}", name, value),
+            Self::OutOfRange { name, value } => write!(f, "{} must be in [0, 1], got {}", name, value),
+            Self::Inverted { lower, upper } => {
+                write!(f, "lower bound {} must be <= upper bound {}", lower, upper)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfidenceError {}
+
+/// Check that `value`, described as `name`, is a finite number in [0, 1]
+pub fn check_unit(name: &'static str, value: f64) -> Result<f64, ConfidenceError> {
+    if !value.is_finite() {
+        return Err(ConfidenceError::NotFinite { name, value });
+    }
+    if !(0.0..=1.0).contains(&value) {
+        return Err(ConfidenceError::OutOfRange { name, value });
+    }
+    Ok(value)
+}
+
 /// Confidence interval representing [lower, upper] bounds
 /// 
 /// Per ADR-003, we use intervals to capture both:
 /// - The confidence level itself
 /// - Certainty about that confidence level
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(description = "Confidence interval; lower <= upper"))]
 pub struct ConfidenceInterval {
     /// Lower bound [0.0, 1.0]
+    #[cfg_attr(feature = "schema", schemars(range(min = 0.0, max = 1.0)))]
     pub lower: f64,
     /// Upper bound [0.0, 1.0]
+    #[cfg_attr(feature = "schema", schemars(range(min = 0.0, max = 1.0)))]
     pub upper: f64,
 }
 
 impl ConfidenceInterval {
-    /// Create a new confidence interval
+    /// Create a new confidence interval from bounds known to be valid
+    /// 
+    /// Use [`try_new`](Self::try_new) for bounds from outside the process.
     /// 
     /// # Panics
-    /// Panics if bounds are invalid (lower > upper or out of [0, 1])
+    /// Panics if bounds are invalid (not finite, out of [0, 1] or lower > upper)
     pub fn new(lower: f64, upper: f64) -> Self {
-        assert!((0.0..=1.0).contains(&lower), "Lower bound must be in [0, 1]");
-        assert!((0.0..=1.0).contains(&upper), "Upper bound must be in [0, 1]");
-        assert!(lower <= upper, "Lower bound must be <= upper bound");
+        Self::try_new(lower, upper).unwrap_or_else(|e| panic!("Invalid confidence interval: {}", e))
+    }
+
+    /// Create a new confidence interval, checking its bounds
+    /// 
+    /// # Errors
+    /// Returns a [`ConfidenceError`] if a bound is NaN, infinite or outside
+    /// [0, 1], or the lower bound exceeds the upper bound
+    pub fn try_new(lower: f64, upper: f64) -> Result<Self, ConfidenceError> {
+        let lower = check_unit("lower bound", lower)?;
+        let upper = check_unit("upper bound", upper)?;
+        if lower > upper {
+            return Err(ConfidenceError::Inverted { lower, upper });
+        }
         
-        Self { lower, upper }
+        Ok(Self { lower, upper })
     }
 
     /// Get the midpoint of the interval
@@ -40,6 +112,112 @@ impl ConfidenceInterval {
     pub fn contains(&self, value: f64) -> bool {
         value >= self.lower && value <= self.upper
     }
+
+    /// Confidence that this claim and an independent `other` both hold
+    ///
+    /// Bounds multiply, so the result is never above either input.
+    pub fn and(&self, other: &Self) -> Self {
+        Self::from_bounds(self.lower * other.lower, self.upper * other.upper)
+    }
+
+    /// Confidence that at least one of this claim and an independent `other` holds
+    ///
+    /// The noisy-or of the bounds, so the result is never below either input.
+    pub fn or(&self, other: &Self) -> Self {
+        Self::from_bounds(
+            1.0 - (1.0 - self.lower) * (1.0 - other.lower),
+            1.0 - (1.0 - self.upper) * (1.0 - other.upper),
+        )
+    }
+
+    /// Confidence that the claim does not hold
+    pub fn complement(&self) -> Self {
+        Self::from_bounds(1.0 - self.upper, 1.0 - self.lower)
+    }
+
+    /// The values both intervals allow, if they overlap
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let lower = self.lower.max(other.lower);
+        let upper = self.upper.min(other.upper);
+        (lower <= upper).then_some(Self { lower, upper })
+    }
+
+    /// The narrowest interval containing both
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            lower: self.lower.min(other.lower),
+            upper: self.upper.max(other.upper),
+        }
+    }
+
+    /// Update this prior with an independent observation of the same claim
+    ///
+    /// Each bound combines by Bayes' rule, treating the observation as the
+    /// probability the evidence gives the claim: `p·q / (p·q + (1-p)(1-q))`.
+    /// An observation of `[0.5, 0.5]` carries no evidence and leaves the
+    /// prior unchanged. Where a certain prior meets a certain observation to
+    /// the contrary the rule is undefined, and the bound keeps the prior's.
+    pub fn update(&self, observation: &Self) -> Self {
+        let posterior = |p: f64, q: f64| {
+            let agree = p * q;
+            let evidence = agree + (1.0 - p) * (1.0 - q);
+            if evidence > 0.0 { agree / evidence } else { p }
+        };
+        Self::from_bounds(posterior(self.lower, observation.lower), posterior(self.upper, observation.upper))
+    }
+
+    /// Discount this confidence by the trust placed in its source
+    ///
+    /// As in subjective logic, distrust becomes uncertainty rather than
+    /// disbelief: the lower bound scales by `trust` and the interval widens
+    /// towards `[0, 1]`, which is what a source with no trust asserts.
+    ///
+    /// # Errors
+    /// Returns a [`ConfidenceError`] if `trust` is not a finite number in [0, 1]
+    pub fn discount(&self, trust: f64) -> Result<Self, ConfidenceError> {
+        let trust = check_unit("trust", trust)?;
+        Ok(Self::from_bounds(self.lower * trust, self.upper * trust + (1.0 - trust)))
+    }
+
+    /// An interval from bounds computed from valid intervals, absorbing rounding
+    fn from_bounds(lower: f64, upper: f64) -> Self {
+        let upper = upper.clamp(0.0, 1.0);
+        Self {
+            lower: lower.clamp(0.0, upper),
+            upper,
+        }
+    }
+}
+
+/// Deserialized from `{"lower", "upper"}`, refusing bounds [`try_new`](ConfidenceInterval::try_new) refuses
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ConfidenceInterval {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Bounds {
+            lower: f64,
+            upper: f64,
+        }
+
+        let Bounds { lower, upper } = Bounds::deserialize(deserializer)?;
+        Self::try_new(lower, upper).map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De)serialize a `(lower, upper)` pair as a [`ConfidenceInterval`]
+#[cfg(feature = "serde")]
+pub(crate) mod serde_pair {
+    use super::ConfidenceInterval;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(&(lower, upper): &(f64, f64), serializer: S) -> Result<S::Ok, S::Error> {
+        ConfidenceInterval { lower, upper }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(f64, f64), D::Error> {
+        let interval = ConfidenceInterval::deserialize(deserializer)?;
+        Ok((interval.lower, interval.upper))
+    }
 }
 
 #[cfg(test)]
@@ -70,6 +248,55 @@ mod tests {
     fn test_invalid_bounds() {
         ConfidenceInterval::new(0.9, 0.5); // Lower > upper
     }
+
+    #[test]
+    fn test_interval_arithmetic() {
+        let a = ConfidenceInterval::new(0.6, 0.8);
+        let b = ConfidenceInterval::new(0.5, 0.9);
+        let close = |x: ConfidenceInterval, lower: f64, upper: f64| {
+            (x.lower - lower).abs() < 1e-9 && (x.upper - upper).abs() < 1e-9
+        };
+
+        assert!(close(a.and(&b), 0.3, 0.72));
+        assert!(close(a.or(&b), 0.8, 0.98));
+        assert!(close(a.complement(), 0.2, 0.4));
+        assert_eq!(a.intersect(&b), Some(ConfidenceInterval::new(0.6, 0.8)));
+        assert_eq!(a.intersect(&ConfidenceInterval::new(0.9, 1.0)), None);
+        assert_eq!(a.hull(&b), ConfidenceInterval::new(0.5, 0.9));
+        // 0.6 * 0.5 / (0.6 * 0.5 + 0.4 * 0.5) = 0.6; 0.72 / (0.72 + 0.02) ≈ 0.973
+        assert!(close(a.update(&b), 0.6, 0.72 / 0.74));
+        assert!(close(a.discount(0.5).unwrap(), 0.3, 0.9));
+        assert!(matches!(a.discount(f64::NAN), Err(ConfidenceError::NotFinite { name: "trust", .. })));
+
+        // A certain prior is not moved by a contrary certain observation
+        let certain = ConfidenceInterval::new(1.0, 1.0);
+        assert_eq!(certain.update(&ConfidenceInterval::new(0.0, 0.0)), certain);
+    }
+
+    #[test]
+    fn test_try_new_rejects_invalid_bounds() {
+        assert_eq!(ConfidenceInterval::try_new(0.7, 0.9), Ok(ConfidenceInterval::new(0.7, 0.9)));
+        assert_eq!(
+            ConfidenceInterval::try_new(0.9, 0.5),
+            Err(ConfidenceError::Inverted { lower: 0.9, upper: 0.5 })
+        );
+        assert!(matches!(
+            ConfidenceInterval::try_new(f64::NAN, 0.5),
+            Err(ConfidenceError::NotFinite { name: "lower bound", .. })
+        ));
+        assert!(matches!(
+            ConfidenceInterval::try_new(0.5, f64::INFINITY),
+            Err(ConfidenceError::NotFinite { name: "upper bound", .. })
+        ));
+        assert!(matches!(
+            ConfidenceInterval::try_new(-0.1, 0.5),
+            Err(ConfidenceError::OutOfRange { name: "lower bound", .. })
+        ));
+        assert_eq!(
+            ConfidenceInterval::try_new(0.5, 1.5).unwrap_err().to_string(),
+            "upper bound must be in [0, 1], got 1.5"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +304,10 @@ mod proptests {
     use super::*;
     use proptest::prelude::*;
 
+    fn interval() -> impl Strategy<Value = ConfidenceInterval> {
+        (0.0..=1.0f64, 0.0..=1.0f64).prop_map(|(a, b)| ConfidenceInterval::new(a.min(b), a.max(b)))
+    }
+
     proptest! {
         /// Property: Confidence interval always satisfies lower <= upper
         #[test]
@@ -87,6 +318,91 @@ mod proptests {
             }
         }
 
+        /// Property: try_new accepts exactly the valid bounds, including NaN and infinities
+        #[test]
+        fn test_try_new_validity(
+            lower in prop_oneof![-2.0..2.0f64, Just(f64::NAN), Just(f64::INFINITY), Just(f64::NEG_INFINITY)],
+            upper in prop_oneof![-2.0..2.0f64, Just(f64::NAN), Just(f64::INFINITY), Just(f64::NEG_INFINITY)],
+        ) {
+            let valid = (0.0..=1.0).contains(&lower) && (0.0..=1.0).contains(&upper) && lower <= upper;
+            prop_assert_eq!(ConfidenceInterval::try_new(lower, upper).is_ok(), valid);
+        }
+
+        /// Property: Every operation yields a valid interval
+        #[test]
+        fn test_operations_are_closed(a in interval(), b in interval(), trust in 0.0..=1.0f64) {
+            let mut results = vec![a.and(&b), a.or(&b), a.complement(), a.hull(&b), a.update(&b), a.discount(trust).unwrap()];
+            results.extend(a.intersect(&b));
+            for result in results {
+                prop_assert!(ConfidenceInterval::try_new(result.lower, result.upper).is_ok(), "{:?}", result);
+            }
+        }
+
+        /// Property: Conjunction is at most, and disjunction at least, either input
+        #[test]
+        fn test_conjunction_and_disjunction_bounds(a in interval(), b in interval()) {
+            let both = a.and(&b);
+            let either = a.or(&b);
+
+            prop_assert!(both.lower <= a.lower.min(b.lower) && both.upper <= a.upper.min(b.upper));
+            prop_assert!(either.lower >= a.lower.max(b.lower) && either.upper >= a.upper.max(b.upper));
+            prop_assert_eq!(a.and(&b), b.and(&a));
+            prop_assert_eq!(a.or(&b), b.or(&a));
+        }
+
+        /// Property: De Morgan's law relates conjunction and disjunction
+        #[test]
+        fn test_de_morgan(a in interval(), b in interval()) {
+            let left = a.or(&b).complement();
+            let right = a.complement().and(&b.complement());
+
+            prop_assert!((left.lower - right.lower).abs() < 1e-9);
+            prop_assert!((left.upper - right.upper).abs() < 1e-9);
+        }
+
+        /// Property: The hull contains, and the intersection is contained in, both inputs
+        #[test]
+        fn test_hull_and_intersection(a in interval(), b in interval()) {
+            let hull = a.hull(&b);
+            prop_assert!(hull.contains(a.lower) && hull.contains(a.upper));
+            prop_assert!(hull.contains(b.lower) && hull.contains(b.upper));
+
+            match a.intersect(&b) {
+                Some(common) => {
+                    prop_assert!(a.contains(common.lower) && a.contains(common.upper));
+                    prop_assert!(b.contains(common.lower) && b.contains(common.upper));
+                }
+                None => prop_assert!(a.upper < b.lower || b.upper < a.lower),
+            }
+        }
+
+        /// Property: Updating moves each bound towards the observation, and a neutral one changes nothing
+        #[test]
+        fn test_update_direction(prior in interval(), observation in interval()) {
+            let posterior = prior.update(&observation);
+            let neutral = prior.update(&ConfidenceInterval::new(0.5, 0.5));
+
+            if observation.lower >= 0.5 {
+                prop_assert!(posterior.lower >= prior.lower - 1e-12);
+            } else {
+                prop_assert!(posterior.lower <= prior.lower + 1e-12);
+            }
+            prop_assert!((neutral.lower - prior.lower).abs() < 1e-12);
+            prop_assert!((neutral.upper - prior.upper).abs() < 1e-12);
+        }
+
+        /// Property: Discounting keeps the interval inside the vacuous one and around its input
+        #[test]
+        fn test_discount(a in interval(), trust in 0.0..=1.0f64, less in 0.0..=1.0f64) {
+            let discounted = a.discount(trust).unwrap();
+            let further = a.discount(trust * less).unwrap();
+
+            prop_assert!(discounted.lower <= a.lower && discounted.upper >= a.upper);
+            prop_assert!(further.lower <= discounted.lower + 1e-12 && further.upper >= discounted.upper - 1e-12);
+            prop_assert_eq!(a.discount(1.0).unwrap(), a);
+            prop_assert_eq!(a.discount(0.0).unwrap(), ConfidenceInterval::new(0.0, 1.0));
+        }
+
         /// Property: Midpoint is always between bounds
         #[test]
         fn test_midpoint_bounds(lower in 0.0..=1.0, upper in 0.0..=1.0) {
@@ -3,7 +3,7 @@
 //! Implements the deterministic confidence formula for computing effective
 //! confidence intervals from provenance, staleness, and relationship data.
 
-use crate::{ConfidenceInterval, ProvenanceEntry, Relationship};
+use crate::{Claim, ConfidenceInterval, ProvenanceEntry, Relationship, StalenessPolicy};
 use crate::relationship::RelationshipType;
 use std::collections::HashSet;
 
@@ -22,6 +22,8 @@ pub struct ConfidenceConfig {
     pub penalty_factor: f64,
     /// Instance trust scaling factor [0.0, 1.0]
     pub instance_trust: f64,
+    /// Half-lives for staleness decay, per tier and namespace
+    pub staleness: StalenessPolicy,
 }
 
 impl Default for ConfidenceConfig {
@@ -30,6 +32,7 @@ impl Default for ConfidenceConfig {
             boost_factor: BOOST_FACTOR,
             penalty_factor: PENALTY_FACTOR,
             instance_trust: 1.0, // Full trust for local instance
+            staleness: StalenessPolicy::default(),
         }
     }
 }
@@ -67,8 +70,20 @@ pub fn compute_effective_confidence(
     config: &ConfidenceConfig,
 ) -> ConfidenceInterval {
     // Step 1: Provenance aggregation
-    let (aggregate_lower, aggregate_upper) = aggregate_provenance(provenance);
+    let aggregate = aggregate_provenance(provenance);
     
+    adjust_confidence(aggregate, current_time, stale_at, half_life_ms, related_claims, config)
+}
+
+/// Steps 2 to 4 of the formula, applied to an aggregate interval
+fn adjust_confidence(
+    (aggregate_lower, aggregate_upper): (f64, f64),
+    current_time: u64,
+    stale_at: Option<u64>,
+    half_life_ms: u64,
+    related_claims: &[(Relationship, RelatedClaimData)],
+    config: &ConfidenceConfig,
+) -> ConfidenceInterval {
     // Step 2: Staleness decay
     let staleness_factor = compute_staleness_factor(current_time, stale_at, half_life_ms);
     let stale_lower = aggregate_lower * staleness_factor;
@@ -82,8 +97,8 @@ pub fn compute_effective_confidence(
     let adjusted_upper = (stale_upper * support_boost * contradiction_penalty).min(1.0);
     
     // Step 4: Instance trust scaling
-    let final_lower = (adjusted_lower * config.instance_trust).clamp(0.0, 1.0);
-    let final_upper = (adjusted_upper * config.instance_trust).clamp(0.0, 1.0);
+    let final_lower = clamp_unit(adjusted_lower * config.instance_trust);
+    let final_upper = clamp_unit(adjusted_upper * config.instance_trust);
     
     // Ensure lower <= upper
     let final_lower = final_lower.min(final_upper);
@@ -91,6 +106,67 @@ pub fn compute_effective_confidence(
     ConfidenceInterval::new(final_lower, final_upper)
 }
 
+/// Compute a claim's effective confidence interval
+///
+/// Starts from the claim's base interval, which the store widens as
+/// assertions corroborate the claim, and runs steps 2 to 4 of the formula
+/// with the claim's `stale_at` and the half-life `config.staleness` gives the
+/// claim's tier and namespace. Effective confidence therefore never exceeds
+/// the stored interval unless a supporting claim raises it.
+/// `current_time` is in Unix seconds, like the claim's timestamps.
+pub fn compute_claim_confidence(
+    claim: &Claim,
+    current_time: u64,
+    related_claims: &[(Relationship, RelatedClaimData)],
+    config: &ConfidenceConfig,
+) -> ConfidenceInterval {
+    const MS_PER_SEC: u64 = 1000;
+    
+    adjust_confidence(
+        claim.confidence,
+        current_time.saturating_mul(MS_PER_SEC),
+        claim.stale_at.map(|stale_at| stale_at.saturating_mul(MS_PER_SEC)),
+        config.staleness.half_life_secs(claim).saturating_mul(MS_PER_SEC),
+        related_claims,
+        config,
+    )
+}
+
+/// A claim's base interval after staleness decay at `current_time` (Unix seconds)
+///
+/// This is the [`RelatedClaimData::stale_confidence`] of a related claim.
+pub fn stale_confidence(claim: &Claim, current_time: u64, staleness: &StalenessPolicy) -> ConfidenceInterval {
+    let factor = compute_staleness_factor(current_time, claim.stale_at, staleness.half_life_secs(claim));
+    let (lower, upper) = claim.confidence;
+    let upper = clamp_unit(upper * factor);
+    ConfidenceInterval::new(clamp_unit(lower * factor).min(upper), upper)
+}
+
+/// Clamp to [0, 1], treating NaN (from a malformed strength or trust) as no confidence
+fn clamp_unit(value: f64) -> f64 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Combine a claim's base confidence with an independent corroborating assertion
+///
+/// Mirrors provenance aggregation: the upper bound is the probability that at
+/// least one source is right, and the lower bound is anchored to the stronger
+/// of the two lower bounds. Corroboration therefore never lowers confidence.
+pub fn corroborate(existing: ConfidenceInterval, incoming: ConfidenceInterval) -> ConfidenceInterval {
+    // Guard against rounding pulling the noisy-or below either input
+    let upper = (1.0 - (1.0 - existing.upper) * (1.0 - incoming.upper))
+        .max(existing.upper)
+        .max(incoming.upper)
+        .min(1.0);
+    let lower = existing.lower.max(incoming.lower);
+    
+    ConfidenceInterval::new(lower, upper)
+}
+
 /// Step 1: Aggregate confidence from multiple provenance entries
 ///
 /// Uses the "probability of at least one source being right" model for upper bound
@@ -279,6 +355,19 @@ mod tests {
         assert!((contradiction_penalty - 0.82).abs() < 0.01);
     }
 
+    #[test]
+    fn test_corroborate() {
+        let combined = corroborate(
+            ConfidenceInterval::new(0.6, 0.8),
+            ConfidenceInterval::new(0.7, 0.7),
+        );
+        
+        // Lower = max(0.6, 0.7) = 0.7
+        assert!((combined.lower - 0.7).abs() < 0.01);
+        // Upper = 1 - (0.2 * 0.3) = 0.94
+        assert!((combined.upper - 0.94).abs() < 0.01);
+    }
+
     #[test]
     fn test_full_confidence_computation() {
         let provenance = vec![
@@ -300,6 +389,83 @@ mod tests {
         assert!((confidence.lower - 0.533).abs() < 0.01);
         assert!((confidence.upper - 0.8).abs() < 0.01);
     }
+
+    #[test]
+    fn test_claim_confidence_uses_half_life_of_tier() {
+        use crate::staleness::HOUR_SECS;
+        use crate::{ClaimId, Tier};
+        
+        let claim = |tier: Tier| Claim {
+            id: ClaimId::new(),
+            namespace: "acme".to_string(),
+            subject: "Alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.9),
+            tier,
+            created_at: 0,
+            stale_at: Some(0),
+        };
+        let config = ConfidenceConfig::default();
+        
+        // One ephemeral half-life after going stale
+        let now = 4 * HOUR_SECS;
+        let ephemeral = compute_claim_confidence(&claim(Tier::Ephemeral), now, &[], &config);
+        let project = compute_claim_confidence(&claim(Tier::Project), now, &[], &config);
+        let fresh = compute_claim_confidence(&claim(Tier::Ephemeral), 0, &[], &config);
+        
+        assert!((ephemeral.upper - fresh.upper / 2.0).abs() < 1e-9);
+        assert!(project.upper > ephemeral.upper);
+    }
+    
+    #[test]
+    fn test_claim_confidence_starts_from_base() {
+        use crate::staleness::HOUR_SECS;
+        use crate::{ClaimId, Tier};
+        
+        let claim = Claim {
+            id: ClaimId::new(),
+            namespace: "acme".to_string(),
+            subject: "Alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.6, 0.8),
+            tier: Tier::Ephemeral,
+            created_at: 0,
+            stale_at: Some(HOUR_SECS),
+        };
+        let config = ConfidenceConfig::default();
+        
+        assert_eq!(compute_claim_confidence(&claim, HOUR_SECS, &[], &config), ConfidenceInterval::new(0.6, 0.8));
+        
+        // One ephemeral half-life after going stale
+        let later = HOUR_SECS + 4 * HOUR_SECS;
+        let decayed = compute_claim_confidence(&claim, later, &[], &config);
+        assert!((decayed.upper - 0.4).abs() < 1e-9);
+        assert_eq!(stale_confidence(&claim, later, &config.staleness), decayed);
+    }
+    
+    #[test]
+    fn test_non_finite_inputs_do_not_panic() {
+        let provenance = vec![
+            ProvenanceEntry::new("user:alice".to_string(), 1000, "user".to_string()),
+        ];
+        // Fields are public, so a relationship can bypass `Relationship::try_new`
+        let mut relationship = Relationship::new(
+            crate::ClaimId::from_value(1),
+            crate::ClaimId::from_value(2),
+            RelationshipType::Contradicts,
+            1.0,
+            1000,
+        );
+        relationship.strength = f64::NAN;
+        let related = vec![(relationship, RelatedClaimData { stale_confidence: ConfidenceInterval::new(0.7, 0.9) })];
+        let config = ConfidenceConfig { instance_trust: f64::INFINITY, ..Default::default() };
+        
+        let confidence = compute_effective_confidence(&provenance, 1000, None, 1000, &[], &related, &config);
+        
+        assert!(ConfidenceInterval::try_new(confidence.lower, confidence.upper).is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -403,6 +569,22 @@ mod proptests {
             prop_assert!(conf2.upper >= conf1.upper);
         }
 
+        /// Property: Corroboration never lowers either bound and stays valid
+        #[test]
+        fn test_corroboration_monotonic(
+            a in 0.0f64..=1.0, b in 0.0f64..=1.0,
+            c in 0.0f64..=1.0, d in 0.0f64..=1.0,
+        ) {
+            let existing = ConfidenceInterval::new(a.min(b), a.max(b));
+            let incoming = ConfidenceInterval::new(c.min(d), c.max(d));
+            let combined = corroborate(existing, incoming);
+            
+            prop_assert!(combined.lower <= combined.upper);
+            prop_assert!(combined.upper <= 1.0);
+            prop_assert!(combined.lower >= existing.lower);
+            prop_assert!(combined.upper >= existing.upper);
+        }
+
         /// Property: Staleness always reduces or maintains confidence
         #[test]
         fn test_staleness_decreases_confidence(
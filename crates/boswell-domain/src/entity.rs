@@ -0,0 +1,388 @@
+//! Entity module - typed subjects and objects
+//!
+//! Claim subjects and objects are written `kind:identifier`, such as
+//! `person:john_doe` or `company:acme`. Everything after the first `:` is the
+//! identifier, so `url:https://example.com` is a `url` entity.
+//!
+//! Three kinds are literal values rather than references to things, matching
+//! what the extractor asks the model for:
+//! - `date:2025-01-01` (also `date:2025` and `date:2025-01` for partial dates)
+//! - `number:42.5`
+//! - `measurement:10kg` (a number followed by a unit)
+//!
+//! [`Entity::parse`] splits and normalizes either form. Stores index claims by
+//! the kinds of their subject and object and by the [`Literal::sort_key`] of
+//! literal objects, which is what kind patterns (`person:*`) and literal range
+//! queries run on.
+
+use std::fmt;
+
+/// Separator between an entity's kind and its identifier
+pub const SEPARATOR: char = ':';
+
+/// Identifier that matches every entity of a kind in query patterns (`person:*`)
+pub const WILDCARD: &str = "*";
+
+/// Datatype of a literal entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LiteralType {
+    /// Calendar date (`date:`)
+    Date,
+
+    /// Plain number (`number:`)
+    Number,
+
+    /// Number with a unit (`measurement:`)
+    Measurement,
+}
+
+impl LiteralType {
+    /// The entity kind written for this datatype
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LiteralType::Date => "date",
+            LiteralType::Number => "number",
+            LiteralType::Measurement => "measurement",
+        }
+    }
+
+    /// The datatype written as `kind`, if it is a literal kind
+    pub fn from_kind(kind: &str) -> Option<Self> {
+        match kind {
+            "date" => Some(LiteralType::Date),
+            "number" => Some(LiteralType::Number),
+            "measurement" => Some(LiteralType::Measurement),
+            _ => None,
+        }
+    }
+}
+
+/// Typed value of a literal entity
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    /// Calendar date; `month` and `day` are 0 when the date gives only a year or month
+    Date {
+        /// Year
+        year: u16,
+        /// Month (1-12), or 0 for a year
+        month: u8,
+        /// Day of the month, or 0 for a year or month
+        day: u8,
+    },
+
+    /// Plain number
+    Number(f64),
+
+    /// Number with a unit, such as `10kg`
+    Measurement {
+        /// Numeric value
+        value: f64,
+        /// Unit as written, such as `kg`
+        unit: String,
+    },
+}
+
+impl Literal {
+    /// Parse the identifier of a literal entity of the given datatype
+    ///
+    /// # Errors
+    /// Returns a description of what the identifier should look like
+    pub fn parse(datatype: LiteralType, identifier: &str) -> Result<Self, String> {
+        match datatype {
+            LiteralType::Date => parse_date(identifier).ok_or_else(|| {
+                format!("'{}' is not a date (expected YYYY, YYYY-MM or YYYY-MM-DD)", identifier)
+            }),
+            LiteralType::Number => parse_number(identifier)
+                .map(Literal::Number)
+                .ok_or_else(|| format!("'{}' is not a number", identifier)),
+            LiteralType::Measurement => parse_measurement(identifier).ok_or_else(|| {
+                format!("'{}' is not a measurement (expected a number followed by a unit)", identifier)
+            }),
+        }
+    }
+
+    /// Datatype of this literal
+    pub fn datatype(&self) -> LiteralType {
+        match self {
+            Literal::Date { .. } => LiteralType::Date,
+            Literal::Number(_) => LiteralType::Number,
+            Literal::Measurement { .. } => LiteralType::Measurement,
+        }
+    }
+
+    /// Value that orders literals of one datatype
+    ///
+    /// Dates map to `YYYYMMDD`, so a partial date sorts at the start of its
+    /// period; numbers and measurements map to their value (measurements
+    /// are compared by value regardless of unit).
+    pub fn sort_key(&self) -> f64 {
+        self.bounds().0
+    }
+
+    /// Lowest and highest sort keys this literal stands for
+    ///
+    /// Equal for everything but partial dates, which span their year or month.
+    pub fn bounds(&self) -> (f64, f64) {
+        match self {
+            Literal::Date { year, month, day } => {
+                let start = f64::from(*year) * 10_000.0 + f64::from(*month) * 100.0 + f64::from(*day);
+                let end = match (month, day) {
+                    (0, _) => start + 1231.0,
+                    (_, 0) => start + 31.0,
+                    _ => start,
+                };
+                (start, end)
+            }
+            Literal::Number(value) | Literal::Measurement { value, .. } => (*value, *value),
+        }
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Literal::Date { year, month: 0, .. } => write!(f, "{:04}", year),
+            Literal::Date { year, month, day: 0 } => write!(f, "{:04}-{:02}", year, month),
+            Literal::Date { year, month, day } => write!(f, "{:04}-{:02}-{:02}", year, month, day),
+            Literal::Number(value) => write!(f, "{}", value),
+            Literal::Measurement { value, unit } => write!(f, "{}{}", value, unit),
+        }
+    }
+}
+
+/// A parsed and normalized claim subject or object
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    /// Kind, lowercase (`person`, `company`, `date`)
+    pub kind: String,
+
+    /// Identifier within the kind, normalized
+    pub identifier: String,
+
+    /// Typed value when the kind is a literal datatype
+    pub literal: Option<Literal>,
+}
+
+impl Entity {
+    /// Parse `value` as `kind:identifier`
+    ///
+    /// Kinds are lowercased and may contain ASCII letters, digits, `-`, `_`
+    /// and `.`. Identifiers of reference kinds are lowercased with runs of
+    /// whitespace replaced by `_`, so `person:John Doe` and `person:john_doe`
+    /// are the same entity. Literal identifiers are parsed and written back
+    /// in canonical form (`date:2025-1-5` becomes `date:2025-01-05`).
+    ///
+    /// # Errors
+    /// Returns a description of the first problem found
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let (kind, identifier) = value.trim().split_once(SEPARATOR)
+            .ok_or_else(|| format!("'{}' does not match kind:identifier format", value))?;
+
+        let kind = normalize_kind(kind);
+        let identifier = identifier.trim();
+        if kind.is_empty() || identifier.is_empty() {
+            return Err(format!("'{}' has an empty kind or identifier", value));
+        }
+        if let Some(c) = kind.chars().find(|&c| !is_kind_char(c)) {
+            return Err(format!("'{}' has disallowed character {:?} in its kind", value, c));
+        }
+
+        match LiteralType::from_kind(&kind) {
+            Some(datatype) => {
+                let literal = Literal::parse(datatype, identifier)?;
+                Ok(Self {
+                    kind,
+                    identifier: literal.to_string(),
+                    literal: Some(literal),
+                })
+            }
+            None => Ok(Self {
+                kind,
+                identifier: identifier.split_whitespace().collect::<Vec<_>>().join("_").to_lowercase(),
+                literal: None,
+            }),
+        }
+    }
+
+    /// Parse a literal entity such as `date:2025` or `number:3`
+    ///
+    /// # Errors
+    /// Returns an error if `value` is not an entity or its kind is not a literal datatype
+    pub fn parse_literal(value: &str) -> Result<Literal, String> {
+        Self::parse(value)?
+            .literal
+            .ok_or_else(|| format!("'{}' is not a date, number or measurement literal", value))
+    }
+
+    /// The kind a query pattern matches in full, if it is `kind:*`
+    pub fn kind_pattern(pattern: &str) -> Option<String> {
+        let (kind, identifier) = pattern.trim().split_once(SEPARATOR)?;
+        let kind = normalize_kind(kind);
+        (identifier.trim() == WILDCARD && !kind.is_empty()).then_some(kind)
+    }
+
+    /// Whether this entity is a literal value rather than a reference
+    pub fn is_literal(&self) -> bool {
+        self.literal.is_some()
+    }
+}
+
+impl fmt::Display for Entity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.kind, SEPARATOR, self.identifier)
+    }
+}
+
+impl std::str::FromStr for Entity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Kind in the form stores index it: trimmed and lowercase
+pub fn normalize_kind(kind: &str) -> String {
+    kind.trim().to_lowercase()
+}
+
+fn is_kind_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+}
+
+fn parse_date(identifier: &str) -> Option<Literal> {
+    let mut parts = identifier.split('-');
+    let year = parse_digits(parts.next()?, 4)?;
+    let month = match parts.next() {
+        Some(month) => parse_digits(month, 2)?,
+        None => 0,
+    };
+    let day = match parts.next() {
+        Some(day) => parse_digits(day, 2)?,
+        None => 0,
+    };
+    if parts.next().is_some() || !(1..=9999).contains(&year) {
+        return None;
+    }
+
+    let valid = match (month, day) {
+        (0, _) => true,
+        (1..=12, 0) => true,
+        (1..=12, _) => day <= days_in_month(year, month),
+        _ => false,
+    };
+    valid.then_some(Literal::Date {
+        year,
+        month: month as u8,
+        day: day as u8,
+    })
+}
+
+/// ASCII digits only, at most `max_len` of them
+fn parse_digits(value: &str, max_len: usize) -> Option<u16> {
+    if value.is_empty() || value.len() > max_len || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn days_in_month(year: u16, month: u16) -> u16 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A finite number, allowing `,` and `_` as digit group separators
+fn parse_number(identifier: &str) -> Option<f64> {
+    let digits: String = identifier.chars().filter(|&c| c != ',' && c != '_').collect();
+    digits.parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+fn parse_measurement(identifier: &str) -> Option<Literal> {
+    let split = identifier
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | ',' | '_')))?;
+    let (value, unit) = identifier.split_at(split);
+    let unit = unit.trim();
+    if unit.is_empty() {
+        return None;
+    }
+
+    Some(Literal::Measurement {
+        value: parse_number(value.trim())?,
+        unit: unit.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reference() {
+        let entity = Entity::parse(" Person:John  Doe ").unwrap();
+        assert_eq!(entity.kind, "person");
+        assert_eq!(entity.identifier, "john_doe");
+        assert!(!entity.is_literal());
+        assert_eq!(entity.to_string(), "person:john_doe");
+
+        let url = Entity::parse("url:https://example.com").unwrap();
+        assert_eq!(url.kind, "url");
+        assert_eq!(url.identifier, "https://example.com");
+
+        assert!(Entity::parse("alice").is_err());
+        assert!(Entity::parse("person:").is_err());
+        assert!(Entity::parse(":alice").is_err());
+        assert!(Entity::parse("a person:alice").is_err());
+    }
+
+    #[test]
+    fn test_parse_literals() {
+        let date = Entity::parse("date:2025-1-5").unwrap();
+        assert_eq!(date.identifier, "2025-01-05");
+        assert_eq!(date.literal, Some(Literal::Date { year: 2025, month: 1, day: 5 }));
+        assert_eq!(Entity::parse("date:2024-02-29").unwrap().identifier, "2024-02-29");
+        assert!(Entity::parse("date:2025-02-29").is_err());
+        assert!(Entity::parse("date:2025-13").is_err());
+        assert!(Entity::parse("date:next tuesday").is_err());
+
+        assert_eq!(Entity::parse("number:1,000").unwrap().literal, Some(Literal::Number(1000.0)));
+        assert_eq!(Entity::parse("number:2.50").unwrap().identifier, "2.5");
+        assert!(Entity::parse("number:many").is_err());
+
+        let measurement = Entity::parse("measurement:10 kg").unwrap();
+        assert_eq!(measurement.identifier, "10kg");
+        assert_eq!(
+            measurement.literal,
+            Some(Literal::Measurement { value: 10.0, unit: "kg".to_string() })
+        );
+        assert!(Entity::parse("measurement:10").is_err());
+        assert!(Entity::parse("measurement:kg").is_err());
+    }
+
+    #[test]
+    fn test_literal_ordering() {
+        let year = Entity::parse_literal("date:2025").unwrap();
+        let month = Entity::parse_literal("date:2025-06").unwrap();
+        let day = Entity::parse_literal("date:2025-06-15").unwrap();
+
+        assert!(year.sort_key() < month.sort_key());
+        assert!(month.sort_key() < day.sort_key());
+        assert!(day.sort_key() < year.bounds().1);
+        assert!(day.sort_key() < month.bounds().1);
+        assert!(year.bounds().1 < Entity::parse_literal("date:2026-01-01").unwrap().sort_key());
+
+        assert!(Entity::parse_literal("person:alice").is_err());
+    }
+
+    #[test]
+    fn test_kind_pattern() {
+        assert_eq!(Entity::kind_pattern("person:*"), Some("person".to_string()));
+        assert_eq!(Entity::kind_pattern("Date:*"), Some("date".to_string()));
+        assert_eq!(Entity::kind_pattern("person:alice"), None);
+        assert_eq!(Entity::kind_pattern(":*"), None);
+        assert_eq!(Entity::kind_pattern("person"), None);
+    }
+}
@@ -0,0 +1,131 @@
+//! Event module - the change feed of store mutations
+
+use crate::{ClaimId, RelationshipType, Tier};
+
+/// A store mutation recorded in the event log
+///
+/// Stores append one event per mutation in the same transaction as the
+/// mutation itself, so the log never shows a change that was rolled back.
+/// Each event keeps the namespace and tier its claim had at that moment, so
+/// readers can filter events on them even after the claim has moved or been
+/// evicted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreEvent {
+    /// Position in the log; starts at 1 and only increases (gaps are possible)
+    pub seq: u64,
+
+    /// When the mutation was recorded (Unix seconds)
+    pub recorded_at: u64,
+
+    /// Namespace of the claim when the event was recorded (the source claim for relationships)
+    ///
+    /// `None` only for events logged before stores recorded it, whose claim was gone by then.
+    pub namespace: Option<String>,
+
+    /// Tier of the claim when the event was recorded, after the change for promotions and demotions
+    ///
+    /// `None` only for events logged before stores recorded it, whose claim was gone by then.
+    pub tier: Option<Tier>,
+
+    /// What changed
+    pub kind: EventKind,
+}
+
+/// What a [`StoreEvent`] records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    /// A new claim was stored
+    Asserted(ClaimId),
+
+    /// An assertion duplicated a stored claim and corroborated it
+    Corroborated(ClaimId),
+
+    /// A claim moved to a longer-lived tier
+    Promoted {
+        /// The claim
+        claim_id: ClaimId,
+        /// Tier before the change
+        from: Tier,
+        /// Tier after the change
+        to: Tier,
+    },
+
+    /// A claim moved to a shorter-lived tier
+    Demoted {
+        /// The claim
+        claim_id: ClaimId,
+        /// Tier before the change
+        from: Tier,
+        /// Tier after the change
+        to: Tier,
+    },
+
+    /// A claim was deleted along with its provenance and relationships
+    Evicted(ClaimId),
+
+    /// A claim's namespace subtree moved; the event records the new namespace
+    Moved(ClaimId),
+
+    /// A relationship was added, or an existing one's strength replaced
+    RelationshipAdded {
+        /// Source claim
+        from_claim: ClaimId,
+        /// Target claim
+        to_claim: ClaimId,
+        /// Relationship type
+        relationship_type: RelationshipType,
+    },
+}
+
+impl EventKind {
+    /// The event for moving a claim from one tier to another, if the tier changes
+    pub fn tier_change(claim_id: ClaimId, from: Tier, to: Tier) -> Option<Self> {
+        match to.cmp(&from) {
+            std::cmp::Ordering::Greater => Some(EventKind::Promoted { claim_id, from, to }),
+            std::cmp::Ordering::Less => Some(EventKind::Demoted { claim_id, from, to }),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// The claim the event is about (the source claim for relationships)
+    pub fn claim_id(&self) -> ClaimId {
+        match self {
+            EventKind::Asserted(id) | EventKind::Corroborated(id) | EventKind::Evicted(id) | EventKind::Moved(id) => *id,
+            EventKind::Promoted { claim_id, .. } | EventKind::Demoted { claim_id, .. } => *claim_id,
+            EventKind::RelationshipAdded { from_claim, .. } => *from_claim,
+        }
+    }
+
+    /// Event name, as stored in event logs
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventKind::Asserted(_) => "asserted",
+            EventKind::Corroborated(_) => "corroborated",
+            EventKind::Promoted { .. } => "promoted",
+            EventKind::Demoted { .. } => "demoted",
+            EventKind::Evicted(_) => "evicted",
+            EventKind::Moved(_) => "moved",
+            EventKind::RelationshipAdded { .. } => "relationship_added",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tier_change_direction() {
+        let id = ClaimId::new();
+
+        assert_eq!(
+            EventKind::tier_change(id, Tier::Task, Tier::Permanent),
+            Some(EventKind::Promoted { claim_id: id, from: Tier::Task, to: Tier::Permanent })
+        );
+        assert_eq!(
+            EventKind::tier_change(id, Tier::Project, Tier::Ephemeral),
+            Some(EventKind::Demoted { claim_id: id, from: Tier::Project, to: Tier::Ephemeral })
+        );
+        assert_eq!(EventKind::tier_change(id, Tier::Task, Tier::Task), None);
+    }
+}
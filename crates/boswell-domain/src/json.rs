@@ -0,0 +1,184 @@
+//! JSON representation of domain types (`serde` feature)
+//!
+//! [`Claim`](crate::Claim), [`ClaimId`](crate::ClaimId),
+//! [`ProvenanceEntry`](crate::ProvenanceEntry),
+//! [`Relationship`](crate::Relationship) and [`Tier`](crate::Tier) serialize
+//! to the format below. It is versioned by [`JSON_FORMAT_VERSION`]; fields
+//! may be added within a version, so readers must ignore fields they do not
+//! know, while renaming, removing or retyping a field bumps the version.
+//!
+//! - `ClaimId`: hyphenated UUID string (`"0190a4b2-0000-7000-8000-000000000001"`)
+//! - `Tier`: `"ephemeral"`, `"task"`, `"project"` or `"permanent"`, read ignoring case
+//! - `Claim`: `id`, `namespace`, `subject`, `predicate`, `object`,
+//!   `confidence` (`{"lower", "upper"}`), `tier`, `created_at` and
+//!   `stale_at` (Unix seconds; `stale_at` may be `null` or omitted)
+//! - `ProvenanceEntry`: `source`, `timestamp`, `rationale` (string or `null`;
+//!   may be omitted) and `source_type`
+//! - `Relationship`: `from_claim` and `to_claim` (claim IDs),
+//!   `relationship_type` (`supports`, `contradicts`, `derived_from`,
+//!   `references` or `supersedes`), `strength` and `created_at`
+//!
+//! Deserializing checks what the constructors check: claim IDs must be
+//! UUIDs, confidence bounds and strengths finite numbers in [0, 1], and a
+//! lower bound no greater than its upper bound.
+//!
+//! With the `schema` feature, [`json_schema`] generates a JSON Schema of
+//! the format. `schema/boswell-domain.v1.schema.json` in this crate is a
+//! generated copy for consumers in other languages; regenerate it with
+//!
+//! ```text
+//! cargo run -p boswell-domain --features schema --example json_schema > crates/boswell-domain/schema/boswell-domain.v1.schema.json
+//! ```
+
+/// Version of the JSON representation of domain types
+pub const JSON_FORMAT_VERSION: u32 = 1;
+
+/// JSON Schema (draft 2020-12) of the JSON representation
+///
+/// Every type is a definition under `$defs`, named after the Rust type.
+#[cfg(feature = "schema")]
+pub fn json_schema() -> schemars::Schema {
+    use crate::{Claim, ClaimId, ProvenanceEntry, Relationship, Tier};
+
+    let mut generator = schemars::generate::SchemaSettings::draft2020_12().into_generator();
+    generator.subschema_for::<Claim>();
+    generator.subschema_for::<ClaimId>();
+    generator.subschema_for::<ProvenanceEntry>();
+    generator.subschema_for::<Relationship>();
+    generator.subschema_for::<Tier>();
+
+    schemars::json_schema!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": format!("Boswell domain types, JSON format v{}", JSON_FORMAT_VERSION),
+        "$defs": generator.take_definitions(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Claim, ClaimId, ProvenanceEntry, Relationship, RelationshipType, Tier};
+    use serde_json::json;
+
+    fn id(n: u8) -> ClaimId {
+        ClaimId::from_string(&format!("0190a4b2-0000-7000-8000-0000000000{:02x}", n)).unwrap()
+    }
+
+    fn claim() -> Claim {
+        Claim {
+            id: id(1),
+            namespace: "acme/eng".to_string(),
+            subject: "person:alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "person:bob".to_string(),
+            confidence: (0.8, 0.9),
+            tier: Tier::Project,
+            created_at: 1000,
+            stale_at: None,
+        }
+    }
+
+    #[test]
+    fn test_claim_representation() {
+        let value = serde_json::to_value(claim()).unwrap();
+        assert_eq!(value, json!({
+            "id": "0190a4b2-0000-7000-8000-000000000001",
+            "namespace": "acme/eng",
+            "subject": "person:alice",
+            "predicate": "knows",
+            "object": "person:bob",
+            "confidence": {"lower": 0.8, "upper": 0.9},
+            "tier": "project",
+            "created_at": 1000,
+            "stale_at": null,
+        }));
+        assert_eq!(serde_json::from_value::<Claim>(value).unwrap(), claim());
+
+        // Readers ignore unknown fields, accept any tier case and default stale_at
+        let lenient = json!({
+            "id": "0190a4b2-0000-7000-8000-000000000001",
+            "namespace": "acme/eng",
+            "subject": "person:alice",
+            "predicate": "knows",
+            "object": "person:bob",
+            "confidence": {"lower": 0.8, "upper": 0.9},
+            "tier": "Project",
+            "created_at": 1000,
+            "added_in_a_later_release": true,
+        });
+        assert_eq!(serde_json::from_value::<Claim>(lenient).unwrap(), claim());
+    }
+
+    #[test]
+    fn test_relationship_and_provenance_representation() {
+        let relationship = Relationship::new(id(1), id(2), RelationshipType::DerivedFrom, 0.5, 7);
+        let value = serde_json::to_value(&relationship).unwrap();
+        assert_eq!(value, json!({
+            "from_claim": "0190a4b2-0000-7000-8000-000000000001",
+            "to_claim": "0190a4b2-0000-7000-8000-000000000002",
+            "relationship_type": "derived_from",
+            "strength": 0.5,
+            "created_at": 7,
+        }));
+        assert_eq!(serde_json::from_value::<Relationship>(value).unwrap(), relationship);
+
+        let entry = ProvenanceEntry::new("user:alice".to_string(), 500, "user".to_string());
+        let value = serde_json::to_value(&entry).unwrap();
+        assert_eq!(value, json!({"source": "user:alice", "timestamp": 500, "rationale": null, "source_type": "user"}));
+        let read: ProvenanceEntry =
+            serde_json::from_value(json!({"source": "user:alice", "timestamp": 500, "source_type": "user"})).unwrap();
+        assert_eq!(read, entry);
+    }
+
+    #[test]
+    fn test_deserializing_rejects_invalid_values() {
+        let with = |field: &str, value: serde_json::Value| {
+            let mut claim = serde_json::to_value(claim()).unwrap();
+            claim[field] = value;
+            serde_json::from_value::<Claim>(claim).unwrap_err().to_string()
+        };
+
+        assert!(with("id", json!("not-a-uuid")).contains("Invalid UUIDv7"));
+        assert!(with("id", json!(42)).contains("invalid type"));
+        assert!(with("tier", json!("session")).contains("Invalid tier 'session'"));
+        assert!(with("confidence", json!({"lower": 0.9, "upper": 0.1})).contains("lower bound 0.9 must be <= upper bound 0.1"));
+        assert!(with("confidence", json!({"lower": 0.5, "upper": 1.5})).contains("upper bound must be in [0, 1]"));
+
+        let relationship = json!({
+            "from_claim": "0190a4b2-0000-7000-8000-000000000001",
+            "to_claim": "0190a4b2-0000-7000-8000-000000000002",
+            "relationship_type": "supports",
+            "strength": 1.5,
+            "created_at": 7,
+        });
+        let err = serde_json::from_value::<Relationship>(relationship).unwrap_err();
+        assert!(err.to_string().contains("strength must be in [0, 1]"));
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_json_schema_is_current() {
+        let committed: serde_json::Value =
+            serde_json::from_str(include_str!("../schema/boswell-domain.v1.schema.json")).unwrap();
+        assert_eq!(
+            serde_json::to_value(json_schema()).unwrap(),
+            committed,
+            "schema/boswell-domain.v1.schema.json is out of date; regenerate it with the json_schema example",
+        );
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_json_schema_describes_representation() {
+        let schema = serde_json::to_value(json_schema()).unwrap();
+        let defs = &schema["$defs"];
+
+        assert_eq!(defs["ClaimId"]["format"], "uuid");
+        assert_eq!(defs["Tier"]["enum"], json!(["ephemeral", "task", "project", "permanent"]));
+        assert_eq!(defs["Claim"]["properties"]["confidence"]["$ref"], "#/$defs/ConfidenceInterval");
+        assert_eq!(defs["ConfidenceInterval"]["properties"]["lower"]["maximum"], 1.0);
+        assert!(defs["Claim"]["required"].as_array().unwrap().contains(&json!("tier")));
+        assert!(!defs["Claim"]["required"].as_array().unwrap().contains(&json!("stale_at")));
+        assert_eq!(defs["Relationship"]["properties"]["relationship_type"]["$ref"], "#/$defs/RelationshipType");
+    }
+}
@@ -8,9 +8,20 @@
 //!
 //! - **Claim**: The fundamental unit - a statement with confidence, not a fact
 //! - **Confidence Interval**: [lower, upper] bounds representing certainty
+//! - **Entity**: A typed subject or object (`person:alice`, `date:2025-01-01`)
+//! - **Alias**: A reversible record that two entities are the same
+//! - **Predicate registry**: Canonical predicates, synonyms, inverses and cardinality per namespace
 //! - **Provenance**: Source tracking for every claim
 //! - **Relationships**: Pairwise connections between claims
 //! - **Tiers**: Lifecycle stages (ephemeral → task → project → permanent)
+//! - **Staleness policy**: When claims go stale and how fast they decay, per tier and namespace
+//! - **Events**: The append-only log of store mutations
+//! - **JSON**: A versioned JSON representation of claims, relationships and provenance
+//!
+//! ## Features
+//!
+//! - `serde`: `Serialize`/`Deserialize` for the types described in [`json`]
+//! - `schema`: JSON Schema generation for that representation (implies `serde`)
 //!
 //! ## Architecture
 //!
@@ -23,19 +34,33 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+pub mod alias;
 pub mod claim;
 pub mod confidence;
 pub mod confidence_computation;
+pub mod entity;
+pub mod event;
+#[cfg(feature = "serde")]
+pub mod json;
 pub mod namespace;
+pub mod predicate;
 pub mod provenance;
 pub mod relationship;
+pub mod resolver;
+pub mod staleness;
 pub mod tier;
 pub mod traits;
 
 // Re-exports for convenience
+pub use alias::{AliasGraph, EntityAlias, MergeProposal};
 pub use claim::{Claim, ClaimId};
-pub use confidence::ConfidenceInterval;
-pub use namespace::Namespace;
+pub use confidence::{ConfidenceError, ConfidenceInterval};
+pub use entity::{Entity, Literal, LiteralType};
+pub use event::{EventKind, StoreEvent};
+pub use namespace::{Namespace, NamespacePolicy, NamespaceStats};
+pub use predicate::{Cardinality, PredicateDefinition, PredicateMatch, PredicateRegistry, ValueType};
 pub use provenance::ProvenanceEntry;
 pub use relationship::{Relationship, RelationshipType};
+pub use resolver::EntityResolver;
+pub use staleness::{StalenessPolicy, StalenessRule};
 pub use tier::Tier;
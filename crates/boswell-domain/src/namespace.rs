@@ -1,4 +1,221 @@
 //! Namespace module (per ADR-006 - convention-based namespaces)
+//!
+//! A namespace is one or more segments joined by `/`, such as
+//! `development/boswell/claim-store`. Segments are non-empty and contain ASCII
+//! letters, digits and the characters a [`NamespacePolicy`] allows (`-`, `_`
+//! and `.` by default). The policy also caps the number of segments.
+//!
+//! Hierarchy is purely textual: `a/b` is inside `a`, but `ab` is not.
+//! [`in_subtree`] is the segment-aware prefix match stores use for
+//! namespace queries, and [`rebase`] the rewrite they apply when a subtree
+//! moves. [`NamespaceStats`] summarizes what a namespace holds.
+
+use crate::{Claim, Tier};
+use std::collections::HashMap;
+
+/// Separator between namespace segments
+pub const SEPARATOR: char = '/';
+
+/// Limits every namespace written to a store must respect
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamespacePolicy {
+    /// Maximum number of segments
+    pub max_depth: usize,
+    
+    /// Characters allowed in segments besides ASCII letters and digits
+    pub extra_chars: String,
+}
+
+impl Default for NamespacePolicy {
+    fn default() -> Self {
+        Self {
+            max_depth: 6,
+            extra_chars: "-_.".to_string(),
+        }
+    }
+}
+
+impl NamespacePolicy {
+    /// Check `value` against the namespace grammar and this policy
+    ///
+    /// # Errors
+    /// Returns a description of the first problem found
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Err("Namespace cannot be empty".to_string());
+        }
+        
+        let mut depth = 0;
+        for segment in value.split(SEPARATOR) {
+            depth += 1;
+            if segment.is_empty() {
+                return Err(format!("Namespace '{}' has an empty segment", value));
+            }
+            if let Some(c) = segment.chars().find(|&c| !self.allows(c)) {
+                return Err(format!("Namespace '{}' contains disallowed character {:?}", value, c));
+            }
+        }
+        
+        if depth > self.max_depth {
+            return Err(format!(
+                "Namespace '{}' has {} segments, more than the maximum of {}",
+                value, depth, self.max_depth
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    fn allows(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric() || (c != SEPARATOR && self.extra_chars.contains(c))
+    }
+}
+
+/// Whether `namespace` is `root` or one of its descendants
+///
+/// Matches whole segments, so `project/x` is in the subtree of `project` but
+/// `projectX` is not. A trailing separator on `root` is ignored, and an empty
+/// root contains every namespace.
+pub fn in_subtree(namespace: &str, root: &str) -> bool {
+    let root = root.trim_end_matches(SEPARATOR);
+    if root.is_empty() {
+        return true;
+    }
+    
+    match namespace.strip_prefix(root) {
+        Some(rest) => rest.is_empty() || rest.starts_with(SEPARATOR),
+        None => false,
+    }
+}
+
+/// `namespace` with its `from` prefix replaced by `to`
+///
+/// Returns `None` if `namespace` is not in the subtree of `from` (or `from` is
+/// empty), so `rebase("a/b/c", "a/b", "x")` is `x/c` but `rebase("a/bc", "a/b", "x")`
+/// is `None`.
+pub fn rebase(namespace: &str, from: &str, to: &str) -> Option<String> {
+    let from = from.trim_end_matches(SEPARATOR);
+    let to = to.trim_end_matches(SEPARATOR);
+    if from.is_empty() || !in_subtree(namespace, from) {
+        return None;
+    }
+    
+    Some(format!("{}{}", to, &namespace[from.len()..]))
+}
+
+/// Check that the subtree at `from` may be moved to `to`
+///
+/// Both must be non-empty and neither may lie inside the other. Whether the
+/// destination is free is for the store to decide.
+///
+/// # Errors
+/// Returns a description of the problem
+pub fn check_move(from: &str, to: &str) -> Result<(), String> {
+    let from = from.trim_end_matches(SEPARATOR);
+    let to = to.trim_end_matches(SEPARATOR);
+    if from.is_empty() || to.is_empty() {
+        return Err("Cannot move to or from the root namespace".to_string());
+    }
+    if in_subtree(to, from) || in_subtree(from, to) {
+        return Err(format!("Namespaces '{}' and '{}' overlap", from, to));
+    }
+    
+    Ok(())
+}
+
+/// What one namespace holds, counting only claims stored directly in it
+///
+/// Stores report one entry per namespace that holds claims; [`rollup`](Self::rollup)
+/// turns those into totals for whole subtrees.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NamespaceStats {
+    /// The namespace
+    pub namespace: String,
+    
+    /// Number of claims
+    pub claims: u64,
+    
+    /// Claims per tier, in [`Tier::ALL`] order
+    pub tier_counts: [u64; 4],
+    
+    /// Sum of the claims' base confidence midpoints
+    pub confidence_sum: f64,
+    
+    /// Latest claim creation or provenance timestamp (Unix seconds, 0 if none)
+    pub last_write: u64,
+    
+    /// `contradicts` relationships whose source claim is in the namespace
+    pub contradictions: u64,
+}
+
+impl NamespaceStats {
+    /// Empty statistics for `namespace`
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self { namespace: namespace.into(), ..Default::default() }
+    }
+    
+    /// Count `claim`, last written at `last_write`
+    pub fn record_claim(&mut self, claim: &Claim, last_write: u64) {
+        self.claims += 1;
+        self.tier_counts[claim.tier as usize] += 1;
+        self.confidence_sum += (claim.confidence.0 + claim.confidence.1) / 2.0;
+        self.last_write = self.last_write.max(last_write);
+    }
+    
+    /// Number of claims in `tier`
+    pub fn tier_count(&self, tier: Tier) -> u64 {
+        self.tier_counts[tier as usize]
+    }
+    
+    /// Mean confidence midpoint, or `None` if there are no claims
+    pub fn average_confidence(&self) -> Option<f64> {
+        (self.claims > 0).then(|| self.confidence_sum / self.claims as f64)
+    }
+    
+    /// Add `other`'s counts to these, keeping this namespace
+    pub fn merge(&mut self, other: &NamespaceStats) {
+        self.claims += other.claims;
+        for (count, other) in self.tier_counts.iter_mut().zip(other.tier_counts) {
+            *count += other;
+        }
+        self.confidence_sum += other.confidence_sum;
+        self.last_write = self.last_write.max(other.last_write);
+        self.contradictions += other.contradictions;
+    }
+    
+    /// Sort entries parents first, siblings by segment (`a`, `a/b`, `a-b`)
+    pub fn sort(stats: &mut [NamespaceStats]) {
+        stats.sort_by(|a, b| a.namespace.split(SEPARATOR).cmp(b.namespace.split(SEPARATOR)));
+    }
+    
+    /// Subtree totals for `root` and every namespace below it
+    ///
+    /// Each per-namespace entry in the subtree is added to itself and to all
+    /// of its ancestors up to `root`, so namespaces that hold no claims of
+    /// their own still appear when their descendants do. The result is
+    /// [sorted](Self::sort); an empty root covers every namespace.
+    pub fn rollup(stats: &[NamespaceStats], root: &str) -> Vec<NamespaceStats> {
+        let root = root.trim_end_matches(SEPARATOR);
+        let mut totals: HashMap<&str, NamespaceStats> = HashMap::new();
+        
+        for entry in stats.iter().filter(|entry| in_subtree(&entry.namespace, root)) {
+            let mut namespace = entry.namespace.as_str();
+            loop {
+                totals.entry(namespace)
+                    .or_insert_with(|| NamespaceStats::new(namespace))
+                    .merge(entry);
+                match namespace.rsplit_once(SEPARATOR) {
+                    Some((parent, _)) if in_subtree(parent, root) => namespace = parent,
+                    _ => break,
+                }
+            }
+        }
+        
+        let mut totals: Vec<NamespaceStats> = totals.into_values().collect();
+        Self::sort(&mut totals);
+        totals
+    }
+}
 
 /// Namespace for organizing claims
 /// 
@@ -8,18 +225,20 @@
 pub struct Namespace(String);
 
 impl Namespace {
-    /// Create a new namespace
+    /// Create a new namespace under the default [`NamespacePolicy`]
     /// 
     /// # Errors
     /// Returns error if namespace format is invalid
     pub fn new(value: String) -> Result<Self, String> {
-        // Basic validation - extend based on requirements
-        if value.is_empty() {
-            return Err("Namespace cannot be empty".to_string());
-        }
-        
-        // TODO: Add depth validation when max depth is configured
-        
+        Self::with_policy(value, &NamespacePolicy::default())
+    }
+    
+    /// Create a new namespace under `policy`
+    /// 
+    /// # Errors
+    /// Returns error if namespace format is invalid or exceeds the policy's depth
+    pub fn with_policy(value: String, policy: &NamespacePolicy) -> Result<Self, String> {
+        policy.validate(&value)?;
         Ok(Self(value))
     }
 
@@ -30,12 +249,40 @@ impl Namespace {
 
     /// Get depth (number of slash-separated components)
     pub fn depth(&self) -> usize {
-        self.0.split('/').count()
+        self.0.split(SEPARATOR).count()
+    }
+    
+    /// The slash-separated components, outermost first
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split(SEPARATOR)
+    }
+    
+    /// The namespace one level up, if this is not a top-level namespace
+    pub fn parent(&self) -> Option<Namespace> {
+        self.0.rsplit_once(SEPARATOR).map(|(parent, _)| Self(parent.to_string()))
+    }
+    
+    /// Every enclosing namespace, nearest first
+    pub fn ancestors(&self) -> impl Iterator<Item = Namespace> {
+        std::iter::successors(self.parent(), Namespace::parent)
     }
 
     /// Check if this namespace is a parent of another
+    ///
+    /// Any enclosing namespace counts, not only the immediate parent; same as
+    /// [`is_ancestor_of`](Self::is_ancestor_of).
     pub fn is_parent_of(&self, other: &Namespace) -> bool {
-        other.0.starts_with(&self.0) && other.0.len() > self.0.len()
+        self.is_ancestor_of(other)
+    }
+    
+    /// Check if `other` is strictly inside this namespace
+    pub fn is_ancestor_of(&self, other: &Namespace) -> bool {
+        self != other && in_subtree(&other.0, &self.0)
+    }
+    
+    /// Check if this namespace is strictly inside `other`
+    pub fn is_descendant_of(&self, other: &Namespace) -> bool {
+        other.is_ancestor_of(self)
     }
 }
 
@@ -63,6 +310,82 @@ mod tests {
         assert!(parent.is_parent_of(&child));
         assert!(!child.is_parent_of(&parent));
     }
+    
+    #[test]
+    fn test_hierarchy_is_segment_aware() {
+        let project = Namespace::new("project".to_string()).unwrap();
+        let sibling = Namespace::new("projectX/task".to_string()).unwrap();
+        let task = Namespace::new("project/task/sub".to_string()).unwrap();
+        
+        assert!(!project.is_ancestor_of(&sibling));
+        assert!(task.is_descendant_of(&project));
+        assert_eq!(task.parent().unwrap().as_str(), "project/task");
+        assert_eq!(
+            task.ancestors().map(|ns| ns.as_str().to_string()).collect::<Vec<_>>(),
+            vec!["project/task", "project"]
+        );
+        assert!(project.parent().is_none());
+        
+        assert!(in_subtree("project", "project/"));
+        assert!(in_subtree("anything", ""));
+        assert!(!in_subtree("projectX", "project"));
+    }
+    
+    #[test]
+    fn test_policy_rejects_malformed_namespaces() {
+        let policy = NamespacePolicy { max_depth: 2, ..Default::default() };
+        
+        assert!(Namespace::with_policy("acme/web-site_v2.0".to_string(), &policy).is_ok());
+        for invalid in ["", "/acme", "acme/", "acme//eng", "acme:eng", "acme eng", "a/b/c"] {
+            assert!(Namespace::with_policy(invalid.to_string(), &policy).is_err(), "accepted {:?}", invalid);
+        }
+    }
+    
+    #[test]
+    fn test_rebase_and_check_move() {
+        assert_eq!(rebase("a/b/c", "a/b", "x").as_deref(), Some("x/c"));
+        assert_eq!(rebase("a/b", "a/b/", "x/y").as_deref(), Some("x/y"));
+        assert_eq!(rebase("a/bc", "a/b", "x"), None);
+        assert_eq!(rebase("a", "", "x"), None);
+        
+        assert!(check_move("a/b", "c").is_ok());
+        assert!(check_move("a/b", "a/bc").is_ok());
+        for (from, to) in [("a", "a"), ("a", "a/b"), ("a/b", "a"), ("", "a"), ("a", "/")] {
+            assert!(check_move(from, to).is_err(), "allowed {:?} -> {:?}", from, to);
+        }
+    }
+    
+    #[test]
+    fn test_stats_rollup() {
+        let mut claim = Claim::new(
+            crate::ClaimId::new(), "a/b".to_string(), "s".to_string(), "p".to_string(), "o".to_string(),
+            (0.6, 0.8), Tier::Task, 100,
+        );
+        let mut deep = NamespaceStats::new("a/b");
+        deep.record_claim(&claim, 100);
+        claim.tier = Tier::Permanent;
+        claim.confidence = (0.2, 0.4);
+        deep.record_claim(&claim, 300);
+        deep.contradictions = 1;
+        let mut sibling = NamespaceStats::new("a-b");
+        sibling.record_claim(&claim, 200);
+        
+        assert_eq!(deep.claims, 2);
+        assert_eq!(deep.tier_count(Tier::Task), 1);
+        assert!((deep.average_confidence().unwrap() - 0.5).abs() < 1e-9);
+        assert_eq!(NamespaceStats::new("empty").average_confidence(), None);
+        
+        let all = NamespaceStats::rollup(&[sibling.clone(), deep.clone()], "");
+        let names: Vec<&str> = all.iter().map(|stats| stats.namespace.as_str()).collect();
+        assert_eq!(names, vec!["a", "a/b", "a-b"]);
+        assert_eq!(all[0].claims, 2);
+        assert_eq!(all[0].last_write, 300);
+        assert_eq!(all[0].contradictions, 1);
+        
+        let subtree = NamespaceStats::rollup(&[sibling, deep], "a/b");
+        assert_eq!(subtree.len(), 1);
+        assert_eq!(subtree[0].tier_count(Tier::Permanent), 1);
+    }
 }
 
 #[cfg(test)]
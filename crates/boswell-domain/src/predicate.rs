@@ -0,0 +1,392 @@
+//! Predicate module - a per-namespace vocabulary of predicates
+//!
+//! Predicates are free text, so extraction produces `works_at`, `employed_by`
+//! and `worksAt` for one relation. A [`PredicateRegistry`] declares, per
+//! namespace, the canonical name of a predicate with its synonyms, its
+//! inverse, whether it holds one value or many, and what its objects must be.
+//!
+//! Definitions registered for a namespace apply to its whole subtree; the
+//! nearest enclosing namespace that knows a predicate wins, and definitions
+//! for the empty namespace apply everywhere. Names are compared after
+//! [`normalize_predicate`], so `worksAt`, `Works At` and `works_at` are one
+//! name. Predicates nobody declared are left as they are.
+//!
+//! The gatekeeper rewrites incoming claims with [`PredicateRegistry::normalize`]
+//! (swapping subject and object for a declared inverse), and the janitor uses
+//! [`Cardinality`] to decide that two objects of a single-valued predicate
+//! contradict each other.
+
+use crate::entity::normalize_kind;
+use crate::namespace::SEPARATOR;
+use crate::{Claim, Entity};
+use std::collections::HashMap;
+use std::fmt;
+
+/// How many objects a subject may have for a predicate at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cardinality {
+    /// At most one (`born_on`, `works_at`); different objects conflict
+    Single,
+
+    /// Any number (`knows`, `likes`)
+    #[default]
+    Multi,
+}
+
+/// What the object of a predicate must be
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ValueType {
+    /// Anything
+    #[default]
+    Any,
+
+    /// A reference entity of any kind (`person:alice`), not a literal
+    Reference,
+
+    /// A date, number or measurement literal
+    Literal,
+
+    /// An entity of this kind (`company`, `date`)
+    Kind(String),
+}
+
+impl ValueType {
+    /// Whether `object` is a valid value of this type
+    pub fn accepts(&self, object: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Reference => Entity::parse(object).is_ok_and(|entity| !entity.is_literal()),
+            Self::Literal => Entity::parse(object).is_ok_and(|entity| entity.is_literal()),
+            Self::Kind(kind) => Entity::parse(object).is_ok_and(|entity| entity.kind == normalize_kind(kind)),
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "any value"),
+            Self::Reference => write!(f, "a reference entity"),
+            Self::Literal => write!(f, "a date, number or measurement"),
+            Self::Kind(kind) => write!(f, "a {} entity", normalize_kind(kind)),
+        }
+    }
+}
+
+/// The declaration of one predicate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateDefinition {
+    /// Canonical name, normalized; claims are rewritten to it
+    pub name: String,
+
+    /// Other names rewritten to the canonical one, normalized
+    pub synonyms: Vec<String>,
+
+    /// The predicate meaning the same with subject and object swapped
+    ///
+    /// Unless the inverse is declared as a predicate of its own, claims using
+    /// it are rewritten to this predicate with subject and object swapped. An
+    /// inverse equal to the name makes the predicate symmetric.
+    pub inverse: Option<String>,
+
+    /// How many objects a subject may have at once
+    pub cardinality: Cardinality,
+
+    /// What objects must be
+    pub value_type: ValueType,
+}
+
+impl PredicateDefinition {
+    /// A multi-valued predicate accepting any object, without synonyms or inverse
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: normalize_predicate(name),
+            synonyms: Vec::new(),
+            inverse: None,
+            cardinality: Cardinality::Multi,
+            value_type: ValueType::Any,
+        }
+    }
+
+    /// Rewrite these names to this predicate
+    pub fn with_synonyms<'a>(mut self, synonyms: impl IntoIterator<Item = &'a str>) -> Self {
+        self.synonyms.extend(synonyms.into_iter().map(normalize_predicate));
+        self
+    }
+
+    /// Declare the predicate meaning the same with subject and object swapped
+    pub fn with_inverse(mut self, inverse: &str) -> Self {
+        self.inverse = Some(normalize_predicate(inverse));
+        self
+    }
+
+    /// Allow at most one object per subject
+    pub fn single_valued(mut self) -> Self {
+        self.cardinality = Cardinality::Single;
+        self
+    }
+
+    /// Require objects of this type
+    pub fn with_value_type(mut self, value_type: ValueType) -> Self {
+        self.value_type = value_type;
+        self
+    }
+
+    /// Whether a subject may have at most one object
+    pub fn is_single_valued(&self) -> bool {
+        self.cardinality == Cardinality::Single
+    }
+}
+
+/// A definition found for a predicate, and whether it was found as the inverse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PredicateMatch<'a> {
+    /// The definition the predicate resolves to
+    pub definition: &'a PredicateDefinition,
+
+    /// Whether the predicate is the definition's inverse, so subject and object swap
+    pub inverted: bool,
+}
+
+/// The predicates declared for one namespace
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Vocabulary {
+    definitions: Vec<PredicateDefinition>,
+
+    /// Canonical names and synonyms to their definition
+    names: HashMap<String, usize>,
+
+    /// Inverses to the definition they invert
+    inverses: HashMap<String, usize>,
+}
+
+/// Predicate definitions by namespace
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PredicateRegistry {
+    vocabularies: HashMap<String, Vocabulary>,
+}
+
+impl PredicateRegistry {
+    /// An empty registry, which leaves every predicate as it is
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether no predicate is declared anywhere
+    pub fn is_empty(&self) -> bool {
+        self.vocabularies.is_empty()
+    }
+
+    /// Declare `definition` for `namespace` and its subtree (`""` for every namespace)
+    ///
+    /// # Errors
+    /// Returns a description of the conflict if the name or a synonym is
+    /// already declared in this namespace, or the inverse already inverts
+    /// another predicate here
+    pub fn register(&mut self, namespace: &str, definition: PredicateDefinition) -> Result<(), String> {
+        let namespace = namespace.trim_end_matches(SEPARATOR);
+        if definition.name.is_empty() {
+            return Err("Predicate name cannot be empty".to_string());
+        }
+
+        let vocabulary = self.vocabularies.entry(namespace.to_string()).or_default();
+        let index = vocabulary.definitions.len();
+        let names: Vec<&String> = std::iter::once(&definition.name).chain(&definition.synonyms).collect();
+        if let Some(taken) = names.iter().find(|name| vocabulary.names.contains_key(name.as_str())) {
+            return Err(format!("Predicate '{}' is already declared in namespace '{}'", taken, namespace));
+        }
+        let inverse = definition.inverse.as_ref().filter(|inverse| **inverse != definition.name);
+        if let Some(inverse) = inverse.filter(|inverse| vocabulary.inverses.contains_key(inverse.as_str())) {
+            return Err(format!("Predicate '{}' is already an inverse in namespace '{}'", inverse, namespace));
+        }
+
+        for name in names {
+            vocabulary.names.insert(name.clone(), index);
+        }
+        if let Some(inverse) = inverse {
+            vocabulary.inverses.insert(inverse.clone(), index);
+        }
+        vocabulary.definitions.push(definition);
+        Ok(())
+    }
+
+    /// The definition `predicate` resolves to in `namespace`, if any
+    ///
+    /// Looks in `namespace`, then each enclosing namespace, then the empty
+    /// namespace. Within a namespace, declared names and synonyms take
+    /// precedence over inverses.
+    pub fn lookup(&self, namespace: &str, predicate: &str) -> Option<PredicateMatch<'_>> {
+        let key = normalize_predicate(predicate);
+        let scopes = std::iter::successors(Some(namespace.trim_end_matches(SEPARATOR)), |scope| {
+            (!scope.is_empty()).then(|| scope.rsplit_once(SEPARATOR).map_or("", |(parent, _)| parent))
+        });
+
+        for scope in scopes {
+            let Some(vocabulary) = self.vocabularies.get(scope) else {
+                continue;
+            };
+            if let Some(&index) = vocabulary.names.get(&key) {
+                return Some(PredicateMatch { definition: &vocabulary.definitions[index], inverted: false });
+            }
+            if let Some(&index) = vocabulary.inverses.get(&key) {
+                return Some(PredicateMatch { definition: &vocabulary.definitions[index], inverted: true });
+            }
+        }
+        None
+    }
+
+    /// How many objects a subject may have for `predicate` in `namespace`
+    ///
+    /// Undeclared predicates are multi-valued.
+    pub fn cardinality(&self, namespace: &str, predicate: &str) -> Cardinality {
+        self.lookup(namespace, predicate)
+            .map_or(Cardinality::Multi, |found| found.definition.cardinality)
+    }
+
+    /// `claim` with its predicate rewritten to the canonical name
+    ///
+    /// A claim using an inverse has its subject and object swapped. Claims
+    /// with undeclared predicates come back unchanged.
+    ///
+    /// # Errors
+    /// Returns a description of the problem if the object (after any swap)
+    /// is not of the predicate's value type
+    pub fn normalize(&self, claim: &Claim) -> Result<Claim, String> {
+        let Some(found) = self.lookup(&claim.namespace, &claim.predicate) else {
+            return Ok(claim.clone());
+        };
+
+        let definition = found.definition;
+        let (subject, object) = if found.inverted {
+            (&claim.object, &claim.subject)
+        } else {
+            (&claim.subject, &claim.object)
+        };
+        if !definition.value_type.accepts(object) {
+            return Err(format!(
+                "'{}' is not a valid object of '{}', which expects {}",
+                object, definition.name, definition.value_type
+            ));
+        }
+
+        Ok(Claim {
+            subject: subject.clone(),
+            predicate: definition.name.clone(),
+            object: object.clone(),
+            ..claim.clone()
+        })
+    }
+}
+
+/// The form predicates are compared in
+///
+/// Trims, splits camelCase words (`worksAt` becomes `works_at`), lowercases,
+/// and joins words separated by whitespace or `-` with single `_`s.
+pub fn normalize_predicate(predicate: &str) -> String {
+    let mut normalized = String::with_capacity(predicate.len() + 4);
+    let mut previous: Option<char> = None;
+    for c in predicate.trim().chars() {
+        let separator = c.is_whitespace() || c == '-' || c == '_';
+        let word_break = c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit());
+        if (separator || word_break) && !normalized.is_empty() && !normalized.ends_with(['_', ':']) {
+            normalized.push('_');
+        }
+        if !separator {
+            normalized.extend(c.to_lowercase());
+        }
+        previous = Some(c);
+    }
+    normalized.trim_end_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ClaimId, Tier};
+
+    fn claim(namespace: &str, subject: &str, predicate: &str, object: &str) -> Claim {
+        Claim::new(
+            ClaimId::new(),
+            namespace.to_string(),
+            subject.to_string(),
+            predicate.to_string(),
+            object.to_string(),
+            (0.8, 0.9),
+            Tier::Task,
+            0,
+        )
+    }
+
+    fn registry() -> PredicateRegistry {
+        let mut registry = PredicateRegistry::new();
+        registry.register("", PredicateDefinition::new("works_at")
+            .with_synonyms(["employed_by"])
+            .with_inverse("employs")
+            .single_valued()
+            .with_value_type(ValueType::Kind("company".to_string()))).unwrap();
+        registry.register("", PredicateDefinition::new("knows").with_inverse("knows")).unwrap();
+        registry.register("acme", PredicateDefinition::new("reports_to").single_valued()).unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_normalize_predicate() {
+        assert_eq!(normalize_predicate("worksAt"), "works_at");
+        assert_eq!(normalize_predicate(" Works  At "), "works_at");
+        assert_eq!(normalize_predicate("works-at"), "works_at");
+        assert_eq!(normalize_predicate("rel:worksAt"), "rel:works_at");
+        assert_eq!(normalize_predicate("has__property_"), "has_property");
+    }
+
+    #[test]
+    fn test_synonyms_and_inverses_normalize_claims() {
+        let registry = registry();
+
+        let normalized = registry.normalize(&claim("x", "person:alice", "employedBy", "company:acme")).unwrap();
+        assert_eq!(normalized.predicate, "works_at");
+
+        let normalized = registry.normalize(&claim("x", "company:acme", "employs", "person:alice")).unwrap();
+        assert_eq!(
+            (normalized.subject.as_str(), normalized.predicate.as_str(), normalized.object.as_str()),
+            ("person:alice", "works_at", "company:acme")
+        );
+
+        // Symmetric predicates keep their direction
+        let normalized = registry.normalize(&claim("x", "person:alice", "knows", "person:bob")).unwrap();
+        assert_eq!(normalized.subject, "person:alice");
+
+        let unknown = claim("x", "person:alice", "likesTea", "drink:tea");
+        assert_eq!(registry.normalize(&unknown).unwrap(), unknown);
+    }
+
+    #[test]
+    fn test_value_type_is_enforced() {
+        let registry = registry();
+
+        let err = registry.normalize(&claim("x", "person:alice", "works_at", "city:paris")).unwrap_err();
+        assert!(err.contains("company"));
+        assert!(ValueType::Literal.accepts("date:2025"));
+        assert!(!ValueType::Reference.accepts("number:3"));
+    }
+
+    #[test]
+    fn test_namespaces_scope_definitions() {
+        let registry = registry();
+
+        assert_eq!(registry.cardinality("acme/eng", "reportsTo"), Cardinality::Single);
+        assert_eq!(registry.cardinality("acmecorp", "reports_to"), Cardinality::Multi);
+        assert_eq!(registry.cardinality("anything", "works_at"), Cardinality::Single);
+        assert_eq!(registry.cardinality("anything", "knows"), Cardinality::Multi);
+    }
+
+    #[test]
+    fn test_conflicting_declarations_are_refused() {
+        let mut registry = registry();
+
+        assert!(registry.register("", PredicateDefinition::new("employed_by")).is_err());
+        assert!(registry.register("", PredicateDefinition::new("staff_of").with_inverse("employs")).is_err());
+        // Another namespace may redefine it
+        assert!(registry.register("acme", PredicateDefinition::new("employed_by")).is_ok());
+        assert!(!registry.lookup("acme/eng", "employedBy").unwrap().definition.is_single_valued());
+    }
+}
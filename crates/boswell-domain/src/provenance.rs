@@ -2,6 +2,8 @@
 
 /// A single provenance entry tracking the source of a claim
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProvenanceEntry {
     /// Source identifier (e.g., "user:alice", "agent:gpt4", "synthesis:v1")
     pub source: String,
@@ -10,6 +12,7 @@ pub struct ProvenanceEntry {
     pub timestamp: u64,
     
     /// Optional rationale or reasoning
+    #[cfg_attr(feature = "serde", serde(default))]
     pub rationale: Option<String>,
     
     /// Source type (e.g., "user", "agent", "extraction", "synthesis")
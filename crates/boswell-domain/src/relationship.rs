@@ -1,9 +1,12 @@
 //! Relationship module (per ADR-002 - pairwise relationships only)
 
 use super::ClaimId;
+use crate::confidence::{check_unit, ConfidenceError};
 
 /// Type of relationship between claims
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RelationshipType {
     /// One claim supports another (increases confidence)
     Supports,
@@ -26,6 +29,8 @@ pub enum RelationshipType {
 /// Per ADR-002, we only model pairwise relationships.
 /// Compound relationships are handled by the Synthesizer creating derived claims.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Relationship {
     /// Source claim ID
     pub from_claim: ClaimId,
@@ -37,6 +42,8 @@ pub struct Relationship {
     pub relationship_type: RelationshipType,
     
     /// Strength of relationship [0.0, 1.0]
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_strength"))]
+    #[cfg_attr(feature = "schema", schemars(range(min = 0.0, max = 1.0)))]
     pub strength: f64,
     
     /// When this relationship was established
@@ -44,7 +51,12 @@ pub struct Relationship {
 }
 
 impl Relationship {
-    /// Create a new relationship
+    /// Create a new relationship with a strength known to be valid
+    /// 
+    /// Use [`try_new`](Self::try_new) for strengths from outside the process.
+    /// 
+    /// # Panics
+    /// Panics if the strength is not a finite number in [0, 1]
     pub fn new(
         from_claim: ClaimId,
         to_claim: ClaimId,
@@ -52,14 +64,37 @@ impl Relationship {
         strength: f64,
         created_at: u64,
     ) -> Self {
-        assert!((0.0..=1.0).contains(&strength), "Strength must be in [0, 1]");
+        Self::try_new(from_claim, to_claim, relationship_type, strength, created_at)
+            .unwrap_or_else(|e| panic!("Invalid relationship: {}", e))
+    }
+
+    /// Create a new relationship, checking its strength
+    /// 
+    /// # Errors
+    /// Returns a [`ConfidenceError`] if the strength is NaN, infinite or
+    /// outside [0, 1]
+    pub fn try_new(
+        from_claim: ClaimId,
+        to_claim: ClaimId,
+        relationship_type: RelationshipType,
+        strength: f64,
+        created_at: u64,
+    ) -> Result<Self, ConfidenceError> {
+        let strength = check_unit("strength", strength)?;
         
-        Self {
+        Ok(Self {
             from_claim,
             to_claim,
             relationship_type,
             strength,
             created_at,
-        }
+        })
     }
 }
+
+/// Deserialize a strength, refusing values [`Relationship::try_new`] refuses
+#[cfg(feature = "serde")]
+fn deserialize_strength<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    let strength = <f64 as serde::Deserialize>::deserialize(deserializer)?;
+    check_unit("strength", strength).map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,219 @@
+//! Entity resolution: proposing merges of entities named differently
+//!
+//! The resolver looks at the subjects and objects of a set of claims, resolves
+//! each through the current [`AliasGraph`], and scores every pair of distinct
+//! entities of the same kind. Pairs scoring at least the threshold come back
+//! as [`MergeProposal`]s; nothing is merged until a caller records the alias.
+//!
+//! Scores come from [`name_similarity`]. With an embedder the score is the
+//! mean of the name similarity and the cosine similarity of the identifiers'
+//! embeddings, so `person:bob` and `person:robert` can surface when the model
+//! places them together. Embedding models live outside the domain; callers
+//! pass one in as a function from text to vector.
+
+use crate::alias::name_similarity;
+use crate::{AliasGraph, Claim, Entity, MergeProposal};
+use std::collections::HashMap;
+
+/// Lowest score a pair needs to be proposed, unless configured otherwise
+pub const DEFAULT_MERGE_THRESHOLD: f64 = 0.85;
+
+/// Embeds the words of an identifier, or `None` when it cannot
+pub type Embedder = Box<dyn Fn(&str) -> Option<Vec<f32>> + Send + Sync>;
+
+/// Proposes entity merges from the claims that mention them
+pub struct EntityResolver {
+    embedder: Option<Embedder>,
+    min_score: f64,
+}
+
+impl Default for EntityResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityResolver {
+    /// Create a resolver scoring names only, with [`DEFAULT_MERGE_THRESHOLD`]
+    pub fn new() -> Self {
+        Self {
+            embedder: None,
+            min_score: DEFAULT_MERGE_THRESHOLD,
+        }
+    }
+
+    /// Blend embedding similarity of the identifiers into each score
+    ///
+    /// With a store's embedding model:
+    /// `resolver.with_embedder(move |text| model.embed(text).ok())`.
+    pub fn with_embedder(mut self, embed: impl Fn(&str) -> Option<Vec<f32>> + Send + Sync + 'static) -> Self {
+        self.embedder = Some(Box::new(embed));
+        self
+    }
+
+    /// Set the lowest score a pair needs to be proposed
+    pub fn with_min_score(mut self, min_score: f64) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// The lowest score a pair needs to be proposed
+    pub fn min_score(&self) -> f64 {
+        self.min_score
+    }
+
+    /// Propose merges among the entities `claims` mention, best first
+    ///
+    /// Entities are resolved through `graph` first, so already merged
+    /// entities are considered once and never proposed again. Literals and
+    /// values that do not parse as entities are ignored. Of each pair, the
+    /// entity mentioned more often (then the longer identifier) is proposed
+    /// as the canonical one.
+    pub fn propose(&self, claims: &[Claim], graph: &AliasGraph) -> Vec<MergeProposal> {
+        self.propose_at_least(claims, graph, self.min_score)
+    }
+
+    /// Like [`propose`](Self::propose), with a threshold for this call only
+    pub fn propose_at_least(&self, claims: &[Claim], graph: &AliasGraph, min_score: f64) -> Vec<MergeProposal> {
+        let mut mentions: HashMap<String, (Entity, usize)> = HashMap::new();
+        for value in claims.iter().flat_map(|claim| [&claim.subject, &claim.object]) {
+            let Ok(entity) = Entity::parse(&graph.resolve(value)) else {
+                continue;
+            };
+            if entity.is_literal() {
+                continue;
+            }
+            mentions.entry(entity.to_string()).or_insert((entity, 0)).1 += 1;
+        }
+
+        let mut entities: Vec<(String, Entity, usize)> = mentions.into_iter()
+            .map(|(name, (entity, count))| (name, entity, count))
+            .collect();
+        entities.sort_by(|a, b| a.0.cmp(&b.0));
+        let embeddings: Vec<Option<Vec<f32>>> = entities.iter()
+            .map(|(_, entity, _)| self.embed(entity))
+            .collect();
+
+        let mut proposals = Vec::new();
+        for i in 0..entities.len() {
+            for j in (i + 1)..entities.len() {
+                let (a, b) = (&entities[i], &entities[j]);
+                if a.1.kind != b.1.kind {
+                    continue;
+                }
+
+                let names = name_similarity(&a.0, &b.0);
+                let embedding = match (&embeddings[i], &embeddings[j]) {
+                    (Some(x), Some(y)) if x.len() == y.len() => Some(cosine_similarity(x, y).clamp(0.0, 1.0)),
+                    _ => None,
+                };
+                let score = embedding.map_or(names, |embedding| (names + embedding) / 2.0);
+                if score < min_score {
+                    continue;
+                }
+
+                let (alias, canonical) = if Self::prefer(a, b) { (b, a) } else { (a, b) };
+                proposals.push(MergeProposal {
+                    alias: alias.0.clone(),
+                    canonical: canonical.0.clone(),
+                    name_similarity: names,
+                    embedding_similarity: embedding,
+                    score,
+                });
+            }
+        }
+
+        proposals.sort_by(|a, b| b.score.total_cmp(&a.score)
+            .then_with(|| a.canonical.cmp(&b.canonical))
+            .then_with(|| a.alias.cmp(&b.alias)));
+        proposals
+    }
+
+    /// Embed an identifier as words, if an embedder is configured and accepts it
+    fn embed(&self, entity: &Entity) -> Option<Vec<f32>> {
+        let embed = self.embedder.as_ref()?;
+        embed(&entity.identifier.replace(['_', '-', '.'], " "))
+    }
+
+    /// Whether `a` should be canonical over `b`
+    fn prefer(a: &(String, Entity, usize), b: &(String, Entity, usize)) -> bool {
+        (a.2, a.1.identifier.len(), &b.0) > (b.2, b.1.identifier.len(), &a.0)
+    }
+}
+
+/// Cosine similarity of two vectors of equal length, 0 when either is zero
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| f64::from(*x) * f64::from(*y)).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| f64::from(*x).powi(2)).sum::<f64>().sqrt();
+    let (norm_a, norm_b) = (norm(a), norm(b));
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ClaimId, EntityAlias, Tier};
+
+    fn claim(subject: &str, object: &str) -> Claim {
+        Claim::new(
+            ClaimId::new(),
+            "test".to_string(),
+            subject.to_string(),
+            "knows".to_string(),
+            object.to_string(),
+            (0.8, 0.9),
+            Tier::Task,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_proposes_similar_names_of_one_kind() {
+        let claims = vec![
+            claim("person:john_doe", "company:acme"),
+            claim("person:john_doe", "person:alice"),
+            claim("person:j_doe", "company:acme_corp"),
+            claim("company:john_doe", "number:42"),
+        ];
+
+        let proposals = EntityResolver::new().propose(&claims, &AliasGraph::default());
+
+        let doe = proposals.iter()
+            .find(|p| p.alias == "person:j_doe")
+            .expect("j_doe should be proposed");
+        assert_eq!(doe.canonical, "person:john_doe");
+        assert!(doe.embedding_similarity.is_none());
+        assert!(proposals.iter().all(|p| p.alias.split(':').next() == p.canonical.split(':').next()));
+        assert!(proposals.iter().all(|p| p.alias != "person:alice" && p.canonical != "person:alice"));
+        assert!(proposals.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_merged_entities_are_not_proposed_again() {
+        let claims = vec![claim("person:john_doe", "person:j_doe")];
+        let merged = EntityAlias::new("person:j_doe", "person:john_doe", "test", 0).unwrap();
+
+        let proposals = EntityResolver::new().propose(&claims, &AliasGraph::new(&[merged]));
+
+        assert!(proposals.is_empty());
+    }
+
+    #[test]
+    fn test_embedding_similarity_is_blended_into_the_score() {
+        let claims = vec![claim("person:john_doe", "person:j_doe")];
+        // Letter counts, so identifiers sharing letters embed close together
+        let resolver = EntityResolver::new()
+            .with_embedder(|text| Some(('a'..='z').map(|c| text.matches(c).count() as f32).collect()))
+            .with_min_score(0.0);
+
+        let proposals = resolver.propose(&claims, &AliasGraph::default());
+
+        assert_eq!(proposals.len(), 1);
+        let embedding = proposals[0].embedding_similarity.expect("model was configured");
+        let expected = (proposals[0].name_similarity + embedding) / 2.0;
+        assert!((proposals[0].score - expected).abs() < 1e-9);
+    }
+}
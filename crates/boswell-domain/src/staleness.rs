@@ -0,0 +1,285 @@
+//! Staleness policies - when claims go stale and how fast they decay
+//!
+//! A claim is fresh until its `stale_at`, after which the confidence formula
+//! halves its interval every half-life (see
+//! [`confidence_computation`](crate::confidence_computation)). A
+//! [`StalenessPolicy`] supplies both: a rule per tier, overridden for
+//! namespace subtrees. Stores use it to set `stale_at` when a claim is
+//! inserted and to push it back when the claim is corroborated.
+//!
+//! Like claim timestamps, every time here is in seconds.
+
+use crate::claim::Claim;
+use crate::namespace::{self, SEPARATOR};
+use crate::tier::Tier;
+
+/// One hour in seconds
+pub const HOUR_SECS: u64 = 60 * 60;
+
+/// One day in seconds
+pub const DAY_SECS: u64 = 24 * HOUR_SECS;
+
+/// How long a claim stays fresh, and how fast its confidence decays afterwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StalenessRule {
+    /// Time from assertion or last corroboration until the claim is stale (None = never stale)
+    pub ttl_secs: Option<u64>,
+
+    /// Time for confidence to halve once the claim is stale
+    pub half_life_secs: u64,
+}
+
+impl StalenessRule {
+    /// A rule that marks claims stale `ttl_secs` seconds after they are written
+    pub fn new(ttl_secs: u64, half_life_secs: u64) -> Self {
+        Self { ttl_secs: Some(ttl_secs), half_life_secs }
+    }
+
+    /// A rule under which claims never go stale
+    pub fn never_stale(half_life_secs: u64) -> Self {
+        Self { ttl_secs: None, half_life_secs }
+    }
+
+    /// The built-in rule for a tier
+    ///
+    /// Claims stay fresh for one half-life: 4 hours for ephemeral, 3 days
+    /// for task, 4 weeks for project and 6 months for permanent claims.
+    pub fn default_for(tier: Tier) -> Self {
+        let half_life_secs = match tier {
+            Tier::Ephemeral => 4 * HOUR_SECS,
+            Tier::Task => 3 * DAY_SECS,
+            Tier::Project => 28 * DAY_SECS,
+            Tier::Permanent => 182 * DAY_SECS,
+        };
+        Self::new(half_life_secs, half_life_secs)
+    }
+
+    /// When a claim written at `written_at` (Unix seconds) goes stale under this rule
+    pub fn stale_at(&self, written_at: u64) -> Option<u64> {
+        self.ttl_secs.map(|ttl| written_at.saturating_add(ttl))
+    }
+}
+
+/// A rule for one namespace subtree, optionally limited to one tier
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NamespaceRule {
+    namespace: String,
+    tier: Option<Tier>,
+    rule: StalenessRule,
+}
+
+/// Staleness rules per tier, with overrides for namespace subtrees
+///
+/// The rule for a claim comes from the override on the deepest namespace
+/// containing the claim's namespace, preferring an override for the claim's
+/// tier over one for every tier at the same namespace. Claims no override
+/// covers use their tier's rule.
+///
+/// # Examples
+///
+/// ```
+/// use boswell_domain::{StalenessPolicy, StalenessRule, Tier};
+/// use boswell_domain::staleness::{DAY_SECS, HOUR_SECS};
+///
+/// let policy = StalenessPolicy::default()
+///     .with_namespace("news", StalenessRule::new(HOUR_SECS, HOUR_SECS))
+///     .with_namespace_tier("news/archive", Tier::Permanent, StalenessRule::never_stale(DAY_SECS));
+///
+/// assert_eq!(policy.rule("news/today", Tier::Task).ttl_secs, Some(HOUR_SECS));
+/// assert_eq!(policy.rule("news/archive", Tier::Permanent).ttl_secs, None);
+/// assert_eq!(policy.rule("news/archive", Tier::Task).ttl_secs, Some(HOUR_SECS));
+/// assert_eq!(policy.rule("docs", Tier::Task), StalenessRule::default_for(Tier::Task));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StalenessPolicy {
+    /// Indexed like [`Tier::ALL`]
+    tiers: [StalenessRule; 4],
+    namespaces: Vec<NamespaceRule>,
+}
+
+impl Default for StalenessPolicy {
+    fn default() -> Self {
+        Self {
+            tiers: Tier::ALL.map(StalenessRule::default_for),
+            namespaces: Vec::new(),
+        }
+    }
+}
+
+impl StalenessPolicy {
+    /// A policy under which no claim goes stale
+    ///
+    /// Half-lives keep their defaults, for claims given a `stale_at` explicitly.
+    pub fn disabled() -> Self {
+        Self {
+            tiers: Tier::ALL.map(|tier| StalenessRule::never_stale(StalenessRule::default_for(tier).half_life_secs)),
+            namespaces: Vec::new(),
+        }
+    }
+
+    /// Replace the rule for a tier
+    pub fn with_tier(mut self, tier: Tier, rule: StalenessRule) -> Self {
+        self.tiers[tier_index(tier)] = rule;
+        self
+    }
+
+    /// Apply `rule` to every tier in the subtree rooted at `namespace`
+    pub fn with_namespace(self, namespace: impl Into<String>, rule: StalenessRule) -> Self {
+        self.with_override(namespace.into(), None, rule)
+    }
+
+    /// Apply `rule` to claims of `tier` in the subtree rooted at `namespace`
+    pub fn with_namespace_tier(self, namespace: impl Into<String>, tier: Tier, rule: StalenessRule) -> Self {
+        self.with_override(namespace.into(), Some(tier), rule)
+    }
+
+    fn with_override(mut self, namespace: String, tier: Option<Tier>, rule: StalenessRule) -> Self {
+        let namespace = namespace.trim_end_matches(SEPARATOR).to_string();
+        self.namespaces.retain(|existing| existing.namespace != namespace || existing.tier != tier);
+        self.namespaces.push(NamespaceRule { namespace, tier, rule });
+        self
+    }
+
+    /// The rule for claims of `tier` in `namespace`
+    pub fn rule(&self, namespace: &str, tier: Tier) -> StalenessRule {
+        self.namespaces.iter()
+            .filter(|o| o.tier.is_none_or(|t| t == tier) && namespace::in_subtree(namespace, &o.namespace))
+            .max_by_key(|o| (depth(&o.namespace), o.tier.is_some()))
+            .map(|o| o.rule)
+            .unwrap_or(self.tiers[tier_index(tier)])
+    }
+
+    /// The rule for `claim`
+    pub fn rule_for(&self, claim: &Claim) -> StalenessRule {
+        self.rule(&claim.namespace, claim.tier)
+    }
+
+    /// When `claim`, written at `written_at`, goes stale
+    pub fn stale_at(&self, claim: &Claim, written_at: u64) -> Option<u64> {
+        self.rule_for(claim).stale_at(written_at)
+    }
+
+    /// `stale_at` to store for a newly inserted claim
+    ///
+    /// An explicit `stale_at` is kept; otherwise the claim goes stale its
+    /// rule's TTL after `created_at`.
+    pub fn stale_at_on_insert(&self, claim: &Claim) -> Option<u64> {
+        claim.stale_at.or_else(|| self.stale_at(claim, claim.created_at))
+    }
+
+    /// `stale_at` for the stored claim `existing` once corroborated at `corroborated_at`
+    ///
+    /// Corroboration restarts the clock, but never brings `stale_at` forward
+    /// and never starts one for a claim that does not go stale.
+    pub fn stale_at_on_corroboration(&self, existing: &Claim, corroborated_at: u64) -> Option<u64> {
+        let current = existing.stale_at?;
+        Some(self.stale_at(existing, corroborated_at).map_or(current, |refreshed| refreshed.max(current)))
+    }
+
+    /// Half-life of `claim`'s confidence once it is stale
+    pub fn half_life_secs(&self, claim: &Claim) -> u64 {
+        self.rule_for(claim).half_life_secs
+    }
+}
+
+fn tier_index(tier: Tier) -> usize {
+    Tier::ALL.iter().position(|&t| t == tier).expect("Tier::ALL lists every tier")
+}
+
+/// Number of segments, with the empty root namespace at depth 0
+fn depth(namespace: &str) -> usize {
+    if namespace.is_empty() {
+        0
+    } else {
+        namespace.split(SEPARATOR).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClaimId;
+
+    fn claim(namespace: &str, tier: Tier) -> Claim {
+        Claim {
+            id: ClaimId::new(),
+            namespace: namespace.to_string(),
+            subject: "Alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.9),
+            tier,
+            created_at: 1000,
+            stale_at: None,
+        }
+    }
+
+    #[test]
+    fn test_default_rules_by_tier() {
+        let policy = StalenessPolicy::default();
+
+        assert_eq!(policy.stale_at(&claim("acme", Tier::Ephemeral), 1000), Some(1000 + 4 * HOUR_SECS));
+        assert_eq!(policy.half_life_secs(&claim("acme", Tier::Task)), 3 * DAY_SECS);
+        assert!(policy.rule("acme", Tier::Permanent).half_life_secs > policy.rule("acme", Tier::Project).half_life_secs);
+        assert_eq!(StalenessPolicy::disabled().stale_at(&claim("acme", Tier::Ephemeral), 1000), None);
+    }
+
+    #[test]
+    fn test_stale_at_on_write() {
+        let policy = StalenessPolicy::default().with_namespace("acme", StalenessRule::new(100, 100));
+        let mut stored = claim("acme", Tier::Task);
+
+        assert_eq!(policy.stale_at_on_insert(&stored), Some(1100));
+        stored.stale_at = Some(5000);
+        assert_eq!(policy.stale_at_on_insert(&stored), Some(5000));
+
+        // Corroboration pushes stale_at back, never forward
+        assert_eq!(policy.stale_at_on_corroboration(&stored, 6000), Some(6100));
+        assert_eq!(policy.stale_at_on_corroboration(&stored, 2000), Some(5000));
+
+        stored.stale_at = None;
+        assert_eq!(policy.stale_at_on_corroboration(&stored, 6000), None);
+    }
+
+    #[test]
+    fn test_stale_at_for_seconds_timestamp() {
+        let mut stored = claim("acme", Tier::Ephemeral);
+        stored.created_at = 1_700_000_000;
+
+        // Four hours later, not four hours' worth of milliseconds
+        assert_eq!(StalenessPolicy::default().stale_at_on_insert(&stored), Some(1_700_000_000 + 4 * 60 * 60));
+    }
+
+    #[test]
+    fn test_deepest_namespace_override_wins() {
+        let short = StalenessRule::new(10, 10);
+        let shorter = StalenessRule::new(5, 5);
+        let policy = StalenessPolicy::default()
+            .with_tier(Tier::Task, StalenessRule::new(100, 100))
+            .with_namespace("acme", short)
+            .with_namespace_tier("acme/eng", Tier::Ephemeral, shorter);
+
+        assert_eq!(policy.rule("other", Tier::Task), StalenessRule::new(100, 100));
+        assert_eq!(policy.rule("acme/eng/infra", Tier::Task), short);
+        assert_eq!(policy.rule("acme/eng/infra", Tier::Ephemeral), shorter);
+        assert_eq!(policy.rule("acme-corp", Tier::Ephemeral), StalenessRule::default_for(Tier::Ephemeral));
+
+        // A root override covers every namespace, below more specific ones
+        let policy = policy.with_namespace("", StalenessRule::never_stale(1));
+        assert_eq!(policy.rule("other", Tier::Task).ttl_secs, None);
+        assert_eq!(policy.rule("acme", Tier::Task), short);
+    }
+
+    #[test]
+    fn test_tier_override_beats_namespace_wide_rule() {
+        let wide = StalenessRule::new(10, 10);
+        let narrow = StalenessRule::new(20, 20);
+        let policy = StalenessPolicy::default()
+            .with_namespace_tier("acme/", Tier::Project, narrow)
+            .with_namespace("acme", wide)
+            .with_namespace("acme", StalenessRule::new(30, 30));
+
+        assert_eq!(policy.rule("acme", Tier::Project), narrow);
+        assert_eq!(policy.rule("acme", Tier::Task), StalenessRule::new(30, 30));
+    }
+}
@@ -1,4 +1,10 @@
 //! Tier module - lifecycle stages for claims
+//!
+//! [`Tier::as_str`] and [`Tier::parse`] are the one mapping between tiers and
+//! their names (`ephemeral`, `task`, `project`, `permanent`); `Display`,
+//! `FromStr` and the optional serde support all go through them.
+
+use std::fmt;
 
 /// Tier in the claim lifecycle
 /// 
@@ -7,7 +13,9 @@
 /// - Task: Medium-term, specific task context
 /// - Project: Long-term, project-level knowledge
 /// - Permanent: Indefinite, core knowledge
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Tiers are ordered from shortest- to longest-lived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Tier {
     /// Short-lived claims (hours to days)
     Ephemeral,
@@ -23,6 +31,9 @@ pub enum Tier {
 }
 
 impl Tier {
+    /// Every tier, shortest-lived first
+    pub const ALL: [Tier; 4] = [Tier::Ephemeral, Tier::Task, Tier::Project, Tier::Permanent];
+
     /// Get the tier name as a string
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -33,7 +44,7 @@ impl Tier {
         }
     }
 
-    /// Parse a tier from a string (internal use)
+    /// Parse a tier from its name, ignoring case
     pub fn parse(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "ephemeral" => Some(Tier::Ephemeral),
@@ -65,11 +76,50 @@ impl Tier {
     }
 }
 
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl std::str::FromStr for Tier {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::parse(s).ok_or_else(|| format!("Invalid tier: {}", s))
+        Self::parse(s).ok_or_else(|| {
+            format!("Invalid tier '{}' (expected ephemeral, task, project or permanent)", s)
+        })
+    }
+}
+
+/// Serialized as the lowercase tier name; deserialized case-insensitively
+#[cfg(feature = "serde")]
+impl serde::Serialize for Tier {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Tier {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Tier {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Tier".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Lifecycle tier, shortest-lived first",
+            "type": "string",
+            "enum": Tier::ALL.map(|tier| tier.as_str()),
+        })
     }
 }
 
@@ -85,6 +135,15 @@ mod tests {
         assert_eq!(Tier::Permanent.next(), None);
     }
 
+    #[test]
+    fn test_tier_names() {
+        for tier in Tier::ALL {
+            assert_eq!(tier.to_string().parse::<Tier>(), Ok(tier));
+        }
+        assert_eq!("Permanent".parse::<Tier>(), Ok(Tier::Permanent));
+        assert!("session".parse::<Tier>().unwrap_err().contains("expected ephemeral, task, project or permanent"));
+    }
+
     #[test]
     fn test_tier_demotion() {
         assert_eq!(Tier::Permanent.previous(), Some(Tier::Project));
@@ -3,7 +3,14 @@
 //! These traits define the boundaries between domain logic and infrastructure.
 //! Infrastructure implementations live in other crates.
 
-use crate::{Claim, ClaimId, Relationship};
+use crate::confidence_computation::{self, ConfidenceConfig, RelatedClaimData};
+use crate::{
+    Claim, ClaimId, ConfidenceInterval, EntityAlias, Literal, NamespaceStats, ProvenanceEntry, Relationship, RelationshipType,
+    StalenessPolicy, StoreEvent, Tier,
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
 
 /// Trait for storing and retrieving claims
 /// 
@@ -13,29 +20,582 @@ pub trait ClaimStore {
     type Error;
 
     /// Assert a new claim into the store
-    fn assert_claim(&mut self, claim: Claim) -> Result<ClaimId, Self::Error>;
+    ///
+    /// If a claim with the same normalized content (see [`Claim::content_hash`])
+    /// already exists, no new claim is created: the assertion corroborates the
+    /// existing claim and `AssertOutcome::Corroborated` is returned.
+    fn assert_claim(&mut self, claim: Claim) -> Result<AssertOutcome, Self::Error>;
+
+    /// Assert a claim together with the provenance of this assertion
+    ///
+    /// On corroboration the provenance is appended to the existing claim.
+    /// The default implementation discards the provenance.
+    fn assert_claim_with_provenance(
+        &mut self,
+        claim: Claim,
+        _provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, Self::Error> {
+        self.assert_claim(claim)
+    }
+
+    /// Assert a batch of claims and relationships
+    ///
+    /// Each item reports its own result. With `all_or_nothing` set, the first
+    /// failure aborts the batch with an error instead. Relationships that point
+    /// at a claim in the batch follow it to the claim it corroborated.
+    ///
+    /// The default implementation writes items one at a time and cannot roll
+    /// back items written before an all-or-nothing failure; transactional
+    /// stores should override it.
+    fn assert_batch(&mut self, batch: ClaimBatch) -> Result<BatchOutcome<Self::Error>, Self::Error> {
+        let mut outcome = BatchOutcome::default();
+        let mut resolved_ids = Vec::new();
+        
+        for item in batch.claims {
+            let asserted_id = item.claim.id;
+            match self.assert_claim_with_provenance(item.claim, item.provenance) {
+                Ok(result) => {
+                    resolved_ids.push((asserted_id, result.claim_id()));
+                    outcome.claims.push(Ok(result));
+                }
+                Err(e) if batch.all_or_nothing => return Err(e),
+                Err(e) => outcome.claims.push(Err(e)),
+            }
+        }
+        
+        let resolve = |id: ClaimId| {
+            resolved_ids.iter()
+                .find(|(asserted, _)| *asserted == id)
+                .map_or(id, |(_, stored)| *stored)
+        };
+        
+        for mut relationship in batch.relationships {
+            relationship.from_claim = resolve(relationship.from_claim);
+            relationship.to_claim = resolve(relationship.to_claim);
+            match self.add_relationship(relationship) {
+                Ok(()) => outcome.relationships.push(Ok(())),
+                Err(e) if batch.all_or_nothing => return Err(e),
+                Err(e) => outcome.relationships.push(Err(e)),
+            }
+        }
+        
+        Ok(outcome)
+    }
 
     /// Get a claim by ID
     fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error>;
 
+    /// Get the provenance recorded for a claim, oldest first
+    ///
+    /// Stores that do not track provenance return an empty list.
+    fn get_provenance(&self, _id: ClaimId) -> Result<Vec<ProvenanceEntry>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    /// Get the provenance of several claims at once, keyed by claim
+    ///
+    /// Claims without provenance are left out. The default implementation
+    /// calls [`get_provenance`](Self::get_provenance) per claim; stores should
+    /// override it with a single lookup.
+    fn get_provenance_batch(&self, ids: &[ClaimId]) -> Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error> {
+        let mut provenance = HashMap::new();
+        for &id in ids {
+            let entries = self.get_provenance(id)?;
+            if !entries.is_empty() {
+                provenance.insert(id, entries);
+            }
+        }
+        Ok(provenance)
+    }
+
+    /// Find the claim whose normalized content hash matches `content_hash`
+    ///
+    /// The default implementation scans all claims; stores should override it
+    /// with an indexed lookup.
+    fn find_by_content_hash(&self, content_hash: &str) -> Result<Option<Claim>, Self::Error> {
+        Ok(self.query_claims(&ClaimQuery::default())?
+            .into_iter()
+            .find(|claim| claim.content_hash() == content_hash))
+    }
+
     /// Query claims matching criteria
+    ///
+    /// Results are ordered by `created_at`, then by ID, and `after` and
+    /// `limit` apply after ordering.
     fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error>;
 
     /// Add a relationship between claims
+    ///
+    /// A relationship with the same endpoints and type replaces the existing
+    /// one's strength and timestamp.
     fn add_relationship(&mut self, relationship: Relationship) -> Result<(), Self::Error>;
 
-    /// Get relationships for a claim
+    /// Get relationships in which the claim is either endpoint, in the order they were first added
     fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, Self::Error>;
+
+    /// Get relationships in which any of the claims is either endpoint, each once
+    ///
+    /// The default implementation calls [`get_relationships`](Self::get_relationships)
+    /// per claim; stores should override it with a single lookup.
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> Result<Vec<Relationship>, Self::Error> {
+        let mut relationships = Vec::new();
+        for &id in ids {
+            // A relationship between two of the claims is listed under its source
+            relationships.extend(self.get_relationships(id)?
+                .into_iter()
+                .filter(|r| r.from_claim == id || !ids.contains(&r.from_claim)));
+        }
+        Ok(relationships)
+    }
+
+    /// Move a claim to `tier`, recording a promotion or demotion event
+    ///
+    /// Returns `true` if the tier changed, and `false` if the claim was
+    /// already in `tier`. Stores must fail when the claim does not exist.
+    fn set_tier(&mut self, id: ClaimId, tier: Tier) -> Result<bool, Self::Error>;
+
+    /// Permanently delete a claim with its provenance and relationships, recording an eviction event
+    ///
+    /// Returns `true` if the claim existed.
+    fn delete_claim(&mut self, id: ClaimId) -> Result<bool, Self::Error>;
+
+    /// Compact secondary indexes whose tombstone ratio has reached `threshold`
+    ///
+    /// Returns the number of tombstoned entries reclaimed. Stores without
+    /// secondary indexes can rely on the default no-op.
+    fn compact_indexes(&mut self, _threshold: f64) -> Result<usize, Self::Error> {
+        Ok(0)
+    }
+
+    /// Read up to `limit` events with a sequence number above `after`, oldest first
+    ///
+    /// Pass 0 to read from the start of the log, then the `seq` of the last
+    /// event seen to resume. Stores that do not keep an event log return an
+    /// empty list.
+    fn events_since(&self, _after: u64, _limit: usize) -> Result<Vec<StoreEvent>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    /// Statistics for every namespace under `root` that holds claims
+    ///
+    /// One entry per namespace, counting only the claims stored directly in
+    /// it, [sorted](NamespaceStats::sort); use [`NamespaceStats::rollup`] for
+    /// subtree totals. An empty root covers the whole store. The default
+    /// implementation reads every claim in the subtree with its provenance and
+    /// relationships; stores should override it with an aggregate query.
+    fn namespace_stats(&self, root: &str) -> Result<Vec<NamespaceStats>, Self::Error> {
+        let query = ClaimQuery { namespace: Some(root.to_string()), ..Default::default() };
+        let mut stats: HashMap<String, NamespaceStats> = HashMap::new();
+
+        for claim in self.query_claims(&query)? {
+            let last_write = self.get_provenance(claim.id)?
+                .iter()
+                .map(|entry| entry.timestamp)
+                .fold(claim.created_at, u64::max);
+            let contradictions = self.get_relationships(claim.id)?
+                .iter()
+                .filter(|r| r.from_claim == claim.id && r.relationship_type == RelationshipType::Contradicts)
+                .count();
+
+            let entry = stats.entry(claim.namespace.clone())
+                .or_insert_with(|| NamespaceStats::new(claim.namespace.clone()));
+            entry.record_claim(&claim, last_write);
+            entry.contradictions += contradictions as u64;
+        }
+
+        let mut stats: Vec<NamespaceStats> = stats.into_values().collect();
+        NamespaceStats::sort(&mut stats);
+        Ok(stats)
+    }
+
+    /// Move every claim in the subtree at `from` to the same place under `to`
+    ///
+    /// `a/b/c` moved from `a/b` to `x` becomes `x/c`. Claims keep their IDs,
+    /// provenance and relationships; their content hashes are recomputed for
+    /// the new namespace. Each moved claim gets an [`EventKind::Moved`](crate::EventKind::Moved)
+    /// event carrying its new namespace. The move is atomic and returns the
+    /// number of claims moved.
+    ///
+    /// Stores must refuse the move, changing nothing, when the namespaces
+    /// overlap (see [`namespace::check_move`](crate::namespace::check_move))
+    /// or when the subtree at `to` already holds claims. Stores that cannot
+    /// rewrite namespaces keep the default, which moves nothing and returns 0.
+    fn move_namespace(&mut self, _from: &str, _to: &str) -> Result<usize, Self::Error> {
+        Ok(0)
+    }
+
+    /// Record that two entities are the same (see [`EntityAlias`])
+    ///
+    /// Stores must refuse the alias, changing nothing, when
+    /// [`AliasGraph::check_add`](crate::AliasGraph::check_add) rejects it
+    /// against the active aliases. Stores that do not keep aliases discard it.
+    fn add_alias(&mut self, _alias: EntityAlias) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Undo the active merge of `alias`, stamping its record as retracted
+    ///
+    /// The record is kept for auditing. Returns `true` if `alias` had an
+    /// active merge; stores that do not keep aliases have none to retract.
+    fn retract_alias(&mut self, _alias: &str) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    /// Alias records in the order they were added, retracted ones only with `include_retracted`
+    ///
+    /// Stores that do not keep aliases return an empty list.
+    fn entity_aliases(&self, _include_retracted: bool) -> Result<Vec<EntityAlias>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    /// Policy deciding when this store's claims go stale and how fast they decay
+    ///
+    /// Stores with a configurable policy return theirs; the default is
+    /// [`StalenessPolicy::default`].
+    fn staleness_policy(&self) -> StalenessPolicy {
+        StalenessPolicy::default()
+    }
+
+    /// Effective confidence of a claim at `now` (Unix seconds), or `None` if it does not exist
+    ///
+    /// See [`stored_claim_confidence`]. Stores that keep a confidence cache
+    /// override this to record the result.
+    fn effective_confidence(&self, id: ClaimId, now: u64) -> Result<Option<ConfidenceInterval>, Self::Error> {
+        match self.get_claim(id)? {
+            Some(claim) => stored_claim_confidence(self, &claim, now).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Effective confidence of `claim` at `now` (Unix seconds) from what `store` records
+///
+/// Runs [`compute_claim_confidence`](confidence_computation::compute_claim_confidence)
+/// from the claim's stored interval with the claims supporting or
+/// contradicting it, under the store's [staleness policy](ClaimStore::staleness_policy).
+/// A contradiction counts in either direction, support only towards its target.
+pub fn stored_claim_confidence<S: ClaimStore + ?Sized>(
+    store: &S,
+    claim: &Claim,
+    now: u64,
+) -> Result<ConfidenceInterval, S::Error> {
+    let config = ConfidenceConfig { staleness: store.staleness_policy(), ..ConfidenceConfig::default() };
+
+    let mut related = Vec::new();
+    for relationship in store.get_relationships(claim.id)? {
+        let other = match relationship.relationship_type {
+            RelationshipType::Supports if relationship.to_claim == claim.id => relationship.from_claim,
+            RelationshipType::Contradicts if relationship.to_claim == claim.id => relationship.from_claim,
+            RelationshipType::Contradicts => relationship.to_claim,
+            _ => continue,
+        };
+        if let Some(other) = store.get_claim(other)? {
+            let stale_confidence = confidence_computation::stale_confidence(&other, now, &config.staleness);
+            related.push((relationship, RelatedClaimData { stale_confidence }));
+        }
+    }
+
+    Ok(confidence_computation::compute_claim_confidence(claim, now, &related, &config))
+}
+
+/// A store shared behind a mutex is itself a store
+///
+/// Every call, reads included, takes the lock for its duration. Clones share
+/// the store, so `Arc<Mutex<S>>` can be handed out wherever a cloneable store
+/// is expected; stores with their own concurrency control (such as a pooled
+/// SQLite store) should be shared directly instead.
+impl<S: ClaimStore> ClaimStore for Arc<Mutex<S>> {
+    type Error = S::Error;
+
+    fn assert_claim(&mut self, claim: Claim) -> Result<AssertOutcome, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).assert_claim(claim)
+    }
+
+    fn assert_claim_with_provenance(
+        &mut self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).assert_claim_with_provenance(claim, provenance)
+    }
+
+    fn assert_batch(&mut self, batch: ClaimBatch) -> Result<BatchOutcome<Self::Error>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).assert_batch(batch)
+    }
+
+    fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).get_claim(id)
+    }
+
+    fn get_provenance(&self, id: ClaimId) -> Result<Vec<ProvenanceEntry>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).get_provenance(id)
+    }
+
+    fn get_provenance_batch(&self, ids: &[ClaimId]) -> Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).get_provenance_batch(ids)
+    }
+
+    fn find_by_content_hash(&self, content_hash: &str) -> Result<Option<Claim>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).find_by_content_hash(content_hash)
+    }
+
+    fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).query_claims(query)
+    }
+
+    fn add_relationship(&mut self, relationship: Relationship) -> Result<(), Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).add_relationship(relationship)
+    }
+
+    fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).get_relationships(id)
+    }
+
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> Result<Vec<Relationship>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).get_relationships_batch(ids)
+    }
+
+    fn set_tier(&mut self, id: ClaimId, tier: Tier) -> Result<bool, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).set_tier(id, tier)
+    }
+
+    fn delete_claim(&mut self, id: ClaimId) -> Result<bool, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).delete_claim(id)
+    }
+
+    fn compact_indexes(&mut self, threshold: f64) -> Result<usize, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).compact_indexes(threshold)
+    }
+
+    fn events_since(&self, after: u64, limit: usize) -> Result<Vec<StoreEvent>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).events_since(after, limit)
+    }
+
+    fn namespace_stats(&self, root: &str) -> Result<Vec<NamespaceStats>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).namespace_stats(root)
+    }
+
+    fn move_namespace(&mut self, from: &str, to: &str) -> Result<usize, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).move_namespace(from, to)
+    }
+
+    fn add_alias(&mut self, alias: EntityAlias) -> Result<(), Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).add_alias(alias)
+    }
+
+    fn retract_alias(&mut self, alias: &str) -> Result<bool, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).retract_alias(alias)
+    }
+
+    fn entity_aliases(&self, include_retracted: bool) -> Result<Vec<EntityAlias>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).entity_aliases(include_retracted)
+    }
+
+    fn staleness_policy(&self) -> StalenessPolicy {
+        self.lock().unwrap_or_else(PoisonError::into_inner).staleness_policy()
+    }
+
+    fn effective_confidence(&self, id: ClaimId, now: u64) -> Result<Option<ConfidenceInterval>, Self::Error> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).effective_confidence(id, now)
+    }
+}
+
+/// Async counterpart of [`ClaimStore`] for callers running on an async runtime
+///
+/// Methods take `&self`, so one handle can serve concurrent tasks; the store
+/// is responsible for its own synchronization. Futures are `Send` so they can
+/// be awaited from multi-threaded runtimes such as tonic's. Synchronous stores
+/// are adapted by running each call on a blocking thread pool (see
+/// `boswell_store::BlockingStore`) rather than on the async worker threads.
+pub trait AsyncClaimStore {
+    /// Error type for store operations
+    type Error;
+
+    /// Assert a claim, corroborating an existing duplicate (see [`ClaimStore::assert_claim`])
+    fn assert_claim(&self, claim: Claim) -> impl Future<Output = Result<AssertOutcome, Self::Error>> + Send;
+
+    /// Assert a claim together with the provenance of this assertion
+    fn assert_claim_with_provenance(
+        &self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> impl Future<Output = Result<AssertOutcome, Self::Error>> + Send;
+
+    /// Assert a batch of claims and relationships (see [`ClaimStore::assert_batch`])
+    fn assert_batch(
+        &self,
+        batch: ClaimBatch,
+    ) -> impl Future<Output = Result<BatchOutcome<Self::Error>, Self::Error>> + Send;
+
+    /// Get a claim by ID
+    fn get_claim(&self, id: ClaimId) -> impl Future<Output = Result<Option<Claim>, Self::Error>> + Send;
+
+    /// Get the provenance recorded for a claim, oldest first
+    fn get_provenance(&self, id: ClaimId) -> impl Future<Output = Result<Vec<ProvenanceEntry>, Self::Error>> + Send;
+
+    /// Get the provenance of several claims at once (see [`ClaimStore::get_provenance_batch`])
+    fn get_provenance_batch(
+        &self,
+        ids: &[ClaimId],
+    ) -> impl Future<Output = Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error>> + Send;
+
+    /// Find the claim whose normalized content hash matches `content_hash`
+    fn find_by_content_hash(&self, content_hash: &str) -> impl Future<Output = Result<Option<Claim>, Self::Error>> + Send;
+
+    /// Query claims matching criteria
+    fn query_claims(&self, query: &ClaimQuery) -> impl Future<Output = Result<Vec<Claim>, Self::Error>> + Send;
+
+    /// Add a relationship between claims
+    fn add_relationship(&self, relationship: Relationship) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Get relationships for a claim
+    fn get_relationships(&self, id: ClaimId) -> impl Future<Output = Result<Vec<Relationship>, Self::Error>> + Send;
+
+    /// Get relationships of several claims at once (see [`ClaimStore::get_relationships_batch`])
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> impl Future<Output = Result<Vec<Relationship>, Self::Error>> + Send;
+
+    /// Compact secondary indexes whose tombstone ratio has reached `threshold`
+    fn compact_indexes(&self, threshold: f64) -> impl Future<Output = Result<usize, Self::Error>> + Send;
+
+    /// Read events after sequence number `after` (see [`ClaimStore::events_since`])
+    fn events_since(&self, after: u64, limit: usize) -> impl Future<Output = Result<Vec<StoreEvent>, Self::Error>> + Send;
+
+    /// Per-namespace statistics under `root` (see [`ClaimStore::namespace_stats`])
+    fn namespace_stats(&self, root: &str) -> impl Future<Output = Result<Vec<NamespaceStats>, Self::Error>> + Send;
+
+    /// Move a namespace subtree (see [`ClaimStore::move_namespace`])
+    fn move_namespace(&self, from: &str, to: &str) -> impl Future<Output = Result<usize, Self::Error>> + Send;
+
+    /// Record that two entities are the same (see [`ClaimStore::add_alias`])
+    fn add_alias(&self, alias: EntityAlias) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Undo the active merge of `alias` (see [`ClaimStore::retract_alias`])
+    fn retract_alias(&self, alias: &str) -> impl Future<Output = Result<bool, Self::Error>> + Send;
+
+    /// Alias records in the order they were added (see [`ClaimStore::entity_aliases`])
+    fn entity_aliases(&self, include_retracted: bool) -> impl Future<Output = Result<Vec<EntityAlias>, Self::Error>> + Send;
+
+    /// Effective confidence of a claim at `now` (see [`ClaimStore::effective_confidence`])
+    fn effective_confidence(
+        &self,
+        id: ClaimId,
+        now: u64,
+    ) -> impl Future<Output = Result<Option<ConfidenceInterval>, Self::Error>> + Send;
+}
+
+/// Outcome of asserting a claim
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssertOutcome {
+    /// The claim was stored as a new claim
+    Inserted(ClaimId),
+
+    /// A claim with the same content already exists and was corroborated
+    Corroborated(ClaimId),
+}
+
+impl AssertOutcome {
+    /// ID of the stored claim (the new one, or the corroborated one)
+    pub fn claim_id(&self) -> ClaimId {
+        match self {
+            AssertOutcome::Inserted(id) | AssertOutcome::Corroborated(id) => *id,
+        }
+    }
+
+    /// Whether the assertion corroborated an existing claim
+    pub fn is_corroborated(&self) -> bool {
+        matches!(self, AssertOutcome::Corroborated(_))
+    }
+}
+
+/// A claim to write as part of a [`ClaimBatch`], with the provenance of this assertion
+#[derive(Debug, Clone)]
+pub struct BatchClaim {
+    /// The claim to assert
+    pub claim: Claim,
+
+    /// Provenance to record for the claim (or to append on corroboration)
+    pub provenance: Vec<ProvenanceEntry>,
+}
+
+impl From<Claim> for BatchClaim {
+    fn from(claim: Claim) -> Self {
+        Self { claim, provenance: Vec::new() }
+    }
+}
+
+/// Claims and relationships to write together (per ADR-018)
+#[derive(Debug, Clone, Default)]
+pub struct ClaimBatch {
+    /// Claims to assert, in order
+    pub claims: Vec<BatchClaim>,
+
+    /// Relationships to add once the claims are written
+    pub relationships: Vec<Relationship>,
+
+    /// Roll back the whole batch if any item fails
+    pub all_or_nothing: bool,
+}
+
+/// Per-item results of [`ClaimStore::assert_batch`], in batch order
+#[derive(Debug)]
+pub struct BatchOutcome<E> {
+    /// One result per claim in the batch
+    pub claims: Vec<Result<AssertOutcome, E>>,
+
+    /// One result per relationship in the batch
+    pub relationships: Vec<Result<(), E>>,
+}
+
+impl<E> Default for BatchOutcome<E> {
+    fn default() -> Self {
+        Self { claims: Vec::new(), relationships: Vec::new() }
+    }
+}
+
+impl<E> BatchOutcome<E> {
+    /// Number of claims inserted as new claims
+    pub fn inserted_count(&self) -> usize {
+        self.claims.iter()
+            .filter(|result| matches!(result, Ok(AssertOutcome::Inserted(_))))
+            .count()
+    }
+
+    /// Number of claims that corroborated an existing claim
+    pub fn corroborated_count(&self) -> usize {
+        self.claims.iter()
+            .filter(|result| matches!(result, Ok(AssertOutcome::Corroborated(_))))
+            .count()
+    }
+
+    /// Number of claims and relationships that failed
+    pub fn error_count(&self) -> usize {
+        self.claims.iter().filter(|result| result.is_err()).count()
+            + self.relationships.iter().filter(|result| result.is_err()).count()
+    }
 }
 
 /// Query criteria for retrieving claims
 #[derive(Debug, Clone, Default)]
 pub struct ClaimQuery {
-    /// Filter by namespace prefix
+    /// Filter to a namespace and its descendants, matching whole segments
+    /// (`project` includes `project/x` but not `projectX`)
     pub namespace: Option<String>,
     
     /// Filter by tier
-    pub tier: Option<String>,
+    pub tier: Option<Tier>,
+    
+    /// Filter by subject entity kind (`person` matches every `person:*` subject)
+    pub subject_kind: Option<String>,
+    
+    /// Filter by object entity kind
+    pub object_kind: Option<String>,
+    
+    /// Only objects that are literals of the same datatype ordered after this one
+    /// (after the end of its period for a partial date)
+    pub object_after: Option<Literal>,
+    
+    /// Only objects that are literals of the same datatype ordered before this one
+    pub object_before: Option<Literal>,
     
     /// Filter by minimum confidence
     pub min_confidence: Option<f64>,
@@ -43,6 +603,10 @@ pub struct ClaimQuery {
     /// Semantic search text (if supported)
     pub semantic_text: Option<String>,
     
+    /// Only claims after this `(created_at, id)` position in result order,
+    /// for paging through results with `limit`
+    pub after: Option<(u64, ClaimId)>,
+    
     /// Maximum results to return
     pub limit: Option<usize>,
 }
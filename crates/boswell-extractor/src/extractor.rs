@@ -10,7 +10,7 @@ use crate::types::{
     ExtractionRequest, ExtractionResult,
 };
 use boswell_domain::traits::{ClaimStore, LlmProvider};
-use boswell_domain::{Claim, ClaimId};
+use boswell_domain::{Claim, ClaimId, ProvenanceEntry, Tier};
 use boswell_gatekeeper::{Gatekeeper, ValidationStatus};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -140,7 +140,7 @@ where
             match self.process_candidate(
                 candidate,
                 &request.namespace,
-                &request.tier,
+                request.tier,
                 &request.source_id,
             ).await {
                 Ok(ProcessResult::Created(result)) => claims_created.push(result),
@@ -212,7 +212,7 @@ where
             let chunk_request = ExtractionRequest {
                 text: chunk.clone(),
                 namespace: request.namespace.clone(),
-                tier: request.tier.clone(),
+                tier: request.tier,
                 source_id: format!("{}:chunk:{}", request.source_id, idx),
                 existing_context: request.existing_context.clone(),
             };
@@ -250,9 +250,14 @@ where
         &self,
         candidate: &ClaimCandidate,
         namespace: &str,
-        tier: &str,
-        _source_id: &str,
+        tier: Tier,
+        source_id: &str,
     ) -> Result<ProcessResult, String> {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        
         // Create a Claim from the candidate
         let claim = Claim {
             id: ClaimId::new(),
@@ -261,14 +266,22 @@ where
             predicate: candidate.predicate.clone(),
             object: candidate.object.clone(),
             confidence: (candidate.confidence_lower, candidate.confidence_upper),
-            tier: tier.to_string(),
-            created_at: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            tier,
+            created_at,
             stale_at: None,
         };
         
+        let provenance = ProvenanceEntry::new(
+            source_id.to_string(),
+            created_at,
+            "extraction".to_string(),
+        ).with_rationale(candidate.raw_expression.clone());
+        
+        // Canonical predicate first, so synonyms and inverses validate and
+        // deduplicate like the claims they restate
+        let claim = self.gatekeeper.normalize(&claim)
+            .map_err(|e| format!("Validation failed: {}", e))?;
+        
         // Validate with Gatekeeper
         // Note: We can't pass the store because of lifetime issues, so we skip duplicate detection here
         let validation = self.gatekeeper.validate(&claim, None::<&S>)
@@ -281,27 +294,31 @@ where
             ));
         }
         
+        let (subject, predicate, object) = (claim.subject.clone(), claim.predicate.clone(), claim.object.clone());
+        
         // Try to assert the claim
-        // The store's duplicate detection will tell us if it's a duplicate
-        let claim_id = {
+        // The store's duplicate detection turns repeats into corroboration
+        let outcome = {
             let mut store = self.store.lock()
                 .map_err(|e| format!("Store lock error: {}", e))?;
-            store.assert_claim(claim.clone())
+            store.assert_claim_with_provenance(claim, vec![provenance])
                 .map_err(|e| format!("Store error: {}", e))?
         };
         
-        // For now, we treat all assertions as "created"
-        // In a real implementation, the store would return whether it's a duplicate
         let result = ClaimResult {
-            claim_id,
-            subject: candidate.subject.clone(),
-            predicate: candidate.predicate.clone(),
-            object: candidate.object.clone(),
+            claim_id: outcome.claim_id(),
+            subject,
+            predicate,
+            object,
             confidence: (candidate.confidence_lower, candidate.confidence_upper),
             raw_expression: candidate.raw_expression.clone(),
         };
         
-        Ok(ProcessResult::Created(result))
+        if outcome.is_corroborated() {
+            Ok(ProcessResult::Corroborated(result))
+        } else {
+            Ok(ProcessResult::Created(result))
+        }
     }
     
     /// Call the LLM provider
@@ -347,8 +364,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text: "Some text".to_string(),
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_source".to_string(),
             existing_context: None,
         };
@@ -365,8 +382,8 @@ mod tests {
         let long_text = "a".repeat(100_000);
         let request = ExtractionRequest {
             text: long_text,
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_source".to_string(),
             existing_context: None,
         };
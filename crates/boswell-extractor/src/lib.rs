@@ -25,6 +25,7 @@
 //! # Example Usage
 //!
 //! ```no_run
+//! use boswell_domain::Tier;
 //! use boswell_extractor::{Extractor, ExtractorConfig, ExtractionRequest};
 //! use boswell_llm::MockProvider;
 //! use boswell_store::SqliteStore;
@@ -42,8 +43,8 @@
 //! // Extract claims from text
 //! let request = ExtractionRequest {
 //!     text: "Alice works at Acme Corp.".to_string(),
-//!     namespace: "engineering:team".to_string(),
-//!     tier: "project".to_string(),
+//!     namespace: "engineering/team".to_string(),
+//!     tier: Tier::Project,
 //!     source_id: "doc_001".to_string(),
 //!     existing_context: None,
 //! };
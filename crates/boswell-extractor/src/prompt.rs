@@ -1,5 +1,7 @@
 //! LLM prompt engineering for claim extraction
 
+use boswell_domain::namespace;
+
 use crate::types::ClaimSummary;
 
 /// Builds prompts for the LLM to extract claims
@@ -65,14 +67,11 @@ impl PromptBuilder {
         prompt
     }
     
-    /// Infer the domain from the namespace
+    /// Infer the domain from the namespace's top-level segment
     fn infer_domain(&self) -> String {
-        let parts: Vec<&str> = self.namespace.split(':').collect();
-        if parts.is_empty() {
-            return "General knowledge".to_string();
-        }
+        let top_level = self.namespace.split(namespace::SEPARATOR).next().unwrap_or_default();
         
-        match parts[0] {
+        match top_level {
             "person" => "Personal information",
             "project" => "Project documentation",
             "company" => "Company information",
@@ -111,7 +110,7 @@ Rules:
   - Well-documented: (0.85, 0.95)
   - Definitive/factual: (0.95, 0.98)
 - Extract relationships between entities, not just entity properties
-- For dates, use format "date:YYYY-MM-DD"
+- For dates, use format "date:YYYY-MM-DD" ("date:YYYY-MM" or "date:YYYY" when only part of the date is known)
 - For numbers, use format "number:value" or include units like "measurement:10kg""#;
 
 const OUTPUT_FORMAT_REMINDER: &str = r#"Output format (JSON array only, no additional text):
@@ -136,11 +135,11 @@ mod tests {
     fn test_prompt_includes_namespace() {
         let builder = PromptBuilder::new(
             "Test text".to_string(),
-            "test:namespace".to_string(),
+            "test/namespace".to_string(),
         );
         
         let prompt = builder.build();
-        assert!(prompt.contains("test:namespace"));
+        assert!(prompt.contains("test/namespace"));
         assert!(prompt.contains("Target namespace:"));
     }
 
@@ -148,7 +147,7 @@ mod tests {
     fn test_prompt_includes_text() {
         let builder = PromptBuilder::new(
             "Alice works at Acme Corp".to_string(),
-            "test:ns".to_string(),
+            "test/ns".to_string(),
         );
         
         let prompt = builder.build();
@@ -168,7 +167,7 @@ mod tests {
         
         let builder = PromptBuilder::new(
             "Test text".to_string(),
-            "test:ns".to_string(),
+            "test/ns".to_string(),
         ).with_existing_claims(existing);
         
         let prompt = builder.build();
@@ -182,7 +181,7 @@ mod tests {
     fn test_prompt_includes_instructions() {
         let builder = PromptBuilder::new(
             "Test text".to_string(),
-            "test:ns".to_string(),
+            "test/ns".to_string(),
         );
         
         let prompt = builder.build();
@@ -195,19 +194,19 @@ mod tests {
     fn test_domain_inference() {
         let builder = PromptBuilder::new(
             "Test".to_string(),
-            "engineering:project".to_string(),
+            "engineering/project".to_string(),
         );
         assert_eq!(builder.infer_domain(), "Software engineering");
         
         let builder = PromptBuilder::new(
             "Test".to_string(),
-            "medical:records".to_string(),
+            "medical/records".to_string(),
         );
         assert_eq!(builder.infer_domain(), "Medical information");
         
         let builder = PromptBuilder::new(
             "Test".to_string(),
-            "unknown:type".to_string(),
+            "unknown/type".to_string(),
         );
         assert_eq!(builder.infer_domain(), "General knowledge");
     }
@@ -225,7 +224,7 @@ mod tests {
         
         let builder = PromptBuilder::new(
             "Test".to_string(),
-            "test:ns".to_string(),
+            "test/ns".to_string(),
         ).with_existing_claims(existing);
         
         let prompt = builder.build();
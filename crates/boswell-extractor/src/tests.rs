@@ -5,6 +5,7 @@ mod tests {
     use crate::{
         Extractor, ExtractorConfig, ExtractionRequest, ChunkStrategy,
     };
+    use boswell_domain::Tier;
     use boswell_gatekeeper::Gatekeeper;
     use boswell_llm::MockProvider;
     use boswell_store::SqliteStore;
@@ -31,8 +32,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text: "Alice works at Acme".to_string(),
-            namespace: "test:company".to_string(),
-            tier: "project".to_string(),
+            namespace: "test/company".to_string(),
+            tier: Tier::Project,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -48,6 +49,107 @@ mod tests {
         assert!(result.is_ok(), "Extraction should succeed");
     }
 
+    #[tokio::test]
+    async fn test_repeated_extraction_corroborates() {
+        let llm = MockProvider::new(r#"[
+            {
+                "subject": "person:alice",
+                "predicate": "relation:works_at",
+                "object": "company:acme",
+                "confidence_lower": 0.9,
+                "confidence_upper": 0.95,
+                "raw_expression": "Alice works at Acme"
+            }
+        ]"#);
+        
+        let store = SqliteStore::new(":memory:", false, 0).unwrap();
+        let gatekeeper = Gatekeeper::default_config();
+        let config = ExtractorConfig::default();
+        
+        let extractor = Extractor::new(llm, store, gatekeeper, config);
+        
+        let request = |source_id: &str| ExtractionRequest {
+            text: "Alice works at Acme".to_string(),
+            namespace: "test/company".to_string(),
+            tier: Tier::Project,
+            source_id: source_id.to_string(),
+            existing_context: None,
+        };
+        
+        let first = extractor.extract(request("doc_1")).await.unwrap();
+        assert_eq!(first.claims_created.len(), 1);
+        assert_eq!(first.claims_corroborated.len(), 0);
+        
+        // The same fact from another source corroborates the existing claim
+        let second = extractor.extract(request("doc_2")).await.unwrap();
+        assert_eq!(second.claims_created.len(), 0);
+        assert_eq!(second.claims_corroborated.len(), 1);
+        assert_eq!(
+            second.claims_corroborated[0].claim_id,
+            first.claims_created[0].claim_id
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extraction_normalizes_predicates() {
+        use boswell_domain::{PredicateDefinition, PredicateRegistry, ValueType};
+        use boswell_gatekeeper::ValidationConfig;
+        
+        let llm = MockProvider::new(r#"[
+            {
+                "subject": "company:acme",
+                "predicate": "relation:employs",
+                "object": "person:alice",
+                "confidence_lower": 0.9,
+                "confidence_upper": 0.95,
+                "raw_expression": "Acme employs Alice"
+            },
+            {
+                "subject": "person:alice",
+                "predicate": "relation:employed_by",
+                "object": "company:acme",
+                "confidence_lower": 0.9,
+                "confidence_upper": 0.95,
+                "raw_expression": "Alice is employed by Acme"
+            },
+            {
+                "subject": "person:alice",
+                "predicate": "relation:employed_by",
+                "object": "city:paris",
+                "confidence_lower": 0.9,
+                "confidence_upper": 0.95,
+                "raw_expression": "Alice is employed by Paris"
+            }
+        ]"#);
+        
+        let mut registry = PredicateRegistry::new();
+        registry.register("test", PredicateDefinition::new("relation:works_at")
+            .with_synonyms(["relation:employed_by"])
+            .with_inverse("relation:employs")
+            .with_value_type(ValueType::Kind("company".to_string()))).unwrap();
+        let gatekeeper = Gatekeeper::new(ValidationConfig { predicate_registry: registry, ..Default::default() });
+        let store = SqliteStore::new(":memory:", false, 0).unwrap();
+        let extractor = Extractor::new(llm, store, gatekeeper, ExtractorConfig::default());
+        
+        let result = extractor.extract(ExtractionRequest {
+            text: "Acme employs Alice".to_string(),
+            namespace: "test/company".to_string(),
+            tier: Tier::Project,
+            source_id: "doc_1".to_string(),
+            existing_context: None,
+        }).await.unwrap();
+        
+        // The inverse is stored in canonical form, and the synonym corroborates it
+        assert_eq!(result.claims_created.len(), 1);
+        let created = &result.claims_created[0];
+        assert_eq!((created.subject.as_str(), created.predicate.as_str(), created.object.as_str()), ("person:alice", "relation:works_at", "company:acme"));
+        assert_eq!(result.claims_corroborated.len(), 1);
+        assert_eq!(result.claims_corroborated[0].claim_id, created.claim_id);
+        
+        // The synonym's value type applies
+        assert_eq!(result.failures.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_extraction_with_invalid_json() {
         let llm = MockProvider::new("This is not JSON");
@@ -59,8 +161,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text: "Some text".to_string(),
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -80,8 +182,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text: "Some text with no extractable claims".to_string(),
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -107,8 +209,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text,
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -132,8 +234,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text,
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -154,8 +256,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text: "Test text".to_string(),
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -210,8 +312,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text: text.to_string(),
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -236,8 +338,8 @@ mod tests {
         
         let request = ExtractionRequest {
             text: text.to_string(),
-            namespace: "test:ns".to_string(),
-            tier: "ephemeral".to_string(),
+            namespace: "test/ns".to_string(),
+            tier: Tier::Ephemeral,
             source_id: "test_001".to_string(),
             existing_context: None,
         };
@@ -1,6 +1,6 @@
 //! Request and response types for extraction
 
-use boswell_domain::ClaimId;
+use boswell_domain::{ClaimId, Tier};
 use serde::{Deserialize, Serialize};
 
 /// Request to extract claims from text
@@ -13,7 +13,7 @@ pub struct ExtractionRequest {
     pub namespace: String,
     
     /// Tier for the extracted claims
-    pub tier: String,
+    pub tier: Tier,
     
     /// Source identifier (hash or user-provided)
     pub source_id: String,
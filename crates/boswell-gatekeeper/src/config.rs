@@ -1,5 +1,7 @@
 //! Gatekeeper configuration
 
+use boswell_domain::{NamespacePolicy, PredicateRegistry};
+
 /// Configuration for validation rules
 #[derive(Debug, Clone)]
 pub struct ValidationConfig {
@@ -21,6 +23,15 @@ pub struct ValidationConfig {
     /// Enable tier appropriateness checking
     pub validate_tier_appropriateness: bool,
     
+    /// Enable namespace grammar, character and depth checking
+    pub validate_namespace: bool,
+    
+    /// Maximum depth and allowed characters for namespaces
+    pub namespace_policy: NamespacePolicy,
+    
+    /// Canonical predicates, synonyms, inverses and value types per namespace
+    pub predicate_registry: PredicateRegistry,
+    
     /// Minimum confidence lower bound for ephemeral tier
     pub ephemeral_min_confidence: f64,
     
@@ -43,6 +54,9 @@ impl Default for ValidationConfig {
             validate_semantic_duplicates: false,
             semantic_duplicate_threshold: 0.95,
             validate_tier_appropriateness: true,
+            validate_namespace: true,
+            namespace_policy: NamespacePolicy::default(),
+            predicate_registry: PredicateRegistry::default(),
             ephemeral_min_confidence: 0.0,
             task_min_confidence: 0.4,
             project_min_confidence: 0.6,
@@ -61,6 +75,9 @@ impl ValidationConfig {
             validate_semantic_duplicates: false,
             semantic_duplicate_threshold: 0.99,
             validate_tier_appropriateness: false,
+            validate_namespace: true,
+            namespace_policy: NamespacePolicy::default(),
+            predicate_registry: PredicateRegistry::default(),
             ephemeral_min_confidence: 0.0,
             task_min_confidence: 0.0,
             project_min_confidence: 0.0,
@@ -77,6 +94,9 @@ impl ValidationConfig {
             validate_semantic_duplicates: true,
             semantic_duplicate_threshold: 0.90,
             validate_tier_appropriateness: true,
+            validate_namespace: true,
+            namespace_policy: NamespacePolicy::default(),
+            predicate_registry: PredicateRegistry::default(),
             ephemeral_min_confidence: 0.0,
             task_min_confidence: 0.5,
             project_min_confidence: 0.7,
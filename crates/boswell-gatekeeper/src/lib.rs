@@ -3,7 +3,8 @@
 //! Evaluates and validates claims for quality control per ADR-008.
 //!
 //! The Gatekeeper provides:
-//! - Claim validation (entity format, confidence bounds)
+//! - Claim validation (entity format, confidence bounds, namespace depth)
+//! - Predicate normalization against a per-namespace registry
 //! - Duplicate detection
 //! - Tier appropriateness checking
 //! - Quality scoring
@@ -2,7 +2,10 @@
 ///!
 ///! Handles bidirectional conversion between gRPC protobuf types and internal domain types.
 
-use boswell_domain::{Claim, ClaimId, ConfidenceInterval as DomainConfidence, Tier as DomainTier};
+use boswell_domain::{
+    Claim, ClaimId, ConfidenceInterval as DomainConfidence, EntityAlias, EventKind, MergeProposal,
+    NamespaceStats, ProvenanceEntry, Relationship, RelationshipType, StoreEvent, Tier as DomainTier,
+};
 use crate::proto;
 
 /// Error type for conversion failures
@@ -23,28 +26,45 @@ pub enum ConversionError {
     /// Missing required field
     #[error("Missing required field: {0}")]
     MissingField(&'static str),
+    
+    /// Invalid relationship type or strength
+    #[error("Invalid relationship: {0}")]
+    InvalidRelationship(String),
+    
+    /// Invalid event type value
+    #[error("Invalid event type: {0}")]
+    InvalidEventType(i32),
 }
 
-/// Convert proto Tier to tier string
-pub fn tier_from_proto(tier: proto::Tier) -> Result<String, ConversionError> {
-    let tier_enum = match tier {
-        proto::Tier::Unspecified => return Err(ConversionError::InvalidTier(0)),
-        proto::Tier::Ephemeral => DomainTier::Ephemeral,
-        proto::Tier::Task => DomainTier::Task,
-        proto::Tier::Project => DomainTier::Project,
-        proto::Tier::Permanent => DomainTier::Permanent,
-    };
-    Ok(tier_enum.as_str().to_string())
+/// Convert proto Tier to domain Tier
+pub fn tier_from_proto(tier: proto::Tier) -> Result<DomainTier, ConversionError> {
+    match tier {
+        proto::Tier::Unspecified => Err(ConversionError::InvalidTier(0)),
+        proto::Tier::Ephemeral => Ok(DomainTier::Ephemeral),
+        proto::Tier::Task => Ok(DomainTier::Task),
+        proto::Tier::Project => Ok(DomainTier::Project),
+        proto::Tier::Permanent => Ok(DomainTier::Permanent),
+    }
 }
 
-/// Convert tier string to proto Tier
-pub fn tier_to_proto(tier: &str) -> proto::Tier {
-    match DomainTier::parse(tier) {
-        Some(DomainTier::Ephemeral) => proto::Tier::Ephemeral,
-        Some(DomainTier::Task) => proto::Tier::Task,
-        Some(DomainTier::Project) => proto::Tier::Project,
-        Some(DomainTier::Permanent) => proto::Tier::Permanent,
-        None => proto::Tier::Unspecified,
+/// Convert domain Tier to proto Tier
+pub fn tier_to_proto(tier: DomainTier) -> proto::Tier {
+    match tier {
+        DomainTier::Ephemeral => proto::Tier::Ephemeral,
+        DomainTier::Task => proto::Tier::Task,
+        DomainTier::Project => proto::Tier::Project,
+        DomainTier::Permanent => proto::Tier::Permanent,
+    }
+}
+
+/// Convert a proto Tier value to a domain Tier
+pub fn domain_tier_from_proto(tier: i32) -> Result<DomainTier, ConversionError> {
+    match proto::Tier::try_from(tier) {
+        Ok(proto::Tier::Ephemeral) => Ok(DomainTier::Ephemeral),
+        Ok(proto::Tier::Task) => Ok(DomainTier::Task),
+        Ok(proto::Tier::Project) => Ok(DomainTier::Project),
+        Ok(proto::Tier::Permanent) => Ok(DomainTier::Permanent),
+        _ => Err(ConversionError::InvalidTier(tier)),
     }
 }
 
@@ -54,19 +74,8 @@ pub fn confidence_from_proto(
 ) -> Result<DomainConfidence, ConversionError> {
     let conf = conf.ok_or(ConversionError::MissingField("confidence"))?;
     
-    if !(0.0..=1.0).contains(&conf.lower) || !(0.0..=1.0).contains(&conf.upper) {
-        return Err(ConversionError::InvalidConfidence(
-            "bounds must be in [0, 1]".to_string()
-        ));
-    }
-    
-    if conf.lower > conf.upper {
-        return Err(ConversionError::InvalidConfidence(
-            "lower must be <= upper".to_string()
-        ));
-    }
-    
-    Ok(DomainConfidence::new(conf.lower, conf.upper))
+    DomainConfidence::try_new(conf.lower, conf.upper)
+        .map_err(|e| ConversionError::InvalidConfidence(e.to_string()))
 }
 
 /// Convert domain ConfidenceInterval to proto ConfidenceInterval
@@ -104,6 +113,264 @@ pub fn claim_from_proto(claim: proto::Claim) -> Result<Claim, ConversionError> {
     })
 }
 
+/// Convert proto ProvenanceEntry to domain ProvenanceEntry
+///
+/// Without an explicit source type, it is the prefix of the source identifier
+/// (`"llm:gpt-4"` → `"llm"`). Non-empty metadata is kept as the rationale.
+pub fn provenance_from_proto(entry: proto::ProvenanceEntry) -> ProvenanceEntry {
+    let source_type = if entry.source_type.is_empty() {
+        entry.source
+            .split_once(':')
+            .map_or(entry.source.as_str(), |(prefix, _)| prefix)
+            .to_string()
+    } else {
+        entry.source_type
+    };
+    
+    let provenance = ProvenanceEntry::new(entry.source, entry.timestamp.max(0) as u64, source_type);
+    
+    if entry.metadata.is_empty() {
+        provenance
+    } else {
+        provenance.with_rationale(entry.metadata)
+    }
+}
+
+/// Convert domain ProvenanceEntry to proto ProvenanceEntry
+pub fn provenance_to_proto(entry: ProvenanceEntry) -> proto::ProvenanceEntry {
+    proto::ProvenanceEntry {
+        source: entry.source,
+        timestamp: entry.timestamp as i64,
+        metadata: entry.rationale.unwrap_or_default(),
+        source_type: entry.source_type,
+    }
+}
+
+/// Convert proto RelationshipType to domain RelationshipType
+pub fn relationship_type_from_proto(relationship_type: i32) -> Result<RelationshipType, ConversionError> {
+    match proto::RelationshipType::try_from(relationship_type) {
+        Ok(proto::RelationshipType::Supports) => Ok(RelationshipType::Supports),
+        Ok(proto::RelationshipType::Contradicts) => Ok(RelationshipType::Contradicts),
+        Ok(proto::RelationshipType::DerivedFrom) => Ok(RelationshipType::DerivedFrom),
+        Ok(proto::RelationshipType::References) => Ok(RelationshipType::References),
+        Ok(proto::RelationshipType::Supersedes) => Ok(RelationshipType::Supersedes),
+        _ => Err(ConversionError::InvalidRelationship(format!("unknown type {}", relationship_type))),
+    }
+}
+
+/// Convert domain RelationshipType to proto RelationshipType
+pub fn relationship_type_to_proto(relationship_type: RelationshipType) -> proto::RelationshipType {
+    match relationship_type {
+        RelationshipType::Supports => proto::RelationshipType::Supports,
+        RelationshipType::Contradicts => proto::RelationshipType::Contradicts,
+        RelationshipType::DerivedFrom => proto::RelationshipType::DerivedFrom,
+        RelationshipType::References => proto::RelationshipType::References,
+        RelationshipType::Supersedes => proto::RelationshipType::Supersedes,
+    }
+}
+
+/// Convert proto RelationshipRecord to domain Relationship
+pub fn relationship_from_proto(record: proto::RelationshipRecord) -> Result<Relationship, ConversionError> {
+    let from_claim = ClaimId::from_string(&record.from_claim_id)
+        .map_err(ConversionError::InvalidClaimId)?;
+    let to_claim = ClaimId::from_string(&record.to_claim_id)
+        .map_err(ConversionError::InvalidClaimId)?;
+    let relationship_type = relationship_type_from_proto(record.relationship_type)?;
+    
+    Relationship::try_new(from_claim, to_claim, relationship_type, record.strength, record.created_at)
+        .map_err(|e| ConversionError::InvalidRelationship(e.to_string()))
+}
+
+/// Convert domain Relationship to proto RelationshipRecord
+pub fn relationship_to_proto(relationship: Relationship) -> proto::RelationshipRecord {
+    proto::RelationshipRecord {
+        from_claim_id: relationship.from_claim.to_string(),
+        to_claim_id: relationship.to_claim.to_string(),
+        relationship_type: relationship_type_to_proto(relationship.relationship_type) as i32,
+        strength: relationship.strength,
+        created_at: relationship.created_at,
+    }
+}
+
+/// Convert proto ClaimRecord to a domain Claim and its provenance
+///
+/// Unlike [`claim_from_proto`], the record's timestamps are kept.
+pub fn claim_record_from_proto(
+    record: proto::ClaimRecord,
+) -> Result<(Claim, Vec<ProvenanceEntry>), ConversionError> {
+    let claim = record.claim.ok_or(ConversionError::MissingField("claim"))?;
+    let claim = Claim {
+        created_at: record.created_at,
+        stale_at: record.stale_at,
+        ..claim_from_proto(claim)?
+    };
+    let provenance = record.provenance.into_iter().map(provenance_from_proto).collect();
+    
+    Ok((claim, provenance))
+}
+
+/// Convert a domain Claim and its provenance to proto ClaimRecord
+pub fn claim_record_to_proto(claim: Claim, provenance: Vec<ProvenanceEntry>) -> proto::ClaimRecord {
+    proto::ClaimRecord {
+        created_at: claim.created_at,
+        stale_at: claim.stale_at,
+        provenance: provenance.into_iter().map(provenance_to_proto).collect(),
+        claim: Some(claim_to_proto(claim)),
+    }
+}
+
+/// The proto EventType of a domain event
+pub fn event_type_to_proto(kind: &EventKind) -> proto::EventType {
+    match kind {
+        EventKind::Asserted(_) => proto::EventType::Asserted,
+        EventKind::Corroborated(_) => proto::EventType::Corroborated,
+        EventKind::Promoted { .. } => proto::EventType::Promoted,
+        EventKind::Demoted { .. } => proto::EventType::Demoted,
+        EventKind::Evicted(_) => proto::EventType::Evicted,
+        EventKind::Moved(_) => proto::EventType::Moved,
+        EventKind::RelationshipAdded { .. } => proto::EventType::RelationshipAdded,
+    }
+}
+
+/// Convert domain StoreEvent to proto WatchEvent
+pub fn event_to_proto(event: StoreEvent) -> proto::WatchEvent {
+    let mut proto_event = proto::WatchEvent {
+        seq: event.seq,
+        recorded_at: event.recorded_at,
+        event_type: event_type_to_proto(&event.kind) as i32,
+        claim_id: event.kind.claim_id().to_string(),
+        namespace: event.namespace,
+        tier: event.tier.map_or(proto::Tier::Unspecified, tier_to_proto) as i32,
+        ..Default::default()
+    };
+    
+    match event.kind {
+        EventKind::Promoted { from, to, .. } | EventKind::Demoted { from, to, .. } => {
+            proto_event.from_tier = tier_to_proto(from) as i32;
+            proto_event.to_tier = tier_to_proto(to) as i32;
+        }
+        EventKind::RelationshipAdded { to_claim, relationship_type, .. } => {
+            proto_event.related_claim_id = to_claim.to_string();
+            proto_event.relationship_type = relationship_type_to_proto(relationship_type) as i32;
+        }
+        EventKind::Asserted(_) | EventKind::Corroborated(_) | EventKind::Evicted(_) | EventKind::Moved(_) => {}
+    }
+    
+    proto_event
+}
+
+/// Convert proto WatchEvent to domain StoreEvent
+pub fn event_from_proto(event: proto::WatchEvent) -> Result<StoreEvent, ConversionError> {
+    let claim_id = ClaimId::from_string(&event.claim_id).map_err(ConversionError::InvalidClaimId)?;
+    
+    let kind = match proto::EventType::try_from(event.event_type) {
+        Ok(proto::EventType::Asserted) => EventKind::Asserted(claim_id),
+        Ok(proto::EventType::Corroborated) => EventKind::Corroborated(claim_id),
+        Ok(proto::EventType::Evicted) => EventKind::Evicted(claim_id),
+        Ok(proto::EventType::Moved) => EventKind::Moved(claim_id),
+        Ok(proto::EventType::Promoted) => EventKind::Promoted {
+            claim_id,
+            from: domain_tier_from_proto(event.from_tier)?,
+            to: domain_tier_from_proto(event.to_tier)?,
+        },
+        Ok(proto::EventType::Demoted) => EventKind::Demoted {
+            claim_id,
+            from: domain_tier_from_proto(event.from_tier)?,
+            to: domain_tier_from_proto(event.to_tier)?,
+        },
+        Ok(proto::EventType::RelationshipAdded) => EventKind::RelationshipAdded {
+            from_claim: claim_id,
+            to_claim: ClaimId::from_string(&event.related_claim_id).map_err(ConversionError::InvalidClaimId)?,
+            relationship_type: relationship_type_from_proto(event.relationship_type)?,
+        },
+        _ => return Err(ConversionError::InvalidEventType(event.event_type)),
+    };
+    
+    let tier = match proto::Tier::try_from(event.tier) {
+        Ok(proto::Tier::Unspecified) => None,
+        _ => Some(domain_tier_from_proto(event.tier)?),
+    };
+    
+    Ok(StoreEvent {
+        seq: event.seq,
+        recorded_at: event.recorded_at,
+        namespace: event.namespace,
+        tier,
+        kind,
+    })
+}
+
+/// Convert domain NamespaceStats to proto NamespaceStats
+pub fn namespace_stats_to_proto(stats: NamespaceStats) -> proto::NamespaceStats {
+    proto::NamespaceStats {
+        claim_count: stats.claims,
+        ephemeral_count: stats.tier_count(DomainTier::Ephemeral),
+        task_count: stats.tier_count(DomainTier::Task),
+        project_count: stats.tier_count(DomainTier::Project),
+        permanent_count: stats.tier_count(DomainTier::Permanent),
+        confidence_sum: stats.confidence_sum,
+        last_write: stats.last_write,
+        contradiction_count: stats.contradictions,
+        namespace: stats.namespace,
+    }
+}
+
+/// Convert proto NamespaceStats to domain NamespaceStats
+pub fn namespace_stats_from_proto(stats: proto::NamespaceStats) -> NamespaceStats {
+    NamespaceStats {
+        namespace: stats.namespace,
+        claims: stats.claim_count,
+        tier_counts: [stats.ephemeral_count, stats.task_count, stats.project_count, stats.permanent_count],
+        confidence_sum: stats.confidence_sum,
+        last_write: stats.last_write,
+        contradictions: stats.contradiction_count,
+    }
+}
+
+/// Convert domain EntityAlias to proto EntityAlias
+pub fn alias_to_proto(alias: EntityAlias) -> proto::EntityAlias {
+    proto::EntityAlias {
+        alias: alias.alias,
+        canonical: alias.canonical,
+        reason: alias.reason,
+        created_at: alias.created_at,
+        retracted_at: alias.retracted_at,
+    }
+}
+
+/// Convert proto EntityAlias to domain EntityAlias
+pub fn alias_from_proto(alias: proto::EntityAlias) -> EntityAlias {
+    EntityAlias {
+        alias: alias.alias,
+        canonical: alias.canonical,
+        reason: alias.reason,
+        created_at: alias.created_at,
+        retracted_at: alias.retracted_at,
+    }
+}
+
+/// Convert domain MergeProposal to proto MergeProposal
+pub fn merge_proposal_to_proto(proposal: MergeProposal) -> proto::MergeProposal {
+    proto::MergeProposal {
+        alias: proposal.alias,
+        canonical: proposal.canonical,
+        name_similarity: proposal.name_similarity,
+        embedding_similarity: proposal.embedding_similarity,
+        score: proposal.score,
+    }
+}
+
+/// Convert proto MergeProposal to domain MergeProposal
+pub fn merge_proposal_from_proto(proposal: proto::MergeProposal) -> MergeProposal {
+    MergeProposal {
+        alias: proposal.alias,
+        canonical: proposal.canonical,
+        name_similarity: proposal.name_similarity,
+        embedding_similarity: proposal.embedding_similarity,
+        score: proposal.score,
+    }
+}
+
 /// Convert domain Claim to proto Claim
 pub fn claim_to_proto(claim: Claim) -> proto::Claim {
     proto::Claim {
@@ -116,7 +383,7 @@ pub fn claim_to_proto(claim: Claim) -> proto::Claim {
             lower: claim.confidence.0,
             upper: claim.confidence.1,
         }),
-        tier: tier_to_proto(&claim.tier) as i32,
+        tier: tier_to_proto(claim.tier) as i32,
     }
 }
 
@@ -127,18 +394,19 @@ mod tests {
     #[test]
     fn test_tier_roundtrip() {
         let tiers = vec![
-            ("ephemeral", proto::Tier::Ephemeral),
-            ("task", proto::Tier::Task),
-            ("project", proto::Tier::Project),
-            ("permanent", proto::Tier::Permanent),
+            (DomainTier::Ephemeral, proto::Tier::Ephemeral),
+            (DomainTier::Task, proto::Tier::Task),
+            (DomainTier::Project, proto::Tier::Project),
+            (DomainTier::Permanent, proto::Tier::Permanent),
         ];
         
-        for (tier_str, proto_tier) in tiers {
-            let proto = tier_to_proto(tier_str);
+        for (tier, proto_tier) in tiers {
+            let proto = tier_to_proto(tier);
             assert_eq!(proto, proto_tier);
             let back = tier_from_proto(proto).unwrap();
-            assert_eq!(tier_str, back);
+            assert_eq!(tier, back);
         }
+        assert!(tier_from_proto(proto::Tier::Unspecified).is_err());
     }
 
     #[test]
@@ -157,6 +425,34 @@ mod tests {
             upper: 0.1,  // Invalid: lower > upper
         };
         assert!(confidence_from_proto(Some(invalid)).is_err());
+        
+        for (lower, upper) in [(f64::NAN, 0.9), (0.1, f64::INFINITY), (f64::NEG_INFINITY, 0.5)] {
+            let err = confidence_from_proto(Some(proto::ConfidenceInterval { lower, upper })).unwrap_err();
+            assert!(err.to_string().contains("finite"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_provenance_from_proto() {
+        let entry = provenance_from_proto(proto::ProvenanceEntry {
+            source: "llm:gpt-4".to_string(),
+            timestamp: 1000,
+            metadata: String::new(),
+            source_type: String::new(),
+        });
+        assert_eq!(entry.source, "llm:gpt-4");
+        assert_eq!(entry.source_type, "llm");
+        assert_eq!(entry.timestamp, 1000);
+        assert_eq!(entry.rationale, None);
+        
+        let entry = provenance_from_proto(proto::ProvenanceEntry {
+            source: "user".to_string(),
+            timestamp: 2000,
+            metadata: "{\"note\":\"manual\"}".to_string(),
+            source_type: String::new(),
+        });
+        assert_eq!(entry.source_type, "user");
+        assert!(entry.rationale.is_some());
     }
 
     #[test]
@@ -168,7 +464,7 @@ mod tests {
             predicate: "knows".to_string(),
             object: "Bob".to_string(),
             confidence: (0.8, 0.95),
-            tier: "task".to_string(),
+            tier: DomainTier::Task,
             created_at: 1000000,
             stale_at: None,
         };
@@ -182,4 +478,104 @@ mod tests {
         assert_eq!(claim.confidence, back.confidence);
         assert_eq!(claim.tier, back.tier);
     }
+    
+    #[test]
+    fn test_claim_record_roundtrip() {
+        let claim = Claim {
+            id: ClaimId::new(),
+            namespace: "acme/eng".to_string(),
+            subject: "Alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.95),
+            tier: DomainTier::Project,
+            created_at: 1000000,
+            stale_at: Some(2000000),
+        };
+        let provenance = vec![
+            ProvenanceEntry::new("llm:gpt-4".to_string(), 1000, "agent".to_string())
+                .with_rationale("extracted".to_string()),
+        ];
+        
+        let record = claim_record_to_proto(claim.clone(), provenance.clone());
+        let (back, back_provenance) = claim_record_from_proto(record).unwrap();
+        
+        assert_eq!(back, claim);
+        assert_eq!(back_provenance, provenance);
+    }
+    
+    #[test]
+    fn test_relationship_roundtrip() {
+        let relationship = Relationship::new(ClaimId::new(), ClaimId::new(), RelationshipType::DerivedFrom, 0.6, 42);
+        let back = relationship_from_proto(relationship_to_proto(relationship.clone())).unwrap();
+        assert_eq!(back, relationship);
+        
+        let invalid = proto::RelationshipRecord { strength: 1.5, ..relationship_to_proto(relationship.clone()) };
+        assert!(relationship_from_proto(invalid).is_err());
+        let invalid = proto::RelationshipRecord { strength: f64::NAN, ..relationship_to_proto(relationship) };
+        assert!(relationship_from_proto(invalid).is_err());
+    }
+    
+    #[test]
+    fn test_event_roundtrip() {
+        let (a, b) = (ClaimId::new(), ClaimId::new());
+        let kinds = [
+            EventKind::Asserted(a),
+            EventKind::Corroborated(a),
+            EventKind::Promoted { claim_id: a, from: DomainTier::Task, to: DomainTier::Project },
+            EventKind::Demoted { claim_id: a, from: DomainTier::Permanent, to: DomainTier::Ephemeral },
+            EventKind::Evicted(a),
+            EventKind::Moved(a),
+            EventKind::RelationshipAdded { from_claim: a, to_claim: b, relationship_type: RelationshipType::Contradicts },
+        ];
+        
+        for (seq, kind) in kinds.into_iter().enumerate() {
+            let event = StoreEvent {
+                seq: seq as u64 + 1,
+                recorded_at: 1000,
+                namespace: Some("acme/eng".to_string()),
+                tier: Some(DomainTier::Project),
+                kind,
+            };
+            assert_eq!(event_from_proto(event_to_proto(event.clone())).unwrap(), event);
+        }
+        
+        let legacy = StoreEvent { seq: 7, recorded_at: 1000, namespace: None, tier: None, kind: EventKind::Evicted(b) };
+        assert_eq!(event_from_proto(event_to_proto(legacy.clone())).unwrap(), legacy);
+        
+        let unspecified = proto::WatchEvent { claim_id: a.to_string(), ..Default::default() };
+        assert!(event_from_proto(unspecified).is_err());
+    }
+    
+    #[test]
+    fn test_namespace_stats_roundtrip() {
+        let stats = NamespaceStats {
+            namespace: "acme/eng".to_string(),
+            claims: 6,
+            tier_counts: [1, 2, 3, 0],
+            confidence_sum: 4.2,
+            last_write: 1000,
+            contradictions: 2,
+        };
+        
+        let proto = namespace_stats_to_proto(stats.clone());
+        assert_eq!(proto.project_count, 3);
+        assert_eq!(namespace_stats_from_proto(proto), stats);
+    }
+    
+    #[test]
+    fn test_alias_and_proposal_roundtrip() {
+        let mut alias = EntityAlias::new("person:j_doe", "person:john_doe", "reviewed", 1000).unwrap();
+        alias.retracted_at = Some(2000);
+        assert_eq!(alias_from_proto(alias_to_proto(alias.clone())), alias);
+        
+        let proposal = MergeProposal {
+            alias: "person:j_doe".to_string(),
+            canonical: "person:john_doe".to_string(),
+            name_similarity: 0.9,
+            embedding_similarity: None,
+            score: 0.9,
+        };
+        assert_eq!(merge_proposal_from_proto(merge_proposal_to_proto(proposal.clone())), proposal);
+    }
 }
@@ -7,6 +7,8 @@
 //! - Assert/Query operations for single claims
 //! - Learn operation for bulk insertion (ADR-012)
 //! - Forget operation for eviction marking
+//! - ListNamespaces/MoveNamespace for browsing and reorganizing namespaces
+//! - Watch stream of store events (assertions, tier changes, evictions, relationships)
 //! - Health checks for instance monitoring
 
 // Include generated protobuf code
@@ -19,5 +21,5 @@ pub mod conversions;
 pub mod service;
 pub mod server;
 
-pub use service::BosWellServiceImpl;
+pub use service::{BosWellServiceImpl, DEFAULT_WATCH_INTERVAL};
 pub use server::{ServerConfig, start_server};
@@ -0,0 +1,96 @@
+//! Boswell gRPC instance
+//!
+//! Opens the claim store named in the config file and serves the Boswell API
+//! over gRPC.
+
+use boswell_grpc::{start_server, ServerConfig};
+use boswell_store::{BlockingStore, StoreConfig};
+use serde::Deserialize;
+use std::env;
+use std::process;
+
+/// Contents of the instance's TOML config file
+#[derive(Debug, Deserialize)]
+struct InstanceConfig {
+    /// Address to listen on
+    #[serde(default = "default_bind_address")]
+    bind_address: String,
+
+    /// Port to listen on
+    #[serde(default = "default_port")]
+    port: u16,
+
+    /// Storage backend; a SQLite database in `boswell.db` if omitted
+    #[serde(default)]
+    store: StoreConfig,
+}
+
+fn default_bind_address() -> String {
+    ServerConfig::default().addr
+}
+
+fn default_port() -> u16 {
+    ServerConfig::default().port
+}
+
+impl Default for InstanceConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: default_bind_address(),
+            port: default_port(),
+            store: StoreConfig::default(),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(e) = run().await {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    // Parse command-line arguments
+    let args: Vec<String> = env::args().collect();
+
+    let config = if args.len() > 2 && args[1] == "--config" {
+        let contents = std::fs::read_to_string(&args[2])?;
+        toml::from_str(&contents)?
+    } else if args.len() > 1 && args[1] == "--help" {
+        print_help();
+        process::exit(0);
+    } else {
+        eprintln!("Warning: No config file specified, using a SQLite store in boswell.db");
+        eprintln!("Usage: boswell-grpc --config <path-to-config.toml>");
+        eprintln!();
+        InstanceConfig::default()
+    };
+
+    // Synchronous stores run on the blocking pool
+    let store = BlockingStore::new(config.store.open()?);
+
+    start_server(ServerConfig::new(config.bind_address, config.port), store).await
+}
+
+fn print_help() {
+    println!("Boswell gRPC - Claim store instance");
+    println!();
+    println!("USAGE:");
+    println!("    boswell-grpc --config <path-to-config.toml>");
+    println!();
+    println!("OPTIONS:");
+    println!("    --config <file>    Load configuration from TOML file");
+    println!("    --help             Print this help message");
+    println!();
+    println!("EXAMPLE:");
+    println!("    boswell-grpc --config config/instance.toml");
+    println!();
+    println!("CONFIGURATION:");
+    println!("    The TOML config file may contain:");
+    println!("    - bind_address: IP address to bind (default: '127.0.0.1')");
+    println!("    - port: Port number (default: 50051)");
+    println!("    - [store]: Storage backend, with backend = 'sqlite' (path) or 'postgres' (url)");
+    println!();
+}
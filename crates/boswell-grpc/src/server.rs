@@ -2,9 +2,9 @@
 ///!
 ///! Handles server initialization, TLS setup, and graceful shutdown.
 
-use std::sync::{Arc, Mutex};
 use tonic::transport::Server;
-use boswell_domain::traits::ClaimStore;
+use boswell_domain::NamespacePolicy;
+use boswell_domain::traits::AsyncClaimStore;
 
 use crate::proto::bos_well_service_server::BosWellServiceServer;
 use crate::service::BosWellServiceImpl;
@@ -26,6 +26,9 @@ pub struct ServerConfig {
     
     /// TLS key path
     pub tls_key_path: Option<String>,
+    
+    /// Depth limit and allowed characters for namespaces of written claims
+    pub namespace_policy: NamespacePolicy,
 }
 
 impl Default for ServerConfig {
@@ -36,6 +39,7 @@ impl Default for ServerConfig {
             enable_tls: false,
             tls_cert_path: None,
             tls_key_path: None,
+            namespace_policy: NamespacePolicy::default(),
         }
     }
 }
@@ -58,6 +62,12 @@ impl ServerConfig {
         self
     }
     
+    /// Enforce a namespace policy other than the default
+    pub fn with_namespace_policy(mut self, policy: NamespacePolicy) -> Self {
+        self.namespace_policy = policy;
+        self
+    }
+    
     /// Get the full server address
     pub fn full_address(&self) -> String {
         format!("{}:{}", self.addr, self.port)
@@ -66,19 +76,23 @@ impl ServerConfig {
 
 /// Start the gRPC server
 ///
+/// Synchronous stores are wrapped in `boswell_store::BlockingStore` so their
+/// calls run on the blocking thread pool. A `PooledStore` inside it lets
+/// queries proceed while a slow Learn holds the writer.
+///
 /// # Errors
 /// Returns error if server fails to start or bind to address
 pub async fn start_server<S>(
     config: ServerConfig,
-    store: Arc<Mutex<S>>,
+    store: S,
 ) -> Result<(), Box<dyn std::error::Error>>
 where
-    S: ClaimStore + Send + Sync + 'static,
+    S: AsyncClaimStore + Send + Sync + 'static,
     S::Error: std::fmt::Debug,
 {
     let addr = config.full_address().parse()?;
     
-    let service = BosWellServiceImpl::new(store);
+    let service = BosWellServiceImpl::new(store).with_namespace_policy(config.namespace_policy);
     let service_server = BosWellServiceServer::new(service);
     
     println!("BosWell gRPC server starting on {}", addr);
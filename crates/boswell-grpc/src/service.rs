@@ -2,38 +2,257 @@
 ///!
 ///! Implements the BosWellService trait generated from proto definitions.
 
-use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
-use boswell_domain::{Claim, ClaimId};
-use boswell_domain::traits::{ClaimStore, ClaimQuery};
+use boswell_domain::{alias, namespace, AliasGraph, Claim, ClaimId, Entity, EntityAlias, EntityResolver, Literal, NamespacePolicy, ProvenanceEntry, Relationship, StoreEvent};
+use boswell_domain::traits::{AsyncClaimStore, BatchClaim, ClaimBatch, ClaimQuery};
+use boswell_gatekeeper::{Gatekeeper, ValidationStatus};
 
 use crate::proto::bos_well_service_server::BosWellService;
 use crate::proto::*;
 use crate::conversions::{
-    claim_from_proto, claim_to_proto, confidence_from_proto,
+    alias_to_proto, claim_from_proto, claim_record_from_proto, claim_record_to_proto, claim_to_proto,
+    confidence_from_proto, event_to_proto, event_type_to_proto, merge_proposal_to_proto,
+    namespace_stats_to_proto, provenance_from_proto, relationship_from_proto, relationship_to_proto,
     tier_from_proto,
 };
 
+/// Stream of records returned by `Export`
+pub type ExportStream = Pin<Box<dyn Stream<Item = Result<ExportRecord, Status>> + Send>>;
+
+/// Stream of events returned by `Watch`
+pub type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchEvent, Status>> + Send>>;
+
+/// How often a caught-up `Watch` polls the event log, unless configured otherwise
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Events read from the store per poll, and buffered per watcher
+const WATCH_BATCH: usize = 256;
+
+/// Claims read from the store per page of an `Export`, and buffered per exporter
+const EXPORT_PAGE: usize = 500;
+
+/// Most claims `ProposeMerges` examines; the resolver compares every pair of their entities
+const MERGE_CLAIMS: usize = 2_000;
+
+/// Most proposals `ProposeMerges` returns
+const MAX_MERGE_PROPOSALS: usize = 1_000;
+
 /// Implementation of the BosWellService
-pub struct BosWellServiceImpl<S: ClaimStore> {
-    store: Arc<Mutex<S>>,
+///
+/// Store calls are awaited, never run on the runtime's worker threads. Wrap a
+/// synchronous store in `boswell_store::BlockingStore`; a `PooledStore` inside
+/// it lets queries run alongside writes, while an `Arc<Mutex<S>>` serializes
+/// every call.
+pub struct BosWellServiceImpl<S: AsyncClaimStore> {
+    store: Arc<S>,
+    gatekeeper: Gatekeeper,
+    namespace_policy: NamespacePolicy,
+    resolver: Arc<EntityResolver>,
+    watch_interval: Duration,
     start_time: std::time::Instant,
 }
 
-impl<S: ClaimStore> BosWellServiceImpl<S> {
+impl<S: AsyncClaimStore> BosWellServiceImpl<S> {
     /// Create a new service instance
-    pub fn new(store: Arc<Mutex<S>>) -> Self {
+    pub fn new(store: S) -> Self {
         Self {
-            store,
+            store: Arc::new(store),
+            gatekeeper: Gatekeeper::default_config(),
+            namespace_policy: NamespacePolicy::default(),
+            resolver: Arc::new(EntityResolver::new()),
+            watch_interval: DEFAULT_WATCH_INTERVAL,
             start_time: std::time::Instant::now(),
         }
     }
+    
+    /// Normalize predicates with this gatekeeper's registry, and validate
+    /// imports that ask for the gatekeeper with it, instead of the default
+    pub fn with_gatekeeper(mut self, gatekeeper: Gatekeeper) -> Self {
+        self.gatekeeper = gatekeeper;
+        self
+    }
+    
+    /// Reject claims whose namespace breaks this policy instead of the default
+    pub fn with_namespace_policy(mut self, policy: NamespacePolicy) -> Self {
+        self.namespace_policy = policy;
+        self
+    }
+    
+    /// Propose merges with this resolver instead of one scoring names only
+    pub fn with_entity_resolver(mut self, resolver: EntityResolver) -> Self {
+        self.resolver = Arc::new(resolver);
+        self
+    }
+    
+    /// Convert a proto claim, checking its namespace against the policy and
+    /// normalizing its predicate
+    fn claim_from_request(&self, claim: crate::proto::Claim) -> Result<Claim, String> {
+        let claim = claim_from_proto(claim).map_err(|e| e.to_string())?;
+        self.namespace_policy.validate(&claim.namespace)?;
+        self.gatekeeper.normalize(&claim)
+    }
+    
+    /// Poll the event log this often for watchers that have caught up
+    pub fn with_watch_interval(mut self, interval: Duration) -> Self {
+        self.watch_interval = interval;
+        self
+    }
+}
+
+impl<S> BosWellServiceImpl<S>
+where
+    S: AsyncClaimStore,
+    S::Error: std::fmt::Debug,
+{
+    /// The store's active aliases
+    async fn alias_graph(&self) -> Result<AliasGraph, Status> {
+        let aliases = self.store.entity_aliases(false)
+            .await
+            .map_err(|e| Status::internal(format!("Reading entity aliases failed: {:?}", e)))?;
+        Ok(AliasGraph::new(&aliases))
+    }
+}
+
+/// Which events a `Watch` call streams
+struct WatchFilter {
+    namespace: Option<String>,
+    tier: Option<boswell_domain::Tier>,
+    event_types: Vec<EventType>,
+}
+
+impl WatchFilter {
+    /// The filter a request asks for, or why the request is invalid
+    fn from_request(req: &WatchRequest) -> Result<Self, String> {
+        let tier = match req.tier {
+            Some(tier) if tier != 0 => Some(
+                tier_from_proto(Tier::try_from(tier).map_err(|_| "Invalid tier".to_string())?)
+                    .map_err(|e| e.to_string())?,
+            ),
+            _ => None,
+        };
+        
+        let event_types = req.event_types.iter()
+            .map(|&event_type| match EventType::try_from(event_type) {
+                Ok(EventType::Unspecified) | Err(_) => Err(format!("Invalid event type: {}", event_type)),
+                Ok(event_type) => Ok(event_type),
+            })
+            .collect::<Result<_, _>>()?;
+        
+        Ok(Self {
+            namespace: req.namespace.clone(),
+            tier,
+            event_types,
+        })
+    }
+    
+    /// Whether `event` passes the filter, judged by the namespace and tier recorded with it
+    fn matches(&self, event: &StoreEvent) -> bool {
+        if !self.event_types.is_empty() && !self.event_types.contains(&event_type_to_proto(&event.kind)) {
+            return false;
+        }
+        
+        self.namespace.as_ref().is_none_or(|root| {
+            event.namespace.as_deref().is_some_and(|ns| namespace::in_subtree(ns, root))
+        }) && self.tier.is_none_or(|tier| event.tier == Some(tier))
+    }
+}
+
+/// Split a subject or object filter into an entity kind (for `kind:*`) or an exact value
+fn entity_pattern(pattern: Option<String>) -> (Option<String>, Option<String>) {
+    match pattern.as_deref().and_then(Entity::kind_pattern) {
+        Some(kind) => (Some(kind), None),
+        None => (None, pattern),
+    }
+}
+
+/// Parse a literal bound such as `date:2025`, treating an empty string as no bound
+fn literal_bound(value: Option<String>) -> Result<Option<Literal>, String> {
+    value.filter(|value| !value.is_empty())
+        .map(|value| Entity::parse_literal(&value))
+        .transpose()
+}
+
+/// Send the claims under `root` to `tx` a page at a time, then the relationships between them
+///
+/// Relationships need every exported ID, so only they are held until the
+/// last page. Stops early, without an error, when the client goes away.
+async fn export_records<S>(
+    store: &S,
+    root: String,
+    tx: &mpsc::Sender<Result<ExportRecord, Status>>,
+) -> Result<(), Status>
+where
+    S: AsyncClaimStore,
+    S::Error: std::fmt::Debug,
+{
+    let export_failed = |e: S::Error| Status::internal(format!("Export failed: {:?}", e));
+    let mut query = ClaimQuery {
+        namespace: Some(root),
+        limit: Some(EXPORT_PAGE),
+        ..Default::default()
+    };
+    let mut exported = HashSet::new();
+    let mut relationships = Vec::new();
+    
+    loop {
+        let claims = store.query_claims(&query).await.map_err(export_failed)?;
+        let Some(last) = claims.last() else {
+            break;
+        };
+        query.after = Some((last.created_at, last.id));
+        let last_page = claims.len() < EXPORT_PAGE;
+        
+        let page: HashSet<ClaimId> = claims.iter().map(|claim| claim.id).collect();
+        let ids: Vec<ClaimId> = claims.iter().map(|claim| claim.id).collect();
+        let mut provenance = store.get_provenance_batch(&ids).await.map_err(export_failed)?;
+        
+        // Listed from the source side so each one appears once
+        relationships.extend(store.get_relationships_batch(&ids)
+            .await
+            .map_err(export_failed)?
+            .into_iter()
+            .filter(|r| page.contains(&r.from_claim)));
+        exported.extend(ids);
+        
+        for claim in claims {
+            let provenance = provenance.remove(&claim.id).unwrap_or_default();
+            let record = ExportRecord {
+                record: Some(export_record::Record::Claim(claim_record_to_proto(claim, provenance))),
+            };
+            if tx.send(Ok(record)).await.is_err() {
+                return Ok(());
+            }
+        }
+        
+        if last_page {
+            break;
+        }
+    }
+    
+    // Links leaving the subtree would dangle in the importing instance
+    for relationship in relationships.into_iter().filter(|r| exported.contains(&r.to_claim)) {
+        let record = ExportRecord {
+            record: Some(export_record::Record::Relationship(relationship_to_proto(relationship))),
+        };
+        if tx.send(Ok(record)).await.is_err() {
+            return Ok(());
+        }
+    }
+    
+    Ok(())
 }
 
 #[tonic::async_trait]
 impl<S> BosWellService for BosWellServiceImpl<S>
 where
-    S: ClaimStore + Send + Sync + 'static,
+    S: AsyncClaimStore + Send + Sync + 'static,
     S::Error: std::fmt::Debug,
 {
     async fn assert(
@@ -47,6 +266,8 @@ where
             return Err(Status::unauthenticated("Missing authentication token"));
         }
         
+        self.namespace_policy.validate(&req.namespace).map_err(Status::invalid_argument)?;
+        
         // Convert proto types to domain types
         let confidence = confidence_from_proto(req.confidence)
             .map_err(|e| Status::invalid_argument(e.to_string()))?;
@@ -56,7 +277,7 @@ where
                 .map_err(|_| Status::invalid_argument("Invalid tier"))?)
                 .map_err(|e| Status::invalid_argument(e.to_string()))?
         } else {
-            "ephemeral".to_string()  // Default tier
+            boswell_domain::Tier::Ephemeral  // Default tier
         };
         
         let created_at = std::time::SystemTime::now()
@@ -76,16 +297,27 @@ where
             created_at,
             stale_at: None,
         };
+        let claim = self.gatekeeper.normalize(&claim).map_err(Status::invalid_argument)?;
         
-        // Assert claim to store
-        let mut store = self.store.lock().unwrap();
-        let result = store.assert_claim(claim.clone())
+        let provenance = req.provenance.into_iter()
+            .map(provenance_from_proto)
+            .collect();
+        
+        // Assert claim to store; duplicates corroborate the existing claim
+        let outcome = self.store.assert_claim_with_provenance(claim, provenance)
+            .await
             .map_err(|e| Status::internal(format!("Failed to assert claim: {:?}", e)))?;
         
+        let message = if outcome.is_corroborated() {
+            "Existing claim corroborated".to_string()
+        } else {
+            "Claim asserted successfully".to_string()
+        };
+        
         Ok(Response::new(AssertResponse {
-            claim_id: result.to_string(),
-            is_duplicate: result == claim.id,  // Simplified duplicate detection
-            message: "Claim asserted successfully".to_string(),
+            claim_id: outcome.claim_id().to_string(),
+            is_duplicate: outcome.is_corroborated(),
+            message,
         }))
     }
 
@@ -102,8 +334,13 @@ where
         
         let filter = req.filter.ok_or_else(|| Status::invalid_argument("Missing filter"))?;
         
+        // `kind:*` selects every entity of a kind; anything else must match exactly
+        let (subject_kind, subject) = entity_pattern(filter.subject);
+        let (object_kind, object) = entity_pattern(filter.object);
+        
         // Build query
-        let query = ClaimQuery {
+        let limit = if req.limit > 0 { req.limit as usize } else { 100 };
+        let mut query = ClaimQuery {
             namespace: filter.namespace,
             tier: filter.tier.and_then(|t| {
                 if t != 0 {
@@ -112,37 +349,79 @@ where
                     None
                 }
             }),
+            subject_kind,
+            object_kind,
+            object_after: literal_bound(filter.object_after).map_err(Status::invalid_argument)?,
+            object_before: literal_bound(filter.object_before).map_err(Status::invalid_argument)?,
             min_confidence: filter.min_confidence.filter(|&c| c > 0.0),
             semantic_text: None,
-            limit: if req.limit > 0 { Some(req.limit as usize) } else { Some(100) },
+            after: None,
+            limit: Some(limit),
         };
         
-        // Query claims from store
-        let store = self.store.lock().unwrap();
-        let claims = store.query_claims(&query)
-            .map_err(|e| Status::internal(format!("Query failed: {:?}", e)))?;
+        // With alias expansion an exact subject or object matches every identifier of its entity
+        let graph = if filter.expand_aliases && (subject.is_some() || object.is_some()) {
+            Some(self.alias_graph().await?)
+        } else {
+            None
+        };
+        let accepted = |pattern: &Option<String>| -> Option<HashSet<String>> {
+            pattern.as_ref().map(|pattern| match &graph {
+                Some(graph) => graph.equivalents(pattern).into_iter().collect(),
+                None => HashSet::from([pattern.clone()]),
+            })
+        };
+        let (subjects, objects) = (accepted(&subject), accepted(&object));
+        let matches = |accepted: &Option<HashSet<String>>, value: &str| match (accepted, &graph) {
+            (None, _) => true,
+            (Some(accepted), Some(_)) => accepted.contains(&alias::normalize(value)),
+            (Some(accepted), None) => accepted.contains(value),
+        };
         
         // Apply additional filters (subject, predicate, object not in ClaimQuery yet)
-        let filtered_claims: Vec<Claim> = claims.into_iter()
-            .filter(|c| {
-                if let Some(ref subject) = filter.subject {
-                    if &c.subject != subject {
-                        return false;
-                    }
-                }
-                if let Some(ref predicate) = filter.predicate {
-                    if &c.predicate != predicate {
-                        return false;
-                    }
+        let wanted = |c: &Claim| {
+            if !matches(&subjects, &c.subject) {
+                return false;
+            }
+            if let Some(ref predicate) = filter.predicate {
+                if &c.predicate != predicate {
+                    return false;
                 }
-                if let Some(ref object) = filter.object {
-                    if &c.object != object {
-                        return false;
-                    }
+            }
+            matches(&objects, &c.object)
+        };
+        
+        // The store applies `limit` before those filters, so keep paging until
+        // `limit` claims match or the store runs out
+        let mut filtered_claims: Vec<Claim> = Vec::new();
+        loop {
+            let page = self.store.query_claims(&query)
+                .await
+                .map_err(|e| Status::internal(format!("Query failed: {:?}", e)))?;
+            let exhausted = page.len() < limit;
+            query.after = page.last().map(|c| (c.created_at, c.id));
+            filtered_claims.extend(page.into_iter().filter(|c| wanted(c)));
+            if exhausted || filtered_claims.len() >= limit {
+                break;
+            }
+        }
+        filtered_claims.truncate(limit);
+        
+        // Deliberate queries adjust the stored confidence for support, contradiction and staleness
+        if req.mode == QueryMode::Deliberate as i32 {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            for claim in &mut filtered_claims {
+                let effective = self.store.effective_confidence(claim.id, now)
+                    .await
+                    .map_err(|e| Status::internal(format!("Confidence computation failed: {:?}", e)))?;
+                if let Some(confidence) = effective {
+                    claim.confidence = (confidence.lower, confidence.upper);
                 }
-                true
-            })
-            .collect();
+            }
+        }
         
         let total_count = filtered_claims.len() as i32;
         
@@ -168,37 +447,87 @@ where
             return Err(Status::unauthenticated("Missing authentication token"));
         }
         
-        let mut inserted_count = 0;
-        let mut duplicate_count = 0;
-        let mut error_count = 0;
-        let mut errors = Vec::new();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
         
-        let mut store = self.store.lock().unwrap();
+        // Invalid claims never reach the store; remember where they sit in the request
+        let mut results: Vec<Option<LearnItemResult>> = Vec::with_capacity(req.claims.len());
+        let mut batch = ClaimBatch {
+            all_or_nothing: req.all_or_nothing,
+            ..Default::default()
+        };
         
         for proto_claim in req.claims {
-            match claim_from_proto(proto_claim) {
+            match self.claim_from_request(proto_claim) {
                 Ok(claim) => {
-                    match store.assert_claim(claim.clone()) {
-                        Ok(_) => inserted_count += 1,
-                        Err(_) => {
-                            error_count += 1;
-                            errors.push(format!("Failed to insert claim {}", claim.id));
-                        }
-                    }
+                    // Learned claims are direct loads (ADR-012)
+                    let provenance = ProvenanceEntry::new(
+                        "learn".to_string(),
+                        now,
+                        "direct_load".to_string(),
+                    );
+                    batch.claims.push(BatchClaim { claim, provenance: vec![provenance] });
+                    results.push(None);
                 }
-                Err(e) => {
-                    error_count += 1;
-                    errors.push(format!("Invalid claim: {}", e));
+                Err(e) if req.all_or_nothing => {
+                    return Err(Status::invalid_argument(format!("Invalid claim: {}", e)));
                 }
+                Err(e) => results.push(Some(LearnItemResult {
+                    error: format!("Invalid claim: {}", e),
+                    ..Default::default()
+                })),
             }
         }
         
+        // One transaction for the whole batch (ADR-018)
+        let outcome = self.store.assert_batch(batch).await.map_err(|e| {
+            if req.all_or_nothing {
+                Status::aborted(format!("Learn batch rolled back: {:?}", e))
+            } else {
+                Status::internal(format!("Learn failed: {:?}", e))
+            }
+        })?;
+        
+        let inserted_count = outcome.inserted_count() as i32;
+        let duplicate_count = outcome.corroborated_count() as i32;
+        
+        let mut stored = outcome.claims.into_iter();
+        let results: Vec<LearnItemResult> = results.into_iter()
+            .map(|result| result.unwrap_or_else(|| match stored.next() {
+                Some(Ok(outcome)) => LearnItemResult {
+                    claim_id: outcome.claim_id().to_string(),
+                    is_duplicate: outcome.is_corroborated(),
+                    error: String::new(),
+                },
+                Some(Err(e)) => LearnItemResult {
+                    error: format!("Failed to insert claim: {:?}", e),
+                    ..Default::default()
+                },
+                None => LearnItemResult {
+                    error: "Claim was not processed".to_string(),
+                    ..Default::default()
+                },
+            }))
+            .collect();
+        
+        let errors: Vec<String> = results.iter()
+            .filter(|result| !result.error.is_empty())
+            .map(|result| result.error.clone())
+            .collect();
+        let error_count = errors.len() as i32;
+        
         Ok(Response::new(LearnResponse {
             inserted_count,
             duplicate_count,
             error_count,
             errors,
-            message: format!("Inserted {} claims, {} errors", inserted_count, error_count),
+            message: format!(
+                "Inserted {} claims, corroborated {} existing claims, {} errors",
+                inserted_count, duplicate_count, error_count
+            ),
+            results,
         }))
     }
 
@@ -216,8 +545,7 @@ where
             .map_err(|e| Status::invalid_argument(format!("Invalid claim ID: {}", e)))?;
         
         // Check if claim exists
-        let store = self.store.lock().unwrap();
-        match store.get_claim(claim_id) {
+        match self.store.get_claim(claim_id).await {
             Ok(Some(_)) => {
                 // TODO: Implement actual eviction marking in Phase 3
                 Ok(Response::new(ForgetResponse {
@@ -240,13 +568,379 @@ where
         }
     }
 
+    type ExportStream = ExportStream;
+
+    async fn export(
+        &self,
+        request: Request<ExportRequest>,
+    ) -> Result<Response<Self::ExportStream>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        let store = Arc::clone(&self.store);
+        let (tx, rx) = mpsc::channel(EXPORT_PAGE);
+        
+        tokio::spawn(async move {
+            if let Err(status) = export_records(&*store, req.namespace, &tx).await {
+                let _ = tx.send(Err(status)).await;
+            }
+        });
+        
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+
+    async fn import(
+        &self,
+        request: Request<ImportRequest>,
+    ) -> Result<Response<ImportResponse>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        let mut response = ImportResponse::default();
+        let mut mapped: HashMap<ClaimId, ClaimId> = HashMap::new();
+        
+        for record in req.claims {
+            let converted = claim_record_from_proto(record)
+                .map_err(|e| e.to_string())
+                .and_then(|(claim, provenance)| {
+                    self.namespace_policy.validate(&claim.namespace)?;
+                    Ok((self.gatekeeper.normalize(&claim)?, provenance))
+                });
+            let (claim, provenance) = match converted {
+                Ok(converted) => converted,
+                Err(e) => {
+                    response.rejected_count += 1;
+                    response.errors.push(format!("Invalid claim: {}", e));
+                    continue;
+                }
+            };
+            
+            if req.use_gatekeeper {
+                let validation = self.gatekeeper.validate_content(&claim);
+                if validation.status != ValidationStatus::Accepted {
+                    response.rejected_count += 1;
+                    response.errors.push(format!(
+                        "Claim {} rejected by gatekeeper: {:?}", claim.id, validation.reasons
+                    ));
+                    continue;
+                }
+            }
+            
+            // Keep the exported ID unless another claim already holds it
+            let original_id = claim.id;
+            let existing = self.store.get_claim(original_id)
+                .await
+                .map_err(|e| Status::internal(format!("Import failed: {:?}", e)))?;
+            let claim = match existing {
+                Some(existing) if existing.content_hash() == claim.content_hash() => {
+                    response.existing_count += 1;
+                    continue;
+                }
+                Some(_) => Claim { id: ClaimId::new(), ..claim },
+                None => claim,
+            };
+            let remapped = claim.id != original_id;
+            
+            match self.store.assert_claim_with_provenance(claim, provenance).await {
+                Ok(outcome) => {
+                    if outcome.is_corroborated() {
+                        response.corroborated_count += 1;
+                    } else if remapped {
+                        response.remapped_count += 1;
+                    } else {
+                        response.inserted_count += 1;
+                    }
+                    
+                    if outcome.claim_id() != original_id {
+                        mapped.insert(original_id, outcome.claim_id());
+                        response.mappings.push(ClaimIdMapping {
+                            original_id: original_id.to_string(),
+                            claim_id: outcome.claim_id().to_string(),
+                        });
+                    }
+                }
+                Err(e) => response.errors.push(format!("Failed to import claim {}: {:?}", original_id, e)),
+            }
+        }
+        
+        for record in req.relationships {
+            let relationship = match relationship_from_proto(record) {
+                Ok(relationship) => relationship,
+                Err(e) => {
+                    response.errors.push(e.to_string());
+                    continue;
+                }
+            };
+            
+            let relationship = Relationship {
+                from_claim: mapped.get(&relationship.from_claim).copied().unwrap_or(relationship.from_claim),
+                to_claim: mapped.get(&relationship.to_claim).copied().unwrap_or(relationship.to_claim),
+                ..relationship
+            };
+            
+            let mut missing = None;
+            for claim_id in [relationship.from_claim, relationship.to_claim] {
+                let found = self.store.get_claim(claim_id)
+                    .await
+                    .map_err(|e| Status::internal(format!("Import failed: {:?}", e)))?;
+                if found.is_none() {
+                    missing = Some(claim_id);
+                }
+            }
+            if let Some(claim_id) = missing {
+                response.errors.push(format!(
+                    "Relationship {} -> {} skipped: claim {} not found",
+                    relationship.from_claim, relationship.to_claim, claim_id
+                ));
+                continue;
+            }
+            
+            match self.store.add_relationship(relationship).await {
+                Ok(()) => response.relationship_count += 1,
+                Err(e) => response.errors.push(format!("Failed to import relationship: {:?}", e)),
+            }
+        }
+        
+        response.message = format!(
+            "Imported {} claims ({} new, {} already present, {} corroborated, {} remapped), {} rejected, {} relationships",
+            response.inserted_count + response.existing_count + response.corroborated_count + response.remapped_count,
+            response.inserted_count,
+            response.existing_count,
+            response.corroborated_count,
+            response.remapped_count,
+            response.rejected_count,
+            response.relationship_count,
+        );
+        
+        Ok(Response::new(response))
+    }
+
+    type WatchStream = WatchStream;
+
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        let filter = WatchFilter::from_request(&req).map_err(Status::invalid_argument)?;
+        let store = Arc::clone(&self.store);
+        let interval = self.watch_interval;
+        let (tx, rx) = mpsc::channel(WATCH_BATCH);
+        
+        // The store has no notifications, so poll its event log until the client goes away
+        tokio::spawn(async move {
+            let mut after = req.after_seq;
+            loop {
+                let events = match store.events_since(after, WATCH_BATCH)
+                    .await
+                    .map_err(|e| Status::internal(format!("Watch failed: {:?}", e)))
+                {
+                    Ok(events) => events,
+                    Err(status) => {
+                        let _ = tx.send(Err(status)).await;
+                        return;
+                    }
+                };
+                let caught_up = events.len() < WATCH_BATCH;
+                
+                for event in events {
+                    after = event.seq;
+                    if !filter.matches(&event) {
+                        continue;
+                    }
+                    if tx.send(Ok(event_to_proto(event))).await.is_err() {
+                        return;
+                    }
+                }
+                
+                if caught_up {
+                    tokio::select! {
+                        _ = tokio::time::sleep(interval) => {}
+                        _ = tx.closed() => return,
+                    }
+                }
+            }
+        });
+        
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+
+    async fn list_namespaces(
+        &self,
+        request: Request<ListNamespacesRequest>,
+    ) -> Result<Response<ListNamespacesResponse>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        let namespaces = self.store.namespace_stats(&req.root)
+            .await
+            .map_err(|e| Status::internal(format!("Listing namespaces failed: {:?}", e)))?;
+        
+        Ok(Response::new(ListNamespacesResponse {
+            namespaces: namespaces.into_iter().map(namespace_stats_to_proto).collect(),
+        }))
+    }
+
+    async fn move_namespace(
+        &self,
+        request: Request<MoveNamespaceRequest>,
+    ) -> Result<Response<MoveNamespaceResponse>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        namespace::check_move(&req.from, &req.to).map_err(Status::invalid_argument)?;
+        
+        let stats_failed = |e| Status::internal(format!("Moving namespace failed: {:?}", e));
+        let sources = self.store.namespace_stats(&req.from).await.map_err(stats_failed)?;
+        if sources.is_empty() {
+            return Err(Status::not_found(format!("Namespace '{}' holds no claims", req.from)));
+        }
+        if !self.store.namespace_stats(&req.to).await.map_err(stats_failed)?.is_empty() {
+            return Err(Status::failed_precondition(format!("Namespace '{}' already holds claims", req.to)));
+        }
+        
+        // Every renamed namespace must still satisfy the policy, depth included
+        for source in &sources {
+            let moved = namespace::rebase(&source.namespace, &req.from, &req.to).unwrap_or_default();
+            self.namespace_policy.validate(&moved).map_err(Status::invalid_argument)?;
+        }
+        
+        let moved_count = self.store.move_namespace(&req.from, &req.to)
+            .await
+            .map_err(|e| Status::internal(format!("Moving namespace failed: {:?}", e)))?;
+        
+        Ok(Response::new(MoveNamespaceResponse {
+            moved_count: moved_count as u64,
+            message: format!("Moved {} claims from '{}' to '{}'", moved_count, req.from, req.to),
+        }))
+    }
+
+    async fn merge_entities(
+        &self,
+        request: Request<MergeEntitiesRequest>,
+    ) -> Result<Response<MergeEntitiesResponse>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        let created_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let alias = EntityAlias::new(&req.alias, &req.canonical, req.reason, created_at)
+            .map_err(Status::invalid_argument)?;
+        self.alias_graph().await?.check_add(&alias).map_err(Status::failed_precondition)?;
+        
+        self.store.add_alias(alias.clone())
+            .await
+            .map_err(|e| Status::internal(format!("Merging entities failed: {:?}", e)))?;
+        
+        Ok(Response::new(MergeEntitiesResponse {
+            message: format!("Merged '{}' into '{}'", alias.alias, alias.canonical),
+            alias: Some(alias_to_proto(alias)),
+        }))
+    }
+
+    async fn unmerge_entity(
+        &self,
+        request: Request<UnmergeEntityRequest>,
+    ) -> Result<Response<UnmergeEntityResponse>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        let retracted = self.store.retract_alias(&req.alias)
+            .await
+            .map_err(|e| Status::internal(format!("Unmerging entity failed: {:?}", e)))?;
+        if !retracted {
+            return Err(Status::not_found(format!("'{}' is not an alias", req.alias)));
+        }
+        
+        Ok(Response::new(UnmergeEntityResponse {
+            message: format!("Unmerged '{}'", alias::normalize(&req.alias)),
+        }))
+    }
+
+    async fn list_aliases(
+        &self,
+        request: Request<ListAliasesRequest>,
+    ) -> Result<Response<ListAliasesResponse>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        let aliases = self.store.entity_aliases(req.include_retracted)
+            .await
+            .map_err(|e| Status::internal(format!("Listing aliases failed: {:?}", e)))?;
+        
+        Ok(Response::new(ListAliasesResponse {
+            aliases: aliases.into_iter().map(alias_to_proto).collect(),
+        }))
+    }
+
+    async fn propose_merges(
+        &self,
+        request: Request<ProposeMergesRequest>,
+    ) -> Result<Response<ProposeMergesResponse>, Status> {
+        let req = request.into_inner();
+        
+        if req.auth_token.is_empty() {
+            return Err(Status::unauthenticated("Missing authentication token"));
+        }
+        
+        // Scoring is quadratic in the entities mentioned, so look at a bounded sample
+        let query = ClaimQuery {
+            namespace: req.namespace,
+            limit: Some(MERGE_CLAIMS),
+            ..Default::default()
+        };
+        let claims = self.store.query_claims(&query)
+            .await
+            .map_err(|e| Status::internal(format!("Proposing merges failed: {:?}", e)))?;
+        let graph = self.alias_graph().await?;
+        
+        let resolver = Arc::clone(&self.resolver);
+        let min_score = req.min_score.unwrap_or(resolver.min_score());
+        let limit = if req.limit > 0 { (req.limit as usize).min(MAX_MERGE_PROPOSALS) } else { 100 };
+        let proposals = tokio::task::spawn_blocking(move || resolver.propose_at_least(&claims, &graph, min_score))
+            .await
+            .map_err(|e| Status::internal(format!("Proposing merges failed: {}", e)))?;
+        
+        Ok(Response::new(ProposeMergesResponse {
+            proposals: proposals.into_iter().take(limit).map(merge_proposal_to_proto).collect(),
+        }))
+    }
+
     async fn health_check(
         &self,
         _request: Request<HealthCheckRequest>,
     ) -> Result<Response<HealthCheckResponse>, Status> {
-        let store = self.store.lock().unwrap();
         let query = ClaimQuery::default();
-        let claim_count = store.query_claims(&query)
+        let claim_count = self.store.query_claims(&query)
+            .await
             .map(|claims| claims.len() as i64)
             .unwrap_or(0);
         
@@ -263,66 +957,625 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use boswell_domain::Relationship;
+    use boswell_domain::{RelationshipType, Tier as DomainTier};
+    use boswell_domain::traits::ClaimStore;
+    use boswell_store::{BlockingStore, InMemoryStore};
+    use std::sync::{Arc, Mutex};
+    use tokio_stream::StreamExt;
     
-    // Mock store for testing
-    struct MockStore;
+    fn test_store() -> BlockingStore<Arc<Mutex<InMemoryStore>>> {
+        BlockingStore::new(Arc::new(Mutex::new(InMemoryStore::new())))
+    }
     
-    impl ClaimStore for MockStore {
-        type Error = String;
-        
-        fn assert_claim(&mut self, claim: Claim) -> Result<ClaimId, Self::Error> {
-            Ok(claim.id)
+    fn test_claim(namespace: &str, subject: &str) -> Claim {
+        Claim {
+            id: ClaimId::new(),
+            namespace: namespace.to_string(),
+            subject: subject.to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.95),
+            tier: DomainTier::Project,
+            created_at: 1000000,
+            stale_at: Some(2000000),
         }
+    }
+    
+    async fn export_all<S>(service: &BosWellServiceImpl<S>, namespace: &str) -> (Vec<ClaimRecord>, Vec<RelationshipRecord>)
+    where
+        S: AsyncClaimStore + Send + Sync + 'static,
+        S::Error: std::fmt::Debug,
+    {
+        let request = Request::new(ExportRequest {
+            namespace: namespace.to_string(),
+            auth_token: "token".to_string(),
+        });
+        let records: Vec<ExportRecord> = service.export(request).await.unwrap().into_inner()
+            .map(Result::unwrap)
+            .collect()
+            .await;
         
-        fn get_claim(&self, _id: ClaimId) -> Result<Option<Claim>, Self::Error> {
-            Ok(Some(Claim {
-                id: ClaimId::new(),
-                namespace: "test".to_string(),
-                subject: "Alice".to_string(),
-                predicate: "knows".to_string(),
-                object: "Bob".to_string(),
-                confidence: (0.8, 0.95),
-                tier: "task".to_string(),
-                created_at: 1000000,
-                stale_at: None,
-            }))
+        let mut claims = Vec::new();
+        let mut relationships = Vec::new();
+        for record in records {
+            match record.record.unwrap() {
+                export_record::Record::Claim(claim) => {
+                    assert!(relationships.is_empty(), "claims must precede relationships");
+                    claims.push(claim);
+                }
+                export_record::Record::Relationship(relationship) => relationships.push(relationship),
+            }
         }
+        (claims, relationships)
+    }
+    
+    async fn next_event(stream: &mut WatchStream) -> WatchEvent {
+        tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("no event within 5s")
+            .unwrap()
+            .unwrap()
+    }
+    
+    #[tokio::test]
+    async fn test_watch_filters_and_resumes() {
+        let shared = Arc::new(Mutex::new(InMemoryStore::new()));
+        let service = BosWellServiceImpl::new(BlockingStore::new(Arc::clone(&shared)))
+            .with_watch_interval(Duration::from_millis(10));
         
-        fn query_claims(&self, _query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
-            Ok(vec![
-                Claim {
-                    id: ClaimId::new(),
-                    namespace: "test".to_string(),
-                    subject: "Alice".to_string(),
-                    predicate: "knows".to_string(),
-                    object: "Bob".to_string(),
-                    confidence: (0.8, 0.95),
-                    tier: "task".to_string(),
-                    created_at: 1000000,
-                    stale_at: None,
-                }
-            ])
+        let alice = test_claim("acme/eng", "Alice");
+        let outside = test_claim("other", "Dave");
+        {
+            let mut store = shared.lock().unwrap();
+            store.assert_claim(alice.clone()).unwrap();
+            store.assert_claim(outside.clone()).unwrap();
+            store.set_tier(outside.id, boswell_domain::Tier::Permanent).unwrap();
         }
         
-        fn add_relationship(&mut self, _relationship: Relationship) -> Result<(), Self::Error> {
-            Ok(())
+        let watch = |after_seq| Request::new(WatchRequest {
+            namespace: Some("acme".to_string()),
+            tier: None,
+            event_types: vec![EventType::Promoted as i32, EventType::RelationshipAdded as i32],
+            after_seq,
+            auth_token: "token".to_string(),
+        });
+        let mut stream = service.watch(watch(0)).await.unwrap().into_inner();
+        
+        // Events recorded after the watch started are delivered too
+        {
+            let mut store = shared.lock().unwrap();
+            store.set_tier(alice.id, boswell_domain::Tier::Permanent).unwrap();
+            store.add_relationship(Relationship::new(alice.id, outside.id, RelationshipType::Contradicts, 0.9, 10)).unwrap();
         }
+        let promoted = next_event(&mut stream).await;
+        assert_eq!((promoted.event_type, promoted.claim_id.clone()), (EventType::Promoted as i32, alice.id.to_string()));
+        assert_eq!(promoted.to_tier, Tier::Permanent as i32);
+        let contradiction = next_event(&mut stream).await;
+        assert_eq!(contradiction.event_type, EventType::RelationshipAdded as i32);
+        assert_eq!(contradiction.relationship_type, crate::proto::RelationshipType::Contradicts as i32);
+        assert_eq!(contradiction.related_claim_id, outside.id.to_string());
+        drop(stream);
+        
+        let mut resumed = service.watch(watch(promoted.seq)).await.unwrap().into_inner();
+        assert_eq!(next_event(&mut resumed).await, contradiction);
         
-        fn get_relationships(&self, _id: ClaimId) -> Result<Vec<Relationship>, Self::Error> {
-            Ok(vec![])
+        let invalid = Request::new(WatchRequest {
+            event_types: vec![EventType::Unspecified as i32],
+            auth_token: "token".to_string(),
+            ..Default::default()
+        });
+        let status = service.watch(invalid).await.err().expect("unspecified event type accepted");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+    
+    #[tokio::test]
+    async fn test_watch_filters_on_recorded_namespace_and_tier() {
+        let shared = Arc::new(Mutex::new(InMemoryStore::new()));
+        let service = BosWellServiceImpl::new(BlockingStore::new(Arc::clone(&shared)))
+            .with_watch_interval(Duration::from_millis(10));
+        
+        let alice = test_claim("acme/eng", "Alice");
+        let marker = test_claim("acme/eng", "Carol");
+        {
+            let mut store = shared.lock().unwrap();
+            store.assert_claim(alice.clone()).unwrap();
+            store.set_tier(alice.id, boswell_domain::Tier::Permanent).unwrap();
+            assert!(store.delete_claim(alice.id).unwrap());
+            store.assert_claim(marker.clone()).unwrap();
         }
+        
+        let watch = |namespace: Option<&str>, tier: Option<Tier>| Request::new(WatchRequest {
+            namespace: namespace.map(str::to_string),
+            tier: tier.map(|tier| tier as i32),
+            auth_token: "token".to_string(),
+            ..Default::default()
+        });
+        
+        // The eviction still carries the namespace the claim was in
+        let mut by_namespace = service.watch(watch(Some("acme"), None)).await.unwrap().into_inner();
+        let types: Vec<i32> = [
+            next_event(&mut by_namespace).await,
+            next_event(&mut by_namespace).await,
+            next_event(&mut by_namespace).await,
+        ].iter().map(|event| event.event_type).collect();
+        assert_eq!(types, [EventType::Asserted as i32, EventType::Promoted as i32, EventType::Evicted as i32]);
+        
+        // Each event is matched on the tier recorded with it, not the claim's tier now
+        let mut by_tier = service.watch(watch(None, Some(Tier::Project))).await.unwrap().into_inner();
+        let asserted = next_event(&mut by_tier).await;
+        assert_eq!((asserted.event_type, asserted.claim_id), (EventType::Asserted as i32, alice.id.to_string()));
+        assert_eq!(asserted.namespace.as_deref(), Some("acme/eng"));
+        assert_eq!(next_event(&mut by_tier).await.claim_id, marker.id.to_string());
+        
+        let mut by_permanent = service.watch(watch(None, Some(Tier::Permanent))).await.unwrap().into_inner();
+        let types: Vec<i32> = [
+            next_event(&mut by_permanent).await,
+            next_event(&mut by_permanent).await,
+        ].iter().map(|event| event.event_type).collect();
+        assert_eq!(types, [EventType::Promoted as i32, EventType::Evicted as i32]);
     }
     
     #[tokio::test]
     async fn test_health_check() {
-        let service = BosWellServiceImpl::new(Arc::new(Mutex::new(MockStore)));
+        let service = BosWellServiceImpl::new(test_store());
         let request = Request::new(HealthCheckRequest {});
         
         let response = service.health_check(request).await.unwrap();
         let health = response.into_inner();
         
         assert_eq!(health.status, health_check_response::Status::Healthy as i32);
-        assert!(health.claim_count >= 0);
+        assert_eq!(health.claim_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_learn_reports_per_claim_results() {
+        let service = BosWellServiceImpl::new(test_store());
+        
+        let valid = claim_to_proto(Claim {
+            id: ClaimId::new(),
+            namespace: "test".to_string(),
+            subject: "Alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.95),
+            tier: DomainTier::Task,
+            created_at: 1000000,
+            stale_at: None,
+        });
+        let invalid = crate::proto::Claim { id: "not-a-ulid".to_string(), ..valid.clone() };
+        
+        let request = Request::new(LearnRequest {
+            claims: vec![invalid.clone(), valid.clone()],
+            skip_duplicates: false,
+            auth_token: "token".to_string(),
+            all_or_nothing: false,
+        });
+        let response = service.learn(request).await.unwrap().into_inner();
+        
+        assert_eq!(response.inserted_count, 1);
+        assert_eq!(response.error_count, 1);
+        assert_eq!(response.results.len(), 2);
+        assert!(!response.results[0].error.is_empty());
+        assert_eq!(response.results[1].claim_id, valid.id);
+        
+        // All-or-nothing rejects the batch outright
+        let request = Request::new(LearnRequest {
+            claims: vec![valid, invalid],
+            skip_duplicates: false,
+            auth_token: "token".to_string(),
+            all_or_nothing: true,
+        });
+        let status = service.learn(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_normalizes_predicates() {
+        use boswell_domain::{PredicateDefinition, PredicateRegistry, ValueType};
+        use boswell_gatekeeper::ValidationConfig;
+        
+        let mut registry = PredicateRegistry::new();
+        registry.register("acme", PredicateDefinition::new("works_at")
+            .with_synonyms(["employed_by"])
+            .with_inverse("employs")
+            .single_valued()
+            .with_value_type(ValueType::Kind("company".to_string()))).unwrap();
+        let gatekeeper = Gatekeeper::new(ValidationConfig { predicate_registry: registry, ..Default::default() });
+        let service = BosWellServiceImpl::new(test_store()).with_gatekeeper(gatekeeper);
+        let assert = |subject: &str, predicate: &str, object: &str| AssertRequest {
+            namespace: "acme/eng".to_string(),
+            subject: subject.to_string(),
+            predicate: predicate.to_string(),
+            object: object.to_string(),
+            confidence: Some(crate::proto::ConfidenceInterval { lower: 0.8, upper: 0.9 }),
+            auth_token: "token".to_string(),
+            ..Default::default()
+        };
+        
+        let first = service.assert(Request::new(assert("company:acme", "employs", "person:alice"))).await.unwrap().into_inner();
+        let second = service.assert(Request::new(assert("person:alice", "worksAt", "company:acme"))).await.unwrap().into_inner();
+        assert!(second.is_duplicate);
+        assert_eq!(second.claim_id, first.claim_id);
+        
+        let status = service.assert(Request::new(assert("person:alice", "works_at", "city:paris"))).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        
+        let learned = Claim {
+            subject: "person:bob".to_string(),
+            predicate: "Employed-By".to_string(),
+            object: "company:acme".to_string(),
+            ..test_claim("acme", "")
+        };
+        let request = Request::new(LearnRequest {
+            claims: vec![claim_to_proto(learned.clone())],
+            skip_duplicates: false,
+            auth_token: "token".to_string(),
+            all_or_nothing: false,
+        });
+        assert_eq!(service.learn(request).await.unwrap().into_inner().inserted_count, 1);
+        let stored = service.store.get_claim(learned.id).await.unwrap().unwrap();
+        assert_eq!(stored.predicate, "works_at");
+    }
+
+    #[tokio::test]
+    async fn test_rejects_non_finite_confidence() {
+        let service = BosWellServiceImpl::new(test_store());
+        for (lower, upper) in [(f64::NAN, 0.9), (0.5, f64::INFINITY)] {
+            let request = Request::new(AssertRequest {
+                namespace: "test".to_string(),
+                subject: "user:alice".to_string(),
+                predicate: "knows".to_string(),
+                object: "user:bob".to_string(),
+                confidence: Some(crate::proto::ConfidenceInterval { lower, upper }),
+                auth_token: "token".to_string(),
+                ..Default::default()
+            });
+            let status = service.assert(request).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        }
+        
+        let claim = test_claim("test", "Alice");
+        let mut relationship = relationship_to_proto(Relationship::new(claim.id, claim.id, RelationshipType::Supports, 0.5, 10));
+        relationship.strength = f64::NAN;
+        let request = Request::new(ImportRequest {
+            claims: vec![claim_record_to_proto(claim, vec![])],
+            relationships: vec![relationship],
+            use_gatekeeper: false,
+            auth_token: "token".to_string(),
+        });
+        let response = service.import(request).await.unwrap().into_inner();
+        assert_eq!(response.inserted_count, 1);
+        assert!(response.errors.iter().any(|e| e.contains("strength")));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_namespaces() {
+        let service = BosWellServiceImpl::new(test_store())
+            .with_namespace_policy(NamespacePolicy { max_depth: 2, ..Default::default() });
+        
+        let request = Request::new(AssertRequest {
+            namespace: "acme/eng/db".to_string(),
+            subject: "user:alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "user:bob".to_string(),
+            confidence: Some(crate::proto::ConfidenceInterval { lower: 0.8, upper: 0.9 }),
+            auth_token: "token".to_string(),
+            ..Default::default()
+        });
+        let status = service.assert(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        
+        let request = Request::new(LearnRequest {
+            claims: vec![claim_to_proto(test_claim("acme:eng", "Alice")), claim_to_proto(test_claim("acme/eng", "Bob"))],
+            skip_duplicates: false,
+            auth_token: "token".to_string(),
+            all_or_nothing: false,
+        });
+        let response = service.learn(request).await.unwrap().into_inner();
+        assert_eq!((response.inserted_count, response.error_count), (1, 1));
+        assert!(response.results[0].error.contains("acme:eng"));
+    }
+
+    #[tokio::test]
+    async fn test_list_and_move_namespaces() {
+        let store = Arc::new(Mutex::new(InMemoryStore::new()));
+        for (namespace, subject) in [("acme/eng", "Alice"), ("acme/eng/db", "Bob"), ("acme/ops", "Carol")] {
+            store.lock().unwrap().assert_claim(test_claim(namespace, subject)).unwrap();
+        }
+        let service = BosWellServiceImpl::new(BlockingStore::new(store))
+            .with_namespace_policy(NamespacePolicy { max_depth: 3, ..Default::default() });
+        let list = |root: &str| ListNamespacesRequest { root: root.to_string(), auth_token: "token".to_string() };
+        let mv = |from: &str, to: &str| MoveNamespaceRequest {
+            from: from.to_string(),
+            to: to.to_string(),
+            auth_token: "token".to_string(),
+        };
+        
+        let listed = service.list_namespaces(Request::new(list("acme/eng"))).await.unwrap().into_inner();
+        let names: Vec<_> = listed.namespaces.iter().map(|stats| stats.namespace.as_str()).collect();
+        assert_eq!(names, vec!["acme/eng", "acme/eng/db"]);
+        assert_eq!(listed.namespaces[0].project_count, 1);
+        
+        let code = |result: Result<Response<MoveNamespaceResponse>, Status>| result.unwrap_err().code();
+        assert_eq!(code(service.move_namespace(Request::new(mv("acme/eng", "acme/ops"))).await), tonic::Code::FailedPrecondition);
+        assert_eq!(code(service.move_namespace(Request::new(mv("acme/qa", "qa"))).await), tonic::Code::NotFound);
+        assert_eq!(code(service.move_namespace(Request::new(mv("acme", "acme/old"))).await), tonic::Code::InvalidArgument);
+        // `acme/eng/db` would land four segments deep
+        assert_eq!(code(service.move_namespace(Request::new(mv("acme/eng", "x/y/z"))).await), tonic::Code::InvalidArgument);
+        
+        let moved = service.move_namespace(Request::new(mv("acme/eng", "eng"))).await.unwrap().into_inner();
+        assert_eq!(moved.moved_count, 2);
+        let listed = service.list_namespaces(Request::new(list(""))).await.unwrap().into_inner();
+        let names: Vec<_> = listed.namespaces.iter().map(|stats| stats.namespace.as_str()).collect();
+        assert_eq!(names, vec!["acme/ops", "eng", "eng/db"]);
+    }
+
+    #[tokio::test]
+    async fn test_query_entity_patterns() {
+        let store = Arc::new(Mutex::new(InMemoryStore::new()));
+        for (subject, object) in [("person:alice", "date:1990-04-02"), ("company:acme", "date:2025-03-01"), ("person:bob", "person:alice")] {
+            store.lock().unwrap().assert_claim(Claim { object: object.to_string(), ..test_claim("facts", subject) }).unwrap();
+        }
+        let service = BosWellServiceImpl::new(BlockingStore::new(store));
+        let query = |filter: QueryFilter| QueryRequest {
+            filter: Some(filter),
+            mode: QueryMode::Fast as i32,
+            limit: 0,
+            auth_token: "token".to_string(),
+        };
+        let subjects = |response: QueryResponse| -> Vec<String> {
+            response.claims.into_iter().map(|claim| claim.subject).collect()
+        };
+        
+        let people = QueryFilter { subject: Some("person:*".to_string()), ..Default::default() };
+        let response = service.query(Request::new(query(people))).await.unwrap().into_inner();
+        assert_eq!(subjects(response), vec!["person:alice", "person:bob"]);
+        
+        let recent = QueryFilter { object_after: Some("date:2000".to_string()), ..Default::default() };
+        let response = service.query(Request::new(query(recent))).await.unwrap().into_inner();
+        assert_eq!(subjects(response), vec!["company:acme"]);
+        
+        let exact = QueryFilter { object: Some("person:alice".to_string()), ..Default::default() };
+        let response = service.query(Request::new(query(exact))).await.unwrap().into_inner();
+        assert_eq!(subjects(response), vec!["person:bob"]);
+        
+        let invalid = QueryFilter { object_before: Some("person:alice".to_string()), ..Default::default() };
+        let status = service.query(Request::new(query(invalid))).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_past_the_first_page() {
+        let store = Arc::new(Mutex::new(InMemoryStore::new()));
+        // Five older claims that the subject filter rejects sort ahead of the matches
+        for i in 0..5 {
+            let claim = Claim { created_at: 1000 + i, ..test_claim("facts", &format!("person:other{}", i)) };
+            store.lock().unwrap().assert_claim(claim).unwrap();
+        }
+        for object in ["city:paris", "city:rome", "city:oslo"] {
+            let claim = Claim { object: object.to_string(), created_at: 2000, ..test_claim("facts", "person:alice") };
+            store.lock().unwrap().assert_claim(claim).unwrap();
+        }
+        let service = BosWellServiceImpl::new(BlockingStore::new(store));
+        
+        let response = service.query(Request::new(QueryRequest {
+            filter: Some(QueryFilter { subject: Some("person:alice".to_string()), ..Default::default() }),
+            mode: QueryMode::Fast as i32,
+            limit: 2,
+            auth_token: "token".to_string(),
+        })).await.unwrap().into_inner();
+        assert_eq!(response.claims.len(), 2);
+        assert!(response.claims.iter().all(|claim| claim.subject == "person:alice"));
+    }
+
+    #[tokio::test]
+    async fn test_deliberate_query_recomputes_confidence() {
+        let store = Arc::new(Mutex::new(InMemoryStore::new()));
+        // Stale since long ago, so its confidence has decayed
+        store.lock().unwrap().assert_claim(test_claim("facts", "Alice")).unwrap();
+        let service = BosWellServiceImpl::new(BlockingStore::new(store));
+        let query = |mode: QueryMode| QueryRequest {
+            filter: Some(QueryFilter::default()),
+            mode: mode as i32,
+            limit: 0,
+            auth_token: "token".to_string(),
+        };
+        let upper = |response: QueryResponse| response.claims[0].confidence.unwrap().upper;
+        
+        let fast = service.query(Request::new(query(QueryMode::Fast))).await.unwrap().into_inner();
+        assert_eq!(upper(fast), 0.95);
+        let deliberate = service.query(Request::new(query(QueryMode::Deliberate))).await.unwrap().into_inner();
+        assert!(upper(deliberate) < 0.1);
+    }
+
+    #[tokio::test]
+    async fn test_deliberate_query_does_not_raise_confidence() {
+        let store = Arc::new(Mutex::new(InMemoryStore::new()));
+        let claim = Claim { confidence: (0.1, 0.2), stale_at: None, ..test_claim("facts", "Alice") };
+        store.lock().unwrap().assert_claim(claim).unwrap();
+        let service = BosWellServiceImpl::new(BlockingStore::new(store));
+        
+        let response = service.query(Request::new(QueryRequest {
+            filter: Some(QueryFilter::default()),
+            mode: QueryMode::Deliberate as i32,
+            limit: 0,
+            auth_token: "token".to_string(),
+        })).await.unwrap().into_inner();
+        let confidence = response.claims[0].confidence.unwrap();
+        assert!(confidence.lower <= 0.1 && confidence.upper <= 0.2, "raised to {:?}", confidence);
+    }
+
+    #[tokio::test]
+    async fn test_entity_aliases() {
+        let store = Arc::new(Mutex::new(InMemoryStore::new()));
+        for (subject, object) in [("person:john_doe", "company:acme"), ("person:j_doe", "city:paris"), ("person:John", "city:rome")] {
+            store.lock().unwrap().assert_claim(Claim { object: object.to_string(), ..test_claim("facts", subject) }).unwrap();
+        }
+        let service = BosWellServiceImpl::new(BlockingStore::new(store));
+        let merge = |alias: &str, canonical: &str| MergeEntitiesRequest {
+            alias: alias.to_string(),
+            canonical: canonical.to_string(),
+            reason: "same person".to_string(),
+            auth_token: "token".to_string(),
+        };
+        let john = |expand_aliases: bool| QueryRequest {
+            filter: Some(QueryFilter { subject: Some("person:john_doe".to_string()), expand_aliases, ..Default::default() }),
+            mode: QueryMode::Fast as i32,
+            limit: 0,
+            auth_token: "token".to_string(),
+        };
+        
+        let proposals = service.propose_merges(Request::new(ProposeMergesRequest {
+            auth_token: "token".to_string(),
+            ..Default::default()
+        })).await.unwrap().into_inner().proposals;
+        assert!(proposals.iter().any(|p| p.alias == "person:j_doe" && p.canonical == "person:john_doe"));
+        
+        service.merge_entities(Request::new(merge("person:J Doe", "person:john_doe"))).await.unwrap();
+        service.merge_entities(Request::new(merge("person:john", "person:john_doe"))).await.unwrap();
+        let code = |result: Result<Response<MergeEntitiesResponse>, Status>| result.unwrap_err().code();
+        assert_eq!(code(service.merge_entities(Request::new(merge("person:john_doe", "person:john"))).await), tonic::Code::FailedPrecondition);
+        assert_eq!(code(service.merge_entities(Request::new(merge("person:john_doe", "company:acme"))).await), tonic::Code::InvalidArgument);
+        
+        let response = service.query(Request::new(john(false))).await.unwrap().into_inner();
+        assert_eq!(response.total_count, 1);
+        let response = service.query(Request::new(john(true))).await.unwrap().into_inner();
+        assert_eq!(response.total_count, 3);
+        
+        let unmerge = UnmergeEntityRequest { alias: "person:john".to_string(), auth_token: "token".to_string() };
+        service.unmerge_entity(Request::new(unmerge.clone())).await.unwrap();
+        let status = service.unmerge_entity(Request::new(unmerge)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        let response = service.query(Request::new(john(true))).await.unwrap().into_inner();
+        assert_eq!(response.total_count, 2);
+        
+        let list = |include_retracted| ListAliasesRequest { include_retracted, auth_token: "token".to_string() };
+        assert_eq!(service.list_aliases(Request::new(list(false))).await.unwrap().into_inner().aliases.len(), 1);
+        let all = service.list_aliases(Request::new(list(true))).await.unwrap().into_inner().aliases;
+        assert_eq!(all.len(), 2);
+        assert!(all[1].retracted_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_export_streams_every_page() {
+        let store = Arc::new(Mutex::new(InMemoryStore::new()));
+        // Every claim has the same `created_at`, so pages are split by ID
+        let claims: Vec<Claim> = (0..2 * EXPORT_PAGE + 1)
+            .map(|i| test_claim("acme", &format!("person:{}", i)))
+            .collect();
+        {
+            let mut store = store.lock().unwrap();
+            for claim in &claims {
+                store.assert_claim(claim.clone()).unwrap();
+            }
+            let (first, last) = (claims[0].id, claims[claims.len() - 1].id);
+            store.add_relationship(Relationship::new(first, last, RelationshipType::Supports, 0.7, 10)).unwrap();
+            store.add_relationship(Relationship::new(last, first, RelationshipType::Contradicts, 0.2, 10)).unwrap();
+        }
+        let service = BosWellServiceImpl::new(BlockingStore::new(store));
+        
+        let (exported, relationships) = export_all(&service, "acme").await;
+        let ids: HashSet<String> = exported.iter().map(|record| record.claim.as_ref().unwrap().id.clone()).collect();
+        assert_eq!(exported.len(), claims.len());
+        assert_eq!(ids.len(), claims.len());
+        assert_eq!(relationships.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let source = Arc::new(Mutex::new(InMemoryStore::new()));
+        let alice = test_claim("acme/eng", "Alice");
+        let carol = test_claim("acme/eng/db", "Carol");
+        let outside = test_claim("other", "Dave");
+        {
+            let mut store = source.lock().unwrap();
+            let provenance = vec![ProvenanceEntry::new("user:alice".to_string(), 500, "user".to_string())];
+            store.assert_claim_with_provenance(alice.clone(), provenance).unwrap();
+            store.assert_claim(carol.clone()).unwrap();
+            store.assert_claim(outside.clone()).unwrap();
+            store.add_relationship(Relationship::new(alice.id, carol.id, RelationshipType::Supports, 0.7, 10)).unwrap();
+            store.add_relationship(Relationship::new(alice.id, outside.id, RelationshipType::References, 0.5, 10)).unwrap();
+        }
+        let exporter = BosWellServiceImpl::new(BlockingStore::new(Arc::clone(&source)));
+        
+        let (claims, relationships) = export_all(&exporter, "acme/eng").await;
+        assert_eq!(claims.len(), 2);
+        assert_eq!(relationships.len(), 1, "links leaving the subtree are not exported");
+        
+        // The target already holds Carol's ID for an unrelated claim
+        let target = Arc::new(Mutex::new(InMemoryStore::new()));
+        let squatter = Claim { id: carol.id, ..test_claim("acme/eng", "Erin") };
+        target.lock().unwrap().assert_claim(squatter.clone()).unwrap();
+        let importer = BosWellServiceImpl::new(BlockingStore::new(Arc::clone(&target)));
+        
+        let request = Request::new(ImportRequest {
+            claims: claims.clone(),
+            relationships: relationships.clone(),
+            use_gatekeeper: false,
+            auth_token: "token".to_string(),
+        });
+        let response = importer.import(request).await.unwrap().into_inner();
+        assert_eq!((response.inserted_count, response.remapped_count, response.relationship_count), (1, 1, 1));
+        assert!(response.errors.is_empty(), "errors: {:?}", response.errors);
+        assert_eq!(response.mappings.len(), 1);
+        assert_eq!(response.mappings[0].original_id, carol.id.to_string());
+        let new_carol = ClaimId::from_string(&response.mappings[0].claim_id).unwrap();
+        
+        {
+            let store = target.lock().unwrap();
+            assert_eq!(store.get_claim(alice.id).unwrap(), Some(alice.clone()));
+            assert_eq!(store.get_claim(carol.id).unwrap(), Some(squatter));
+            assert_eq!(store.get_claim(new_carol).unwrap(), Some(Claim { id: new_carol, ..carol }));
+            assert_eq!(store.get_provenance(alice.id).unwrap()[0].source, "user:alice");
+            let imported = store.get_relationships(alice.id).unwrap();
+            assert_eq!(imported.len(), 1);
+            assert_eq!((imported[0].to_claim, imported[0].strength), (new_carol, 0.7));
+        }
+        
+        // Importing the same export again changes nothing
+        let request = Request::new(ImportRequest {
+            claims,
+            relationships,
+            use_gatekeeper: false,
+            auth_token: "token".to_string(),
+        });
+        let response = importer.import(request).await.unwrap().into_inner();
+        assert_eq!((response.inserted_count, response.existing_count, response.corroborated_count), (0, 1, 1));
+    }
+    
+    #[tokio::test]
+    async fn test_import_through_gatekeeper() {
+        let service = BosWellServiceImpl::new(test_store());
+        let valid = Claim {
+            subject: "user:alice".to_string(),
+            predicate: "likes:coffee".to_string(),
+            object: "beverage:espresso".to_string(),
+            ..test_claim("acme", "")
+        };
+        let invalid = test_claim("acme", "alice");
+        let records = vec![
+            claim_record_to_proto(valid.clone(), vec![]),
+            claim_record_to_proto(invalid.clone(), vec![]),
+        ];
+        
+        let request = Request::new(ImportRequest {
+            claims: records.clone(),
+            relationships: vec![],
+            use_gatekeeper: true,
+            auth_token: "token".to_string(),
+        });
+        let response = service.import(request).await.unwrap().into_inner();
+        assert_eq!((response.inserted_count, response.rejected_count), (1, 1));
+        assert!(response.errors[0].contains(&invalid.id.to_string()));
+        
+        // Without the gatekeeper the entity format is not checked
+        let request = Request::new(ImportRequest {
+            claims: records,
+            relationships: vec![],
+            use_gatekeeper: false,
+            auth_token: "token".to_string(),
+        });
+        let response = service.import(request).await.unwrap().into_inner();
+        assert_eq!((response.inserted_count, response.existing_count), (1, 1));
     }
 }
@@ -1,13 +1,16 @@
 //! Configuration for Janitor operations
 //!
-//! Defines TTLs (Time-To-Live) per tier and sweep intervals.
+//! Defines sweep intervals and tier management thresholds. When claims go
+//! stale and expire comes from the store's `StalenessPolicy`.
 
+use boswell_domain::{StalenessPolicy, StalenessRule, Tier};
+use boswell_domain::staleness::{DAY_SECS, HOUR_SECS};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Configuration for the Janitor service
 ///
-/// Controls tier-specific TTLs, sweep intervals, and operational modes.
+/// Controls sweep intervals, tier management thresholds, and operational modes.
 ///
 /// # Examples
 ///
@@ -16,29 +19,38 @@ use std::time::Duration;
 ///
 /// // Default configuration (balanced)
 /// let config = JanitorConfig::default();
-/// assert_eq!(config.ephemeral_ttl_hours, 12);
+/// assert_eq!(config.sweep_interval_minutes, 60);
 ///
 /// // Aggressive cleanup
 /// let config = JanitorConfig::aggressive();
-/// assert_eq!(config.ephemeral_ttl_hours, 6);
+/// assert_eq!(config.sweep_interval_minutes, 30);
 ///
 /// // Lenient cleanup
 /// let config = JanitorConfig::lenient();
-/// assert_eq!(config.ephemeral_ttl_hours, 24);
+/// assert_eq!(config.sweep_interval_minutes, 120);
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JanitorConfig {
-    /// TTL for Ephemeral tier claims (in hours)
-    /// Default: 12 hours (session lifetime baseline per ADR-019)
-    pub ephemeral_ttl_hours: u64,
+    /// Age at which Ephemeral tier claims are swept (in hours)
+    /// Overrides the staleness policy's Ephemeral rule, see [`Self::staleness_policy`]
+    /// Default: unset (follow the store's policy)
+    #[deprecated(note = "configure the store's staleness policy instead")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ephemeral_ttl_hours: Option<u64>,
 
-    /// TTL for Task tier claims (in hours, if idle)
-    /// Default: 24 hours of inactivity
-    pub task_ttl_hours: u64,
+    /// Age at which Task tier claims are swept (in hours)
+    /// Overrides the staleness policy's Task rule, see [`Self::staleness_policy`]
+    /// Default: unset (follow the store's policy)
+    #[deprecated(note = "configure the store's staleness policy instead")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_ttl_hours: Option<u64>,
 
-    /// Staleness threshold for Project tier (in days)
-    /// Default: 90 days of no access
-    pub project_stale_days: u64,
+    /// Age at which Project tier claims are swept (in days)
+    /// Overrides the staleness policy's Project rule, see [`Self::staleness_policy`]
+    /// Default: unset (follow the store's policy)
+    #[deprecated(note = "configure the store's staleness policy instead")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_stale_days: Option<u64>,
 
     /// How often to run the sweep cycle (in minutes)
     /// Default: Every 60 minutes (hourly)
@@ -50,7 +62,8 @@ pub struct JanitorConfig {
     pub demotion_confidence_threshold: f64,
 
     /// Access frequency threshold for promotion (accesses per week)
-    /// Claims accessed more frequently are candidates for promotion
+    /// Claims accessed more frequently are candidates for promotion; the
+    /// store does not count accesses yet, so no claim is promoted
     /// Default: 7 (daily access)
     pub promotion_access_threshold: u64,
 
@@ -68,6 +81,12 @@ pub struct JanitorConfig {
     /// Default: true
     #[serde(default = "default_auto_demote")]
     pub auto_demote: bool,
+
+    /// Tombstone ratio at which the store's vector index is compacted
+    /// Deleted claims are tombstoned in the index until the ratio reaches this value
+    /// Default: 0.2
+    #[serde(default = "default_vector_compaction_threshold")]
+    pub vector_compaction_threshold: f64,
 }
 
 fn default_auto_promote() -> bool {
@@ -78,72 +97,76 @@ fn default_auto_demote() -> bool {
     true
 }
 
+fn default_vector_compaction_threshold() -> f64 {
+    0.2
+}
+
+#[allow(deprecated)]
 impl Default for JanitorConfig {
     /// Create default configuration with balanced cleanup policies
     ///
-    /// - Ephemeral: 12 hours (ADR-019 session lifetime)
-    /// - Task: 24 hours of inactivity
-    /// - Project: 90 days of no access
     /// - Sweep interval: 60 minutes (hourly)
     /// - Demotion threshold: 0.3 confidence
     /// - Promotion threshold: 7 accesses/week (daily)
     fn default() -> Self {
         Self {
-            ephemeral_ttl_hours: 12,
-            task_ttl_hours: 24,
-            project_stale_days: 90,
+            ephemeral_ttl_hours: None,
+            task_ttl_hours: None,
+            project_stale_days: None,
             sweep_interval_minutes: 60,
             demotion_confidence_threshold: 0.3,
             promotion_access_threshold: 7,
             dry_run: false,
             auto_promote: true,
             auto_demote: true,
+            vector_compaction_threshold: default_vector_compaction_threshold(),
         }
     }
 }
 
+#[allow(deprecated)]
 impl JanitorConfig {
-    /// Aggressive cleanup configuration (shorter TTLs, frequent sweeps)
+    /// Aggressive cleanup configuration (frequent sweeps, eager demotion)
     ///
     /// Suitable for resource-constrained environments or when storage is at premium.
     ///
-    /// - Ephemeral: 6 hours
-    /// - Task: 12 hours
-    /// - Project: 30 days
     /// - Sweep interval: 30 minutes
+    /// - Demotion threshold: 0.4 confidence
+    /// - Promotion threshold: 14 accesses/week
     pub fn aggressive() -> Self {
         Self {
-            ephemeral_ttl_hours: 6,
-            task_ttl_hours: 12,
-            project_stale_days: 30,
+            ephemeral_ttl_hours: None,
+            task_ttl_hours: None,
+            project_stale_days: None,
             sweep_interval_minutes: 30,
             demotion_confidence_threshold: 0.4,
             promotion_access_threshold: 14, // Twice daily
             dry_run: false,
             auto_promote: true,
             auto_demote: true,
+            vector_compaction_threshold: default_vector_compaction_threshold(),
         }
     }
 
-    /// Lenient cleanup configuration (longer TTLs, infrequent sweeps)
+    /// Lenient cleanup configuration (infrequent sweeps, reluctant demotion)
     ///
     /// Suitable for development or when you want to keep claims around longer.
     ///
-    /// - Ephemeral: 24 hours
-    /// - Task: 72 hours (3 days)
-    /// - Project: 180 days (6 months)
     /// - Sweep interval: 120 minutes (2 hours)
+    /// - Demotion threshold: 0.2 confidence
+    /// - Promotion threshold: 3 accesses/week
     pub fn lenient() -> Self {
         Self {
-            ephemeral_ttl_hours: 24,
-            task_ttl_hours: 72,
-            project_stale_days: 180,
+            ephemeral_ttl_hours: None,
+            task_ttl_hours: None,
+            project_stale_days: None,
             sweep_interval_minutes: 120,
             demotion_confidence_threshold: 0.2,
             promotion_access_threshold: 3, // Every other day
             dry_run: false,
             auto_promote: true,
             auto_demote: true,
+            vector_compaction_threshold: default_vector_compaction_threshold(),
         }
     }
 
@@ -152,19 +175,45 @@ impl JanitorConfig {
         Duration::from_secs(self.sweep_interval_minutes * 60)
     }
 
-    /// Get ephemeral TTL as Duration
-    pub fn ephemeral_ttl(&self) -> Duration {
-        Duration::from_secs(self.ephemeral_ttl_hours * 3600)
+    /// Get ephemeral TTL as Duration, if set
+    #[deprecated(note = "configure the store's staleness policy instead")]
+    pub fn ephemeral_ttl(&self) -> Option<Duration> {
+        self.ephemeral_ttl_hours.map(|hours| Duration::from_secs(hours * HOUR_SECS))
     }
 
-    /// Get task TTL as Duration
-    pub fn task_ttl(&self) -> Duration {
-        Duration::from_secs(self.task_ttl_hours * 3600)
+    /// Get task TTL as Duration, if set
+    #[deprecated(note = "configure the store's staleness policy instead")]
+    pub fn task_ttl(&self) -> Option<Duration> {
+        self.task_ttl_hours.map(|hours| Duration::from_secs(hours * HOUR_SECS))
     }
 
-    /// Get project stale threshold as Duration
-    pub fn project_stale_threshold(&self) -> Duration {
-        Duration::from_secs(self.project_stale_days * 86400)
+    /// Get project stale threshold as Duration, if set
+    #[deprecated(note = "configure the store's staleness policy instead")]
+    pub fn project_stale_threshold(&self) -> Option<Duration> {
+        self.project_stale_days.map(|days| Duration::from_secs(days * DAY_SECS))
+    }
+
+    /// `policy` with the tier rules the deprecated TTL settings override
+    ///
+    /// A claim expires two half-lives after it goes stale, so a TTL becomes a
+    /// rule whose time to stale and half-life are each a third of it: claims
+    /// stamped under the rule are swept the TTL after they are written.
+    /// Claims the store already gave a `stale_at` keep it and only decay at
+    /// the new half-life.
+    pub fn staleness_policy(&self, policy: StalenessPolicy) -> StalenessPolicy {
+        [
+            (Tier::Ephemeral, self.ephemeral_ttl()),
+            (Tier::Task, self.task_ttl()),
+            (Tier::Project, self.project_stale_threshold()),
+        ]
+        .into_iter()
+        .fold(policy, |policy, (tier, ttl)| match ttl {
+            Some(ttl) => {
+                let third = ttl.as_secs() / 3;
+                policy.with_tier(tier, StalenessRule::new(third, third))
+            }
+            None => policy,
+        })
     }
 }
 
@@ -175,9 +224,6 @@ mod tests {
     #[test]
     fn test_default_config() {
         let config = JanitorConfig::default();
-        assert_eq!(config.ephemeral_ttl_hours, 12);
-        assert_eq!(config.task_ttl_hours, 24);
-        assert_eq!(config.project_stale_days, 90);
         assert_eq!(config.sweep_interval_minutes, 60);
         assert_eq!(config.demotion_confidence_threshold, 0.3);
         assert_eq!(config.promotion_access_threshold, 7);
@@ -189,21 +235,15 @@ mod tests {
     #[test]
     fn test_aggressive_config() {
         let config = JanitorConfig::aggressive();
-        assert_eq!(config.ephemeral_ttl_hours, 6);
-        assert_eq!(config.task_ttl_hours, 12);
-        assert_eq!(config.project_stale_days, 30);
         assert_eq!(config.sweep_interval_minutes, 30);
-        assert!(config.ephemeral_ttl_hours < JanitorConfig::default().ephemeral_ttl_hours);
+        assert!(config.demotion_confidence_threshold > JanitorConfig::default().demotion_confidence_threshold);
     }
 
     #[test]
     fn test_lenient_config() {
         let config = JanitorConfig::lenient();
-        assert_eq!(config.ephemeral_ttl_hours, 24);
-        assert_eq!(config.task_ttl_hours, 72);
-        assert_eq!(config.project_stale_days, 180);
         assert_eq!(config.sweep_interval_minutes, 120);
-        assert!(config.ephemeral_ttl_hours > JanitorConfig::default().ephemeral_ttl_hours);
+        assert!(config.demotion_confidence_threshold < JanitorConfig::default().demotion_confidence_threshold);
     }
 
     #[test]
@@ -211,9 +251,25 @@ mod tests {
         let config = JanitorConfig::default();
         
         assert_eq!(config.sweep_interval(), Duration::from_secs(60 * 60));
-        assert_eq!(config.ephemeral_ttl(), Duration::from_secs(12 * 3600));
-        assert_eq!(config.task_ttl(), Duration::from_secs(24 * 3600));
-        assert_eq!(config.project_stale_threshold(), Duration::from_secs(90 * 86400));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_legacy_ttls_override_the_staleness_policy() {
+        let config: JanitorConfig = serde_json::from_str(
+            r#"{"ephemeral_ttl_hours": 6, "project_stale_days": 30, "sweep_interval_minutes": 60,
+                "demotion_confidence_threshold": 0.3, "promotion_access_threshold": 7}"#,
+        ).unwrap();
+        assert_eq!(config.ephemeral_ttl(), Some(Duration::from_secs(6 * 3600)));
+        assert_eq!(config.task_ttl(), None);
+
+        let policy = config.staleness_policy(StalenessPolicy::default());
+        assert_eq!(policy.rule("test", Tier::Ephemeral), StalenessRule::new(2 * HOUR_SECS, 2 * HOUR_SECS));
+        assert_eq!(policy.rule("test", Tier::Task), StalenessRule::default_for(Tier::Task));
+        assert_eq!(policy.rule("test", Tier::Project), StalenessRule::new(10 * DAY_SECS, 10 * DAY_SECS));
+
+        let policy = JanitorConfig::default().staleness_policy(StalenessPolicy::default());
+        assert_eq!(policy, StalenessPolicy::default());
     }
 
     #[test]
@@ -222,8 +278,8 @@ mod tests {
         let serialized = serde_json::to_string(&config).unwrap();
         let deserialized: JanitorConfig = serde_json::from_str(&serialized).unwrap();
         
-        assert_eq!(config.ephemeral_ttl_hours, deserialized.ephemeral_ttl_hours);
-        assert_eq!(config.task_ttl_hours, deserialized.task_ttl_hours);
+        assert_eq!(config.sweep_interval_minutes, deserialized.sweep_interval_minutes);
+        assert_eq!(config.demotion_confidence_threshold, deserialized.demotion_confidence_threshold);
         assert_eq!(config.dry_run, deserialized.dry_run);
     }
 }
@@ -1,8 +1,9 @@
 //! Core Janitor implementation for tier management and cleanup
 
 use crate::{JanitorConfig, JanitorError, JanitorMetrics};
-use boswell_domain::{Claim, ClaimId, Tier};
+use boswell_domain::{AliasGraph, Cardinality, Claim, ClaimId, PredicateRegistry, Relationship, RelationshipType, StalenessPolicy, Tier};
 use boswell_domain::traits::{ClaimStore, ClaimQuery};
+use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Current timestamp in seconds since Unix epoch
@@ -13,12 +14,35 @@ fn current_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Half-lives past `stale_at` after which a claim expires, its confidence decayed to a quarter
+const EXPIRY_HALF_LIVES: u64 = 2;
+
+/// When `claim` goes stale under `policy`
+///
+/// Stores set `stale_at` from their policy on insert; this covers claims
+/// written without one.
+fn stale_at(policy: &StalenessPolicy, claim: &Claim) -> Option<u64> {
+    policy.stale_at_on_insert(claim)
+}
+
+/// Whether `claim` is stale at `now`
+fn is_stale(policy: &StalenessPolicy, claim: &Claim, now: u64) -> bool {
+    stale_at(policy, claim).is_some_and(|stale_at| now >= stale_at)
+}
+
+/// Whether `claim` has expired at `now`
+fn is_expired(policy: &StalenessPolicy, claim: &Claim, now: u64) -> bool {
+    let decay = policy.half_life_secs(claim).saturating_mul(EXPIRY_HALF_LIVES);
+    stale_at(policy, claim).is_some_and(|stale_at| now >= stale_at.saturating_add(decay))
+}
+
 /// Janitor service for automated tier management and cleanup
 ///
 /// Responsible for:
-/// - Sweeping stale claims per tier TTLs
+/// - Sweeping claims the store's staleness policy has expired
 /// - Promoting claims based on access patterns
 /// - Demoting claims based on staleness and low confidence
+/// - Linking conflicting claims of single-valued predicates
 /// - Collecting metrics on cleanup operations
 ///
 /// # Examples
@@ -41,6 +65,7 @@ fn current_timestamp() -> u64 {
 pub struct Janitor {
     config: JanitorConfig,
     metrics: JanitorMetrics,
+    predicates: PredicateRegistry,
 }
 
 impl Janitor {
@@ -49,9 +74,19 @@ impl Janitor {
         Self {
             config,
             metrics: JanitorMetrics::new(),
+            predicates: PredicateRegistry::new(),
         }
     }
 
+    /// Detect contradictions among claims of this registry's single-valued predicates
+    ///
+    /// Without a registry no predicate is known to be single-valued, so the
+    /// contradiction step does nothing.
+    pub fn with_predicate_registry(mut self, predicates: PredicateRegistry) -> Self {
+        self.predicates = predicates;
+        self
+    }
+
     /// Create a Janitor with default configuration
     pub fn default_config() -> Self {
         Self::new(JanitorConfig::default())
@@ -70,10 +105,12 @@ impl Janitor {
     /// Perform a complete sweep cycle across all tiers
     ///
     /// This is the main entry point for cleanup operations. It:
-    /// 1. Sweeps ephemeral claims past TTL
-    /// 2. Sweeps task claims past TTL
-    /// 3. Reviews project claims for staleness
+    /// 1. Sweeps expired ephemeral claims
+    /// 2. Sweeps expired task claims
+    /// 3. Sweeps expired project claims
     /// 4. Performs tier promotions/demotions if enabled
+    /// 5. Links contradicting claims of single-valued predicates
+    /// 6. Compacts the store's vector index once enough entries are tombstoned
     ///
     /// Returns the updated metrics after the sweep.
     pub fn sweep<S: ClaimStore>(&mut self, store: &mut S) -> Result<JanitorMetrics, JanitorError>
@@ -95,6 +132,9 @@ impl Janitor {
             self.demote_candidates(store)?;
         }
 
+        self.detect_contradictions(store)?;
+        self.compact_indexes(store)?;
+
         // Record sweep completion
         self.metrics.record_sweep();
         
@@ -105,42 +145,39 @@ impl Janitor {
         Ok(self.metrics.clone())
     }
 
-    /// Sweep ephemeral tier claims past TTL
-    ///
-    /// Deletes claims in the Ephemeral tier that have exceeded their TTL.
+    /// Sweep expired ephemeral tier claims
     fn sweep_ephemeral<S: ClaimStore>(&mut self, store: &mut S) -> Result<usize, JanitorError>
     where
         S::Error: std::fmt::Display,
     {
-        self.sweep_tier(store, Tier::Ephemeral, self.config.ephemeral_ttl().as_secs())
+        self.sweep_tier(store, Tier::Ephemeral)
     }
 
-    /// Sweep task tier claims past TTL
-    ///
-    /// Deletes claims in the Task tier that have exceeded their TTL.
+    /// Sweep expired task tier claims
     fn sweep_tasks<S: ClaimStore>(&mut self, store: &mut S) -> Result<usize, JanitorError>
     where
         S::Error: std::fmt::Display,
     {
-        self.sweep_tier(store, Tier::Task, self.config.task_ttl().as_secs())
+        self.sweep_tier(store, Tier::Task)
     }
 
-    /// Review project tier claims for staleness
-    ///
-    /// Deletes claims in the Project tier that haven't been accessed in the staleness threshold.
+    /// Sweep expired project tier claims
     fn sweep_projects<S: ClaimStore>(&mut self, store: &mut S) -> Result<usize, JanitorError>
     where
         S::Error: std::fmt::Display,
     {
-        self.sweep_tier(store, Tier::Project, self.config.project_stale_threshold().as_secs())
+        self.sweep_tier(store, Tier::Project)
     }
 
     /// Generic sweep implementation for a specific tier
+    ///
+    /// A claim expires [`EXPIRY_HALF_LIVES`] half-lives after it goes stale,
+    /// both taken from the store's staleness policy and any tier rules the
+    /// config's deprecated TTLs override.
     fn sweep_tier<S: ClaimStore>(
         &mut self,
         store: &mut S,
         tier: Tier,
-        ttl_secs: u64,
     ) -> Result<usize, JanitorError>
     where
         S::Error: std::fmt::Display,
@@ -151,20 +188,19 @@ impl Janitor {
         }
 
         let now = current_timestamp();
-        let cutoff = now.saturating_sub(ttl_secs);
+        let policy = self.config.staleness_policy(store.staleness_policy());
 
         // Query claims in this tier
         let query = ClaimQuery {
-            tier: Some(tier.as_str().to_string()),
+            tier: Some(tier),
             ..Default::default()
         };
 
         let claims = store.query_claims(&query)
             .map_err(|e| JanitorError::Store(e.to_string()))?;
 
-        // Filter for stale claims (created before cutoff)
         let stale_claims: Vec<&Claim> = claims.iter()
-            .filter(|claim| claim.created_at < cutoff)
+            .filter(|claim| is_expired(&policy, claim, now))
             .collect();
 
         if stale_claims.is_empty() {
@@ -180,38 +216,145 @@ impl Janitor {
             return Ok(0);
         }
 
-        // Delete stale claims
-        let deleted_count = stale_claims.len();
-        
-        // Note: ClaimStore doesn't have a delete method yet, so we'll just log for now
-        // In a full implementation, we'd call store.delete_claims(stale_claim_ids)
+        // Delete expired claims; the store records an eviction event for each
+        let mut deleted_count = 0;
+        for claim in stale_claims {
+            if store.delete_claim(claim.id).map_err(|e| JanitorError::Store(e.to_string()))? {
+                deleted_count += 1;
+            }
+        }
+
         tracing::info!(
-            "Would delete {} stale claims from {:?} tier (created before {})",
+            "Deleted {} expired claims from {:?} tier",
             deleted_count,
-            tier,
-            cutoff
+            tier
         );
 
         self.metrics.record_deletion(tier, deleted_count);
         Ok(deleted_count)
     }
 
+    /// Link claims giving different objects for a single-valued predicate
+    ///
+    /// Claims are compared in canonical form: predicates through the
+    /// registry, subjects and objects through the store's entity aliases.
+    /// Within a namespace, every pair of claims about one subject with
+    /// different objects gets a `Contradicts` relationship, unless one
+    /// already links them in either direction.
+    fn detect_contradictions<S: ClaimStore>(&mut self, store: &mut S) -> Result<usize, JanitorError>
+    where
+        S::Error: std::fmt::Display,
+    {
+        if self.predicates.is_empty() {
+            return Ok(0);
+        }
+
+        let claims = store.query_claims(&ClaimQuery::default())
+            .map_err(|e| JanitorError::Store(e.to_string()))?;
+        let aliases = store.entity_aliases(false)
+            .map_err(|e| JanitorError::Store(e.to_string()))?;
+        let graph = AliasGraph::new(&aliases);
+
+        // (namespace, subject, predicate) to the claims and their objects
+        let mut groups: BTreeMap<(String, String, String), Vec<(ClaimId, String)>> = BTreeMap::new();
+        for claim in &claims {
+            let Ok(claim) = self.predicates.normalize(claim) else {
+                continue;
+            };
+            if self.predicates.cardinality(&claim.namespace, &claim.predicate) != Cardinality::Single {
+                continue;
+            }
+            groups.entry((claim.namespace, graph.resolve(&claim.subject), claim.predicate))
+                .or_default()
+                .push((claim.id, graph.resolve(&claim.object)));
+        }
+
+        let mut conflicts = Vec::new();
+        for values in groups.values() {
+            for (i, (a, a_object)) in values.iter().enumerate() {
+                for (b, b_object) in &values[i + 1..] {
+                    if a_object != b_object {
+                        conflicts.push((*a, *b));
+                    }
+                }
+            }
+        }
+
+        let mut linked = 0;
+        for (a, b) in conflicts {
+            let existing = store.get_relationships(a)
+                .map_err(|e| JanitorError::Store(e.to_string()))?;
+            let known = existing.iter().any(|relationship| {
+                relationship.relationship_type == RelationshipType::Contradicts
+                    && (relationship.from_claim == b || relationship.to_claim == b)
+            });
+            if known {
+                continue;
+            }
+
+            if self.config.dry_run {
+                tracing::info!("DRY RUN: Would mark claims {} and {} as contradicting", a, b);
+                continue;
+            }
+
+            let relationship = Relationship::new(a, b, RelationshipType::Contradicts, 1.0, current_timestamp());
+            store.add_relationship(relationship)
+                .map_err(|e| JanitorError::Store(e.to_string()))?;
+            linked += 1;
+        }
+
+        if linked > 0 {
+            tracing::info!("Marked {} pairs of claims as contradicting", linked);
+            self.metrics.record_contradictions(linked);
+        }
+
+        Ok(linked)
+    }
+
+    /// Compact the store's secondary indexes (e.g. the HNSW vector index)
+    ///
+    /// Deleted claims are tombstoned in the vector index; this reclaims them
+    /// once the tombstone ratio reaches the configured threshold.
+    fn compact_indexes<S: ClaimStore>(&mut self, store: &mut S) -> Result<usize, JanitorError>
+    where
+        S::Error: std::fmt::Display,
+    {
+        if self.config.dry_run {
+            return Ok(0);
+        }
+
+        let reclaimed = store.compact_indexes(self.config.vector_compaction_threshold)
+            .map_err(|e| JanitorError::Store(e.to_string()))?;
+
+        if reclaimed > 0 {
+            tracing::info!("Compacted vector index, reclaimed {} tombstoned entries", reclaimed);
+            self.metrics.record_compaction(reclaimed);
+        }
+
+        Ok(reclaimed)
+    }
+
     /// Promote claims that meet promotion criteria
     ///
     /// Criteria:
-    /// - High access frequency (above threshold)
+    /// - High access frequency (at least `promotion_access_threshold`); the
+    ///   store does not track accesses yet, so no claim is promoted
     /// - Good confidence (above demotion threshold)
+    /// - Fresh under the store's staleness policy
     /// - Not already at Permanent tier
     fn promote_candidates<S: ClaimStore>(&mut self, store: &mut S) -> Result<usize, JanitorError>
     where
         S::Error: std::fmt::Display,
     {
-        let mut promoted = 0;
+        let mut candidates = Vec::new();
+        let policy = self.config.staleness_policy(store.staleness_policy());
+        let now = current_timestamp();
 
-        // Check each tier for promotion candidates (except Permanent)
+        // Check each tier for promotion candidates (except Permanent), before
+        // promoting any so a claim moves up at most one tier per sweep
         for tier in [Tier::Ephemeral, Tier::Task, Tier::Project] {
             let query = ClaimQuery {
-                tier: Some(tier.as_str().to_string()),
+                tier: Some(tier),
                 min_confidence: Some(self.config.demotion_confidence_threshold),
                 ..Default::default()
             };
@@ -221,17 +364,22 @@ impl Janitor {
 
             for claim in claims {
                 // Check if claim meets promotion criteria
-                if self.should_promote(&claim) {
+                if self.should_promote(&policy, &claim, now) {
                     if let Some(next_tier) = tier.next() {
-                        if self.promote_claim(store, claim.id, tier, next_tier)? {
-                            promoted += 1;
-                            self.metrics.record_promotion(tier);
-                        }
+                        candidates.push((claim.id, tier, next_tier));
                     }
                 }
             }
         }
 
+        let mut promoted = 0;
+        for (claim_id, tier, next_tier) in candidates {
+            if self.promote_claim(store, claim_id, tier, next_tier)? {
+                promoted += 1;
+                self.metrics.record_promotion(tier);
+            }
+        }
+
         Ok(promoted)
     }
 
@@ -239,18 +387,21 @@ impl Janitor {
     ///
     /// Criteria:
     /// - Low confidence (below threshold)
-    /// - Stale (no recent access)
+    /// - Stale under the store's staleness policy
     /// - Not already at Ephemeral tier
     fn demote_candidates<S: ClaimStore>(&mut self, store: &mut S) -> Result<usize, JanitorError>
     where
         S::Error: std::fmt::Display,
     {
-        let mut demoted = 0;
+        let mut candidates = Vec::new();
+        let policy = self.config.staleness_policy(store.staleness_policy());
+        let now = current_timestamp();
 
-        // Check each tier for demotion candidates (except Ephemeral)
+        // Check each tier for demotion candidates (except Ephemeral), before
+        // demoting any so a claim moves down at most one tier per sweep
         for tier in [Tier::Permanent, Tier::Project, Tier::Task] {
             let query = ClaimQuery {
-                tier: Some(tier.as_str().to_string()),
+                tier: Some(tier),
                 ..Default::default()
             };
 
@@ -259,79 +410,48 @@ impl Janitor {
 
             for claim in claims {
                 // Check if claim meets demotion criteria
-                if self.should_demote(&claim) {
+                if self.should_demote(&policy, &claim, now) {
                     if let Some(prev_tier) = tier.previous() {
-                        if self.demote_claim(store, claim.id, tier, prev_tier)? {
-                            demoted += 1;
-                            self.metrics.record_demotion(tier);
-                        }
+                        candidates.push((claim.id, tier, prev_tier));
                     }
                 }
             }
         }
 
+        let mut demoted = 0;
+        for (claim_id, tier, prev_tier) in candidates {
+            if self.demote_claim(store, claim_id, tier, prev_tier)? {
+                demoted += 1;
+                self.metrics.record_demotion(tier);
+            }
+        }
+
         Ok(demoted)
     }
 
     /// Determine if a claim should be promoted
-    fn should_promote(&self, claim: &Claim) -> bool {
+    fn should_promote(&self, policy: &StalenessPolicy, claim: &Claim, now: u64) -> bool {
         // Promotion criteria:
         // 1. Confidence is good (above demotion threshold)
         // 2. Claim is not stale
         
         let confidence_good = claim.confidence.0 >= self.config.demotion_confidence_threshold;
-        
-        // Parse tier from string
-        let tier = match Tier::parse(&claim.tier) {
-            Some(t) => t,
-            None => return false, // Invalid tier, skip
-        };
-        
-        // Check staleness based on current tier
-        let not_stale = match tier {
-            Tier::Ephemeral => {
-                let age_hours = (current_timestamp() - claim.created_at) / 3600;
-                age_hours < self.config.ephemeral_ttl_hours / 2 // Active in first half of TTL
-            }
-            Tier::Task => {
-                let age_hours = (current_timestamp() - claim.created_at) / 3600;
-                age_hours < self.config.task_ttl_hours / 2
-            }
-            Tier::Project => {
-                let age_days = (current_timestamp() - claim.created_at) / 86400;
-                age_days < self.config.project_stale_days / 2
-            }
-            Tier::Permanent => false, // Already at top
-        };
+        let not_stale = claim.tier != Tier::Permanent && !is_stale(policy, claim, now);
 
         confidence_good && not_stale
     }
 
     /// Determine if a claim should be demoted
-    fn should_demote(&self, claim: &Claim) -> bool {
+    fn should_demote(&self, policy: &StalenessPolicy, claim: &Claim, now: u64) -> bool {
         // Demotion criteria:
         // 1. Low confidence (below threshold)
-        // 2. Stale (approaching TTL)
+        // 2. Stale
         
         let confidence_low = claim.confidence.0 < self.config.demotion_confidence_threshold;
         
-        // Parse tier from string
-        let tier = match Tier::parse(&claim.tier) {
-            Some(t) => t,
-            None => return false, // Invalid tier, skip
-        };
-        
-        // Check staleness based on tier-specific TTLs
-        let is_stale = match tier {
+        let is_stale = match claim.tier {
             Tier::Ephemeral => false, // Don't demote from Ephemeral, just delete
-            Tier::Task => {
-                let age_hours = (current_timestamp() - claim.created_at) / 3600;
-                age_hours > self.config.task_ttl_hours * 3 / 4 // In last 25% of TTL
-            }
-            Tier::Project => {
-                let age_days = (current_timestamp() - claim.created_at) / 86400;
-                age_days > self.config.project_stale_days * 3 / 4
-            }
+            Tier::Task | Tier::Project => is_stale(policy, claim, now),
             Tier::Permanent => {
                 // Only demote Permanent if confidence is very low
                 claim.confidence.0 < 0.2
@@ -352,32 +472,23 @@ impl Janitor {
     where
         S::Error: std::fmt::Display,
     {
-        if self.config.dry_run {
-            tracing::info!(
-                "DRY RUN: Would promote claim {} from {:?} to {:?}",
-                claim_id,
-                from_tier,
-                to_tier
-            );
-            return Ok(false);
-        }
-
-        // Note: ClaimStore doesn't have an update_tier method yet
-        // In a full implementation, we'd call store.update_claim_tier(claim_id, to_tier)
-        tracing::info!(
-            "Would promote claim {} from {:?} to {:?}",
+        // The store does not count accesses yet, so no claim can reach
+        // `promotion_access_threshold`; candidates are only logged until it does
+        tracing::debug!(
+            "{}Would promote claim {} from {:?} to {:?} once access counts are tracked",
+            if self.config.dry_run { "DRY RUN: " } else { "" },
             claim_id,
             from_tier,
             to_tier
         );
 
-        Ok(true)
+        Ok(false)
     }
 
     /// Demote a claim to the previous tier
     fn demote_claim<S: ClaimStore>(
         &self,
-        _store: &mut S,
+        store: &mut S,
         claim_id: ClaimId,
         from_tier: Tier,
         to_tier: Tier,
@@ -395,94 +506,39 @@ impl Janitor {
             return Ok(false);
         }
 
-        // Note: ClaimStore doesn't have an update_tier method yet
-        // In a full implementation, we'd call store.update_claim_tier(claim_id, to_tier)
-        tracing::info!(
-            "Would demote claim {} from {:?} to {:?}",
-            claim_id,
-            from_tier,
-            to_tier
-        );
+        let changed = store.set_tier(claim_id, to_tier)
+            .map_err(|e| JanitorError::Store(e.to_string()))?;
+        if changed {
+            tracing::info!(
+                "Demoted claim {} from {:?} to {:?}",
+                claim_id,
+                from_tier,
+                to_tier
+            );
+        }
 
-        Ok(true)
+        Ok(changed)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    // Mock store for testing
-    struct MockStore {
-        claims: Vec<Claim>,
-    }
-
-    impl MockStore {
-        fn new() -> Self {
-            Self { claims: Vec::new() }
-        }
-
-        fn add_claim(&mut self, mut claim: Claim) {
-            claim.id = ClaimId::new();
-            self.claims.push(claim);
-        }
-    }
-
-    impl ClaimStore for MockStore {
-        type Error = String;
-
-        fn assert_claim(&mut self, claim: Claim) -> Result<ClaimId, Self::Error> {
-            let id = claim.id;
-            self.claims.push(claim);
-            Ok(id)
-        }
-
-        fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
-            Ok(self.claims.iter().find(|c| c.id == id).cloned())
-        }
-
-        fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
-            let mut results = self.claims.clone();
-
-            // Filter by tier
-            if let Some(tier_str) = &query.tier {
-                results.retain(|c| c.tier == *tier_str);
-            }
-
-            // Filter by min confidence
-            if let Some(min_conf) = query.min_confidence {
-                results.retain(|c| c.confidence.0 >= min_conf);
-            }
-
-            // Apply limit
-            if let Some(limit) = query.limit {
-                results.truncate(limit);
-            }
-
-            Ok(results)
-        }
-
-        fn add_relationship(&mut self, _relationship: boswell_domain::Relationship) -> Result<(), Self::Error> {
-            Ok(())
-        }
-
-        fn get_relationships(&self, _id: ClaimId) -> Result<Vec<boswell_domain::Relationship>, Self::Error> {
-            Ok(Vec::new())
-        }
-    }
+    use boswell_store::InMemoryStore;
 
     fn create_test_claim(tier: Tier, age_hours: u64, confidence: f64) -> Claim {
         let now = current_timestamp();
         let created_at = now - (age_hours * 3600);
+        let id = ClaimId::new();
 
         Claim {
-            id: ClaimId::new(),
+            id,
             namespace: "test".to_string(),
-            subject: "entity:test".to_string(),
+            subject: format!("entity:{}", id),
             predicate: "has_property".to_string(),
             object: "value:123".to_string(),
             confidence: (confidence, confidence + 0.1),
-            tier: tier.as_str().to_string(), // Convert Tier to String
+            tier,
             created_at,
             stale_at: None,
         }
@@ -497,39 +553,102 @@ mod tests {
 
     #[test]
     fn test_sweep_ephemeral_stale_claims() {
-        let mut store = MockStore::new();
+        let mut store = InMemoryStore::new();
         let config = JanitorConfig {
-            ephemeral_ttl_hours: 12,
             dry_run: false,
             ..Default::default()
         };
         let mut janitor = Janitor::new(config);
 
-        // Add stale ephemeral claim (20 hours old)
-        store.add_claim(create_test_claim(Tier::Ephemeral, 20, 0.8));
+        // Add expired ephemeral claim (20 hours old)
+        let expired = create_test_claim(Tier::Ephemeral, 20, 0.8);
+        store.assert_claim(expired.clone()).unwrap();
         
         // Add fresh ephemeral claim (2 hours old)
-        store.add_claim(create_test_claim(Tier::Ephemeral, 2, 0.8));
+        let fresh = create_test_claim(Tier::Ephemeral, 2, 0.8);
+        store.assert_claim(fresh.clone()).unwrap();
 
         let result = janitor.sweep_ephemeral(&mut store).unwrap();
         
-        // Should identify 1 stale claim
+        // Should delete the 1 expired claim
         assert_eq!(result, 1);
         assert_eq!(janitor.metrics().deleted.get(&Tier::Ephemeral), Some(&1));
+        assert!(store.get_claim(expired.id).unwrap().is_none());
+        assert!(store.get_claim(fresh.id).unwrap().is_some());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_sweep_honors_legacy_ttls() {
+        let mut store = InMemoryStore::new();
+        // Stale after 4 hours under the default policy, expired after 12
+        let claim = create_test_claim(Tier::Ephemeral, 10, 0.8);
+        store.assert_claim(claim.clone()).unwrap();
+
+        assert_eq!(Janitor::default_config().sweep_ephemeral(&mut store).unwrap(), 0);
+
+        let config = JanitorConfig { ephemeral_ttl_hours: Some(6), ..Default::default() };
+        assert_eq!(Janitor::new(config).sweep_ephemeral(&mut store).unwrap(), 1);
+        assert!(store.get_claim(claim.id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_sweep_applies_tier_changes_through_the_store() {
+        use boswell_domain::EventKind;
+
+        let mut store = InMemoryStore::new();
+        let mut janitor = Janitor::default_config();
+
+        let expired = create_test_claim(Tier::Ephemeral, 20, 0.8);
+        let fresh = create_test_claim(Tier::Ephemeral, 2, 0.8);
+        let stale = create_test_claim(Tier::Task, 96, 0.2);
+        for claim in [&expired, &fresh, &stale] {
+            store.assert_claim(claim.clone()).unwrap();
+        }
+        let after = store.events_since(0, 100).unwrap().last().unwrap().seq;
+
+        janitor.sweep(&mut store).unwrap();
+
+        assert_eq!(store.get_claim(fresh.id).unwrap().unwrap().tier, Tier::Ephemeral);
+        assert_eq!(store.get_claim(stale.id).unwrap().unwrap().tier, Tier::Ephemeral);
+        assert!(store.get_claim(expired.id).unwrap().is_none());
+        assert_eq!(janitor.metrics().demoted.get(&Tier::Task), Some(&1));
+
+        let kinds: Vec<EventKind> = store.events_since(after, 100).unwrap().into_iter().map(|event| event.kind).collect();
+        assert_eq!(kinds, vec![
+            EventKind::Evicted(expired.id),
+            EventKind::Demoted { claim_id: stale.id, from: Tier::Task, to: Tier::Ephemeral },
+        ]);
+    }
+
+    #[test]
+    fn test_sweeps_do_not_promote_fresh_claims() {
+        let mut store = InMemoryStore::new();
+        let mut janitor = Janitor::default_config();
+
+        let fresh = create_test_claim(Tier::Ephemeral, 0, 0.9);
+        store.assert_claim(fresh.clone()).unwrap();
+
+        // Without access counts no claim reaches `promotion_access_threshold`
+        for _ in 0..3 {
+            janitor.sweep(&mut store).unwrap();
+        }
+
+        assert_eq!(store.get_claim(fresh.id).unwrap().unwrap().tier, Tier::Ephemeral);
+        assert_eq!(janitor.metrics().total_promoted(), 0);
     }
 
     #[test]
     fn test_sweep_respects_dry_run() {
-        let mut store = MockStore::new();
+        let mut store = InMemoryStore::new();
         let config = JanitorConfig {
-            ephemeral_ttl_hours: 12,
             dry_run: true, // Dry run enabled
             ..Default::default()
         };
         let mut janitor = Janitor::new(config);
 
-        // Add stale claim
-        store.add_claim(create_test_claim(Tier::Ephemeral, 20, 0.8));
+        // Add expired claim
+        store.assert_claim(create_test_claim(Tier::Ephemeral, 20, 0.8)).unwrap();
 
         let result = janitor.sweep_ephemeral(&mut store).unwrap();
         
@@ -540,13 +659,13 @@ mod tests {
 
     #[test]
     fn test_sweep_never_deletes_permanent() {
-        let mut store = MockStore::new();
+        let mut store = InMemoryStore::new();
         let mut janitor = Janitor::default_config();
 
         // Add old permanent claim
-        store.add_claim(create_test_claim(Tier::Permanent, 10000, 0.8));
+        store.assert_claim(create_test_claim(Tier::Permanent, 10000, 0.8)).unwrap();
 
-        let result = janitor.sweep_tier(&mut store, Tier::Permanent, 1).unwrap();
+        let result = janitor.sweep_tier(&mut store, Tier::Permanent).unwrap();
         
         // Should never sweep Permanent tier
         assert_eq!(result, 0);
@@ -555,50 +674,54 @@ mod tests {
     #[test]
     fn test_should_promote_logic() {
         let janitor = Janitor::default_config();
+        let policy = StalenessPolicy::default();
+        let now = current_timestamp();
 
         // Fresh claim with good confidence - should promote
         let claim = create_test_claim(Tier::Ephemeral, 2, 0.8);
-        assert!(janitor.should_promote(&claim));
+        assert!(janitor.should_promote(&policy, &claim, now));
 
         // Old claim - should not promote
         let claim = create_test_claim(Tier::Ephemeral, 20, 0.8);
-        assert!(!janitor.should_promote(&claim));
+        assert!(!janitor.should_promote(&policy, &claim, now));
 
         // Low confidence - should not promote
         let claim = create_test_claim(Tier::Ephemeral, 2, 0.2);
-        assert!(!janitor.should_promote(&claim));
+        assert!(!janitor.should_promote(&policy, &claim, now));
     }
 
     #[test]
     fn test_should_demote_logic() {
         let janitor = Janitor::default_config();
+        let policy = StalenessPolicy::default();
+        let now = current_timestamp();
 
-        // Task tier: old + low confidence - should demote
-        let claim = create_test_claim(Tier::Task, 30, 0.2);
-        assert!(janitor.should_demote(&claim));
+        // Task tier: stale (4 days old) + low confidence - should demote
+        let claim = create_test_claim(Tier::Task, 96, 0.2);
+        assert!(janitor.should_demote(&policy, &claim, now));
 
         // Task tier: fresh - should not demote
         let claim = create_test_claim(Tier::Task, 2, 0.2);
-        assert!(!janitor.should_demote(&claim));
+        assert!(!janitor.should_demote(&policy, &claim, now));
 
         // Task tier: good confidence - should not demote
-        let claim = create_test_claim(Tier::Task, 30, 0.8);
-        assert!(!janitor.should_demote(&claim));
+        let claim = create_test_claim(Tier::Task, 96, 0.8);
+        assert!(!janitor.should_demote(&policy, &claim, now));
 
         // Ephemeral: should not demote (just delete)
         let claim = create_test_claim(Tier::Ephemeral, 30, 0.1);
-        assert!(!janitor.should_demote(&claim));
+        assert!(!janitor.should_demote(&policy, &claim, now));
     }
 
     #[test]
     fn test_full_sweep_cycle() {
-        let mut store = MockStore::new();
+        let mut store = InMemoryStore::new();
         let mut janitor = Janitor::default_config();
 
         // Add various claims
-        store.add_claim(create_test_claim(Tier::Ephemeral, 20, 0.8)); // Stale
-        store.add_claim(create_test_claim(Tier::Task, 30, 0.8)); // Stale
-        store.add_claim(create_test_claim(Tier::Project, 2, 0.8)); // Fresh
+        store.assert_claim(create_test_claim(Tier::Ephemeral, 20, 0.8)).unwrap(); // Expired
+        store.assert_claim(create_test_claim(Tier::Task, 240, 0.8)).unwrap(); // Expired
+        store.assert_claim(create_test_claim(Tier::Project, 2, 0.8)).unwrap(); // Fresh
 
         let metrics = janitor.sweep(&mut store).unwrap();
         
@@ -609,9 +732,9 @@ mod tests {
     #[test]
     fn test_metrics_reset() {
         let mut janitor = Janitor::default_config();
-        let mut store = MockStore::new();
+        let mut store = InMemoryStore::new();
 
-        store.add_claim(create_test_claim(Tier::Ephemeral, 20, 0.8));
+        store.assert_claim(create_test_claim(Tier::Ephemeral, 20, 0.8)).unwrap();
         janitor.sweep(&mut store).unwrap();
 
         assert!(janitor.metrics().sweep_count > 0);
@@ -621,4 +744,68 @@ mod tests {
         assert_eq!(janitor.metrics().sweep_count, 0);
         assert_eq!(janitor.metrics().total_deleted(), 0);
     }
+
+    #[test]
+    fn test_sweep_links_contradictions_of_single_valued_predicates() {
+        use boswell_domain::{EntityAlias, PredicateDefinition};
+
+        let mut predicates = PredicateRegistry::new();
+        predicates.register("", PredicateDefinition::new("born_in").with_inverse("birthplace_of").single_valued()).unwrap();
+        let mut store = boswell_store::SqliteStore::new(":memory:", false, 0).unwrap();
+        let claim = |subject: &str, predicate: &str, object: &str| Claim {
+            subject: subject.to_string(),
+            predicate: predicate.to_string(),
+            object: object.to_string(),
+            ..create_test_claim(Tier::Permanent, 1, 0.8)
+        };
+
+        let paris = claim("person:alice", "born_in", "city:paris");
+        let lyon = claim("city:lyon", "birthplaceOf", "person:alice");
+        let paris_alias = claim("person:alice", "bornIn", "city:paris_fr");
+        let multi = claim("person:alice", "visited", "city:rome");
+        for claim in [&paris, &lyon, &paris_alias, &multi, &claim("person:alice", "visited", "city:oslo")] {
+            store.assert_claim(claim.clone()).unwrap();
+        }
+        store.add_alias(EntityAlias::new("city:paris_fr", "city:paris", "test", 0).unwrap()).unwrap();
+
+        let mut dry_run = Janitor::new(JanitorConfig { dry_run: true, ..Default::default() })
+            .with_predicate_registry(predicates.clone());
+        assert_eq!(dry_run.detect_contradictions(&mut store).unwrap(), 0);
+        assert!(store.get_relationships(paris.id).unwrap().is_empty());
+
+        let mut janitor = Janitor::default_config().with_predicate_registry(predicates);
+        janitor.sweep(&mut store).unwrap();
+
+        let contradicts = |id: ClaimId| -> Vec<ClaimId> {
+            store.get_relationships(id).unwrap().into_iter()
+                .filter(|r| r.relationship_type == RelationshipType::Contradicts)
+                .map(|r| if r.from_claim == id { r.to_claim } else { r.from_claim })
+                .collect()
+        };
+        assert_eq!(contradicts(lyon.id).len(), 2);
+        assert_eq!(contradicts(paris.id), vec![lyon.id]);
+        assert!(contradicts(multi.id).is_empty());
+        assert_eq!(janitor.metrics().contradictions_found, 2);
+
+        // Pairs already linked are not linked again
+        janitor.sweep(&mut store).unwrap();
+        assert_eq!(janitor.metrics().contradictions_found, 2);
+    }
+
+    #[test]
+    fn test_sweep_compacts_sqlite_vector_index() {
+        let mut store = boswell_store::SqliteStore::new(":memory:", true, 8).unwrap();
+        let mut janitor = Janitor::default_config();
+
+        let fresh = create_test_claim(Tier::Permanent, 1, 0.8);
+        let mut doomed = create_test_claim(Tier::Permanent, 1, 0.8);
+        doomed.subject = "entity:doomed".to_string();
+        store.assert_claim(fresh).unwrap();
+        store.assert_claim(doomed.clone()).unwrap();
+        store.delete_claim(doomed.id).unwrap();
+
+        let metrics = janitor.sweep(&mut store).unwrap();
+
+        assert_eq!(metrics.index_entries_compacted, 1);
+    }
 }
@@ -6,8 +6,11 @@
 //!
 //! The Janitor is responsible for:
 //! - **Tier management**: Promoting and demoting claims based on access patterns and confidence
-//! - **Stale claim detection**: Identifying claims past their TTL (time-to-live)
+//! - **Stale claim detection**: Identifying claims the store's `StalenessPolicy` has expired
 //! - **Garbage collection**: Removing stale claims to reclaim storage
+//! - **Index compaction**: Rebuilding the vector index once deleted entries pile up
+//! - **Contradiction detection**: Linking claims that give different objects for a
+//!   single-valued predicate declared in a `PredicateRegistry`
 //! - **Metrics collection**: Tracking cleanup operations for monitoring
 //!
 //! # Architecture
@@ -17,12 +20,18 @@
 //!
 //! ## Tier Lifecycle (per ADR-007)
 //!
-//! | Tier | TTL | Promotion Trigger | Demotion Trigger |
-//! |------|-----|-------------------|------------------|
-//! | **Ephemeral** | 12 hours | Access frequency > threshold | Session end or TTL |
-//! | **Task** | 24 hours (if idle) | Reinforcement (2+ supporting claims) | Task complete or timeout |
-//! | **Project** | 90 days (if unused) | Explicit promotion or high access | No access for 90 days |
-//! | **Permanent** | Never | Explicit promotion only | Manual only (never automatic) |
+//! When a claim goes stale, and how fast its confidence then halves, comes
+//! from the store's `StalenessPolicy` - the same policy the stores use to set
+//! `stale_at` and the confidence formula uses to decay it. A claim expires,
+//! and is swept, two half-lives after it went stale, when its confidence has
+//! decayed to a quarter. Under the default policy:
+//!
+//! | Tier | Stale after | Expires after | Promotion Trigger | Demotion Trigger |
+//! |------|-------------|---------------|-------------------|------------------|
+//! | **Ephemeral** | 4 hours | 12 hours | Fresh with good confidence | Never (expires instead) |
+//! | **Task** | 3 days | 9 days | Fresh with good confidence | Stale with low confidence |
+//! | **Project** | 4 weeks | 12 weeks | Fresh with good confidence | Stale with low confidence |
+//! | **Permanent** | 6 months | Never | - | Confidence below 0.2 |
 //!
 //! # Usage
 //!
@@ -67,13 +76,13 @@
 //! ```
 //! use boswell_janitor::JanitorConfig;
 //!
-//! // Default: Balanced cleanup (12h ephemeral, 24h task, 90d project)
+//! // Default: Balanced cleanup (hourly sweeps)
 //! let config = JanitorConfig::default();
 //!
-//! // Aggressive: Shorter TTLs for resource-constrained environments
+//! // Aggressive: Frequent sweeps for resource-constrained environments
 //! let config = JanitorConfig::aggressive();
 //!
-//! // Lenient: Longer TTLs for development or when keeping claims longer
+//! // Lenient: Infrequent sweeps for development or when keeping claims longer
 //! let config = JanitorConfig::lenient();
 //! ```
 //!
@@ -83,15 +92,13 @@
 //!
 //! ```toml
 //! [janitor]
-//! ephemeral_ttl_hours = 12
-//! task_ttl_hours = 24
-//! project_stale_days = 90
 //! sweep_interval_minutes = 60
 //! demotion_confidence_threshold = 0.3
 //! promotion_access_threshold = 7
 //! dry_run = false
 //! auto_promote = true
 //! auto_demote = true
+//! vector_compaction_threshold = 0.2
 //! ```
 //!
 //! # Metrics
@@ -17,6 +17,12 @@ pub struct JanitorMetrics {
     /// Claims demoted per tier (from → to)
     pub demoted: HashMap<Tier, usize>,
     
+    /// Tombstoned vector index entries reclaimed by compaction
+    pub index_entries_compacted: usize,
+    
+    /// Pairs of claims newly marked as contradicting
+    pub contradictions_found: usize,
+    
     /// Total sweep iterations completed
     pub sweep_count: usize,
     
@@ -45,6 +51,16 @@ impl JanitorMetrics {
         *self.demoted.entry(from_tier).or_insert(0) += 1;
     }
 
+    /// Record a vector index compaction
+    pub fn record_compaction(&mut self, reclaimed: usize) {
+        self.index_entries_compacted += reclaimed;
+    }
+
+    /// Record pairs of claims marked as contradicting
+    pub fn record_contradictions(&mut self, count: usize) {
+        self.contradictions_found += count;
+    }
+
     /// Record a sweep cycle completion
     pub fn record_sweep(&mut self) {
         self.sweep_count += 1;
@@ -70,6 +86,8 @@ impl JanitorMetrics {
         self.deleted.clear();
         self.promoted.clear();
         self.demoted.clear();
+        self.index_entries_compacted = 0;
+        self.contradictions_found = 0;
         self.sweep_count = 0;
         self.total_runtime_secs = 0;
     }
@@ -110,6 +128,16 @@ impl JanitorMetrics {
             lines.push(format!("  Total: {}", self.total_demoted()));
         }
 
+        if self.index_entries_compacted > 0 {
+            lines.push(String::new());
+            lines.push(format!("Index entries compacted: {}", self.index_entries_compacted));
+        }
+
+        if self.contradictions_found > 0 {
+            lines.push(String::new());
+            lines.push(format!("Contradictions found: {}", self.contradictions_found));
+        }
+
         lines.join("\n")
     }
 }
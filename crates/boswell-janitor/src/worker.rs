@@ -1,6 +1,7 @@
 //! Background worker for continuous Janitor operation
 
 use crate::{Janitor, JanitorConfig, JanitorError};
+use boswell_domain::PredicateRegistry;
 use boswell_domain::traits::ClaimStore;
 use tokio::time::{interval, Duration};
 
@@ -42,6 +43,12 @@ impl JanitorWorker {
         }
     }
 
+    /// Detect contradictions among claims of this registry's single-valued predicates
+    pub fn with_predicate_registry(mut self, predicates: PredicateRegistry) -> Self {
+        self.janitor = self.janitor.with_predicate_registry(predicates);
+        self
+    }
+
     /// Create a worker with default configuration
     pub fn default_config() -> Self {
         Self::new(JanitorConfig::default())
@@ -179,60 +186,9 @@ mod tests {
     use super::*;
     use crate::JanitorConfig;
     use boswell_domain::{Claim, ClaimId, Tier};
-    use boswell_domain::traits::ClaimQuery;
+    use boswell_store::InMemoryStore;
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    // Mock store for testing
-    struct MockStore {
-        claims: Vec<Claim>,
-    }
-
-    impl MockStore {
-        fn new() -> Self {
-            Self { claims: Vec::new() }
-        }
-    }
-
-    impl ClaimStore for MockStore {
-        type Error = String;
-
-        fn assert_claim(&mut self, claim: Claim) -> Result<ClaimId, Self::Error> {
-            let id = claim.id;
-            self.claims.push(claim);
-            Ok(id)
-        }
-
-        fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
-            Ok(self.claims.iter().find(|c| c.id == id).cloned())
-        }
-
-        fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
-            let mut results = self.claims.clone();
-
-            if let Some(tier_str) = &query.tier {
-                results.retain(|c| c.tier == *tier_str);
-            }
-
-            if let Some(min_conf) = query.min_confidence {
-                results.retain(|c| c.confidence.0 >= min_conf);
-            }
-
-            if let Some(limit) = query.limit {
-                results.truncate(limit);
-            }
-
-            Ok(results)
-        }
-
-        fn add_relationship(&mut self, _relationship: boswell_domain::Relationship) -> Result<(), Self::Error> {
-            Ok(())
-        }
-
-        fn get_relationships(&self, _id: ClaimId) -> Result<Vec<boswell_domain::Relationship>, Self::Error> {
-            Ok(Vec::new())
-        }
-    }
-
     fn current_timestamp() -> u64 {
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -243,15 +199,16 @@ mod tests {
     fn create_test_claim(tier: Tier, age_hours: u64) -> Claim {
         let now = current_timestamp();
         let created_at = now - (age_hours * 3600);
+        let id = ClaimId::new();
 
         Claim {
-            id: ClaimId::new(),
+            id,
             namespace: "test".to_string(),
-            subject: "entity:test".to_string(),
+            subject: format!("entity:{}", id),
             predicate: "has_property".to_string(),
             object: "value:123".to_string(),
             confidence: (0.8, 0.9),
-            tier: tier.as_str().to_string(), // Convert Tier to String
+            tier,
             created_at,
             stale_at: None,
         }
@@ -265,11 +222,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_run_cycles() {
-        let mut store = MockStore::new();
-        store.claims.push(create_test_claim(Tier::Ephemeral, 20));
+        let mut store = InMemoryStore::new();
+        store.assert_claim(create_test_claim(Tier::Ephemeral, 20)).unwrap();
 
         let config = JanitorConfig {
-            ephemeral_ttl_hours: 12,
             sweep_interval_minutes: 1, // 1 minute minimum (1 ms would panic)
             dry_run: false,
             ..Default::default()
@@ -285,13 +241,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_metrics_tracking() {
-        let mut store = MockStore::new();
+        let mut store = InMemoryStore::new();
         
         // Add stale claim
-        store.claims.push(create_test_claim(Tier::Ephemeral, 20));
+        store.assert_claim(create_test_claim(Tier::Ephemeral, 20)).unwrap();
 
         let config = JanitorConfig {
-            ephemeral_ttl_hours: 12,
             sweep_interval_minutes: 1, // 1 minute minimum
             dry_run: false,
             ..Default::default()
@@ -308,7 +263,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_reset_metrics() {
-        let store = MockStore::new();
+        let store = InMemoryStore::new();
         let config = JanitorConfig {
             sweep_interval_minutes: 1, // 1 minute minimum
             ..Default::default()
@@ -10,6 +10,9 @@
 //! - `boswell_forget` - Remove claims
 //! - `boswell_semantic_search` - Semantic search with embeddings
 //!
+//! And one MCP resource:
+//! - `boswell://namespaces` - Namespace tree with per-subtree statistics
+//!
 //! # Example
 //!
 //! ```no_run
@@ -24,6 +27,7 @@
 
 mod error;
 mod protocol;
+mod resources;
 mod server;
 mod tools;
 
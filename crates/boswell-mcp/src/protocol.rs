@@ -115,6 +115,8 @@ pub struct InitializeResponse {
 pub struct Capabilities {
     /// Tools capability
     pub tools: ToolsCapability,
+    /// Resources capability
+    pub resources: ResourcesCapability,
 }
 
 /// Tools capability
@@ -123,3 +125,50 @@ pub struct ToolsCapability {
     /// Whether tools are supported
     pub supported: bool,
 }
+
+/// Resources capability
+#[derive(Debug, Serialize)]
+pub struct ResourcesCapability {
+    /// Whether resources are supported
+    pub supported: bool,
+}
+
+/// MCP resource list response
+#[derive(Debug, Serialize)]
+pub struct ResourceListResponse {
+    /// Available resources
+    pub resources: Vec<ResourceDefinition>,
+}
+
+/// Resource definition
+#[derive(Debug, Serialize)]
+pub struct ResourceDefinition {
+    /// Resource URI
+    pub uri: String,
+    /// Resource name
+    pub name: String,
+    /// Resource description
+    pub description: String,
+    /// MIME type of the contents
+    #[serde(rename = "mimeType")]
+    pub mime_type: String,
+}
+
+/// MCP resource read response
+#[derive(Debug, Serialize)]
+pub struct ResourceReadResponse {
+    /// Resource contents
+    pub contents: Vec<ResourceContent>,
+}
+
+/// Contents of one resource
+#[derive(Debug, Serialize)]
+pub struct ResourceContent {
+    /// Resource URI
+    pub uri: String,
+    /// MIME type of the contents
+    #[serde(rename = "mimeType")]
+    pub mime_type: String,
+    /// Text contents
+    pub text: String,
+}
@@ -0,0 +1,136 @@
+//! MCP resources - Read-only views of Boswell state
+
+use boswell_domain::{NamespaceStats, Tier};
+use boswell_sdk::BoswellClient;
+use serde::Serialize;
+use crate::error::McpError;
+
+/// URI of the namespace listing; append `/<namespace>` to list one subtree
+pub const NAMESPACES_URI: &str = "boswell://namespaces";
+
+/// Namespace listing resource contents
+#[derive(Debug, Serialize)]
+pub struct NamespaceListing {
+    /// Namespace the listing starts at (empty for every namespace)
+    pub root: String,
+    /// The root and every namespace below it, parents first
+    pub namespaces: Vec<NamespaceInfo>,
+}
+
+/// Totals for one namespace and everything below it
+#[derive(Debug, Serialize)]
+pub struct NamespaceInfo {
+    /// Namespace
+    pub namespace: String,
+    /// Number of claims
+    pub claims: u64,
+    /// Claims per tier
+    pub tiers: TierCounts,
+    /// Mean confidence midpoint (absent without claims)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_confidence: Option<f64>,
+    /// Contradicts relationships from claims in the subtree
+    pub contradictions: u64,
+    /// Latest write (Unix timestamp)
+    pub last_write: u64,
+}
+
+/// Claim counts per tier
+#[derive(Debug, Serialize)]
+pub struct TierCounts {
+    /// Ephemeral claims
+    pub ephemeral: u64,
+    /// Task claims
+    pub task: u64,
+    /// Project claims
+    pub project: u64,
+    /// Permanent claims
+    pub permanent: u64,
+}
+
+impl From<NamespaceStats> for NamespaceInfo {
+    fn from(stats: NamespaceStats) -> Self {
+        Self {
+            claims: stats.claims,
+            tiers: TierCounts {
+                ephemeral: stats.tier_count(Tier::Ephemeral),
+                task: stats.tier_count(Tier::Task),
+                project: stats.tier_count(Tier::Project),
+                permanent: stats.tier_count(Tier::Permanent),
+            },
+            average_confidence: stats.average_confidence(),
+            contradictions: stats.contradictions,
+            last_write: stats.last_write,
+            namespace: stats.namespace,
+        }
+    }
+}
+
+/// The namespace root a resource URI asks for, if it is a namespace listing URI
+///
+/// `boswell://namespaces` lists everything; `boswell://namespaces/acme/eng`
+/// lists the `acme/eng` subtree.
+pub fn namespace_root(uri: &str) -> Option<&str> {
+    let rest = uri.strip_prefix(NAMESPACES_URI)?;
+    if rest.is_empty() {
+        return Some("");
+    }
+    rest.strip_prefix('/')
+}
+
+/// Read the namespace listing below `root`
+///
+/// # Arguments
+///
+/// * `client` - Boswell client instance
+/// * `root` - Namespace to start at (empty for every namespace)
+///
+/// # Returns
+///
+/// Result containing subtree totals for each namespace or an error
+pub async fn read_namespaces(
+    client: &mut BoswellClient,
+    root: &str,
+) -> Result<NamespaceListing, McpError> {
+    let namespaces = client
+        .list_namespaces(root)
+        .await
+        .map_err(|e| McpError::BoswellError(e.to_string()))?;
+
+    Ok(NamespaceListing {
+        root: root.to_string(),
+        namespaces: NamespaceStats::rollup(&namespaces, root)
+            .into_iter()
+            .map(NamespaceInfo::from)
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespace_root() {
+        assert_eq!(namespace_root("boswell://namespaces"), Some(""));
+        assert_eq!(namespace_root("boswell://namespaces/acme/eng"), Some("acme/eng"));
+        assert_eq!(namespace_root("boswell://namespacesX"), None);
+        assert_eq!(namespace_root("boswell://claims"), None);
+    }
+
+    #[test]
+    fn test_namespace_info_serialize() {
+        let mut stats = NamespaceStats::new("acme");
+        stats.claims = 2;
+        stats.tier_counts = [0, 1, 1, 0];
+        stats.confidence_sum = 1.4;
+
+        let json = serde_json::to_value(NamespaceInfo::from(stats)).unwrap();
+        assert_eq!(json["tiers"]["project"], 1);
+        assert_eq!(json["average_confidence"], 0.7);
+        assert!(serde_json::to_value(NamespaceInfo::from(NamespaceStats::new("empty")))
+            .unwrap()
+            .get("average_confidence")
+            .is_none());
+    }
+}
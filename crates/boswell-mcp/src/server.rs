@@ -1,5 +1,6 @@
 //! MCP server implementation
 
+use boswell_domain::Tier;
 use boswell_sdk::BoswellClient;
 use serde_json::{json, Value};
 use std::io::{BufRead, BufReader, Write};
@@ -8,6 +9,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::error::McpError;
 use crate::protocol::*;
+use crate::resources;
 use crate::tools;
 
 /// MCP Server
@@ -97,6 +99,8 @@ impl McpServer {
             "initialize" => self.handle_initialize(id),
             "tools/list" => self.handle_tools_list(id),
             "tools/call" => self.handle_tool_call(id, request.params),
+            "resources/list" => self.handle_resources_list(id),
+            "resources/read" => self.handle_resource_read(id, request.params),
             _ => {
                 let error = JsonRpcError::new(
                     id,
@@ -118,6 +122,7 @@ impl McpServer {
             },
             capabilities: Capabilities {
                 tools: ToolsCapability { supported: true },
+                resources: ResourcesCapability { supported: true },
             },
         };
 
@@ -184,6 +189,59 @@ impl McpServer {
         }
     }
 
+    /// Handle resources/list request
+    fn handle_resources_list(&self, id: Option<Value>) -> Value {
+        let resources = vec![ResourceDefinition {
+            uri: resources::NAMESPACES_URI.to_string(),
+            name: "namespaces".to_string(),
+            description: "Namespace tree with claim counts per tier, average confidence, contradictions and last write time. Append /<namespace> to the URI for one subtree".to_string(),
+            mime_type: "application/json".to_string(),
+        }];
+
+        let response = ResourceListResponse { resources };
+        let json_response = JsonRpcResponse::new(id, serde_json::to_value(response).unwrap());
+        serde_json::to_value(json_response).unwrap()
+    }
+
+    /// Handle resources/read request
+    fn handle_resource_read(&mut self, id: Option<Value>, params: Value) -> Value {
+        let uri = match params.get("uri").and_then(|v| v.as_str()) {
+            Some(uri) => uri.to_string(),
+            None => {
+                let error = JsonRpcError::new(id, -32602, "Missing resource URI".to_string());
+                return serde_json::to_value(error).unwrap();
+            }
+        };
+
+        let Some(root) = resources::namespace_root(&uri) else {
+            let error = JsonRpcError::new(id, -32602, format!("Resource not found: {}", uri));
+            return serde_json::to_value(error).unwrap();
+        };
+
+        let result = self
+            .runtime
+            .block_on(resources::read_namespaces(&mut self.client, root))
+            .and_then(|listing| Ok(serde_json::to_string_pretty(&listing)?));
+
+        match result {
+            Ok(text) => {
+                let response = ResourceReadResponse {
+                    contents: vec![ResourceContent {
+                        uri,
+                        mime_type: "application/json".to_string(),
+                        text,
+                    }],
+                };
+                let json_response = JsonRpcResponse::new(id, serde_json::to_value(response).unwrap());
+                serde_json::to_value(json_response).unwrap()
+            }
+            Err(e) => {
+                let error = JsonRpcError::new(id, e.error_code(), e.to_string());
+                serde_json::to_value(error).unwrap()
+            }
+        }
+    }
+
     /// Call assert tool
     fn call_assert_tool(&mut self, params: Value) -> Result<Value, McpError> {
         let params: tools::AssertParams = serde_json::from_value(params)?;
@@ -241,7 +299,7 @@ impl McpServer {
                     "predicate": {"type": "string", "description": "Predicate (relationship or attribute)"},
                     "object": {"type": "string", "description": "Object (value or related entity)"},
                     "confidence": {"type": "number", "description": "Confidence score (0.0-1.0)", "minimum": 0.0, "maximum": 1.0},
-                    "tier": {"type": "string", "enum": ["Transient", "Session", "Permanent"], "description": "Persistence tier"}
+                    "tier": {"type": "string", "enum": Tier::ALL.map(|tier| tier.as_str()), "description": "Persistence tier"}
                 },
                 "required": ["namespace", "subject", "predicate", "object"]
             }),
@@ -256,11 +314,14 @@ impl McpServer {
                 "type": "object",
                 "properties": {
                     "namespace": {"type": "string", "description": "Filter by namespace"},
-                    "subject": {"type": "string", "description": "Filter by subject"},
+                    "subject": {"type": "string", "description": "Filter by subject (kind:* for every entity of a kind)"},
                     "predicate": {"type": "string", "description": "Filter by predicate"},
-                    "object": {"type": "string", "description": "Filter by object"},
+                    "object": {"type": "string", "description": "Filter by object (kind:* for every entity of a kind)"},
                     "min_confidence": {"type": "number", "description": "Minimum confidence threshold"},
-                    "tier": {"type": "string", "enum": ["Transient", "Session", "Permanent"]}
+                    "object_after": {"type": "string", "description": "Only date, number or measurement objects after this literal (e.g. date:2025)"},
+                    "object_before": {"type": "string", "description": "Only date, number or measurement objects before this literal"},
+                    "expand_aliases": {"type": "boolean", "description": "Also match subject and object through entity aliases (person:j_doe for person:john_doe)"},
+                    "tier": {"type": "string", "enum": Tier::ALL.map(|tier| tier.as_str()), "description": "Filter by tier"}
                 }
             }),
         }
@@ -284,7 +345,7 @@ impl McpServer {
                                 "predicate": {"type": "string"},
                                 "object": {"type": "string"},
                                 "confidence": {"type": "number"},
-                                "tier": {"type": "string", "enum": ["Transient", "Session", "Permanent"]}
+                                "tier": {"type": "string", "enum": Tier::ALL.map(|tier| tier.as_str())}
                             },
                             "required": ["namespace", "subject", "predicate", "object"]
                         }
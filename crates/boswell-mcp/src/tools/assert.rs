@@ -1,7 +1,7 @@
 //! Assert tool - Add a new claim to Boswell
 
 use boswell_sdk::BoswellClient;
-use boswell_domain::Tier;
+use boswell_domain::{ConfidenceInterval, Tier};
 use serde::{Deserialize, Serialize};
 use crate::error::McpError;
 
@@ -19,9 +19,9 @@ pub struct AssertParams {
     /// Confidence score (0.0 - 1.0)
     #[serde(default)]
     pub confidence: Option<f64>,
-    /// Tier (Transient, Session, Permanent)
+    /// Tier (ephemeral, task, project or permanent; case-insensitive)
     #[serde(default)]
-    pub tier: Option<String>,
+    pub tier: Option<Tier>,
 }
 
 /// Result of asserting a claim
@@ -49,16 +49,10 @@ pub async fn handle_assert(
     client: &mut BoswellClient,
     params: AssertParams,
 ) -> Result<AssertResult, McpError> {
-    // Parse tier if provided (convert to lowercase string format)
-    let tier = match params.tier {
-        Some(ref t) => {
-            // Validate tier by parsing
-            let tier_enum = t.parse::<Tier>()
-                .map_err(|_| McpError::InvalidRequest(format!("Invalid tier: {}", t)))?;
-            Some(tier_enum)
-        }
-        None => None,
-    };
+    if let Some(confidence) = params.confidence {
+        ConfidenceInterval::try_new(confidence, confidence)
+            .map_err(|e| McpError::InvalidRequest(format!("Invalid confidence: {}", e)))?;
+    }
 
     // Assert the claim
     let claim_id = client
@@ -68,7 +62,7 @@ pub async fn handle_assert(
             &params.predicate,
             &params.object,
             params.confidence,
-            tier,
+            params.tier,
         )
         .await
         .map_err(|e| McpError::BoswellError(e.to_string()))?;
@@ -100,7 +94,7 @@ mod tests {
         assert_eq!(params.predicate, "hasProperty");
         assert_eq!(params.object, "value1");
         assert_eq!(params.confidence, Some(0.9));
-        assert_eq!(params.tier, Some("Permanent".to_string()));
+        assert_eq!(params.tier, Some(Tier::Permanent));
     }
 
     #[test]
@@ -116,4 +110,34 @@ mod tests {
         assert_eq!(params.confidence, None);
         assert_eq!(params.tier, None);
     }
+
+    #[test]
+    fn test_assert_params_reject_unknown_tier() {
+        let json = r#"{
+            "namespace": "test",
+            "subject": "entity1",
+            "predicate": "hasProperty",
+            "object": "value1",
+            "tier": "Session"
+        }"#;
+
+        let err = serde_json::from_str::<AssertParams>(json).unwrap_err();
+        assert!(err.to_string().contains("Invalid tier 'Session'"));
+    }
+
+    #[tokio::test]
+    async fn test_assert_rejects_invalid_confidence() {
+        let mut client = BoswellClient::new("http://localhost:8080");
+        let params = AssertParams {
+            namespace: "test".to_string(),
+            subject: "user:alice".to_string(),
+            predicate: "rel:knows".to_string(),
+            object: "user:bob".to_string(),
+            confidence: Some(1.5),
+            tier: None,
+        };
+
+        let result = handle_assert(&mut client, params).await;
+        assert!(matches!(result, Err(McpError::InvalidRequest(msg)) if msg.contains("confidence")));
+    }
 }
@@ -1,7 +1,7 @@
 //! Learn tool - Batch insert multiple claims
 
 use boswell_sdk::BoswellClient;
-use boswell_domain::Tier;
+use boswell_domain::{ConfidenceInterval, Tier};
 use serde::{Deserialize, Serialize};
 use crate::error::McpError;
 
@@ -19,7 +19,9 @@ pub struct LearnClaim {
     /// Confidence score (0.0 - 1.0)
     #[serde(default)]
     pub confidence: Option<f64>,
-    /// Tier (Transient, Session, Permanent)
+    /// Tier (ephemeral, task, project or permanent; case-insensitive)
+    ///
+    /// Kept as a string so an unknown tier fails only this claim.
     #[serde(default)]
     pub tier: Option<String>,
 }
@@ -72,8 +74,8 @@ pub async fn handle_learn(
             Some(ref t) => {
                 match t.parse::<Tier>() {
                     Ok(tier_enum) => Some(tier_enum),
-                    Err(_) => {
-                        errors.push(format!("Claim {}: Invalid tier '{}'", idx, t));
+                    Err(e) => {
+                        errors.push(format!("Claim {}: {}", idx, e));
                         continue;
                     }
                 }
@@ -81,6 +83,13 @@ pub async fn handle_learn(
             None => None,
         };
 
+        if let Some(confidence) = claim.confidence {
+            if let Err(e) = ConfidenceInterval::try_new(confidence, confidence) {
+                errors.push(format!("Claim {}: Invalid confidence: {}", idx, e));
+                continue;
+            }
+        }
+
         // Assert the claim
         match client
             .assert(
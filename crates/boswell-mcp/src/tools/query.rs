@@ -25,7 +25,16 @@ pub struct QueryParams {
     pub min_confidence: Option<f64>,
     /// Filter by tier
     #[serde(default)]
-    pub tier: Option<String>,
+    pub tier: Option<Tier>,
+    /// Only literal objects after this one (e.g. `date:2025`)
+    #[serde(default)]
+    pub object_after: Option<String>,
+    /// Only literal objects before this one
+    #[serde(default)]
+    pub object_before: Option<String>,
+    /// Match subject and object through entity aliases
+    #[serde(default)]
+    pub expand_aliases: bool,
 }
 
 /// Result of querying claims
@@ -33,41 +42,8 @@ pub struct QueryParams {
 pub struct QueryResult {
     /// Number of claims found
     pub count: usize,
-    /// List of claims
-    pub claims: Vec<ClaimInfo>,
-}
-
-/// Simplified claim information for display
-#[derive(Debug, Serialize)]
-pub struct ClaimInfo {
-    /// Claim ID
-    pub id: String,
-    /// Namespace
-    pub namespace: String,
-    /// Subject
-    pub subject: String,
-    /// Predicate
-    pub predicate: String,
-    /// Object
-    pub object: String,
-    /// Confidence interval [lower, upper]
-    pub confidence: (f64, f64),
-    /// Tier
-    pub tier: String,
-}
-
-impl From<Claim> for ClaimInfo {
-    fn from(claim: Claim) -> Self {
-        Self {
-            id: claim.id.to_string(),
-            namespace: claim.namespace,
-            subject: claim.subject,
-            predicate: claim.predicate,
-            object: claim.object,
-            confidence: claim.confidence,
-            tier: claim.tier,
-        }
-    }
+    /// Matching claims, in the domain JSON representation (`boswell_domain::json`)
+    pub claims: Vec<Claim>,
 }
 
 /// Handle boswell_query tool invocation
@@ -86,16 +62,6 @@ pub async fn handle_query(
     client: &mut BoswellClient,
     params: QueryParams,
 ) -> Result<QueryResult, McpError> {
-    // Parse tier if provided
-    let tier = match params.tier {
-        Some(ref t) => {
-            let tier_enum = t.parse::<Tier>()
-                .map_err(|_| McpError::InvalidRequest(format!("Invalid tier: {}", t)))?;
-            Some(tier_enum)
-        }
-        None => None,
-    };
-
     // Build query filter
     let filter = QueryFilter {
         namespace: params.namespace,
@@ -103,7 +69,10 @@ pub async fn handle_query(
         predicate: params.predicate,
         object: params.object,
         min_confidence: params.min_confidence,
-        tier,
+        tier: params.tier,
+        object_after: params.object_after,
+        object_before: params.object_before,
+        expand_aliases: params.expand_aliases,
     };
 
     // Execute query
@@ -112,12 +81,9 @@ pub async fn handle_query(
         .await
         .map_err(|e| McpError::BoswellError(e.to_string()))?;
 
-    let count = claims.len();
-    let claims_info: Vec<ClaimInfo> = claims.into_iter().map(ClaimInfo::from).collect();
-
     Ok(QueryResult {
-        count,
-        claims: claims_info,
+        count: claims.len(),
+        claims,
     })
 }
 
@@ -139,6 +105,27 @@ mod tests {
         let params: QueryParams = serde_json::from_str(json).unwrap();
         assert_eq!(params.namespace, Some("test".to_string()));
         assert_eq!(params.min_confidence, Some(0.8));
+        assert_eq!(params.tier, Some(Tier::Permanent));
+    }
+
+    #[test]
+    fn test_query_result_uses_domain_representation() {
+        let claim = Claim::new(
+            boswell_domain::ClaimId::new(),
+            "test".to_string(),
+            "entity1".to_string(),
+            "hasProperty".to_string(),
+            "value1".to_string(),
+            (0.8, 0.9),
+            Tier::Task,
+            1000,
+        );
+        let result = QueryResult { count: 1, claims: vec![claim.clone()] };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["claims"][0]["id"], claim.id.to_string());
+        assert_eq!(json["claims"][0]["confidence"], serde_json::json!({"lower": 0.8, "upper": 0.9}));
+        assert_eq!(json["claims"][0]["tier"], "task");
     }
 
     #[test]
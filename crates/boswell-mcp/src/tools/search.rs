@@ -30,22 +30,14 @@ fn default_threshold() -> f64 {
 }
 
 /// Search result with similarity score
+///
+/// A claim in the domain JSON representation (`boswell_domain::json`) with
+/// a `similarity` field added.
 #[derive(Debug, Serialize)]
 pub struct SearchResultItem {
-    /// Claim ID
-    pub id: String,
-    /// Namespace
-    pub namespace: String,
-    /// Subject
-    pub subject: String,
-    /// Predicate
-    pub predicate: String,
-    /// Object
-    pub object: String,
-    /// Confidence interval [lower, upper]
-    pub confidence: (f64, f64),
-    /// Tier
-    pub tier: String,
+    /// Matching claim
+    #[serde(flatten)]
+    pub claim: Claim,
     /// Similarity score (0.0 - 1.0)
     pub similarity: f64,
 }
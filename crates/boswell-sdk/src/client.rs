@@ -1,30 +1,94 @@
 //! Boswell client implementation.
 
 use crate::error::SdkError;
+use crate::rdf::{write_rdf, RdfFormat, Reification};
 use crate::session::establish_session;
-use boswell_domain::{Claim, ClaimId, Tier};
+use crate::transfer::{ExportHeader, ExportLine, ExportSummary, ImportOptions, ImportReport};
+use boswell_domain::{
+    Claim, ClaimId, ConfidenceInterval as DomainConfidence, EntityAlias, MergeProposal, NamespaceStats, StoreEvent,
+    Tier,
+};
+use boswell_grpc::conversions::{
+    alias_from_proto, domain_tier_from_proto, event_from_proto, merge_proposal_from_proto, namespace_stats_from_proto,
+    tier_to_proto,
+};
 use boswell_grpc::proto::{
-    bos_well_service_client::BosWellServiceClient, AssertRequest, AssertResponse, ConfidenceInterval,
-    ForgetRequest, ForgetResponse, LearnRequest, LearnResponse, QueryFilter as GrpcQueryFilter,
-    QueryMode as GrpcQueryMode, QueryRequest, QueryResponse, Tier as GrpcTier,
+    bos_well_service_client::BosWellServiceClient, export_record, AssertRequest, AssertResponse,
+    ClaimRecord, ConfidenceInterval, EventType, ExportRecord, ExportRequest, ForgetRequest, ForgetResponse,
+    ImportRequest, ImportResponse, LearnRequest, LearnResponse, ListAliasesRequest, ListNamespacesRequest,
+    MergeEntitiesRequest, MoveNamespaceRequest, ProposeMergesRequest, QueryFilter as GrpcQueryFilter,
+    QueryMode as GrpcQueryMode, QueryRequest, QueryResponse, RelationshipRecord, Tier as GrpcTier,
+    UnmergeEntityRequest, WatchEvent, WatchRequest,
 };
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
 use tonic::transport::Channel;
+use tonic::Streaming;
 
 /// Query filter for claim queries
 #[derive(Debug, Default, Clone)]
 pub struct QueryFilter {
     /// Namespace filter
     pub namespace: Option<String>,
-    /// Subject filter
+    /// Subject filter (`kind:*` matches every entity of a kind)
     pub subject: Option<String>,
     /// Predicate filter
     pub predicate: Option<String>,
-    /// Object filter
+    /// Object filter (`kind:*` matches every entity of a kind)
     pub object: Option<String>,
     /// Minimum confidence threshold
     pub min_confidence: Option<f64>,
     /// Tier filter
     pub tier: Option<Tier>,
+    /// Only literal objects after this one, such as `date:2025`
+    pub object_after: Option<String>,
+    /// Only literal objects before this one
+    pub object_before: Option<String>,
+    /// Match the subject and object filters through entity aliases
+    pub expand_aliases: bool,
+}
+
+/// Which events [`BoswellClient::watch`] streams
+///
+/// Namespace and tier filters apply to the values recorded with each event,
+/// so evictions and claims that have since moved still match.
+#[derive(Debug, Default, Clone)]
+pub struct WatchFilter {
+    /// Namespace subtree of the event's claim
+    pub namespace: Option<String>,
+    /// Tier of the event's claim when it was recorded
+    pub tier: Option<Tier>,
+    /// Event types to stream; empty for every type
+    pub event_types: Vec<EventType>,
+    /// Start after this sequence number; 0 replays the whole event log
+    pub after_seq: u64,
+}
+
+/// A running watch started by [`BoswellClient::watch`]
+pub struct ClaimWatch {
+    stream: Streaming<WatchEvent>,
+    last_seq: u64,
+}
+
+impl ClaimWatch {
+    /// Wait for the next matching event
+    ///
+    /// Returns `None` once the instance ends the stream.
+    pub async fn next(&mut self) -> Result<Option<StoreEvent>, SdkError> {
+        let Some(event) = self.stream.message().await? else {
+            return Ok(None);
+        };
+        let event = event_from_proto(event).map_err(|e| SdkError::GrpcError(e.to_string()))?;
+        self.last_seq = event.seq;
+        Ok(Some(event))
+    }
+
+    /// Sequence number of the last event received, or the starting point
+    ///
+    /// Pass it as [`WatchFilter::after_seq`] to resume after reconnecting.
+    pub fn last_seq(&self) -> u64 {
+        self.last_seq
+    }
 }
 
 /// Boswell SDK client
@@ -101,19 +165,22 @@ impl BoswellClient {
         confidence: Option<f64>,
         tier: Option<Tier>,
     ) -> Result<ClaimId, SdkError> {
+        let confidence_interval = confidence
+            .map(|c| DomainConfidence::try_new(c, c))
+            .transpose()
+            .map_err(|e| SdkError::InvalidArgument(format!("Invalid confidence: {}", e)))?
+            .map(|c| ConfidenceInterval {
+                lower: c.lower,
+                upper: c.upper,
+            });
         let mut retried = false;
         
         loop {
             let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
             let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
 
-            let confidence_interval = confidence.map(|c| ConfidenceInterval {
-                lower: c,
-                upper: c,
-            });
-
             let tier_i32 = tier
-                .map(|t| grpc_tier_from_domain_tier(t))
+                .map(|t| tier_to_proto(t) as i32)
                 .unwrap_or(GrpcTier::Unspecified as i32);
 
             let request = AssertRequest {
@@ -157,7 +224,10 @@ impl BoswellClient {
                 predicate: filter.predicate.clone(),
                 object: filter.object.clone(),
                 min_confidence: filter.min_confidence,
-                tier: filter.tier.map(grpc_tier_from_domain_tier),
+                tier: filter.tier.map(|t| tier_to_proto(t) as i32),
+                object_after: filter.object_after.clone(),
+                object_before: filter.object_before.clone(),
+                expand_aliases: filter.expand_aliases,
             };
 
             let request = QueryRequest {
@@ -192,6 +262,11 @@ impl BoswellClient {
 
     /// Learn multiple claims in batch
     pub async fn learn(&mut self, claims: Vec<Claim>) -> Result<LearnResponse, SdkError> {
+        for claim in &claims {
+            DomainConfidence::try_new(claim.confidence.0, claim.confidence.1).map_err(|e| {
+                SdkError::InvalidArgument(format!("Claim {} has an invalid confidence: {}", claim.id, e))
+            })?;
+        }
         let mut retried = false;
         
         loop {
@@ -204,6 +279,7 @@ impl BoswellClient {
                 claims: grpc_claims,
                 skip_duplicates: false,
                 auth_token: token.clone(),
+                all_or_nothing: false,
             };
 
             match client.learn(request).await {
@@ -256,27 +332,412 @@ impl BoswellClient {
     }
 }
 
-// Helper functions for type conversion
+impl BoswellClient {
+    /// Export a namespace subtree as JSON Lines
+    ///
+    /// Writes a header, every claim in `namespace` and its descendants with
+    /// provenance, then the relationships between exported claims. See
+    /// [`ExportLine`] for the format.
+    pub async fn export<W: Write>(&mut self, namespace: &str, mut writer: W) -> Result<ExportSummary, SdkError> {
+        let mut stream = self.export_stream(namespace).await?;
+        writeln!(writer, "{}", ExportLine::Header(ExportHeader::new(namespace)).to_line())?;
+
+        let mut summary = ExportSummary::default();
+        while let Some(line) = next_export_line(&mut stream, &mut summary).await? {
+            writeln!(writer, "{}", line.to_line())?;
+        }
+
+        writer.flush()?;
+        Ok(summary)
+    }
+
+    /// Export a namespace subtree as RDF
+    ///
+    /// Exports the same claims, provenance and relationships as
+    /// [`export`](Self::export), serialized with [`write_rdf`].
+    pub async fn export_rdf<W: Write>(
+        &mut self,
+        namespace: &str,
+        format: RdfFormat,
+        reification: Reification,
+        writer: W,
+    ) -> Result<ExportSummary, SdkError> {
+        let mut stream = self.export_stream(namespace).await?;
+
+        let mut summary = ExportSummary::default();
+        let mut lines = Vec::new();
+        while let Some(line) = next_export_line(&mut stream, &mut summary).await? {
+            lines.push(line);
+        }
+
+        write_rdf(&lines, format, reification, writer)?;
+        Ok(summary)
+    }
+
+    /// Start an Export call, reconnecting once if the session expired
+    async fn export_stream(&mut self, namespace: &str) -> Result<Streaming<ExportRecord>, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = ExportRequest {
+                namespace: namespace.to_string(),
+                auth_token: token.clone(),
+            };
+
+            match client.export(request).await {
+                Ok(r) => return Ok(r.into_inner()),
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+
+    /// Watch the instance's event log
+    ///
+    /// Streams every event after `filter.after_seq` that passes the filter,
+    /// then keeps streaming new ones as they are recorded.
+    pub async fn watch(&mut self, filter: WatchFilter) -> Result<ClaimWatch, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = WatchRequest {
+                namespace: filter.namespace.clone(),
+                tier: filter.tier.map(|t| tier_to_proto(t) as i32),
+                event_types: filter.event_types.iter().map(|&event_type| event_type as i32).collect(),
+                after_seq: filter.after_seq,
+                auth_token: token.clone(),
+            };
 
-fn grpc_tier_from_domain_tier(tier: Tier) -> i32 {
-    match tier {
-        Tier::Ephemeral => GrpcTier::Ephemeral as i32,
-        Tier::Task => GrpcTier::Task as i32,
-        Tier::Project => GrpcTier::Project as i32,
-        Tier::Permanent => GrpcTier::Permanent as i32,
+            match client.watch(request).await {
+                Ok(r) => {
+                    return Ok(ClaimWatch {
+                        stream: r.into_inner(),
+                        last_seq: filter.after_seq,
+                    });
+                }
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+
+    /// Import a JSON Lines export
+    ///
+    /// Claims keep their exported IDs. A claim whose ID is already taken by
+    /// different content gets a new ID, and one that duplicates an existing
+    /// claim corroborates it; relationships follow both kinds of remapping.
+    /// With `use_gatekeeper`, the instance validates every claim first.
+    pub async fn import<R: BufRead>(&mut self, reader: R, options: ImportOptions) -> Result<ImportReport, SdkError> {
+        let mut claims = Vec::new();
+        let mut relationships = Vec::new();
+        let mut header_seen = false;
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let parsed = ExportLine::parse(&line)
+                .map_err(|e| SdkError::FormatError(format!("line {}: {}", index + 1, e)))?;
+            match parsed {
+                ExportLine::Header(header) if !header_seen => {
+                    header.check()?;
+                    header_seen = true;
+                }
+                ExportLine::Header(_) => {
+                    return Err(SdkError::FormatError(format!("line {}: unexpected header", index + 1)));
+                }
+                _ if !header_seen => {
+                    return Err(SdkError::FormatError(format!("line {}: missing export header", index + 1)));
+                }
+                ExportLine::Claim(claim) => claims.push(ClaimRecord::from(claim)),
+                ExportLine::Relationship(relationship) => {
+                    relationships.push(RelationshipRecord::from(relationship));
+                }
+            }
+        }
+        if !header_seen {
+            return Err(SdkError::FormatError("empty export".to_string()));
+        }
+
+        let mut report = ImportReport::default();
+        let mut mapped: HashMap<String, String> = HashMap::new();
+
+        for batch in claims.chunks(options.batch_size.max(1)) {
+            let response = self.import_batch(batch.to_vec(), vec![], options.use_gatekeeper).await?;
+            for mapping in &response.mappings {
+                mapped.insert(mapping.original_id.clone(), mapping.claim_id.clone());
+            }
+            report.add(response);
+        }
+
+        // Claims from earlier batches were remapped on this side of the wire
+        for relationship in &mut relationships {
+            if let Some(claim_id) = mapped.get(&relationship.from_claim_id) {
+                relationship.from_claim_id = claim_id.clone();
+            }
+            if let Some(claim_id) = mapped.get(&relationship.to_claim_id) {
+                relationship.to_claim_id = claim_id.clone();
+            }
+        }
+        for batch in relationships.chunks(options.batch_size.max(1)) {
+            let response = self.import_batch(vec![], batch.to_vec(), options.use_gatekeeper).await?;
+            report.add(response);
+        }
+
+        Ok(report)
+    }
+
+    /// Send one Import request
+    async fn import_batch(
+        &mut self,
+        claims: Vec<ClaimRecord>,
+        relationships: Vec<RelationshipRecord>,
+        use_gatekeeper: bool,
+    ) -> Result<ImportResponse, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = ImportRequest {
+                claims: claims.clone(),
+                relationships: relationships.clone(),
+                use_gatekeeper,
+                auth_token: token.clone(),
+            };
+
+            match client.import(request).await {
+                Ok(r) => return Ok(r.into_inner()),
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+    /// Statistics for each namespace under `root` that holds claims
+    ///
+    /// Each entry counts only the claims stored directly in its namespace, and
+    /// parents come before children; pass an empty root for every namespace.
+    /// Use [`NamespaceStats::rollup`] for subtree totals.
+    pub async fn list_namespaces(&mut self, root: &str) -> Result<Vec<NamespaceStats>, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = ListNamespacesRequest {
+                root: root.to_string(),
+                auth_token: token.clone(),
+            };
+
+            match client.list_namespaces(request).await {
+                Ok(r) => {
+                    return Ok(r.into_inner().namespaces.into_iter().map(namespace_stats_from_proto).collect());
+                }
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+
+    /// Move every claim in the subtree at `from` under `to`
+    ///
+    /// `from/x` becomes `to/x`; claims keep their IDs. The instance refuses
+    /// the move if the destination already holds claims. Returns the number
+    /// of claims moved.
+    pub async fn move_namespace(&mut self, from: &str, to: &str) -> Result<u64, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = MoveNamespaceRequest {
+                from: from.to_string(),
+                to: to.to_string(),
+                auth_token: token.clone(),
+            };
+
+            match client.move_namespace(request).await {
+                Ok(r) => return Ok(r.into_inner().moved_count),
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+
+    /// Record that `alias` names the same entity as `canonical`
+    ///
+    /// The instance refuses merges across kinds, of literals, of an entity
+    /// that is already an alias, and merges that would form a cycle.
+    pub async fn merge_entities(&mut self, alias: &str, canonical: &str, reason: &str) -> Result<EntityAlias, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = MergeEntitiesRequest {
+                alias: alias.to_string(),
+                canonical: canonical.to_string(),
+                reason: reason.to_string(),
+                auth_token: token.clone(),
+            };
+
+            match client.merge_entities(request).await {
+                Ok(r) => {
+                    return r.into_inner().alias
+                        .map(alias_from_proto)
+                        .ok_or_else(|| SdkError::GrpcError("Merge response is missing the alias".to_string()));
+                }
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+
+    /// Undo the merge of `alias`; the instance keeps the record as retracted
+    pub async fn unmerge_entity(&mut self, alias: &str) -> Result<(), SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = UnmergeEntityRequest {
+                alias: alias.to_string(),
+                auth_token: token.clone(),
+            };
+
+            match client.unmerge_entity(request).await {
+                Ok(_) => return Ok(()),
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+
+    /// Entity aliases, oldest first, with retracted ones if asked for
+    pub async fn list_aliases(&mut self, include_retracted: bool) -> Result<Vec<EntityAlias>, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = ListAliasesRequest {
+                include_retracted,
+                auth_token: token.clone(),
+            };
+
+            match client.list_aliases(request).await {
+                Ok(r) => return Ok(r.into_inner().aliases.into_iter().map(alias_from_proto).collect()),
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
+    }
+
+    /// Merges the instance suggests among entities in `namespace`, best first
+    ///
+    /// Nothing is merged; pass a proposal to [`merge_entities`](Self::merge_entities)
+    /// to accept it. Without `min_score` the instance's threshold applies.
+    pub async fn propose_merges(
+        &mut self,
+        namespace: Option<&str>,
+        min_score: Option<f64>,
+        limit: usize,
+    ) -> Result<Vec<MergeProposal>, SdkError> {
+        let mut retried = false;
+
+        loop {
+            let client = self.grpc_client.as_mut().ok_or(SdkError::NotConnected)?;
+            let token = self.session_token.as_ref().ok_or(SdkError::NotConnected)?;
+
+            let request = ProposeMergesRequest {
+                namespace: namespace.map(str::to_string),
+                min_score,
+                limit: limit as i32,
+                auth_token: token.clone(),
+            };
+
+            match client.propose_merges(request).await {
+                Ok(r) => return Ok(r.into_inner().proposals.into_iter().map(merge_proposal_from_proto).collect()),
+                Err(e) if matches!(e.code(), tonic::Code::Unauthenticated) && !retried => {
+                    // Session expired - try to reconnect once
+                    self.reconnect().await?;
+                    retried = true;
+                }
+                Err(e) => return Err(SdkError::from(e)),
+            }
+        }
     }
 }
 
-fn domain_tier_from_grpc(tier: i32) -> Result<String, String> {
-    match GrpcTier::try_from(tier) {
-        Ok(GrpcTier::Ephemeral) => Ok("ephemeral".to_string()),
-        Ok(GrpcTier::Task) => Ok("task".to_string()),
-        Ok(GrpcTier::Project) => Ok("project".to_string()),
-        Ok(GrpcTier::Permanent) => Ok("permanent".to_string()),
-        _ => Err("Invalid tier".to_string()),
+/// Read the next claim or relationship from an Export stream, counting it
+async fn next_export_line(
+    stream: &mut Streaming<ExportRecord>,
+    summary: &mut ExportSummary,
+) -> Result<Option<ExportLine>, SdkError> {
+    while let Some(record) = stream.message().await? {
+        match record.record {
+            Some(export_record::Record::Claim(claim)) => {
+                summary.claims += 1;
+                return Ok(Some(ExportLine::Claim(claim.try_into()?)));
+            }
+            Some(export_record::Record::Relationship(relationship)) => {
+                summary.relationships += 1;
+                return Ok(Some(ExportLine::Relationship(relationship.into())));
+            }
+            None => {}
+        }
     }
+    Ok(None)
 }
 
+// Helper functions for type conversion
+
 fn grpc_claim_to_domain(claim: &boswell_grpc::proto::Claim) -> Result<Claim, String> {
     let claim_id =
         ClaimId::from_string(&claim.id).map_err(|e| format!("Invalid claim ID: {}", e))?;
@@ -284,20 +745,12 @@ fn grpc_claim_to_domain(claim: &boswell_grpc::proto::Claim) -> Result<Claim, Str
     let confidence = claim
         .confidence
         .as_ref()
-        .map(|c| (c.lower, c.upper))
         .ok_or("Missing confidence interval")?;
+    let confidence = DomainConfidence::try_new(confidence.lower, confidence.upper)
+        .map(|c| (c.lower, c.upper))
+        .map_err(|e| format!("Invalid confidence bounds: {}", e))?;
 
-    // Validate confidence bounds
-    if confidence.0 < 0.0
-        || confidence.0 > 1.0
-        || confidence.1 < 0.0
-        || confidence.1 > 1.0
-        || confidence.0 > confidence.1
-    {
-        return Err("Invalid confidence bounds".to_string());
-    }
-
-    let tier = domain_tier_from_grpc(claim.tier)?;
+    let tier = domain_tier_from_proto(claim.tier).map_err(|e| e.to_string())?;
 
     let created_at = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -318,10 +771,7 @@ fn grpc_claim_to_domain(claim: &boswell_grpc::proto::Claim) -> Result<Claim, Str
 }
 
 fn domain_claim_to_grpc(claim: Claim) -> boswell_grpc::proto::Claim {
-    // Convert tier string to proto Tier  
-    let tier = Tier::parse(&claim.tier)
-        .map(grpc_tier_from_domain_tier)
-        .unwrap_or(GrpcTier::Unspecified as i32);
+    let tier = tier_to_proto(claim.tier) as i32;
 
     boswell_grpc::proto::Claim {
         id: claim.id.to_string(),
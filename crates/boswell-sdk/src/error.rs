@@ -29,9 +29,21 @@ pub enum SdkError {
     #[error("No instances available")]
     NoInstancesAvailable,
 
+    /// A value given to the SDK or the server is invalid, such as a NaN confidence
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     /// Client not connected (connect() must be called first)
     #[error("Client not connected - call connect() first")]
     NotConnected,
+
+    /// Malformed export file
+    #[error("Format error: {0}")]
+    FormatError(String),
+
+    /// Reading or writing an export file failed
+    #[error("I/O error: {0}")]
+    IoError(String),
 }
 
 impl From<reqwest::Error> for SdkError {
@@ -63,11 +75,18 @@ impl From<tonic::Status> for SdkError {
             Code::PermissionDenied => SdkError::AuthError(status.message().to_string()),
             Code::Unavailable => SdkError::ConnectionError(format!("gRPC unavailable: {}", status.message())),
             Code::DeadlineExceeded => SdkError::ConnectionError("Request timeout".to_string()),
+            Code::InvalidArgument => SdkError::InvalidArgument(status.message().to_string()),
             _ => SdkError::GrpcError(format!("{}: {}", status.code(), status.message())),
         }
     }
 }
 
+impl From<std::io::Error> for SdkError {
+    fn from(e: std::io::Error) -> Self {
+        SdkError::IoError(e.to_string())
+    }
+}
+
 impl From<serde_json::Error> for SdkError {
     fn from(e: serde_json::Error) -> Self {
         SdkError::SessionError(format!("JSON parsing error: {}", e))
@@ -27,8 +27,16 @@
 
 mod client;
 mod error;
+mod rdf;
 mod session;
+mod transfer;
 
-pub use client::{BoswellClient, QueryFilter};
+pub use boswell_grpc::proto::EventType;
+pub use client::{BoswellClient, ClaimWatch, QueryFilter, WatchFilter};
 pub use error::SdkError;
+pub use rdf::{write_rdf, RdfFormat, Reification, VOCAB_IRI};
+pub use transfer::{
+    ClaimLine, ConfidenceLine, ExportHeader, ExportLine, ExportSummary, ImportOptions, ImportReport,
+    ProvenanceLine, RelationshipLine, EXPORT_FORMAT, EXPORT_FORMAT_VERSION,
+};
 
@@ -0,0 +1,553 @@
+//! RDF serialization of exports.
+
+use crate::error::SdkError;
+use crate::transfer::{ClaimLine, ExportLine, RelationshipLine};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// IRI namespace of the Boswell vocabulary (`bv:` in Turtle and JSON-LD)
+pub const VOCAB_IRI: &str = "urn:boswell:vocab:";
+const ENTITY_IRI: &str = "urn:boswell:entity:";
+const PREDICATE_IRI: &str = "urn:boswell:predicate:";
+const CLAIM_IRI: &str = "urn:boswell:claim:";
+const RELATIONSHIP_IRI: &str = "urn:boswell:relationship:";
+const RDF_IRI: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const XSD_IRI: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Prefixes declared in Turtle and in the JSON-LD context
+const PREFIXES: [(&str, &str); 7] = [
+    ("bv", VOCAB_IRI),
+    ("be", ENTITY_IRI),
+    ("bp", PREDICATE_IRI),
+    ("bc", CLAIM_IRI),
+    ("br", RELATIONSHIP_IRI),
+    ("rdf", RDF_IRI),
+    ("xsd", XSD_IRI),
+];
+
+/// RDF syntax written by [`write_rdf`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdfFormat {
+    /// N-Triples, one triple per line
+    NTriples,
+    /// Turtle with prefixes, grouped by subject
+    Turtle,
+    /// JSON-LD with a `@graph` of node objects
+    JsonLd,
+}
+
+/// How claim metadata is attached to the triple a claim asserts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reification {
+    /// RDF-star quoted triples (`<< s p o >>`); N-Triples and Turtle only
+    Star,
+    /// Classic `rdf:Statement` nodes with `rdf:subject`, `rdf:predicate`
+    /// and `rdf:object`
+    Statements,
+}
+
+impl RdfFormat {
+    /// Reification used when none is requested: RDF-star where the syntax
+    /// supports it, statements for JSON-LD
+    pub fn default_reification(self) -> Reification {
+        match self {
+            RdfFormat::NTriples | RdfFormat::Turtle => Reification::Star,
+            RdfFormat::JsonLd => Reification::Statements,
+        }
+    }
+}
+
+/// Write exported claims and relationships as RDF
+///
+/// Every claim contributes its plain `subject predicate object` triple, with
+/// subjects and objects as `urn:boswell:entity:` IRIs and predicates as
+/// `urn:boswell:predicate:` IRIs, so the graph can be queried directly. The
+/// claim itself is a `bv:Claim` node (`urn:boswell:claim:<id>`) carrying
+/// `bv:namespace`, `bv:confidenceLower`, `bv:confidenceUpper`, `bv:tier`,
+/// `bv:createdAt`, `bv:staleAt` and one `bv:provenance` node per entry. With
+/// [`Reification::Star`] the claim points at its triple through
+/// `bv:statement << s p o >>`; with [`Reification::Statements`] the claim
+/// node is also an `rdf:Statement`.
+///
+/// Relationships become `bv:supports`, `bv:contradicts`, `bv:derivedFrom`,
+/// `bv:references` or `bv:supersedes` edges between claim nodes, annotated
+/// with `bv:strength` and `bv:createdAt` in the same reification style.
+/// Header lines are ignored.
+pub fn write_rdf<'a, W: Write>(
+    lines: impl IntoIterator<Item = &'a ExportLine>,
+    format: RdfFormat,
+    reification: Reification,
+    mut writer: W,
+) -> Result<(), SdkError> {
+    if format == RdfFormat::JsonLd && reification == Reification::Star {
+        return Err(SdkError::FormatError(
+            "JSON-LD has no RDF-star syntax; use statement reification".to_string(),
+        ));
+    }
+
+    let mut graph = Vec::new();
+    for line in lines {
+        match line {
+            ExportLine::Header(_) => {}
+            ExportLine::Claim(claim) => claim_triples(claim, reification, &mut graph),
+            ExportLine::Relationship(relationship) => {
+                relationship_triples(relationship, reification, &mut graph)
+            }
+        }
+    }
+
+    match format {
+        RdfFormat::NTriples => {
+            for triple in &graph {
+                writeln!(writer, "{} .", ntriples_triple(triple))?;
+            }
+        }
+        RdfFormat::Turtle => write_turtle(&graph, &mut writer)?,
+        RdfFormat::JsonLd => {
+            serde_json::to_writer_pretty(&mut writer, &json_ld(&graph))
+                .map_err(|e| SdkError::IoError(e.to_string()))?;
+            writeln!(writer)?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// An RDF term
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    Iri(String),
+    /// Lexical value and datatype; `None` is a plain string
+    Literal(String, Option<&'static str>),
+    /// RDF-star quoted triple
+    Quoted(Box<Triple>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Triple {
+    subject: Term,
+    predicate: String,
+    object: Term,
+}
+
+impl Triple {
+    fn new(subject: &Term, predicate: String, object: Term) -> Self {
+        Self {
+            subject: subject.clone(),
+            predicate,
+            object,
+        }
+    }
+}
+
+fn vocab(name: &str) -> String {
+    format!("{}{}", VOCAB_IRI, name)
+}
+
+fn rdf(name: &str) -> String {
+    format!("{}{}", RDF_IRI, name)
+}
+
+fn iri(base: &str, value: &str) -> Term {
+    Term::Iri(format!("{}{}", base, percent_encode(value)))
+}
+
+fn string(value: &str) -> Term {
+    Term::Literal(value.to_string(), None)
+}
+
+fn double(value: f64) -> Term {
+    Term::Literal(value.to_string(), Some("double"))
+}
+
+fn date_time(seconds: u64) -> Term {
+    Term::Literal(format_date_time(seconds), Some("dateTime"))
+}
+
+fn claim_triples(claim: &ClaimLine, reification: Reification, graph: &mut Vec<Triple>) {
+    let claim_iri = format!("{}{}", CLAIM_IRI, percent_encode(&claim.id));
+    let node = Term::Iri(claim_iri.clone());
+    let statement = Triple {
+        subject: iri(ENTITY_IRI, &claim.subject),
+        predicate: format!("{}{}", PREDICATE_IRI, percent_encode(&claim.predicate)),
+        object: iri(ENTITY_IRI, &claim.object),
+    };
+
+    graph.push(statement.clone());
+    graph.push(Triple::new(&node, rdf("type"), Term::Iri(vocab("Claim"))));
+    reify(&node, statement, reification, graph);
+    graph.push(Triple::new(&node, vocab("namespace"), string(&claim.namespace)));
+    graph.push(Triple::new(&node, vocab("confidenceLower"), double(claim.confidence.lower)));
+    graph.push(Triple::new(&node, vocab("confidenceUpper"), double(claim.confidence.upper)));
+    graph.push(Triple::new(&node, vocab("tier"), string(claim.tier.as_str())));
+    graph.push(Triple::new(&node, vocab("createdAt"), date_time(claim.created_at)));
+    if let Some(stale_at) = claim.stale_at {
+        graph.push(Triple::new(&node, vocab("staleAt"), date_time(stale_at)));
+    }
+
+    for (index, entry) in claim.provenance.iter().enumerate() {
+        let provenance = Term::Iri(format!("{}/provenance/{}", claim_iri, index));
+
+        graph.push(Triple::new(&node, vocab("provenance"), provenance.clone()));
+        graph.push(Triple::new(&provenance, rdf("type"), Term::Iri(vocab("Provenance"))));
+        graph.push(Triple::new(&provenance, vocab("source"), string(&entry.source)));
+        graph.push(Triple::new(&provenance, vocab("sourceType"), string(&entry.source_type)));
+        // Provenance timestamps are in milliseconds, unlike the claim's
+        graph.push(Triple::new(&provenance, vocab("timestamp"), date_time(entry.timestamp / 1_000)));
+        if let Some(rationale) = &entry.rationale {
+            graph.push(Triple::new(&provenance, vocab("rationale"), string(rationale)));
+        }
+    }
+}
+
+fn relationship_triples(relationship: &RelationshipLine, reification: Reification, graph: &mut Vec<Triple>) {
+    let edge = Triple {
+        subject: iri(CLAIM_IRI, &relationship.from),
+        predicate: vocab(&camel_case(&relationship.relationship_type)),
+        object: iri(CLAIM_IRI, &relationship.to),
+    };
+    graph.push(edge.clone());
+
+    let node = match reification {
+        Reification::Star => Term::Quoted(Box::new(edge)),
+        Reification::Statements => {
+            let node = iri(
+                RELATIONSHIP_IRI,
+                &format!("{}-{}-{}", relationship.from, relationship.relationship_type, relationship.to),
+            );
+            reify(&node, edge, reification, graph);
+            node
+        }
+    };
+    graph.push(Triple::new(&node, vocab("strength"), double(relationship.strength)));
+    graph.push(Triple::new(&node, vocab("createdAt"), date_time(relationship.created_at)));
+}
+
+/// Link `node` to the triple it describes
+fn reify(node: &Term, statement: Triple, reification: Reification, graph: &mut Vec<Triple>) {
+    match reification {
+        Reification::Star => {
+            graph.push(Triple::new(node, vocab("statement"), Term::Quoted(Box::new(statement))));
+        }
+        Reification::Statements => {
+            graph.push(Triple::new(node, rdf("type"), Term::Iri(rdf("Statement"))));
+            graph.push(Triple::new(node, rdf("subject"), statement.subject));
+            graph.push(Triple::new(node, rdf("predicate"), Term::Iri(statement.predicate)));
+            graph.push(Triple::new(node, rdf("object"), statement.object));
+        }
+    }
+}
+
+/// Percent-encode everything outside the IRI unreserved characters
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// `derived_from` -> `derivedFrom`
+fn camel_case(name: &str) -> String {
+    let mut words = name.split('_');
+    let mut camel = words.next().unwrap_or_default().to_string();
+    for word in words {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+    camel
+}
+
+/// Unix seconds as an `xsd:dateTime` in UTC
+fn format_date_time(seconds: u64) -> String {
+    // Civil-from-days conversion on the proleptic Gregorian calendar
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let time = seconds % 86_400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+/// Escape a string for a quoted N-Triples or Turtle literal
+fn escape_literal(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn ntriples_term(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{}>", iri),
+        Term::Literal(value, None) => format!("\"{}\"", escape_literal(value)),
+        Term::Literal(value, Some(datatype)) => {
+            format!("\"{}\"^^<{}{}>", escape_literal(value), XSD_IRI, datatype)
+        }
+        Term::Quoted(triple) => format!("<< {} >>", ntriples_triple(triple)),
+    }
+}
+
+fn ntriples_triple(triple: &Triple) -> String {
+    format!(
+        "{} <{}> {}",
+        ntriples_term(&triple.subject),
+        triple.predicate,
+        ntriples_term(&triple.object)
+    )
+}
+
+/// Prefixed name for `iri`, if one of [`PREFIXES`] covers it and the local
+/// part is valid in Turtle
+fn compact(iri: &str) -> Option<String> {
+    PREFIXES.iter().find_map(|(prefix, base)| {
+        let local = iri.strip_prefix(base)?;
+        let valid = !local.is_empty()
+            && !local.starts_with(['-', '.'])
+            && !local.ends_with('.')
+            && local.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'%'));
+        valid.then(|| format!("{}:{}", prefix, local))
+    })
+}
+
+fn turtle_iri(iri: &str) -> String {
+    compact(iri).unwrap_or_else(|| format!("<{}>", iri))
+}
+
+fn turtle_term(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => turtle_iri(iri),
+        Term::Literal(value, None) => format!("\"{}\"", escape_literal(value)),
+        Term::Literal(value, Some(datatype)) => format!("\"{}\"^^xsd:{}", escape_literal(value), datatype),
+        Term::Quoted(triple) => format!(
+            "<< {} {} {} >>",
+            turtle_term(&triple.subject),
+            turtle_predicate(&triple.predicate),
+            turtle_term(&triple.object)
+        ),
+    }
+}
+
+fn turtle_predicate(predicate: &str) -> String {
+    if predicate == rdf("type") {
+        "a".to_string()
+    } else {
+        turtle_iri(predicate)
+    }
+}
+
+/// Write Turtle, folding consecutive triples about one subject with `;`
+fn write_turtle<W: Write>(graph: &[Triple], writer: &mut W) -> Result<(), SdkError> {
+    for (prefix, base) in PREFIXES {
+        writeln!(writer, "@prefix {}: <{}> .", prefix, base)?;
+    }
+
+    let mut previous: Option<&Term> = None;
+    for triple in graph {
+        let predicate_object = format!("{} {}", turtle_predicate(&triple.predicate), turtle_term(&triple.object));
+        if previous == Some(&triple.subject) {
+            write!(writer, " ;\n    {}", predicate_object)?;
+        } else {
+            if previous.is_some() {
+                writeln!(writer, " .")?;
+            }
+            write!(writer, "\n{} {}", turtle_term(&triple.subject), predicate_object)?;
+        }
+        previous = Some(&triple.subject);
+    }
+    if previous.is_some() {
+        writeln!(writer, " .")?;
+    }
+    Ok(())
+}
+
+/// Build a JSON-LD document with one node object per subject
+///
+/// Quoted triples never reach this point; [`write_rdf`] refuses RDF-star
+/// for JSON-LD.
+fn json_ld(graph: &[Triple]) -> Value {
+    let mut nodes: Vec<Map<String, Value>> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+
+    for triple in graph {
+        let (Term::Iri(subject), Term::Iri(_) | Term::Literal(..)) = (&triple.subject, &triple.object) else {
+            continue;
+        };
+        let position = *index.entry(subject.as_str()).or_insert_with(|| {
+            let mut node = Map::new();
+            node.insert("@id".to_string(), json!(compact(subject).unwrap_or_else(|| subject.clone())));
+            nodes.push(node);
+            nodes.len() - 1
+        });
+
+        let (key, value) = if triple.predicate == rdf("type") {
+            let Term::Iri(class) = &triple.object else { continue };
+            ("@type".to_string(), json!(compact(class).unwrap_or_else(|| class.clone())))
+        } else {
+            let value = match &triple.object {
+                Term::Iri(iri) => json!({ "@id": compact(iri).unwrap_or_else(|| iri.clone()) }),
+                Term::Literal(value, None) => json!(value),
+                Term::Literal(value, Some(datatype)) => {
+                    json!({ "@value": value, "@type": format!("xsd:{}", datatype) })
+                }
+                Term::Quoted(_) => continue,
+            };
+            (compact(&triple.predicate).unwrap_or_else(|| triple.predicate.clone()), value)
+        };
+
+        let node = &mut nodes[position];
+        match node.get_mut(&key) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => *existing = json!([existing.take(), value]),
+            None => {
+                node.insert(key, value);
+            }
+        }
+    }
+
+    let context: Map<String, Value> = PREFIXES
+        .iter()
+        .map(|(prefix, base)| (prefix.to_string(), json!(base)))
+        .collect();
+
+    json!({ "@context": context, "@graph": nodes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transfer::{ConfidenceLine, ProvenanceLine};
+    use boswell_domain::Tier;
+
+    fn export() -> Vec<ExportLine> {
+        let claim = |id: &str, object: &str| ClaimLine {
+            id: id.to_string(),
+            namespace: "acme/eng".to_string(),
+            subject: "Alice Smith".to_string(),
+            predicate: "works_on".to_string(),
+            object: object.to_string(),
+            confidence: ConfidenceLine { lower: 0.8, upper: 0.9 },
+            tier: Tier::Project,
+            created_at: 1_700_000_000,
+            stale_at: None,
+            provenance: vec![ProvenanceLine {
+                source: "user:alice".to_string(),
+                source_type: "user".to_string(),
+                timestamp: 1_700_000_000_500,
+                rationale: Some("said \"so\"".to_string()),
+            }],
+        };
+
+        vec![
+            ExportLine::Claim(claim("01A", "Boswell")),
+            ExportLine::Claim(claim("01B", "Rust")),
+            ExportLine::Relationship(RelationshipLine {
+                from: "01B".to_string(),
+                to: "01A".to_string(),
+                relationship_type: "derived_from".to_string(),
+                strength: 0.5,
+                created_at: 1_700_000_000,
+            }),
+        ]
+    }
+
+    fn render(format: RdfFormat, reification: Reification) -> String {
+        let mut output = Vec::new();
+        write_rdf(&export(), format, reification, &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_ntriples_star() {
+        let output = render(RdfFormat::NTriples, Reification::Star);
+        let triple = "<urn:boswell:entity:Alice%20Smith> <urn:boswell:predicate:works_on> <urn:boswell:entity:Boswell>";
+
+        assert!(output.contains(&format!("{} .\n", triple)));
+        assert!(output.contains(&format!(
+            "<urn:boswell:claim:01A> <urn:boswell:vocab:statement> << {} >> .\n",
+            triple
+        )));
+        assert!(output.contains(
+            "<urn:boswell:claim:01A> <urn:boswell:vocab:createdAt> \"2023-11-14T22:13:20Z\"^^<http://www.w3.org/2001/XMLSchema#dateTime> .\n"
+        ));
+        assert!(output.contains("<urn:boswell:vocab:rationale> \"said \\\"so\\\"\" .\n"));
+        assert!(output.contains(
+            "<urn:boswell:claim:01A/provenance/0> <urn:boswell:vocab:timestamp> \"2023-11-14T22:13:20Z\"^^<http://www.w3.org/2001/XMLSchema#dateTime> .\n"
+        ));
+        assert!(output.contains(
+            "<< <urn:boswell:claim:01B> <urn:boswell:vocab:derivedFrom> <urn:boswell:claim:01A> >> <urn:boswell:vocab:strength> \"0.5\""
+        ));
+        assert!(output.lines().all(|line| line.ends_with(" .")));
+    }
+
+    #[test]
+    fn test_turtle_groups_subjects() {
+        let output = render(RdfFormat::Turtle, Reification::Statements);
+
+        assert!(output.starts_with("@prefix bv: <urn:boswell:vocab:> .\n"));
+        assert!(output.contains("\nbc:01A a bv:Claim ;\n    a rdf:Statement ;\n    rdf:subject be:Alice%20Smith ;"));
+        assert!(output.contains("    bv:confidenceLower \"0.8\"^^xsd:double ;"));
+        assert!(output.contains("\nbr:01B-derived_from-01A a rdf:Statement ;"));
+        assert!(!output.contains("<<"));
+    }
+
+    #[test]
+    fn test_json_ld_nodes() {
+        let output = render(RdfFormat::JsonLd, Reification::Statements);
+        let document: Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(document["@context"]["bv"], VOCAB_IRI);
+        let graph = document["@graph"].as_array().unwrap();
+        let claim = graph.iter().find(|node| node["@id"] == "bc:01A").unwrap();
+        assert_eq!(claim["@type"], json!(["bv:Claim", "rdf:Statement"]));
+        assert_eq!(claim["bv:namespace"], "acme/eng");
+        assert_eq!(claim["rdf:object"], json!({ "@id": "be:Boswell" }));
+        assert_eq!(claim["bv:confidenceUpper"], json!({ "@value": "0.9", "@type": "xsd:double" }));
+
+        let alice = graph.iter().find(|node| node["@id"] == "be:Alice%20Smith").unwrap();
+        assert_eq!(alice["bp:works_on"], json!([{ "@id": "be:Boswell" }, { "@id": "be:Rust" }]));
+    }
+
+    #[test]
+    fn test_json_ld_rejects_star() {
+        let result = write_rdf(&export(), RdfFormat::JsonLd, Reification::Star, Vec::new());
+        assert!(matches!(result, Err(SdkError::FormatError(_))));
+    }
+
+    #[test]
+    fn test_format_date_time() {
+        assert_eq!(format_date_time(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_date_time(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_date_time(1_700_000_000), "2023-11-14T22:13:20Z");
+    }
+}
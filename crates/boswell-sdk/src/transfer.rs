@@ -0,0 +1,395 @@
+//! Portable JSON Lines export format.
+
+use crate::error::SdkError;
+use boswell_domain::{ClaimId, Tier};
+use boswell_grpc::conversions::{domain_tier_from_proto, tier_to_proto};
+use boswell_grpc::proto::{
+    ClaimRecord, ConfidenceInterval, ProvenanceEntry, RelationshipRecord, RelationshipType,
+};
+use serde::{Deserialize, Serialize};
+
+/// Value of the header's `format` field
+pub const EXPORT_FORMAT: &str = "boswell-export";
+
+/// Version of the export format written by this SDK
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// One line of an export file
+///
+/// An export is a UTF-8 text file with one JSON object per line. Every object
+/// has a `type` field:
+///
+/// 1. `header` (first line): `format` (always `"boswell-export"`), format
+///    `version`, the exported `namespace` and `exported_at` (Unix milliseconds)
+/// 2. `claim` (one per claim): `id`, `namespace`, `subject`, `predicate`,
+///    `object`, `confidence` (`{"lower", "upper"}`), `tier`, `created_at`,
+///    `stale_at` (or `null`) and `provenance`, a list of `{"source",
+///    "source_type", "timestamp", "rationale"}` with `timestamp` in Unix
+///    milliseconds
+/// 3. `relationship` (after all claims): `from` and `to` claim IDs,
+///    `relationship_type` (`supports`, `contradicts`, `derived_from`,
+///    `references` or `supersedes`), `strength` and `created_at`
+///
+/// Only relationships between two exported claims are included. Readers
+/// must reject files whose `version` is newer than they support, and ignore
+/// fields they do not know.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportLine {
+    /// First line, describing the export
+    Header(ExportHeader),
+    /// A claim with its provenance
+    Claim(ClaimLine),
+    /// A relationship between two exported claims
+    Relationship(RelationshipLine),
+}
+
+/// Header line of an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportHeader {
+    /// Always [`EXPORT_FORMAT`]
+    pub format: String,
+    /// Format version
+    pub version: u32,
+    /// Namespace subtree that was exported
+    pub namespace: String,
+    /// When the export was taken (Unix milliseconds)
+    pub exported_at: u64,
+}
+
+/// Claim line of an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClaimLine {
+    /// Claim ID, kept on import unless the target already uses it
+    pub id: String,
+    /// Namespace
+    pub namespace: String,
+    /// Subject entity
+    pub subject: String,
+    /// Predicate
+    pub predicate: String,
+    /// Object entity
+    pub object: String,
+    /// Confidence interval
+    pub confidence: ConfidenceLine,
+    /// Lifecycle tier
+    pub tier: Tier,
+    /// When the claim was first asserted
+    pub created_at: u64,
+    /// When the claim becomes stale, if ever
+    pub stale_at: Option<u64>,
+    /// Provenance chain
+    #[serde(default)]
+    pub provenance: Vec<ProvenanceLine>,
+}
+
+/// Confidence interval of a claim line
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceLine {
+    /// Lower bound
+    pub lower: f64,
+    /// Upper bound
+    pub upper: f64,
+}
+
+/// Provenance entry of a claim line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvenanceLine {
+    /// Source identifier
+    pub source: String,
+    /// Source category
+    pub source_type: String,
+    /// When the entry was recorded (Unix milliseconds)
+    pub timestamp: u64,
+    /// Optional rationale
+    #[serde(default)]
+    pub rationale: Option<String>,
+}
+
+/// Relationship line of an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipLine {
+    /// Source claim ID
+    pub from: String,
+    /// Target claim ID
+    pub to: String,
+    /// Relationship type
+    pub relationship_type: String,
+    /// Strength in [0, 1]
+    pub strength: f64,
+    /// When the relationship was established
+    pub created_at: u64,
+}
+
+/// Options for [`BoswellClient::import`](crate::BoswellClient::import)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Validate each claim with the instance's gatekeeper before storing it
+    pub use_gatekeeper: bool,
+    /// Claims sent per request
+    pub batch_size: usize,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            use_gatekeeper: false,
+            batch_size: 500,
+        }
+    }
+}
+
+/// Counts from [`BoswellClient::export`](crate::BoswellClient::export)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportSummary {
+    /// Claims written
+    pub claims: usize,
+    /// Relationships written
+    pub relationships: usize,
+}
+
+/// Outcome of [`BoswellClient::import`](crate::BoswellClient::import)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportReport {
+    /// Claims stored under their exported ID
+    pub inserted: usize,
+    /// Claims already present with the same ID and content
+    pub existing: usize,
+    /// Claims that duplicated an existing claim under another ID
+    pub corroborated: usize,
+    /// Claims given a new ID because theirs was taken
+    pub remapped: usize,
+    /// Claims refused as invalid or by the gatekeeper
+    pub rejected: usize,
+    /// Relationships stored
+    pub relationships: usize,
+    /// Exported ID and stored ID of every corroborated or remapped claim
+    pub mappings: Vec<(ClaimId, ClaimId)>,
+    /// Per-item failures
+    pub errors: Vec<String>,
+}
+
+impl ImportReport {
+    /// Fold one Import response into the report
+    pub(crate) fn add(&mut self, response: boswell_grpc::proto::ImportResponse) {
+        self.inserted += response.inserted_count as usize;
+        self.existing += response.existing_count as usize;
+        self.corroborated += response.corroborated_count as usize;
+        self.remapped += response.remapped_count as usize;
+        self.rejected += response.rejected_count as usize;
+        self.relationships += response.relationship_count as usize;
+        self.mappings.extend(response.mappings.into_iter().filter_map(|mapping| {
+            Some((
+                ClaimId::from_string(&mapping.original_id).ok()?,
+                ClaimId::from_string(&mapping.claim_id).ok()?,
+            ))
+        }));
+        self.errors.extend(response.errors);
+    }
+}
+
+impl ExportLine {
+    /// Parse one line of an export
+    pub fn parse(line: &str) -> Result<Self, SdkError> {
+        serde_json::from_str(line).map_err(|e| SdkError::FormatError(e.to_string()))
+    }
+
+    /// Serialize as one line, without the trailing newline
+    pub fn to_line(&self) -> String {
+        serde_json::to_string(self).expect("export lines always serialize")
+    }
+}
+
+impl ExportHeader {
+    /// Header for an export of `namespace` taken now
+    pub fn new(namespace: &str) -> Self {
+        let exported_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+
+        Self {
+            format: EXPORT_FORMAT.to_string(),
+            version: EXPORT_FORMAT_VERSION,
+            namespace: namespace.to_string(),
+            exported_at,
+        }
+    }
+
+    /// Check that this SDK can read the export
+    pub fn check(&self) -> Result<(), SdkError> {
+        if self.format != EXPORT_FORMAT {
+            return Err(SdkError::FormatError(format!("Not a Boswell export (format '{}')", self.format)));
+        }
+        if self.version > EXPORT_FORMAT_VERSION {
+            return Err(SdkError::FormatError(format!(
+                "Export format version {} is newer than supported version {}",
+                self.version, EXPORT_FORMAT_VERSION
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<ClaimRecord> for ClaimLine {
+    type Error = SdkError;
+
+    fn try_from(record: ClaimRecord) -> Result<Self, Self::Error> {
+        let claim = record.claim.unwrap_or_default();
+        let confidence = claim.confidence.unwrap_or_default();
+        let tier = domain_tier_from_proto(claim.tier)
+            .map_err(|e| SdkError::GrpcError(format!("Failed to convert claim {}: {}", claim.id, e)))?;
+
+        Ok(Self {
+            id: claim.id,
+            namespace: claim.namespace,
+            subject: claim.subject,
+            predicate: claim.predicate,
+            object: claim.object,
+            confidence: ConfidenceLine {
+                lower: confidence.lower,
+                upper: confidence.upper,
+            },
+            tier,
+            created_at: record.created_at,
+            stale_at: record.stale_at,
+            provenance: record
+                .provenance
+                .into_iter()
+                .map(|entry| ProvenanceLine {
+                    source: entry.source,
+                    source_type: entry.source_type,
+                    timestamp: entry.timestamp.max(0) as u64,
+                    rationale: Some(entry.metadata).filter(|metadata| !metadata.is_empty()),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl From<ClaimLine> for ClaimRecord {
+    fn from(line: ClaimLine) -> Self {
+        let tier = tier_to_proto(line.tier) as i32;
+
+        Self {
+            claim: Some(boswell_grpc::proto::Claim {
+                id: line.id,
+                namespace: line.namespace,
+                subject: line.subject,
+                predicate: line.predicate,
+                object: line.object,
+                confidence: Some(ConfidenceInterval {
+                    lower: line.confidence.lower,
+                    upper: line.confidence.upper,
+                }),
+                tier,
+            }),
+            created_at: line.created_at,
+            stale_at: line.stale_at,
+            provenance: line
+                .provenance
+                .into_iter()
+                .map(|entry| ProvenanceEntry {
+                    source: entry.source,
+                    timestamp: entry.timestamp as i64,
+                    metadata: entry.rationale.unwrap_or_default(),
+                    source_type: entry.source_type,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<RelationshipRecord> for RelationshipLine {
+    fn from(record: RelationshipRecord) -> Self {
+        let relationship_type = match RelationshipType::try_from(record.relationship_type) {
+            Ok(RelationshipType::Supports) => "supports",
+            Ok(RelationshipType::Contradicts) => "contradicts",
+            Ok(RelationshipType::DerivedFrom) => "derived_from",
+            Ok(RelationshipType::References) => "references",
+            Ok(RelationshipType::Supersedes) => "supersedes",
+            _ => "unspecified",
+        };
+
+        Self {
+            from: record.from_claim_id,
+            to: record.to_claim_id,
+            relationship_type: relationship_type.to_string(),
+            strength: record.strength,
+            created_at: record.created_at,
+        }
+    }
+}
+
+impl From<RelationshipLine> for RelationshipRecord {
+    fn from(line: RelationshipLine) -> Self {
+        // Unknown types are sent as unspecified and rejected by the instance
+        let relationship_type = match line.relationship_type.as_str() {
+            "supports" => RelationshipType::Supports,
+            "contradicts" => RelationshipType::Contradicts,
+            "derived_from" => RelationshipType::DerivedFrom,
+            "references" => RelationshipType::References,
+            "supersedes" => RelationshipType::Supersedes,
+            _ => RelationshipType::Unspecified,
+        };
+
+        Self {
+            from_claim_id: line.from,
+            to_claim_id: line.to,
+            relationship_type: relationship_type as i32,
+            strength: line.strength,
+            created_at: line.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_line_round_trip() {
+        let line = r#"{"type":"claim","id":"0190a4b2-0000-7000-8000-000000000001","namespace":"acme/eng","subject":"Alice","predicate":"knows","object":"Bob","confidence":{"lower":0.8,"upper":0.9},"tier":"project","created_at":1000,"stale_at":null,"provenance":[{"source":"user:alice","source_type":"user","timestamp":500,"rationale":null}]}"#;
+
+        let ExportLine::Claim(claim) = ExportLine::parse(line).unwrap() else {
+            panic!("expected a claim line");
+        };
+        assert_eq!(claim.provenance[0].source_type, "user");
+        assert_eq!(ClaimLine::try_from(ClaimRecord::from(claim.clone())).unwrap(), claim);
+
+        let parsed = ExportLine::Claim(claim);
+        assert_eq!(ExportLine::parse(&parsed.to_line()).unwrap(), parsed);
+    }
+
+    #[test]
+    fn test_claim_record_without_tier_is_rejected() {
+        let record = ClaimRecord {
+            claim: Some(boswell_grpc::proto::Claim { id: "a".to_string(), ..Default::default() }),
+            ..Default::default()
+        };
+        assert!(matches!(ClaimLine::try_from(record), Err(SdkError::GrpcError(_))));
+    }
+
+    #[test]
+    fn test_relationship_line_round_trip() {
+        let line = RelationshipLine {
+            from: "a".to_string(),
+            to: "b".to_string(),
+            relationship_type: "derived_from".to_string(),
+            strength: 0.5,
+            created_at: 7,
+        };
+        assert_eq!(RelationshipLine::from(RelationshipRecord::from(line.clone())), line);
+    }
+
+    #[test]
+    fn test_header_check() {
+        let header = ExportHeader::new("acme");
+        assert!(header.check().is_ok());
+
+        let newer = ExportHeader { version: EXPORT_FORMAT_VERSION + 1, ..header.clone() };
+        assert!(matches!(newer.check(), Err(SdkError::FormatError(_))));
+
+        let other = ExportHeader { format: "csv".to_string(), ..header };
+        assert!(matches!(other.check(), Err(SdkError::FormatError(_))));
+    }
+}
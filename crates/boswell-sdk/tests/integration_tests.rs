@@ -4,7 +4,7 @@
 //! For Phase 2, we test the core SDK behavior with unit tests.
 //! Full integration testing is implemented in Phase 3 with async SDK.
 
-use boswell_sdk::{BoswellClient, SdkError};
+use boswell_sdk::{BoswellClient, ImportOptions, SdkError};
 
 #[tokio::test]
 async fn test_sdk_not_connected_error() {
@@ -30,3 +30,43 @@ fn test_sdk_client_creation() {
 }
 
 
+
+#[tokio::test]
+async fn test_sdk_rejects_invalid_confidence_before_connecting() {
+    let mut client = BoswellClient::new("http://localhost:8080");
+    
+    for confidence in [f64::NAN, f64::INFINITY, 1.5] {
+        let result = client.assert("test", "user:alice", "rel:knows", "user:bob", Some(confidence), None).await;
+        assert!(matches!(result, Err(SdkError::InvalidArgument(_))), "{:?}", result);
+    }
+    
+    let claim = boswell_domain::Claim::new(
+        boswell_domain::ClaimId::new(),
+        "test".to_string(),
+        "user:alice".to_string(),
+        "rel:knows".to_string(),
+        "user:bob".to_string(),
+        (0.2, f64::NAN),
+        boswell_domain::Tier::Task,
+        0,
+    );
+    let result = client.learn(vec![claim]).await;
+    assert!(matches!(result, Err(SdkError::InvalidArgument(_))));
+}
+
+#[tokio::test]
+async fn test_import_rejects_malformed_export_before_connecting() {
+    let mut client = BoswellClient::new("http://localhost:8080");
+    
+    let missing_header = r#"{"type":"relationship","from":"a","to":"b","relationship_type":"supports","strength":0.5,"created_at":0}"#;
+    let result = client.import(missing_header.as_bytes(), ImportOptions::default()).await;
+    assert!(matches!(result, Err(SdkError::FormatError(_))));
+    
+    let result = client.import("".as_bytes(), ImportOptions::default()).await;
+    assert!(matches!(result, Err(SdkError::FormatError(_))));
+    
+    // A valid file only fails once it needs the connection
+    let header = r#"{"type":"header","format":"boswell-export","version":1,"namespace":"acme","exported_at":0}"#;
+    let result = client.import(header.as_bytes(), ImportOptions::default()).await;
+    assert!(result.is_ok(), "an empty export needs no requests");
+}
@@ -0,0 +1,374 @@
+//! Online backup and restore for SQLite stores
+//!
+//! A backup is a directory holding three files:
+//!
+//! - `claims.db`: a copy of the database made with SQLite's online backup
+//!   API, so the source stays open for reads (and, for a pooled store,
+//!   concurrent readers) while it runs
+//! - `vectors.bin`: the live entries of the HNSW index at the same point,
+//!   present only when the store has vector search enabled
+//! - `manifest.json`: a [`BackupManifest`] describing both
+//!
+//! Writes to the store wait while a backup runs, so the database copy and
+//! the vector snapshot always describe the same set of claims.
+//!
+//! Restoring checks the manifest against the restoring store before touching
+//! the target: the backup must come from a schema this build can migrate,
+//! and its embeddings from the same model and dimension. A backup without a
+//! vector snapshot is restored by re-embedding every claim.
+
+use boswell_domain::ClaimId;
+use rusqlite::backup::Backup;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::embedding::EmbeddingModel;
+use crate::vector_index::VectorIndex;
+use crate::{migrations, StoreError, SCHEMA_VERSION};
+
+/// Version of the backup directory layout written by this build
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Database copy inside a backup directory
+const DATABASE_FILE: &str = "claims.db";
+
+/// HNSW snapshot inside a backup directory
+const VECTORS_FILE: &str = "vectors.bin";
+
+/// Manifest inside a backup directory
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Identifies a vector snapshot file
+const VECTORS_MAGIC: &[u8; 8] = b"BSWLVEC\0";
+
+/// Pages copied per step of the SQLite backup
+const PAGES_PER_STEP: i32 = 1024;
+
+/// Claim IDs and embeddings held in a vector snapshot
+pub(crate) type VectorEntries = Vec<(ClaimId, Vec<f32>)>;
+
+/// Description of a backup, stored as `manifest.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Layout version of the backup directory
+    pub format_version: u32,
+    
+    /// Schema version of the backed-up database
+    pub schema_version: u32,
+    
+    /// When the backup was taken (Unix milliseconds)
+    pub created_at: u64,
+    
+    /// Number of claims in the backup
+    pub claim_count: u64,
+    
+    /// The vector snapshot, if the store had vector search enabled
+    pub embeddings: Option<EmbeddingSnapshot>,
+}
+
+/// Description of the vector snapshot in a backup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingSnapshot {
+    /// Name of the model that produced the embeddings
+    pub model: String,
+    
+    /// Embedding dimension
+    pub dimension: usize,
+    
+    /// Number of embeddings in `vectors.bin`
+    pub count: u64,
+}
+
+/// Read and check the manifest of the backup in `dir`
+///
+/// # Errors
+///
+/// Returns `InvalidBackup` if the manifest is missing, malformed or from a
+/// newer backup format.
+pub fn read_manifest<P: AsRef<Path>>(dir: P) -> Result<BackupManifest, StoreError> {
+    let path = dir.as_ref().join(MANIFEST_FILE);
+    let file = File::open(&path)
+        .map_err(|e| StoreError::InvalidBackup(format!("Cannot read {}: {}", path.display(), e)))?;
+    let manifest: BackupManifest = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| StoreError::InvalidBackup(format!("Malformed manifest: {}", e)))?;
+    
+    if manifest.format_version > BACKUP_FORMAT_VERSION {
+        return Err(StoreError::InvalidBackup(format!(
+            "Backup format version {} is newer than supported version {}",
+            manifest.format_version, BACKUP_FORMAT_VERSION
+        )));
+    }
+    
+    Ok(manifest)
+}
+
+/// Write a backup of `conn` and `vector_index` to the directory `dir`
+///
+/// The caller must keep writes out until this returns.
+pub(crate) fn write_backup(
+    conn: &Connection,
+    vector_index: Option<&VectorIndex>,
+    embedding_model: Option<&(dyn EmbeddingModel + Send + Sync)>,
+    dir: &Path,
+) -> Result<BackupManifest, StoreError> {
+    fs::create_dir_all(dir)?;
+    if fs::read_dir(dir)?.next().is_some() {
+        return Err(StoreError::InvalidData(format!("Backup directory {} is not empty", dir.display())));
+    }
+    
+    let mut copy = Connection::open(dir.join(DATABASE_FILE))?;
+    Backup::new(conn, &mut copy)?.run_to_completion(PAGES_PER_STEP, Duration::ZERO, None)?;
+    
+    // Read from the copy, which cannot change underneath us
+    let schema_version = migrations::current_version(&copy)?;
+    let claim_count: u64 = copy.query_row("SELECT COUNT(*) FROM claims", [], |row| row.get(0))?;
+    drop(copy);
+    
+    let embeddings = match (vector_index, embedding_model) {
+        (Some(vector_index), Some(embedding_model)) => {
+            let entries = vector_index.entries();
+            write_vectors(&dir.join(VECTORS_FILE), vector_index.dimension(), &entries)?;
+            Some(EmbeddingSnapshot {
+                model: embedding_model.name().to_string(),
+                dimension: vector_index.dimension(),
+                count: entries.len() as u64,
+            })
+        }
+        _ => None,
+    };
+    
+    let manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        schema_version,
+        created_at: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis() as u64),
+        claim_count,
+        embeddings,
+    };
+    
+    let file = File::create(dir.join(MANIFEST_FILE))?;
+    serde_json::to_writer_pretty(BufWriter::new(file), &manifest)
+        .map_err(|e| StoreError::InvalidData(format!("Cannot write manifest: {}", e)))?;
+    
+    Ok(manifest)
+}
+
+/// Copy the database in `backup_dir` to `target`, after checking it can be restored
+///
+/// `embedding_model` is the model of the store that will open `target`;
+/// a vector snapshot from any other model is refused. `target` must not
+/// exist yet. Returns the manifest and the snapshot's entries, if any.
+pub(crate) fn restore_database(
+    backup_dir: &Path,
+    target: &Path,
+    embedding_model: Option<&(dyn EmbeddingModel + Send + Sync)>,
+) -> Result<(BackupManifest, Option<VectorEntries>), StoreError> {
+    let manifest = read_manifest(backup_dir)?;
+    if manifest.schema_version > SCHEMA_VERSION {
+        return Err(StoreError::SchemaTooNew { found: manifest.schema_version, supported: SCHEMA_VERSION });
+    }
+    
+    let vectors = match (&manifest.embeddings, embedding_model) {
+        (Some(snapshot), Some(model)) => {
+            if snapshot.model != model.name() || snapshot.dimension != model.dimension() {
+                return Err(StoreError::InvalidBackup(format!(
+                    "Backup embeddings come from {} ({} dimensions), store uses {} ({} dimensions)",
+                    snapshot.model, snapshot.dimension, model.name(), model.dimension()
+                )));
+            }
+            
+            let entries = read_vectors(&backup_dir.join(VECTORS_FILE), snapshot.dimension)?;
+            if entries.len() as u64 != snapshot.count {
+                return Err(StoreError::InvalidBackup(format!(
+                    "Vector snapshot holds {} embeddings, manifest records {}", entries.len(), snapshot.count
+                )));
+            }
+            Some(entries)
+        }
+        _ => None,
+    };
+    
+    let source = Connection::open_with_flags(
+        backup_dir.join(DATABASE_FILE),
+        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY,
+    )?;
+    let found = migrations::current_version(&source)?;
+    if found != manifest.schema_version {
+        return Err(StoreError::InvalidBackup(format!(
+            "Database is at schema version {}, manifest records {}", found, manifest.schema_version
+        )));
+    }
+    
+    if target.exists() {
+        return Err(StoreError::InvalidData(format!("Restore target {} already exists", target.display())));
+    }
+    let mut restored = Connection::open(target)?;
+    Backup::new(&source, &mut restored)?.run_to_completion(PAGES_PER_STEP, Duration::ZERO, None)?;
+    
+    Ok((manifest, vectors))
+}
+
+/// Fill `vector_index` for a restored database
+///
+/// Loads the snapshot when the backup had one, and otherwise embeds every
+/// claim again with `embedding_model`.
+pub(crate) fn load_vectors(
+    conn: &Connection,
+    vector_index: &VectorIndex,
+    embedding_model: &(dyn EmbeddingModel + Send + Sync),
+    snapshot: Option<VectorEntries>,
+) -> Result<(), StoreError> {
+    let entries = match snapshot {
+        Some(entries) => entries,
+        None => {
+            let mut stmt = conn.prepare("SELECT id, subject, predicate, object FROM claims")?;
+            let claims = stmt.query_map([], |row| {
+                let id: Vec<u8> = row.get(0)?;
+                let text = format!("{} {} {}", row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, String>(3)?);
+                Ok((id, text))
+            })?.collect::<Result<Vec<_>, _>>()?;
+            
+            let mut entries = Vec::with_capacity(claims.len());
+            for (id, text) in claims {
+                let embedding = embedding_model.embed(&text)
+                    .map_err(|e| StoreError::InvalidData(format!("Failed to re-embed claim: {}", e)))?;
+                entries.push((crate::SqliteStore::bytes_to_claim_id(&id)?, embedding));
+            }
+            entries
+        }
+    };
+    
+    for (claim_id, embedding) in entries {
+        vector_index.add(claim_id, &embedding)
+            .map_err(|e| StoreError::InvalidBackup(format!("Cannot load embedding: {}", e)))?;
+    }
+    
+    Ok(())
+}
+
+/// Write `entries` as a vector snapshot
+///
+/// Layout: magic, dimension (u32), count (u64), then per entry the claim ID
+/// (16 bytes, big-endian) and `dimension` little-endian `f32`s.
+fn write_vectors(path: &Path, dimension: usize, entries: &[(ClaimId, Vec<f32>)]) -> Result<(), StoreError> {
+    let mut out = BufWriter::new(File::create(path)?);
+    out.write_all(VECTORS_MAGIC)?;
+    out.write_all(&(dimension as u32).to_le_bytes())?;
+    out.write_all(&(entries.len() as u64).to_le_bytes())?;
+    
+    for (claim_id, embedding) in entries {
+        out.write_all(&claim_id.value().to_be_bytes())?;
+        for value in embedding {
+            out.write_all(&value.to_le_bytes())?;
+        }
+    }
+    
+    out.flush()?;
+    Ok(())
+}
+
+/// Read a vector snapshot written by [`write_vectors`]
+fn read_vectors(path: &Path, dimension: usize) -> Result<VectorEntries, StoreError> {
+    let truncated = |e: std::io::Error| StoreError::InvalidBackup(format!("Truncated vector snapshot: {}", e));
+    let mut input = BufReader::new(File::open(path)?);
+    
+    let mut magic = [0u8; 8];
+    input.read_exact(&mut magic).map_err(truncated)?;
+    if &magic != VECTORS_MAGIC {
+        return Err(StoreError::InvalidBackup(format!("{} is not a vector snapshot", path.display())));
+    }
+    
+    let mut u32_bytes = [0u8; 4];
+    let mut u64_bytes = [0u8; 8];
+    input.read_exact(&mut u32_bytes).map_err(truncated)?;
+    let stored_dimension = u32::from_le_bytes(u32_bytes) as usize;
+    if stored_dimension != dimension {
+        return Err(StoreError::InvalidBackup(format!(
+            "Vector snapshot has dimension {}, manifest records {}", stored_dimension, dimension
+        )));
+    }
+    input.read_exact(&mut u64_bytes).map_err(truncated)?;
+    let count = u64::from_le_bytes(u64_bytes);
+    
+    let mut entries = Vec::new();
+    let mut id_bytes = [0u8; 16];
+    for _ in 0..count {
+        input.read_exact(&mut id_bytes).map_err(truncated)?;
+        let mut embedding = Vec::with_capacity(dimension);
+        for _ in 0..dimension {
+            input.read_exact(&mut u32_bytes).map_err(truncated)?;
+            embedding.push(f32::from_le_bytes(u32_bytes));
+        }
+        entries.push((ClaimId::from_value(u128::from_be_bytes(id_bytes)), embedding));
+    }
+    
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    
+    #[test]
+    fn test_vector_snapshot_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(VECTORS_FILE);
+        let entries = vec![
+            (ClaimId::new(), vec![0.25, -1.0, 3.5]),
+            (ClaimId::new(), vec![0.0, 0.0, f32::MIN_POSITIVE]),
+        ];
+        
+        write_vectors(&path, 3, &entries).unwrap();
+        assert_eq!(read_vectors(&path, 3).unwrap(), entries);
+        assert!(matches!(read_vectors(&path, 4), Err(StoreError::InvalidBackup(_))));
+    }
+    
+    #[test]
+    fn test_truncated_vector_snapshot_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(VECTORS_FILE);
+        write_vectors(&path, 2, &[(ClaimId::new(), vec![1.0, 2.0])]).unwrap();
+        
+        let bytes = fs::read(&path).unwrap();
+        fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        assert!(matches!(read_vectors(&path, 2), Err(StoreError::InvalidBackup(_))));
+    }
+    
+    #[test]
+    fn test_newer_manifest_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let manifest = BackupManifest {
+            format_version: BACKUP_FORMAT_VERSION + 1,
+            schema_version: SCHEMA_VERSION,
+            created_at: 0,
+            claim_count: 0,
+            embeddings: None,
+        };
+        fs::write(dir.path().join(MANIFEST_FILE), serde_json::to_string(&manifest).unwrap()).unwrap();
+        
+        assert!(matches!(read_manifest(dir.path()), Err(StoreError::InvalidBackup(_))));
+    }
+    
+    #[test]
+    fn test_backup_source_is_opened_without_migrating() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("boswell.db");
+        crate::SqliteStore::new(&path, false, 0).unwrap();
+        
+        let store = crate::SqliteStore::open_existing(&path, true, 8).unwrap();
+        let manifest = store.backup(dir.path().join("backup")).unwrap();
+        assert_eq!(manifest.embeddings.map(|snapshot| snapshot.dimension), Some(8));
+        drop(store);
+        
+        // An older schema is refused rather than migrated
+        Connection::open(&path).unwrap()
+            .execute("DELETE FROM schema_info WHERE version = ?1", [SCHEMA_VERSION]).unwrap();
+        assert!(matches!(crate::SqliteStore::open_existing(&path, false, 0), Err(StoreError::InvalidData(_))));
+        let conn = Connection::open(&path).unwrap();
+        assert_eq!(migrations::current_version(&conn).unwrap(), SCHEMA_VERSION - 1);
+    }
+}
@@ -0,0 +1,239 @@
+//! Async adapter for synchronous claim stores
+//!
+//! SQLite calls block the calling thread. [`BlockingStore`] moves each call
+//! onto tokio's blocking thread pool so async callers (the gRPC service in
+//! particular) never park a runtime worker on disk I/O or a held lock.
+
+use boswell_domain::traits::{AssertOutcome, AsyncClaimStore, BatchOutcome, ClaimBatch, ClaimQuery, ClaimStore};
+use boswell_domain::{Claim, ClaimId, ConfidenceInterval, EntityAlias, NamespaceStats, ProvenanceEntry, Relationship, StoreEvent};
+use std::collections::HashMap;
+use std::future::Future;
+
+/// Runs a cloneable [`ClaimStore`] on the blocking thread pool
+///
+/// Every call works on its own clone of the store, so `S` should be a shared
+/// handle: a [`PooledStore`](crate::PooledStore) for concurrent reads, or an
+/// `Arc<Mutex<S>>` for a single-connection store. Must be awaited inside a
+/// tokio runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() -> Result<(), boswell_store::StoreError> {
+/// use boswell_domain::traits::{AsyncClaimStore, ClaimQuery};
+/// use boswell_store::{BlockingStore, PooledStore};
+///
+/// let store = BlockingStore::new(PooledStore::new("boswell.db", false, 0)?);
+/// let claims = store.query_claims(&ClaimQuery::default()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BlockingStore<S> {
+    store: S,
+}
+
+impl<S> BlockingStore<S>
+where
+    S: ClaimStore + Clone + Send + 'static,
+    S::Error: Send + 'static,
+{
+    /// Wrap a synchronous store
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    /// The wrapped store
+    pub fn inner(&self) -> &S {
+        &self.store
+    }
+
+    /// Run `f` with a clone of the store on the blocking pool
+    ///
+    /// A panic inside the store is resumed in the awaiting task, as it would
+    /// have been had the call run inline.
+    fn run<T, F>(&self, f: F) -> impl Future<Output = T> + Send
+    where
+        T: Send + 'static,
+        F: FnOnce(S) -> T + Send + 'static,
+    {
+        let store = self.store.clone();
+        async move {
+            match tokio::task::spawn_blocking(move || f(store)).await {
+                Ok(value) => value,
+                Err(e) => match e.try_into_panic() {
+                    Ok(panic) => std::panic::resume_unwind(panic),
+                    Err(e) => panic!("Blocking store call was cancelled: {}", e),
+                },
+            }
+        }
+    }
+}
+
+impl<S> AsyncClaimStore for BlockingStore<S>
+where
+    S: ClaimStore + Clone + Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Error = S::Error;
+
+    fn assert_claim(&self, claim: Claim) -> impl Future<Output = Result<AssertOutcome, Self::Error>> + Send {
+        self.run(move |mut store| store.assert_claim(claim))
+    }
+
+    fn assert_claim_with_provenance(
+        &self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> impl Future<Output = Result<AssertOutcome, Self::Error>> + Send {
+        self.run(move |mut store| store.assert_claim_with_provenance(claim, provenance))
+    }
+
+    fn assert_batch(
+        &self,
+        batch: ClaimBatch,
+    ) -> impl Future<Output = Result<BatchOutcome<Self::Error>, Self::Error>> + Send {
+        self.run(move |mut store| store.assert_batch(batch))
+    }
+
+    fn get_claim(&self, id: ClaimId) -> impl Future<Output = Result<Option<Claim>, Self::Error>> + Send {
+        self.run(move |store| store.get_claim(id))
+    }
+
+    fn get_provenance(&self, id: ClaimId) -> impl Future<Output = Result<Vec<ProvenanceEntry>, Self::Error>> + Send {
+        self.run(move |store| store.get_provenance(id))
+    }
+
+    fn get_provenance_batch(
+        &self,
+        ids: &[ClaimId],
+    ) -> impl Future<Output = Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error>> + Send {
+        let ids = ids.to_vec();
+        self.run(move |store| store.get_provenance_batch(&ids))
+    }
+
+    fn find_by_content_hash(&self, content_hash: &str) -> impl Future<Output = Result<Option<Claim>, Self::Error>> + Send {
+        let content_hash = content_hash.to_string();
+        self.run(move |store| store.find_by_content_hash(&content_hash))
+    }
+
+    fn query_claims(&self, query: &ClaimQuery) -> impl Future<Output = Result<Vec<Claim>, Self::Error>> + Send {
+        let query = query.clone();
+        self.run(move |store| store.query_claims(&query))
+    }
+
+    fn add_relationship(&self, relationship: Relationship) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.run(move |mut store| store.add_relationship(relationship))
+    }
+
+    fn get_relationships(&self, id: ClaimId) -> impl Future<Output = Result<Vec<Relationship>, Self::Error>> + Send {
+        self.run(move |store| store.get_relationships(id))
+    }
+
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> impl Future<Output = Result<Vec<Relationship>, Self::Error>> + Send {
+        let ids = ids.to_vec();
+        self.run(move |store| store.get_relationships_batch(&ids))
+    }
+
+    fn compact_indexes(&self, threshold: f64) -> impl Future<Output = Result<usize, Self::Error>> + Send {
+        self.run(move |mut store| store.compact_indexes(threshold))
+    }
+
+    fn events_since(&self, after: u64, limit: usize) -> impl Future<Output = Result<Vec<StoreEvent>, Self::Error>> + Send {
+        self.run(move |store| store.events_since(after, limit))
+    }
+
+    fn namespace_stats(&self, root: &str) -> impl Future<Output = Result<Vec<NamespaceStats>, Self::Error>> + Send {
+        let root = root.to_string();
+        self.run(move |store| store.namespace_stats(&root))
+    }
+
+    fn move_namespace(&self, from: &str, to: &str) -> impl Future<Output = Result<usize, Self::Error>> + Send {
+        let (from, to) = (from.to_string(), to.to_string());
+        self.run(move |mut store| store.move_namespace(&from, &to))
+    }
+
+    fn add_alias(&self, alias: EntityAlias) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.run(move |mut store| store.add_alias(alias))
+    }
+
+    fn retract_alias(&self, alias: &str) -> impl Future<Output = Result<bool, Self::Error>> + Send {
+        let alias = alias.to_string();
+        self.run(move |mut store| store.retract_alias(&alias))
+    }
+
+    fn entity_aliases(&self, include_retracted: bool) -> impl Future<Output = Result<Vec<EntityAlias>, Self::Error>> + Send {
+        self.run(move |store| store.entity_aliases(include_retracted))
+    }
+
+    fn effective_confidence(
+        &self,
+        id: ClaimId,
+        now: u64,
+    ) -> impl Future<Output = Result<Option<ConfidenceInterval>, Self::Error>> + Send {
+        self.run(move |store| store.effective_confidence(id, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PooledStore, SqliteStore};
+    use boswell_domain::Tier;
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn test_claim() -> Claim {
+        Claim {
+            id: ClaimId::new(),
+            namespace: "test".to_string(),
+            subject: "Alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.9),
+            tier: Tier::Task,
+            created_at: 1000,
+            stale_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_through_pooled_store() {
+        let dir = TempDir::new().unwrap();
+        let store = BlockingStore::new(PooledStore::new(dir.path().join("boswell.db"), false, 0).unwrap());
+
+        let claim = test_claim();
+        let outcome = store.assert_claim(claim.clone()).await.unwrap();
+        assert_eq!(outcome, AssertOutcome::Inserted(claim.id));
+
+        assert!(store.get_claim(claim.id).await.unwrap().is_some());
+        assert!(store.find_by_content_hash(&claim.content_hash()).await.unwrap().is_some());
+        assert_eq!(store.query_claims(&ClaimQuery::default()).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_blocked_store_does_not_stall_runtime() {
+        let sqlite = Arc::new(Mutex::new(SqliteStore::new(":memory:", false, 0).unwrap()));
+        let store = BlockingStore::new(Arc::clone(&sqlite));
+
+        // Another thread holds the store, as a slow write would
+        let (locked_tx, locked_rx) = mpsc::channel();
+        let holder = std::thread::spawn(move || {
+            let _guard = sqlite.lock().unwrap();
+            locked_tx.send(()).unwrap();
+            std::thread::sleep(Duration::from_millis(200));
+        });
+        locked_rx.recv().unwrap();
+
+        let reader = store.clone();
+        let read = tokio::spawn(async move { reader.query_claims(&ClaimQuery::default()).await });
+
+        // The only runtime thread is free to run timers while the read waits
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!read.is_finished());
+
+        assert!(read.await.unwrap().unwrap().is_empty());
+        holder.join().unwrap();
+    }
+}
@@ -0,0 +1,385 @@
+//! Storage backend selection
+//!
+//! A [`StoreConfig`] names the backend and its connection settings, and is
+//! meant to be embedded in a service's configuration file, as the `boswell-grpc`
+//! instance binary does:
+//!
+//! ```toml
+//! [store]
+//! backend = "postgres"
+//! url = "postgres://boswell@localhost/boswell"
+//! max_connections = 16
+//! ```
+//!
+//! [`StoreConfig::open`] returns a [`ConfiguredStore`], which implements
+//! [`ClaimStore`] whichever backend was chosen.
+
+use boswell_domain::traits::{AssertOutcome, BatchOutcome, ClaimBatch, ClaimQuery, ClaimStore};
+use boswell_domain::{Claim, ClaimId, ConfidenceInterval, EntityAlias, NamespaceStats, ProvenanceEntry, Relationship, StalenessPolicy, StoreEvent, Tier};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[cfg(feature = "postgres")]
+use crate::PostgresStore;
+use crate::vector_index::{DEFAULT_EF_CONSTRUCTION, DEFAULT_M, DEFAULT_MAX_ELEMENTS};
+use crate::{PoolConfig, PooledStore, StoreError, VectorIndexConfig};
+
+/// Default size of the PostgreSQL connection pool
+pub const DEFAULT_MAX_CONNECTIONS: usize = 8;
+
+/// Which storage backend to use, and how to reach it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "backend", rename_all = "lowercase")]
+pub enum StoreConfig {
+    /// SQLite database file, served by a [`PooledStore`]
+    Sqlite {
+        /// Path to the database file
+        path: PathBuf,
+        
+        /// Enables semantic duplicate detection with embeddings of this dimension
+        #[serde(default)]
+        embedding_dimension: Option<usize>,
+        
+        /// Maximum number of read connections
+        #[serde(default = "default_max_readers")]
+        max_readers: usize,
+        
+        /// HNSW connections per node (`embedding_dimension` only)
+        #[serde(default = "default_hnsw_m")]
+        hnsw_m: usize,
+        
+        /// HNSW candidate list size while building (`embedding_dimension` only)
+        #[serde(default = "default_hnsw_ef_construction")]
+        hnsw_ef_construction: usize,
+        
+        /// Maximum number of vectors the HNSW index holds (`embedding_dimension` only)
+        #[serde(default = "default_hnsw_max_elements")]
+        hnsw_max_elements: usize,
+    },
+    
+    /// PostgreSQL database, served by a `PostgresStore`
+    ///
+    /// Requires the `postgres` feature; `embedding_dimension` also requires `pgvector`.
+    Postgres {
+        /// libpq-style connection string or `postgres://` URL
+        url: String,
+        
+        /// Enables semantic duplicate detection through pgvector with embeddings of this dimension
+        #[serde(default)]
+        embedding_dimension: Option<usize>,
+        
+        /// Maximum number of pooled connections
+        #[serde(default = "default_max_connections")]
+        max_connections: usize,
+    },
+}
+
+fn default_max_readers() -> usize {
+    PoolConfig::default().max_readers
+}
+
+fn default_hnsw_m() -> usize {
+    DEFAULT_M
+}
+
+fn default_hnsw_ef_construction() -> usize {
+    DEFAULT_EF_CONSTRUCTION
+}
+
+fn default_hnsw_max_elements() -> usize {
+    DEFAULT_MAX_ELEMENTS
+}
+
+fn default_max_connections() -> usize {
+    DEFAULT_MAX_CONNECTIONS
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self::Sqlite {
+            path: PathBuf::from("boswell.db"),
+            embedding_dimension: None,
+            max_readers: default_max_readers(),
+            hnsw_m: default_hnsw_m(),
+            hnsw_ef_construction: default_hnsw_ef_construction(),
+            hnsw_max_elements: default_hnsw_max_elements(),
+        }
+    }
+}
+
+impl StoreConfig {
+    /// Open the configured store, running any pending migrations
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` if the backend was not compiled in, or the
+    /// backend's error if the store cannot be opened.
+    pub fn open(&self) -> Result<ConfiguredStore, StoreError> {
+        match self {
+            StoreConfig::Sqlite { path, embedding_dimension, max_readers, hnsw_m, hnsw_ef_construction, hnsw_max_elements } => {
+                let config = PoolConfig {
+                    max_readers: *max_readers,
+                    vector_index: VectorIndexConfig {
+                        m: *hnsw_m,
+                        ef_construction: *hnsw_ef_construction,
+                        max_elements: *hnsw_max_elements,
+                    },
+                    ..PoolConfig::default()
+                };
+                let store = PooledStore::with_config(
+                    path,
+                    embedding_dimension.is_some(),
+                    embedding_dimension.unwrap_or(0),
+                    config,
+                )?;
+                Ok(ConfiguredStore::Sqlite(store))
+            }
+            #[cfg(feature = "postgres")]
+            StoreConfig::Postgres { url, embedding_dimension, max_connections } => {
+                let store = match embedding_dimension {
+                    None => PostgresStore::connect(url, *max_connections)?,
+                    #[cfg(feature = "pgvector")]
+                    Some(dimension) => PostgresStore::connect_with_vector_search(url, *max_connections, *dimension)?,
+                    #[cfg(not(feature = "pgvector"))]
+                    Some(_) => {
+                        return Err(StoreError::InvalidData(
+                            "Vector search on PostgreSQL requires the `pgvector` feature of boswell-store".to_string()
+                        ));
+                    }
+                };
+                Ok(ConfiguredStore::Postgres(store))
+            }
+            #[cfg(not(feature = "postgres"))]
+            StoreConfig::Postgres { .. } => Err(StoreError::InvalidData(
+                "The PostgreSQL backend requires the `postgres` feature of boswell-store".to_string()
+            )),
+        }
+    }
+}
+
+/// A store opened from a [`StoreConfig`]
+///
+/// Clones share the underlying pool, so one handle can be given to every
+/// request (for example through [`BlockingStore`](crate::BlockingStore)).
+#[derive(Clone)]
+pub enum ConfiguredStore {
+    /// SQLite backend
+    Sqlite(PooledStore),
+    
+    /// PostgreSQL backend
+    #[cfg(feature = "postgres")]
+    Postgres(PostgresStore),
+}
+
+/// Forward a call to whichever store is configured
+macro_rules! delegate {
+    ($self:expr, $store:ident => $call:expr) => {
+        match $self {
+            ConfiguredStore::Sqlite($store) => $call,
+            #[cfg(feature = "postgres")]
+            ConfiguredStore::Postgres($store) => $call,
+        }
+    };
+}
+
+impl ConfiguredStore {
+    /// Permanently delete a claim along with everything recorded about it
+    ///
+    /// Returns `true` if the claim existed.
+    pub fn delete_claim(&self, claim_id: ClaimId) -> Result<bool, StoreError> {
+        delegate!(self, store => store.delete_claim(claim_id))
+    }
+    
+    /// Move a claim to another tier, recording a promotion or demotion event
+    ///
+    /// Returns `true` if the tier changed.
+    pub fn set_tier(&self, claim_id: ClaimId, tier: Tier) -> Result<bool, StoreError> {
+        delegate!(self, store => store.set_tier(claim_id, tier))
+    }
+    
+    /// Set the policy deciding when written claims go stale
+    pub fn set_staleness_policy(&self, policy: StalenessPolicy) {
+        delegate!(self, store => store.set_staleness_policy(policy))
+    }
+}
+
+impl ClaimStore for ConfiguredStore {
+    type Error = StoreError;
+    
+    fn assert_claim(&mut self, claim: Claim) -> Result<AssertOutcome, Self::Error> {
+        delegate!(self, store => store.assert_claim(claim))
+    }
+    
+    fn assert_claim_with_provenance(
+        &mut self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, Self::Error> {
+        delegate!(self, store => store.assert_claim_with_provenance(claim, provenance))
+    }
+    
+    fn assert_batch(&mut self, batch: ClaimBatch) -> Result<BatchOutcome<Self::Error>, Self::Error> {
+        delegate!(self, store => ClaimStore::assert_batch(store, batch))
+    }
+    
+    fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
+        delegate!(self, store => store.get_claim(id))
+    }
+    
+    fn get_provenance(&self, id: ClaimId) -> Result<Vec<ProvenanceEntry>, Self::Error> {
+        delegate!(self, store => store.get_provenance(id))
+    }
+    
+    fn get_provenance_batch(&self, ids: &[ClaimId]) -> Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error> {
+        delegate!(self, store => store.get_provenance_batch(ids))
+    }
+    
+    fn find_by_content_hash(&self, content_hash: &str) -> Result<Option<Claim>, Self::Error> {
+        delegate!(self, store => store.find_by_content_hash(content_hash))
+    }
+    
+    fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
+        delegate!(self, store => store.query_claims(query))
+    }
+    
+    fn add_relationship(&mut self, relationship: Relationship) -> Result<(), Self::Error> {
+        delegate!(self, store => ClaimStore::add_relationship(store, relationship))
+    }
+    
+    fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, Self::Error> {
+        delegate!(self, store => store.get_relationships(id))
+    }
+    
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> Result<Vec<Relationship>, Self::Error> {
+        delegate!(self, store => store.get_relationships_batch(ids))
+    }
+    
+    fn set_tier(&mut self, id: ClaimId, tier: Tier) -> Result<bool, Self::Error> {
+        delegate!(self, store => ClaimStore::set_tier(store, id, tier))
+    }
+    
+    fn delete_claim(&mut self, id: ClaimId) -> Result<bool, Self::Error> {
+        delegate!(self, store => ClaimStore::delete_claim(store, id))
+    }
+    
+    fn compact_indexes(&mut self, threshold: f64) -> Result<usize, Self::Error> {
+        delegate!(self, store => store.compact_indexes(threshold))
+    }
+    
+    fn events_since(&self, after: u64, limit: usize) -> Result<Vec<StoreEvent>, Self::Error> {
+        delegate!(self, store => store.events_since(after, limit))
+    }
+    
+    fn namespace_stats(&self, root: &str) -> Result<Vec<NamespaceStats>, Self::Error> {
+        delegate!(self, store => store.namespace_stats(root))
+    }
+    
+    fn move_namespace(&mut self, from: &str, to: &str) -> Result<usize, Self::Error> {
+        delegate!(self, store => ClaimStore::move_namespace(store, from, to))
+    }
+    
+    fn add_alias(&mut self, alias: EntityAlias) -> Result<(), Self::Error> {
+        delegate!(self, store => ClaimStore::add_alias(store, alias))
+    }
+    
+    fn retract_alias(&mut self, alias: &str) -> Result<bool, Self::Error> {
+        delegate!(self, store => ClaimStore::retract_alias(store, alias))
+    }
+    
+    fn entity_aliases(&self, include_retracted: bool) -> Result<Vec<EntityAlias>, Self::Error> {
+        delegate!(self, store => store.entity_aliases(include_retracted))
+    }
+    
+    fn staleness_policy(&self) -> StalenessPolicy {
+        delegate!(self, store => store.staleness_policy())
+    }
+    
+    fn effective_confidence(&self, id: ClaimId, now: u64) -> Result<Option<ConfidenceInterval>, Self::Error> {
+        delegate!(self, store => store.effective_confidence(id, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    
+    #[test]
+    fn test_sqlite_is_the_default_backend() {
+        let config: StoreConfig = serde_json::from_str(r#"{"backend": "sqlite", "path": "claims.db"}"#).unwrap();
+        assert_eq!(config, StoreConfig::Sqlite {
+            path: PathBuf::from("claims.db"),
+            embedding_dimension: None,
+            max_readers: PoolConfig::default().max_readers,
+            hnsw_m: VectorIndexConfig::default().m,
+            hnsw_ef_construction: VectorIndexConfig::default().ef_construction,
+            hnsw_max_elements: VectorIndexConfig::default().max_elements,
+        });
+        assert!(matches!(StoreConfig::default(), StoreConfig::Sqlite { .. }));
+    }
+    
+    #[test]
+    fn test_hnsw_parameters_reach_the_vector_index() {
+        let dir = TempDir::new().unwrap();
+        let config: StoreConfig = serde_json::from_value(serde_json::json!({
+            "backend": "sqlite",
+            "path": dir.path().join("boswell.db"),
+            "embedding_dimension": 8,
+            "hnsw_m": 0,
+        })).unwrap();
+        assert!(matches!(config, StoreConfig::Sqlite { hnsw_m: 0, .. }));
+        
+        // m = 0 is rejected when the index is built
+        assert!(matches!(config.open(), Err(StoreError::InvalidData(_))));
+    }
+    
+    #[test]
+    fn test_parses_postgres_backend() {
+        let config: StoreConfig = serde_json::from_str(
+            r#"{"backend": "postgres", "url": "postgres://localhost/boswell", "max_connections": 4}"#
+        ).unwrap();
+        assert_eq!(config, StoreConfig::Postgres {
+            url: "postgres://localhost/boswell".to_string(),
+            embedding_dimension: None,
+            max_connections: 4,
+        });
+    }
+    
+    #[test]
+    fn test_rejects_unknown_backend() {
+        assert!(serde_json::from_str::<StoreConfig>(r#"{"backend": "oracle", "url": "x"}"#).is_err());
+    }
+    
+    #[test]
+    fn test_opens_sqlite_store() {
+        let dir = TempDir::new().unwrap();
+        let config = StoreConfig::Sqlite {
+            path: dir.path().join("boswell.db"),
+            embedding_dimension: None,
+            max_readers: 2,
+            hnsw_m: 16,
+            hnsw_ef_construction: 200,
+            hnsw_max_elements: 10_000,
+        };
+        
+        let mut store = config.open().unwrap();
+        assert!(matches!(store, ConfiguredStore::Sqlite(_)));
+        
+        let claim = Claim {
+            id: ClaimId::new(),
+            namespace: "test".to_string(),
+            subject: "Alice".to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.9),
+            tier: Tier::Task,
+            created_at: 1000,
+            stale_at: None,
+        };
+        store.assert_claim(claim.clone()).unwrap();
+        let stale_at = StalenessPolicy::default().stale_at_on_insert(&claim);
+        assert_eq!(store.get_claim(claim.id).unwrap(), Some(Claim { stale_at, ..claim.clone() }));
+        assert!(store.delete_claim(claim.id).unwrap());
+    }
+}
@@ -0,0 +1,828 @@
+//! Conformance suite for [`ClaimStore`] implementations
+//!
+//! Each check takes a fresh, empty store and panics on the first behaviour
+//! that differs from the contract documented on [`ClaimStore`]. Stores are
+//! expected to start with the default [`StalenessPolicy`]. Run the whole
+//! suite with [`claim_store_conformance!`](crate::claim_store_conformance),
+//! which expands to one `#[test]` per check:
+//!
+//! ```ignore
+//! mod sqlite {
+//!     use boswell_store::SqliteStore;
+//!     boswell_store::claim_store_conformance!(SqliteStore::new(":memory:", false, 0).unwrap());
+//! }
+//! ```
+
+use boswell_domain::traits::{AssertOutcome, BatchClaim, ClaimBatch, ClaimQuery, ClaimStore};
+use boswell_domain::{
+    AliasGraph, Claim, ClaimId, Entity, EntityAlias, EventKind, ProvenanceEntry, Relationship, RelationshipType, StalenessPolicy, Tier,
+};
+use std::fmt::Debug;
+
+/// Generate one `#[test]` per conformance check for the store built by `$make_store`
+///
+/// `$make_store` is evaluated once per test and must produce an empty store.
+/// With `guarded: $make_fixture` it produces a `(guard, store)` pair instead,
+/// and the guard (say, the `TempDir` a file-backed store lives in) is
+/// dropped only after the check has dropped the store.
+/// The checks listed here and in [`checks`] must stay the same.
+#[macro_export]
+macro_rules! claim_store_conformance {
+    (guarded: $make_fixture:expr) => {
+        $crate::claim_store_conformance!(@tests $make_fixture;
+            get_missing_claim,
+            assert_and_get_round_trip,
+            rejects_duplicate_id,
+            rejects_invalid_claims,
+            duplicate_content_corroborates,
+            distinct_facts_stay_distinct,
+            staleness,
+            find_by_content_hash,
+            records_provenance,
+            query_filters,
+            namespace_subtree,
+            entity_filters,
+            namespace_stats,
+            move_namespace,
+            query_ordering_and_limit,
+            relationships,
+            relationship_upsert,
+            effective_confidence,
+            batch_partial_success,
+            batch_all_or_nothing,
+            batch_relationships_follow_corroboration,
+            event_log,
+            tier_changes_and_evictions,
+            entity_aliases,
+        );
+    };
+    ($make_store:expr) => {
+        $crate::claim_store_conformance!(guarded: ((), $make_store));
+    };
+    (@tests $make_fixture:expr; $($check:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $check() {
+                let (_guard, store) = $make_fixture;
+                $crate::conformance::$check(store);
+            }
+        )*
+    };
+}
+
+/// A conformance check, given a fresh, empty store
+pub type Check<S> = fn(S);
+
+/// Every conformance check with its name, for test harnesses that cannot use the macro
+///
+/// Lists the same checks as [`claim_store_conformance!`](crate::claim_store_conformance),
+/// in the same order.
+pub fn checks<S: ClaimStore>() -> Vec<(&'static str, Check<S>)>
+where
+    S::Error: Debug,
+{
+    vec![
+        ("get_missing_claim", get_missing_claim::<S>),
+        ("assert_and_get_round_trip", assert_and_get_round_trip::<S>),
+        ("rejects_duplicate_id", rejects_duplicate_id::<S>),
+        ("rejects_invalid_claims", rejects_invalid_claims::<S>),
+        ("duplicate_content_corroborates", duplicate_content_corroborates::<S>),
+        ("distinct_facts_stay_distinct", distinct_facts_stay_distinct::<S>),
+        ("staleness", staleness::<S>),
+        ("find_by_content_hash", find_by_content_hash::<S>),
+        ("records_provenance", records_provenance::<S>),
+        ("query_filters", query_filters::<S>),
+        ("namespace_subtree", namespace_subtree::<S>),
+        ("entity_filters", entity_filters::<S>),
+        ("namespace_stats", namespace_stats::<S>),
+        ("move_namespace", move_namespace::<S>),
+        ("query_ordering_and_limit", query_ordering_and_limit::<S>),
+        ("relationships", relationships::<S>),
+        ("relationship_upsert", relationship_upsert::<S>),
+        ("effective_confidence", effective_confidence::<S>),
+        ("batch_partial_success", batch_partial_success::<S>),
+        ("batch_all_or_nothing", batch_all_or_nothing::<S>),
+        ("batch_relationships_follow_corroboration", batch_relationships_follow_corroboration::<S>),
+        ("event_log", event_log::<S>),
+        ("tier_changes_and_evictions", tier_changes_and_evictions::<S>),
+        ("entity_aliases", entity_aliases::<S>),
+    ]
+}
+
+fn claim(namespace: &str, subject: &str, created_at: u64) -> Claim {
+    Claim {
+        id: ClaimId::new(),
+        namespace: namespace.to_string(),
+        subject: subject.to_string(),
+        predicate: "relation:knows".to_string(),
+        object: "person:bob".to_string(),
+        confidence: (0.6, 0.8),
+        tier: Tier::Task,
+        created_at,
+        stale_at: None,
+    }
+}
+
+/// `claim` as the store returns it, with `stale_at` set by the default policy
+fn stored(claim: &Claim) -> Claim {
+    Claim { stale_at: StalenessPolicy::default().stale_at_on_insert(claim), ..claim.clone() }
+}
+
+/// Looking up an unknown ID returns `None`, not an error
+pub fn get_missing_claim<S: ClaimStore>(store: S)
+where
+    S::Error: Debug,
+{
+    assert_eq!(store.get_claim(ClaimId::new()).unwrap(), None);
+    assert!(store.get_provenance(ClaimId::new()).unwrap().is_empty());
+    assert!(store.get_relationships(ClaimId::new()).unwrap().is_empty());
+}
+
+/// Every claim field survives a round trip
+pub fn assert_and_get_round_trip<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let claim = Claim {
+        stale_at: Some(2_000),
+        confidence: (0.25, 0.75),
+        tier: Tier::Permanent,
+        ..claim("project/boswell", "person:alice", 1_000)
+    };
+    
+    assert_eq!(store.assert_claim(claim.clone()).unwrap(), AssertOutcome::Inserted(claim.id));
+    assert_eq!(store.get_claim(claim.id).unwrap(), Some(claim));
+}
+
+/// Reusing a claim ID is an error and leaves the original untouched
+pub fn rejects_duplicate_id<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let original = claim("test", "person:alice", 1_000);
+    store.assert_claim(original.clone()).unwrap();
+    
+    let reused = Claim { id: original.id, ..claim("test", "person:carol", 1_001) };
+    assert!(store.assert_claim(reused).is_err());
+    assert_eq!(store.get_claim(original.id).unwrap(), Some(stored(&original)));
+}
+
+/// Out-of-range and inverted confidence intervals are rejected
+pub fn rejects_invalid_claims<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let invalid = [
+        Claim { confidence: (0.5, 1.5), ..claim("test", "person:a", 1) },
+        Claim { confidence: (0.9, 0.1), ..claim("test", "person:b", 1) },
+        Claim { confidence: (-0.1, 0.5), ..claim("test", "person:c", 1) },
+        Claim { confidence: (f64::NAN, 0.5), ..claim("test", "person:d", 1) },
+    ];
+    
+    for claim in invalid {
+        assert!(store.assert_claim(claim.clone()).is_err(), "accepted {:?}", claim);
+        assert_eq!(store.get_claim(claim.id).unwrap(), None);
+    }
+    
+    // An invalid restatement must not corroborate the stored claim either
+    let original = claim("test", "person:e", 1);
+    store.assert_claim(original.clone()).unwrap();
+    let restated = Claim { confidence: (0.9, 0.1), ..claim("test", "person:e", 2) };
+    assert!(store.assert_claim(restated.clone()).is_err(), "accepted {:?}", restated);
+    assert_eq!(store.get_claim(original.id).unwrap(), Some(stored(&original)));
+}
+
+/// A claim with the same normalized content corroborates the original
+pub fn duplicate_content_corroborates<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let original = claim("test", "person:alice", 1_000);
+    store.assert_claim(original.clone()).unwrap();
+    
+    let restated = Claim {
+        subject: "  Person:Alice ".to_string(),
+        confidence: (0.7, 0.9),
+        ..claim("test", "person:alice", 2_000)
+    };
+    let outcome = store.assert_claim(restated.clone()).unwrap();
+    assert_eq!(outcome, AssertOutcome::Corroborated(original.id));
+    assert_eq!(store.get_claim(restated.id).unwrap(), None);
+    
+    let stored = store.get_claim(original.id).unwrap().unwrap();
+    assert_eq!(stored.subject, original.subject);
+    assert_eq!(stored.created_at, original.created_at);
+    assert_eq!(stored.confidence.0, 0.7);
+    assert!((stored.confidence.1 - 0.98).abs() < 1e-9);
+    
+    assert_eq!(store.get_provenance(original.id).unwrap().len(), 2);
+    assert_eq!(store.query_claims(&ClaimQuery::default()).unwrap().len(), 1);
+}
+
+/// Claims with a different subject, object or literal value are never corroborations
+///
+/// However close their embeddings, so stores deduplicating semantically must
+/// check more than the namespace and predicate.
+pub fn distinct_facts_stay_distinct<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let original = Claim { object: "number:1990".to_string(), ..claim("test", "company:acme", 1_000) };
+    store.assert_claim(original.clone()).unwrap();
+    
+    let variants = [
+        Claim { id: ClaimId::new(), subject: "company:globex".to_string(), ..original.clone() },
+        Claim { id: ClaimId::new(), object: "number:1991".to_string(), ..original.clone() },
+        Claim { id: ClaimId::new(), object: "date:1990".to_string(), ..original.clone() },
+    ];
+    for variant in variants {
+        assert_eq!(store.assert_claim(variant.clone()).unwrap(), AssertOutcome::Inserted(variant.id));
+    }
+    
+    assert_eq!(store.query_claims(&ClaimQuery::default()).unwrap().len(), 4);
+    assert_eq!(store.get_provenance(original.id).unwrap().len(), 1);
+}
+
+/// Inserts without `stale_at` get one from the policy, and corroboration pushes it back
+pub fn staleness<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let policy = StalenessPolicy::default();
+    let ttl = policy.rule("test", Tier::Task).ttl_secs.unwrap();
+    
+    let original = claim("test", "person:alice", 1_000);
+    store.assert_claim(original.clone()).unwrap();
+    assert_eq!(store.get_claim(original.id).unwrap().unwrap().stale_at, Some(1_000 + ttl));
+    
+    store.assert_claim(claim("test", "person:alice", 5_000)).unwrap();
+    assert_eq!(store.get_claim(original.id).unwrap().unwrap().stale_at, Some(5_000 + ttl));
+    
+    // An older corroboration does not bring it forward
+    store.assert_claim(claim("test", "person:alice", 2_000)).unwrap();
+    assert_eq!(store.get_claim(original.id).unwrap().unwrap().stale_at, Some(5_000 + ttl));
+    
+    let permanent = Claim { tier: Tier::Permanent, ..claim("test", "person:carol", 1_000) };
+    store.assert_claim(permanent.clone()).unwrap();
+    assert_eq!(
+        store.get_claim(permanent.id).unwrap().unwrap().stale_at,
+        policy.rule("test", Tier::Permanent).stale_at(1_000),
+    );
+}
+
+/// Content hash lookups find the stored claim
+pub fn find_by_content_hash<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let alice = claim("test", "person:alice", 1_000);
+    store.assert_claim(alice.clone()).unwrap();
+    
+    assert_eq!(store.find_by_content_hash(&alice.content_hash()).unwrap(), Some(stored(&alice)));
+    assert_eq!(store.find_by_content_hash(&claim("test", "person:zed", 1).content_hash()).unwrap(), None);
+}
+
+/// Provenance is kept in order, also when read in a batch, and an assertion without any is still recorded
+pub fn records_provenance<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let entries = vec![
+        ProvenanceEntry::new("agent:a".to_string(), 10, "extraction".to_string()).with_rationale("quoted".to_string()),
+        ProvenanceEntry::new("agent:b".to_string(), 20, "direct".to_string()),
+    ];
+    let with_provenance = claim("test", "person:alice", 1_000);
+    store.assert_claim_with_provenance(with_provenance.clone(), entries.clone()).unwrap();
+    assert_eq!(store.get_provenance(with_provenance.id).unwrap(), entries);
+    
+    let without = claim("test", "person:carol", 1_000);
+    store.assert_claim(without.clone()).unwrap();
+    let recorded = store.get_provenance(without.id).unwrap();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].source, "unknown");
+    assert_eq!(recorded[0].timestamp, without.created_at);
+    
+    let missing = ClaimId::new();
+    let batch = store.get_provenance_batch(&[with_provenance.id, without.id, missing]).unwrap();
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch[&with_provenance.id], entries);
+    assert_eq!(batch[&without.id], recorded);
+}
+
+/// Namespace prefix, tier and minimum confidence filters combine
+pub fn query_filters<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let alice = claim("project/boswell", "person:alice", 1);
+    let carol = Claim { tier: Tier::Project, ..claim("project/boswell/docs", "person:carol", 2) };
+    let dave = Claim { confidence: (0.2, 0.4), ..claim("project/boswell", "person:dave", 3) };
+    let erin = claim("personal", "person:erin", 4);
+    for claim in [&alice, &carol, &dave, &erin] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    
+    let ids = |query: ClaimQuery| -> Vec<ClaimId> {
+        store.query_claims(&query).unwrap().into_iter().map(|c| c.id).collect()
+    };
+    
+    assert_eq!(ids(ClaimQuery::default()), vec![alice.id, carol.id, dave.id, erin.id]);
+    assert_eq!(
+        ids(ClaimQuery { namespace: Some("project/boswell".to_string()), ..Default::default() }),
+        vec![alice.id, carol.id, dave.id],
+    );
+    assert_eq!(ids(ClaimQuery { tier: Some(Tier::Project), ..Default::default() }), vec![carol.id]);
+    assert_eq!(ids(ClaimQuery { min_confidence: Some(0.5), ..Default::default() }), vec![alice.id, carol.id, erin.id]);
+    assert_eq!(
+        ids(ClaimQuery {
+            namespace: Some("project".to_string()),
+            tier: Some(Tier::Task),
+            min_confidence: Some(0.5),
+            ..Default::default()
+        }),
+        vec![alice.id],
+    );
+    assert!(ids(ClaimQuery { namespace: Some("nowhere".to_string()), ..Default::default() }).is_empty());
+}
+
+/// A namespace filter matches whole segments, case-sensitively, and treats `LIKE` wildcards literally
+pub fn namespace_subtree<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let root = claim("project", "person:a", 1);
+    let child = claim("project/task", "person:b", 2);
+    let sibling = claim("projectX", "person:c", 3);
+    let underscored = claim("my_ns", "person:d", 4);
+    let lookalike = claim("myXns/task", "person:e", 5);
+    let shouted = claim("PROJECT/task", "person:f", 6);
+    for claim in [&root, &child, &sibling, &underscored, &lookalike, &shouted] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    
+    let ids = |namespace: &str| -> Vec<ClaimId> {
+        let query = ClaimQuery { namespace: Some(namespace.to_string()), ..Default::default() };
+        store.query_claims(&query).unwrap().into_iter().map(|c| c.id).collect()
+    };
+    
+    assert_eq!(ids("project"), vec![root.id, child.id]);
+    assert_eq!(ids("project/"), vec![root.id, child.id]);
+    assert_eq!(ids("project/task"), vec![child.id]);
+    assert_eq!(ids("my_ns"), vec![underscored.id]);
+    assert_eq!(ids("PROJECT"), vec![shouted.id]);
+    assert!(ids("Project").is_empty());
+    assert!(ids("proj").is_empty());
+    assert!(ids("%").is_empty());
+}
+
+/// Entity kind filters match parsed kinds, and literal bounds compare values of one datatype
+pub fn entity_filters<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let object = |subject: &str, object: &str, created_at: u64| Claim {
+        object: object.to_string(),
+        ..claim("facts", subject, created_at)
+    };
+    let born = object("Person:Alice", "date:1990-04-02", 1);
+    let founded = object("company:acme", "date:2025", 2);
+    let shipped = object("product:widget", "date:2026-01-15", 3);
+    let weight = object("product:widget", "measurement:12kg", 4);
+    let staff = object("company:acme", "number:250", 5);
+    let malformed = object("nobody", "date:someday", 6);
+    for claim in [&born, &founded, &shipped, &weight, &staff, &malformed] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    
+    let ids = |query: ClaimQuery| -> Vec<ClaimId> {
+        store.query_claims(&query).unwrap().into_iter().map(|c| c.id).collect()
+    };
+    let literal = |value: &str| Some(Entity::parse_literal(value).unwrap());
+    
+    assert_eq!(ids(ClaimQuery { subject_kind: Some("person".to_string()), ..Default::default() }), vec![born.id]);
+    assert_eq!(
+        ids(ClaimQuery { subject_kind: Some("Company".to_string()), ..Default::default() }),
+        vec![founded.id, staff.id],
+    );
+    assert_eq!(
+        ids(ClaimQuery { object_kind: Some("date".to_string()), ..Default::default() }),
+        vec![born.id, founded.id, shipped.id],
+    );
+    assert_eq!(ids(ClaimQuery { object_after: literal("date:2024"), ..Default::default() }), vec![founded.id, shipped.id]);
+    assert_eq!(ids(ClaimQuery { object_after: literal("date:2025"), ..Default::default() }), vec![shipped.id]);
+    assert_eq!(ids(ClaimQuery { object_before: literal("date:2025-06-01"), ..Default::default() }), vec![born.id, founded.id]);
+    assert_eq!(
+        ids(ClaimQuery {
+            object_after: literal("date:2000"),
+            object_before: literal("date:2026"),
+            ..Default::default()
+        }),
+        vec![founded.id],
+    );
+    assert_eq!(ids(ClaimQuery { object_after: literal("number:100"), ..Default::default() }), vec![staff.id]);
+    assert_eq!(ids(ClaimQuery { object_before: literal("measurement:20kg"), ..Default::default() }), vec![weight.id]);
+    assert!(ids(ClaimQuery {
+        object_kind: Some("number".to_string()),
+        object_after: literal("date:2000"),
+        ..Default::default()
+    }).is_empty());
+}
+
+/// Statistics cover each namespace in the subtree, counting its own claims only
+pub fn namespace_stats<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let task = claim("team/a", "person:a", 100);
+    let mut permanent = claim("team/a", "person:b", 200);
+    permanent.tier = Tier::Permanent;
+    permanent.confidence = (0.2, 0.4);
+    let other = claim("team/b", "person:c", 300);
+    let outside = claim("teamX", "person:d", 400);
+    store.assert_claim(task.clone()).unwrap();
+    store.assert_claim_with_provenance(
+        permanent.clone(),
+        vec![ProvenanceEntry::new("agent".to_string(), 500, "inferred".to_string())],
+    ).unwrap();
+    store.assert_claim(other.clone()).unwrap();
+    store.assert_claim(outside).unwrap();
+    store.add_relationship(Relationship::new(task.id, other.id, RelationshipType::Contradicts, 0.9, 10)).unwrap();
+    store.add_relationship(Relationship::new(other.id, task.id, RelationshipType::Supports, 0.9, 10)).unwrap();
+    
+    let stats = store.namespace_stats("team").unwrap();
+    assert_eq!(stats.iter().map(|s| s.namespace.as_str()).collect::<Vec<_>>(), vec!["team/a", "team/b"]);
+    
+    let a = &stats[0];
+    assert_eq!(a.claims, 2);
+    assert_eq!(a.tier_count(Tier::Task), 1);
+    assert_eq!(a.tier_count(Tier::Permanent), 1);
+    assert!((a.average_confidence().unwrap() - 0.5).abs() < 1e-9);
+    assert_eq!(a.last_write, 500);
+    assert_eq!(a.contradictions, 1);
+    
+    let b = &stats[1];
+    assert_eq!(b.claims, 1);
+    assert_eq!(b.last_write, 300);
+    assert_eq!(b.contradictions, 0);
+    
+    assert_eq!(store.namespace_stats("").unwrap().len(), 3);
+    assert!(store.namespace_stats("tea").unwrap().is_empty());
+}
+
+/// Moving a subtree renames every claim in it, keeping IDs and relationships
+pub fn move_namespace<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let top = claim("src/a", "person:a", 1);
+    let deep = claim("src/a/deep", "person:b", 2);
+    let lookalike = claim("src/ab", "person:c", 3);
+    let occupied = claim("taken/x", "person:d", 4);
+    let other_case = claim("Src/a/deep", "person:e", 5);
+    let other_case_target = claim("Dst/x", "person:f", 6);
+    for claim in [&top, &deep, &lookalike, &occupied, &other_case, &other_case_target] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    store.add_relationship(Relationship::new(top.id, deep.id, RelationshipType::Supports, 0.7, 10)).unwrap();
+    let before = store.events_since(0, usize::MAX).unwrap().last().map_or(0, |event| event.seq);
+    
+    assert!(store.move_namespace("src/a", "taken").is_err());
+    assert!(store.move_namespace("src/a", "src/a/b").is_err());
+    assert!(store.move_namespace("src", "src/a").is_err());
+    assert_eq!(store.get_claim(top.id).unwrap().unwrap().namespace, "src/a");
+    
+    assert_eq!(store.move_namespace("src/a/", "dst").unwrap(), 2);
+    assert_eq!(store.get_claim(top.id).unwrap().unwrap().namespace, "dst");
+    assert_eq!(store.get_claim(deep.id).unwrap().unwrap().namespace, "dst/deep");
+    assert_eq!(store.get_claim(lookalike.id).unwrap().unwrap().namespace, "src/ab");
+    // Namespaces differing only in case are different subtrees
+    assert_eq!(store.get_claim(other_case.id).unwrap().unwrap().namespace, "Src/a/deep");
+    assert_eq!(store.get_claim(other_case_target.id).unwrap().unwrap().namespace, "Dst/x");
+    assert_eq!(store.get_relationships(top.id).unwrap().len(), 1);
+    assert_eq!(store.get_provenance(top.id).unwrap().len(), 1);
+    
+    // Each moved claim is logged under its new namespace; refused moves log nothing
+    let mut moves: Vec<(EventKind, Option<String>)> = store.events_since(before, usize::MAX).unwrap()
+        .into_iter()
+        .map(|event| (event.kind, event.namespace))
+        .collect();
+    moves.sort_by(|a, b| a.1.cmp(&b.1));
+    assert_eq!(moves, vec![
+        (EventKind::Moved(top.id), Some("dst".to_string())),
+        (EventKind::Moved(deep.id), Some("dst/deep".to_string())),
+    ]);
+    
+    // Content hashes follow the new namespace
+    let mut moved = top.clone();
+    moved.namespace = "dst".to_string();
+    assert_eq!(store.find_by_content_hash(&moved.content_hash()).unwrap().map(|c| c.id), Some(top.id));
+    assert!(store.find_by_content_hash(&top.content_hash()).unwrap().is_none());
+    moved.id = ClaimId::new();
+    assert_eq!(store.assert_claim(moved).unwrap(), AssertOutcome::Corroborated(top.id));
+    
+    assert_eq!(store.move_namespace("src/a", "elsewhere").unwrap(), 0);
+}
+
+/// Results are ordered by creation time, then ID, and `after` and `limit` apply after ordering
+pub fn query_ordering_and_limit<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let late = claim("test", "person:late", 300);
+    let early = claim("test", "person:early", 100);
+    let tie_a = claim("test", "person:tie-a", 200);
+    let tie_b = claim("test", "person:tie-b", 200);
+    for claim in [&late, &tie_b, &early, &tie_a] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    
+    let (first_tie, second_tie) = if tie_a.id < tie_b.id { (&tie_a, &tie_b) } else { (&tie_b, &tie_a) };
+    let all: Vec<ClaimId> = store.query_claims(&ClaimQuery::default()).unwrap()
+        .into_iter().map(|c| c.id).collect();
+    assert_eq!(all, vec![early.id, first_tie.id, second_tie.id, late.id]);
+    
+    let limited: Vec<ClaimId> = store.query_claims(&ClaimQuery { limit: Some(2), ..Default::default() }).unwrap()
+        .into_iter().map(|c| c.id).collect();
+    assert_eq!(limited, vec![early.id, first_tie.id]);
+    
+    // Paging resumes after the last claim of the previous page, even within a tie
+    let next_page: Vec<ClaimId> = store.query_claims(&ClaimQuery {
+        after: Some((first_tie.created_at, first_tie.id)),
+        limit: Some(2),
+        ..Default::default()
+    }).unwrap().into_iter().map(|c| c.id).collect();
+    assert_eq!(next_page, vec![second_tie.id, late.id]);
+}
+
+/// Relationships are returned for either endpoint, in the order they were added, and once per batch
+pub fn relationships<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let a = claim("test", "person:a", 1);
+    let b = claim("test", "person:b", 2);
+    let c = claim("test", "person:c", 3);
+    for claim in [&a, &b, &c] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    
+    let supports = Relationship::new(a.id, b.id, RelationshipType::Supports, 0.9, 10);
+    let contradicts = Relationship::new(c.id, b.id, RelationshipType::Contradicts, 0.4, 11);
+    store.add_relationship(supports.clone()).unwrap();
+    store.add_relationship(contradicts.clone()).unwrap();
+    
+    assert_eq!(store.get_relationships(b.id).unwrap(), vec![supports.clone(), contradicts.clone()]);
+    assert_eq!(store.get_relationships(a.id).unwrap(), vec![supports.clone()]);
+    assert_eq!(store.get_relationships(c.id).unwrap(), vec![contradicts.clone()]);
+    
+    // Listed once even when both endpoints are asked for
+    assert_eq!(store.get_relationships_batch(&[a.id, b.id]).unwrap(), vec![supports.clone(), contradicts.clone()]);
+    assert_eq!(store.get_relationships_batch(&[a.id]).unwrap(), vec![supports]);
+    assert!(store.get_relationships_batch(&[]).unwrap().is_empty());
+}
+
+/// Re-adding a relationship updates it in place; a different type is a new relationship
+pub fn relationship_upsert<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let a = claim("test", "person:a", 1);
+    let b = claim("test", "person:b", 2);
+    store.assert_claim(a.clone()).unwrap();
+    store.assert_claim(b.clone()).unwrap();
+    
+    store.add_relationship(Relationship::new(a.id, b.id, RelationshipType::Supports, 0.3, 10)).unwrap();
+    store.add_relationship(Relationship::new(a.id, b.id, RelationshipType::References, 0.5, 11)).unwrap();
+    store.add_relationship(Relationship::new(a.id, b.id, RelationshipType::Supports, 0.8, 12)).unwrap();
+    
+    assert_eq!(store.get_relationships(a.id).unwrap(), vec![
+        Relationship::new(a.id, b.id, RelationshipType::Supports, 0.8, 12),
+        Relationship::new(a.id, b.id, RelationshipType::References, 0.5, 11),
+    ]);
+}
+
+/// Effective confidence decays once a claim is stale and follows support and contradiction
+pub fn effective_confidence<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    assert_eq!(store.effective_confidence(ClaimId::new(), 1_000).unwrap(), None);
+    
+    let plain = claim("test", "person:a", 1_000);
+    let supported = claim("test", "person:b", 1_000);
+    let contradicted = claim("test", "person:c", 1_000);
+    let witness = claim("test", "person:d", 1_000);
+    for claim in [&plain, &supported, &contradicted, &witness] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    store.add_relationship(Relationship::new(witness.id, supported.id, RelationshipType::Supports, 1.0, 10)).unwrap();
+    store.add_relationship(Relationship::new(contradicted.id, witness.id, RelationshipType::Contradicts, 1.0, 11)).unwrap();
+    
+    let effective = |id: ClaimId, now: u64| store.effective_confidence(id, now).unwrap().unwrap();
+    let base = effective(plain.id, 1_000);
+    assert!(effective(supported.id, 1_000).upper > base.upper);
+    assert!(effective(contradicted.id, 1_000).lower < base.lower);
+    
+    // One half-life after going stale
+    let stale_at = stored(&plain).stale_at.unwrap();
+    let half_life = StalenessPolicy::default().rule("test", Tier::Task).half_life_secs;
+    assert!((effective(plain.id, stale_at + half_life).upper - base.upper / 2.0).abs() < 1e-9);
+}
+
+/// Without `all_or_nothing`, failed items are reported and the rest are written
+pub fn batch_partial_success<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let good = claim("test", "person:a", 1);
+    let bad = Claim { confidence: (0.9, 0.1), ..claim("test", "person:b", 2) };
+    let also_good = claim("test", "person:c", 3);
+    
+    let outcome = store.assert_batch(ClaimBatch {
+        claims: vec![good.clone().into(), bad.clone().into(), also_good.clone().into()],
+        relationships: vec![Relationship::new(good.id, also_good.id, RelationshipType::Supports, 0.7, 4)],
+        all_or_nothing: false,
+    }).unwrap();
+    
+    assert_eq!(outcome.inserted_count(), 2);
+    assert_eq!(outcome.error_count(), 1);
+    assert!(outcome.claims[1].is_err());
+    assert_eq!(store.get_claim(bad.id).unwrap(), None);
+    assert!(store.get_claim(also_good.id).unwrap().is_some());
+    assert_eq!(store.get_relationships(good.id).unwrap().len(), 1);
+}
+
+/// With `all_or_nothing`, one failure leaves the store exactly as it was
+pub fn batch_all_or_nothing<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let existing = claim("test", "person:existing", 1);
+    store.assert_claim(existing.clone()).unwrap();
+    
+    let good = claim("test", "person:a", 2);
+    let restated = Claim { confidence: (0.9, 0.95), ..claim("test", "person:existing", 3) };
+    let bad = Claim { confidence: (0.9, 0.1), ..claim("test", "person:b", 4) };
+    
+    let result = store.assert_batch(ClaimBatch {
+        claims: vec![good.clone().into(), restated.into(), bad.into()],
+        relationships: Vec::new(),
+        all_or_nothing: true,
+    });
+    
+    assert!(result.is_err());
+    assert_eq!(store.get_claim(good.id).unwrap(), None);
+    assert_eq!(store.get_claim(existing.id).unwrap(), Some(stored(&existing)));
+    assert_eq!(store.get_provenance(existing.id).unwrap().len(), 1);
+}
+
+/// Relationships in a batch follow claims that corroborated an existing claim
+pub fn batch_relationships_follow_corroboration<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let existing = claim("test", "person:existing", 1);
+    store.assert_claim(existing.clone()).unwrap();
+    
+    let restated = claim("test", "person:existing", 2);
+    let new = claim("test", "person:new", 3);
+    let provenance = vec![ProvenanceEntry::new("agent:a".to_string(), 2, "learn".to_string())];
+    
+    let outcome = store.assert_batch(ClaimBatch {
+        claims: vec![
+            BatchClaim { claim: restated.clone(), provenance },
+            new.clone().into(),
+        ],
+        relationships: vec![Relationship::new(new.id, restated.id, RelationshipType::Supports, 0.6, 4)],
+        all_or_nothing: true,
+    }).unwrap();
+    
+    assert_eq!(outcome.corroborated_count(), 1);
+    assert_eq!(outcome.inserted_count(), 1);
+    assert_eq!(store.get_relationships(existing.id).unwrap(), vec![
+        Relationship::new(new.id, existing.id, RelationshipType::Supports, 0.6, 4),
+    ]);
+    assert!(store.get_relationships(restated.id).unwrap().is_empty());
+}
+
+/// Mutations append events in order, tagged with the claim's namespace and
+/// tier, and a rolled-back batch appends none
+pub fn event_log<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let a = claim("test", "person:a", 1);
+    let b = claim("test", "person:b", 2);
+    store.assert_claim(a.clone()).unwrap();
+    store.assert_claim(b.clone()).unwrap();
+    store.assert_claim(claim("test", "person:a", 3)).unwrap();
+    store.add_relationship(Relationship::new(a.id, b.id, RelationshipType::Supports, 0.8, 4)).unwrap();
+    
+    let events = store.events_since(0, 100).unwrap();
+    assert_eq!(events.iter().map(|event| event.kind).collect::<Vec<_>>(), vec![
+        EventKind::Asserted(a.id),
+        EventKind::Asserted(b.id),
+        EventKind::Corroborated(a.id),
+        EventKind::RelationshipAdded { from_claim: a.id, to_claim: b.id, relationship_type: RelationshipType::Supports },
+    ]);
+    assert!(events.windows(2).all(|pair| pair[0].seq < pair[1].seq));
+    assert!(events.iter().all(|event| event.namespace.as_deref() == Some("test") && event.tier == Some(a.tier)));
+    
+    let after_first = store.events_since(events[0].seq, 2).unwrap();
+    assert_eq!(after_first, events[1..3].to_vec());
+    assert!(store.events_since(events[3].seq, 100).unwrap().is_empty());
+    
+    let bad = Claim { confidence: (0.9, 0.1), ..claim("test", "person:c", 5) };
+    let result = store.assert_batch(ClaimBatch {
+        claims: vec![claim("test", "person:d", 5).into(), bad.into()],
+        relationships: Vec::new(),
+        all_or_nothing: true,
+    });
+    assert!(result.is_err());
+    assert!(store.events_since(events[3].seq, 100).unwrap().is_empty());
+}
+
+/// Tier changes and deletions are logged with the claim's namespace and tier
+/// at the time, and deleting a claim drops its relationships
+pub fn tier_changes_and_evictions<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let a = claim("acme/eng", "person:a", 1);
+    let b = claim("acme/eng", "person:b", 2);
+    store.assert_claim(a.clone()).unwrap();
+    store.assert_claim(b.clone()).unwrap();
+    store.add_relationship(Relationship::new(a.id, b.id, RelationshipType::Supports, 0.8, 3)).unwrap();
+    let after = store.events_since(0, 100).unwrap().last().unwrap().seq;
+    
+    assert!(store.set_tier(a.id, Tier::Project).unwrap());
+    assert!(!store.set_tier(a.id, Tier::Project).unwrap());
+    assert!(store.set_tier(a.id, Tier::Ephemeral).unwrap());
+    assert_eq!(store.get_claim(a.id).unwrap().unwrap().tier, Tier::Ephemeral);
+    
+    assert!(store.delete_claim(a.id).unwrap());
+    assert!(!store.delete_claim(a.id).unwrap());
+    assert!(store.get_claim(a.id).unwrap().is_none());
+    assert!(store.get_relationships(b.id).unwrap().is_empty());
+    assert!(store.set_tier(a.id, Tier::Task).is_err());
+    
+    let events = store.events_since(after, 100).unwrap();
+    assert_eq!(events.iter().map(|event| event.kind).collect::<Vec<_>>(), vec![
+        EventKind::Promoted { claim_id: a.id, from: Tier::Task, to: Tier::Project },
+        EventKind::Demoted { claim_id: a.id, from: Tier::Project, to: Tier::Ephemeral },
+        EventKind::Evicted(a.id),
+    ]);
+    assert!(events.iter().all(|event| event.namespace.as_deref() == Some("acme/eng")));
+    assert_eq!(events.iter().map(|event| event.tier).collect::<Vec<_>>(), vec![
+        Some(Tier::Project),
+        Some(Tier::Ephemeral),
+        Some(Tier::Ephemeral),
+    ]);
+}
+
+/// Aliases are normalized and checked on the way in, and retraction keeps the record
+pub fn entity_aliases<S: ClaimStore>(mut store: S)
+where
+    S::Error: Debug,
+{
+    let alias = |alias: &str, canonical: &str, created_at: u64| EntityAlias {
+        alias: alias.to_string(),
+        canonical: canonical.to_string(),
+        reason: "conformance".to_string(),
+        created_at,
+        retracted_at: None,
+    };
+    
+    assert!(store.entity_aliases(true).unwrap().is_empty());
+    store.add_alias(alias("Person:John", "person:John Doe", 1)).unwrap();
+    store.add_alias(alias("person:j_doe", "person:john", 2)).unwrap();
+    
+    // Kinds must match, an alias points at one entity, and merges cannot form a cycle
+    assert!(store.add_alias(alias("company:john", "person:john_doe", 3)).is_err());
+    assert!(store.add_alias(alias("person:john", "person:jonathan", 3)).is_err());
+    assert!(store.add_alias(alias("person:john_doe", "person:j_doe", 3)).is_err());
+    
+    let active = store.entity_aliases(false).unwrap();
+    assert_eq!(
+        active.iter().map(|a| (a.alias.as_str(), a.canonical.as_str())).collect::<Vec<_>>(),
+        vec![("person:john", "person:john_doe"), ("person:j_doe", "person:john")],
+    );
+    assert_eq!(AliasGraph::new(&active).resolve("person:j_doe"), "person:john_doe");
+    
+    assert!(store.retract_alias("Person:John").unwrap());
+    assert!(!store.retract_alias("person:john").unwrap());
+    assert!(!store.retract_alias("person:nobody").unwrap());
+    
+    let active = store.entity_aliases(false).unwrap();
+    assert_eq!(active.len(), 1);
+    assert_eq!(AliasGraph::new(&active).resolve("person:j_doe"), "person:john");
+    let history = store.entity_aliases(true).unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].alias, "person:john");
+    assert!(history[0].retracted_at.is_some());
+    assert!(history[1].is_active());
+    
+    // A retracted alias can be merged again, elsewhere
+    store.add_alias(alias("person:john", "person:jonathan", 4)).unwrap();
+    assert_eq!(store.entity_aliases(true).unwrap().len(), 3);
+}
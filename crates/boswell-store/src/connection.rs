@@ -0,0 +1,812 @@
+//! Claim store operations over a single SQLite connection
+//!
+//! A [`StoreConnection`] holds no state of its own, so [`SqliteStore`] and
+//! [`PooledStore`](crate::PooledStore) share the same SQL whichever connection
+//! they hand it. Writes run against a connection that is already inside a
+//! transaction, so the caller decides the transaction boundary: one claim per
+//! transaction for `assert_claim`, one transaction for a whole batch.
+//! All statements go through the connection's prepared statement cache.
+
+use boswell_domain::{alias, confidence_computation, namespace};
+use boswell_domain::traits::{AssertOutcome, BatchOutcome, ClaimBatch, ClaimQuery};
+use boswell_domain::{
+    Claim, ClaimId, ConfidenceInterval, EntityAlias, EventKind, NamespaceStats, ProvenanceEntry, Relationship, StalenessPolicy,
+    StoreEvent, Tier,
+};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::collections::HashMap;
+
+use crate::embedding::EmbeddingModel;
+use crate::entities::{self, EntityColumns, EntityFilter};
+use crate::events::{self, EventColumns, EventRow, EVENT_COLUMNS};
+use crate::stats::TierStatsRow;
+use crate::vector_index::VectorIndex;
+use crate::{SqliteStore, StoreError};
+
+/// Nearest neighbours inspected when looking for a semantic duplicate
+const DUPLICATE_CANDIDATES: usize = 5;
+
+/// HNSW search quality used for duplicate detection
+const DUPLICATE_EF_SEARCH: usize = 64;
+
+/// Result of writing one claim
+pub(crate) struct WrittenClaim {
+    /// Inserted or corroborated
+    pub outcome: AssertOutcome,
+    
+    /// Embedding to add to the vector index once the transaction commits
+    pub embedding: Option<Vec<f32>>,
+}
+
+/// Embeddings to add to the vector index once a transaction commits
+pub(crate) type PendingEmbeddings = Vec<(ClaimId, Option<Vec<f32>>)>;
+
+/// Reads and writes claims, provenance and relationships through a single connection
+pub(crate) struct StoreConnection<'a> {
+    conn: &'a Connection,
+    vector_index: Option<&'a VectorIndex>,
+    embedding_model: Option<&'a (dyn EmbeddingModel + Send + Sync)>,
+    duplicate_threshold: f32,
+}
+
+impl<'a> StoreConnection<'a> {
+    pub fn new(
+        conn: &'a Connection,
+        vector_index: Option<&'a VectorIndex>,
+        embedding_model: Option<&'a (dyn EmbeddingModel + Send + Sync)>,
+        duplicate_threshold: f32,
+    ) -> Self {
+        Self { conn, vector_index, embedding_model, duplicate_threshold }
+    }
+    
+    /// Insert `claim`, or corroborate the claim it duplicates
+    ///
+    /// `staleness` sets the inserted claim's `stale_at`, or pushes back the
+    /// corroborated claim's.
+    pub fn assert_claim(
+        &self,
+        claim: &Claim,
+        provenance: Vec<ProvenanceEntry>,
+        staleness: &StalenessPolicy,
+    ) -> Result<WrittenClaim, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(claim.id);
+        
+        // Check if the ID already exists
+        let exists: bool = self.conn.prepare_cached("SELECT 1 FROM claims WHERE id = ?1")?
+            .query_row(params![&id_bytes], |_| Ok(true))
+            .optional()?
+            .unwrap_or(false);
+        
+        if exists {
+            return Err(StoreError::Duplicate);
+        }
+        
+        SqliteStore::confidence_interval(claim.confidence)?;
+        
+        // Every assertion leaves a provenance trail, even when the caller gave none
+        let provenance = if provenance.is_empty() {
+            vec![ProvenanceEntry::new("unknown".to_string(), claim.created_at, "assert".to_string())]
+        } else {
+            provenance
+        };
+        
+        // Exact duplicates (same normalized content) corroborate the existing claim
+        let content_hash = claim.content_hash();
+        if let Some(existing_id) = self.find_id_by_content_hash(&content_hash)? {
+            self.corroborate(existing_id, claim, &provenance, staleness)?;
+            return Ok(WrittenClaim {
+                outcome: AssertOutcome::Corroborated(existing_id),
+                embedding: None,
+            });
+        }
+        
+        // Auto-generate the embedding if vector search is enabled
+        let embedding = match self.embedding_model {
+            Some(embedding_model) if self.vector_index.is_some() => {
+                // Create embedding text from claim content
+                let text = format!("{} {} {}", claim.subject, claim.predicate, claim.object);
+                
+                match embedding_model.embed(&text) {
+                    Ok(embedding) => Some(embedding),
+                    Err(e) => {
+                        // Log error but don't fail the claim insertion
+                        eprintln!("Warning: Failed to generate embedding: {}", e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        
+        // Near-identical claims corroborate too
+        if let Some(embedding) = &embedding {
+            if let Some(existing_id) = self.find_semantic_duplicate(claim, embedding)? {
+                self.corroborate(existing_id, claim, &provenance, staleness)?;
+                return Ok(WrittenClaim {
+                    outcome: AssertOutcome::Corroborated(existing_id),
+                    embedding: None,
+                });
+            }
+        }
+        
+        // Insert the claim and its provenance
+        let entities = EntityColumns::of_claim(claim);
+        self.conn.prepare_cached(
+            "INSERT INTO claims (id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at, content_hash,
+                                 subject_kind, object_kind, object_value)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)"
+        )?.execute(params![
+            &id_bytes,
+            &claim.namespace,
+            &claim.subject,
+            &claim.predicate,
+            &claim.object,
+            claim.confidence.0,
+            claim.confidence.1,
+            claim.tier.as_str(),
+            claim.created_at as i64,
+            staleness.stale_at_on_insert(claim).map(|t| t as i64),
+            &content_hash,
+            entities.subject_kind,
+            entities.object_kind,
+            entities.object_value,
+        ])?;
+        self.insert_provenance(&id_bytes, &provenance, claim.confidence.1)?;
+        self.record_event(&EventKind::Asserted(claim.id))?;
+        
+        Ok(WrittenClaim {
+            outcome: AssertOutcome::Inserted(claim.id),
+            embedding,
+        })
+    }
+    
+    /// Insert or update a relationship
+    ///
+    /// Writes the relationship and its event, so it must run inside a transaction.
+    pub fn add_relationship(&self, relationship: &Relationship) -> Result<(), StoreError> {
+        let from_bytes = SqliteStore::claim_id_to_bytes(relationship.from_claim);
+        let to_bytes = SqliteStore::claim_id_to_bytes(relationship.to_claim);
+        let rel_type = SqliteStore::relationship_type_to_str(relationship.relationship_type);
+        
+        self.conn.prepare_cached(
+            "INSERT INTO relationships (from_claim_id, to_claim_id, relationship_type, strength, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(from_claim_id, to_claim_id, relationship_type) DO UPDATE SET
+             strength = excluded.strength, created_at = excluded.created_at"
+        )?.execute(params![
+            &from_bytes,
+            &to_bytes,
+            rel_type,
+            relationship.strength,
+            relationship.created_at as i64,
+        ])?;
+        self.record_event(&EventKind::RelationshipAdded {
+            from_claim: relationship.from_claim,
+            to_claim: relationship.to_claim,
+            relationship_type: relationship.relationship_type,
+        })?;
+        
+        Ok(())
+    }
+    
+    /// Load a claim by ID
+    pub fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(id);
+        
+        let claim = self.conn.prepare_cached(
+            "SELECT id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at
+             FROM claims WHERE id = ?1"
+        )?.query_row(params![&id_bytes], claim_from_row).optional()?;
+        
+        Ok(claim)
+    }
+    
+    /// Claims matching the query's filters, oldest first
+    pub fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, StoreError> {
+        let mut sql = String::from(
+            "SELECT id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at
+             FROM claims WHERE 1=1"
+        );
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        
+        if let Some(root) = &query.namespace {
+            push_subtree_filter(&mut sql, &mut params, root);
+        }
+        
+        if let Some(tier) = query.tier {
+            sql.push_str(" AND tier = ?");
+            params.push(Box::new(tier.as_str()));
+        }
+        
+        if let Some(min_conf) = query.min_confidence {
+            sql.push_str(" AND base_lower >= ?");
+            params.push(Box::new(min_conf));
+        }
+        
+        let entities = EntityFilter::new(query);
+        if let Some(kind) = entities.subject_kind {
+            sql.push_str(" AND subject_kind = ?");
+            params.push(Box::new(kind));
+        }
+        for kind in entities.object_kinds {
+            sql.push_str(" AND object_kind = ?");
+            params.push(Box::new(kind));
+        }
+        if let Some(after) = entities.object_after {
+            sql.push_str(" AND object_value > ?");
+            params.push(Box::new(after));
+        }
+        if let Some(before) = entities.object_before {
+            sql.push_str(" AND object_value < ?");
+            params.push(Box::new(before));
+        }
+        
+        if let Some((created_at, id)) = query.after {
+            sql.push_str(" AND (created_at, id) > (?, ?)");
+            params.push(Box::new(created_at as i64));
+            params.push(Box::new(SqliteStore::claim_id_to_bytes(id)));
+        }
+        
+        sql.push_str(" ORDER BY created_at, id");
+        
+        if let Some(limit) = query.limit {
+            sql.push_str(" LIMIT ?");
+            params.push(Box::new(limit));
+        }
+        
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+        
+        let claims = stmt.query_map(&param_refs[..], claim_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(claims)
+    }
+    
+    /// Relationships where the claim is either endpoint, in the order they were first added
+    pub fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(id);
+        
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT from_claim_id, to_claim_id, relationship_type, strength, created_at
+             FROM relationships WHERE from_claim_id = ?1 OR to_claim_id = ?1 ORDER BY id"
+        )?;
+        
+        let relationships = stmt.query_map(params![&id_bytes], relationship_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(relationships)
+    }
+    
+    /// Relationships where any of the claims is either endpoint, each once, in the order they were first added
+    pub fn get_relationships_batch(&self, ids: &[ClaimId]) -> Result<Vec<Relationship>, StoreError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let placeholders = id_placeholders(ids.len());
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT from_claim_id, to_claim_id, relationship_type, strength, created_at
+             FROM relationships WHERE from_claim_id IN ({0}) OR to_claim_id IN ({0}) ORDER BY id",
+            placeholders
+        ))?;
+        let id_bytes: Vec<Vec<u8>> = ids.iter().map(|&id| SqliteStore::claim_id_to_bytes(id)).collect();
+        
+        let relationships = stmt.query_map(rusqlite::params_from_iter(&id_bytes), relationship_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(relationships)
+    }
+    
+    /// Provenance entries for a claim, oldest first
+    pub fn get_provenance(&self, id: ClaimId) -> Result<Vec<ProvenanceEntry>, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(id);
+        
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT source, source_type, timestamp, rationale
+             FROM provenance WHERE claim_id = ?1 ORDER BY id"
+        )?;
+        
+        let provenance = stmt.query_map(params![&id_bytes], provenance_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(provenance)
+    }
+    
+    /// Provenance entries for several claims, oldest first, keyed by claim
+    pub fn get_provenance_batch(&self, ids: &[ClaimId]) -> Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, StoreError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT source, source_type, timestamp, rationale, claim_id
+             FROM provenance WHERE claim_id IN ({}) ORDER BY id",
+            id_placeholders(ids.len())
+        ))?;
+        let id_bytes: Vec<Vec<u8>> = ids.iter().map(|&id| SqliteStore::claim_id_to_bytes(id)).collect();
+        
+        let mut provenance: HashMap<ClaimId, Vec<ProvenanceEntry>> = HashMap::new();
+        let mut rows = stmt.query(rusqlite::params_from_iter(&id_bytes))?;
+        while let Some(row) = rows.next()? {
+            let claim_id: Vec<u8> = row.get(4)?;
+            provenance.entry(SqliteStore::bytes_to_claim_id(&claim_id)?)
+                .or_default()
+                .push(provenance_from_row(row)?);
+        }
+        
+        Ok(provenance)
+    }
+    
+    /// Write a batch inside the caller's transaction
+    ///
+    /// Each item runs in its own savepoint. In all-or-nothing mode the first
+    /// failure is returned as `BatchAborted` and the caller must drop the
+    /// transaction. Returns the per-item outcome and the embeddings to index
+    /// once the transaction commits.
+    pub fn assert_batch(
+        &self,
+        batch: ClaimBatch,
+        staleness: &StalenessPolicy,
+    ) -> Result<(BatchOutcome<StoreError>, PendingEmbeddings), StoreError> {
+        let mut outcome = BatchOutcome::default();
+        let mut embeddings = Vec::new();
+        // Claims in the batch may resolve to existing claims; relationships follow them
+        let mut resolved_ids = HashMap::new();
+        
+        for (index, item) in batch.claims.into_iter().enumerate() {
+            let result = self.in_savepoint(|| self.assert_claim(&item.claim, item.provenance, staleness));
+            match result {
+                Ok(written) => {
+                    resolved_ids.insert(item.claim.id, written.outcome.claim_id());
+                    embeddings.push((written.outcome.claim_id(), written.embedding));
+                    outcome.claims.push(Ok(written.outcome));
+                }
+                Err(e) if batch.all_or_nothing => {
+                    return Err(StoreError::BatchAborted(format!("claim {}: {}", index, e)));
+                }
+                Err(e) => outcome.claims.push(Err(e)),
+            }
+        }
+        
+        for (index, mut relationship) in batch.relationships.into_iter().enumerate() {
+            relationship.from_claim = *resolved_ids.get(&relationship.from_claim).unwrap_or(&relationship.from_claim);
+            relationship.to_claim = *resolved_ids.get(&relationship.to_claim).unwrap_or(&relationship.to_claim);
+            
+            match self.in_savepoint(|| self.add_relationship(&relationship)) {
+                Ok(()) => outcome.relationships.push(Ok(())),
+                Err(e) if batch.all_or_nothing => {
+                    return Err(StoreError::BatchAborted(format!("relationship {}: {}", index, e)));
+                }
+                Err(e) => outcome.relationships.push(Err(e)),
+            }
+        }
+        
+        Ok((outcome, embeddings))
+    }
+    
+    /// Delete a claim with its provenance, relationships and cached confidence
+    ///
+    /// Runs inside the caller's transaction and records the eviction. Returns
+    /// `true` if the claim existed.
+    pub fn delete_claim(&self, claim_id: ClaimId) -> Result<bool, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(claim_id);
+        
+        let exists = self.conn.prepare_cached("SELECT 1 FROM claims WHERE id = ?1")?
+            .exists(params![&id_bytes])?;
+        if !exists {
+            return Ok(false);
+        }
+        
+        // Recorded first so the event still picks up the claim's namespace and tier
+        self.record_event(&EventKind::Evicted(claim_id))?;
+        self.conn.prepare_cached("DELETE FROM provenance WHERE claim_id = ?1")?
+            .execute(params![&id_bytes])?;
+        self.conn.prepare_cached("DELETE FROM relationships WHERE from_claim_id = ?1 OR to_claim_id = ?1")?
+            .execute(params![&id_bytes])?;
+        self.conn.prepare_cached("DELETE FROM confidence_cache WHERE claim_id = ?1")?
+            .execute(params![&id_bytes])?;
+        self.conn.prepare_cached("DELETE FROM claims WHERE id = ?1")?
+            .execute(params![&id_bytes])?;
+        
+        Ok(true)
+    }
+    
+    /// Record a claim's effective confidence, computed at `computed_at`, in the confidence cache
+    pub fn cache_confidence(&self, claim_id: ClaimId, confidence: ConfidenceInterval, computed_at: u64) -> Result<(), StoreError> {
+        self.conn.prepare_cached(
+            "INSERT INTO confidence_cache (claim_id, effective_lower, effective_upper, computed_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(claim_id) DO UPDATE SET
+                 effective_lower = excluded.effective_lower,
+                 effective_upper = excluded.effective_upper,
+                 computed_at = excluded.computed_at"
+        )?.execute(params![
+            SqliteStore::claim_id_to_bytes(claim_id),
+            confidence.lower,
+            confidence.upper,
+            computed_at as i64,
+        ])?;
+        Ok(())
+    }
+    
+    /// Move a claim to `tier`, recording a promotion or demotion
+    ///
+    /// Runs inside the caller's transaction. Returns `true` if the tier changed.
+    pub fn set_tier(&self, claim_id: ClaimId, tier: Tier) -> Result<bool, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(claim_id);
+        
+        let current: String = self.conn.prepare_cached("SELECT tier FROM claims WHERE id = ?1")?
+            .query_row(params![&id_bytes], |row| row.get(0))
+            .optional()?
+            .ok_or_else(|| StoreError::NotFound(claim_id.to_string()))?;
+        let current = SqliteStore::str_to_tier(&current)?;
+        
+        let Some(event) = EventKind::tier_change(claim_id, current, tier) else {
+            return Ok(false);
+        };
+        
+        self.conn.prepare_cached("UPDATE claims SET tier = ?1 WHERE id = ?2")?
+            .execute(params![tier.as_str(), &id_bytes])?;
+        self.record_event(&event)?;
+        
+        Ok(true)
+    }
+    
+    /// Per-namespace statistics for the subtree at `root`
+    pub fn namespace_stats(&self, root: &str) -> Result<Vec<NamespaceStats>, StoreError> {
+        let mut sql = String::from(
+            "SELECT namespace, tier, COUNT(*), SUM((base_lower + base_upper) / 2.0),
+                    MAX(MAX(created_at, COALESCE((SELECT MAX(timestamp) FROM provenance WHERE claim_id = claims.id), 0))),
+                    SUM((SELECT COUNT(*) FROM relationships
+                         WHERE from_claim_id = claims.id AND relationship_type = 'contradicts'))
+             FROM claims WHERE 1=1"
+        );
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        push_subtree_filter(&mut sql, &mut params, root);
+        sql.push_str(" GROUP BY namespace, tier");
+        
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+        let rows = stmt.query_map(&param_refs[..], |row| {
+            Ok(TierStatsRow {
+                namespace: row.get(0)?,
+                tier: row.get(1)?,
+                claims: row.get::<_, i64>(2)? as u64,
+                confidence_sum: row.get(3)?,
+                last_write: row.get::<_, i64>(4)? as u64,
+                contradictions: row.get::<_, i64>(5)? as u64,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(TierStatsRow::collect(rows))
+    }
+    
+    /// Move the subtree at `from` under `to`, recomputing content hashes
+    ///
+    /// Runs inside the caller's transaction, recording a `Moved` event per
+    /// claim. Returns the number of claims moved.
+    pub fn move_namespace(&self, from: &str, to: &str) -> Result<usize, StoreError> {
+        namespace::check_move(from, to).map_err(StoreError::InvalidData)?;
+        
+        let mut sql = String::from("SELECT 1 FROM claims WHERE 1=1");
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        push_subtree_filter(&mut sql, &mut params, to);
+        sql.push_str(" LIMIT 1");
+        let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+        if self.conn.prepare_cached(&sql)?.exists(&param_refs[..])? {
+            return Err(StoreError::NamespaceOccupied(to.to_string()));
+        }
+        
+        let mut sql = String::from("SELECT id, namespace, subject, predicate, object FROM claims WHERE 1=1");
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        push_subtree_filter(&mut sql, &mut params, from);
+        let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+        let moves = self.conn.prepare_cached(&sql)?.query_map(&param_refs[..], |row| {
+            let namespace: String = row.get(1)?;
+            let moved = namespace::rebase(&namespace, from, to).unwrap_or(namespace);
+            let content_hash = Claim::hash_content(&moved, &row.get::<_, String>(2)?, &row.get::<_, String>(3)?, &row.get::<_, String>(4)?);
+            Ok((row.get::<_, Vec<u8>>(0)?, moved, content_hash))
+        })?.collect::<Result<Vec<_>, _>>()?;
+        
+        let mut update = self.conn.prepare_cached("UPDATE claims SET namespace = ?1, content_hash = ?2 WHERE id = ?3")?;
+        for (id_bytes, namespace, content_hash) in &moves {
+            update.execute(params![namespace, content_hash, id_bytes])?;
+            self.record_event(&EventKind::Moved(SqliteStore::bytes_to_claim_id(id_bytes)?))?;
+        }
+        
+        Ok(moves.len())
+    }
+    
+    /// Record an alias once it passes the checks against the active aliases
+    ///
+    /// Runs inside the caller's transaction, so the check and the insert see the same aliases.
+    pub fn add_alias(&self, alias: EntityAlias) -> Result<(), StoreError> {
+        let alias = entities::check_alias(alias, &self.entity_aliases(false)?)?;
+        self.conn.prepare_cached(
+            "INSERT INTO entity_aliases (alias, canonical, reason, created_at) VALUES (?1, ?2, ?3, ?4)"
+        )?.execute(params![&alias.alias, &alias.canonical, &alias.reason, alias.created_at as i64])?;
+        
+        Ok(())
+    }
+    
+    /// Stamp the active merge of `alias` as retracted; returns `true` if there was one
+    pub fn retract_alias(&self, alias: &str) -> Result<bool, StoreError> {
+        let retracted = self.conn.prepare_cached(
+            "UPDATE entity_aliases SET retracted_at = ?1 WHERE alias = ?2 AND retracted_at IS NULL"
+        )?.execute(params![events::now(), alias::normalize(alias)])? > 0;
+        
+        Ok(retracted)
+    }
+    
+    /// Alias records in the order they were added
+    pub fn entity_aliases(&self, include_retracted: bool) -> Result<Vec<EntityAlias>, StoreError> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT alias, canonical, reason, created_at, retracted_at
+             FROM entity_aliases WHERE ?1 OR retracted_at IS NULL ORDER BY id"
+        )?;
+        
+        let aliases = stmt.query_map(params![include_retracted], |row| {
+            Ok(EntityAlias {
+                alias: row.get(0)?,
+                canonical: row.get(1)?,
+                reason: row.get(2)?,
+                created_at: row.get::<_, i64>(3)? as u64,
+                retracted_at: row.get::<_, Option<i64>>(4)?.map(|t| t as u64),
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(aliases)
+    }
+    
+    /// Up to `limit` events with a sequence number above `after`, oldest first
+    pub fn events_since(&self, after: u64, limit: usize) -> Result<Vec<StoreEvent>, StoreError> {
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "SELECT {} FROM events WHERE seq > ?1 ORDER BY seq LIMIT ?2",
+            EVENT_COLUMNS
+        ))?;
+        
+        let rows = stmt.query_map(params![after as i64, events::sql_limit(limit)], |row| {
+            Ok(EventRow {
+                seq: row.get(0)?,
+                recorded_at: row.get(1)?,
+                kind: row.get(2)?,
+                claim_id: row.get(3)?,
+                related_claim_id: row.get(4)?,
+                relationship_type: row.get(5)?,
+                from_tier: row.get(6)?,
+                to_tier: row.get(7)?,
+                namespace: row.get(8)?,
+                tier: row.get(9)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        
+        rows.into_iter().map(EventRow::into_event).collect()
+    }
+    
+    /// Look up the ID of the claim with the given content hash (uses `idx_claims_content_hash`)
+    pub fn find_id_by_content_hash(&self, content_hash: &str) -> Result<Option<ClaimId>, StoreError> {
+        let id_bytes: Option<Vec<u8>> = self.conn.prepare_cached(
+            "SELECT id FROM claims WHERE content_hash = ?1 ORDER BY id LIMIT 1"
+        )?.query_row(params![content_hash], |row| row.get(0)).optional()?;
+        
+        id_bytes.map(|bytes| SqliteStore::bytes_to_claim_id(&bytes)).transpose()
+    }
+    
+    /// Find an existing claim that is a semantic duplicate of `claim`
+    ///
+    /// Candidates must clear the similarity threshold and, as a structural
+    /// check, share the claim's namespace and normalized predicate.
+    fn find_semantic_duplicate(&self, claim: &Claim, embedding: &[f32]) -> Result<Option<ClaimId>, StoreError> {
+        let Some(vector_index) = self.vector_index else {
+            return Ok(None);
+        };
+        if vector_index.is_empty() {
+            return Ok(None);
+        }
+        
+        let candidates = vector_index.search(embedding, DUPLICATE_CANDIDATES, DUPLICATE_EF_SEARCH)
+            .map_err(|e| StoreError::InvalidData(format!("Vector search failed: {}", e)))?;
+        
+        for (candidate_id, similarity) in candidates {
+            if similarity < self.duplicate_threshold {
+                continue;
+            }
+            
+            if let Some(existing) = self.get_claim(candidate_id)? {
+                if existing.restates(claim) {
+                    return Ok(Some(existing.id));
+                }
+            }
+        }
+        
+        Ok(None)
+    }
+    
+    /// Record `claim` as corroboration of `existing_id`
+    ///
+    /// Appends the provenance, folds the incoming confidence into the existing
+    /// claim's base interval, pushes back its `stale_at` and invalidates its
+    /// cached effective confidence.
+    fn corroborate(
+        &self,
+        existing_id: ClaimId,
+        claim: &Claim,
+        provenance: &[ProvenanceEntry],
+        staleness: &StalenessPolicy,
+    ) -> Result<(), StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(existing_id);
+        
+        let existing = self.get_claim(existing_id)?
+            .ok_or_else(|| StoreError::NotFound(existing_id.to_string()))?;
+        
+        let combined = confidence_computation::corroborate(
+            SqliteStore::confidence_interval(existing.confidence)?,
+            SqliteStore::confidence_interval(claim.confidence)?,
+        );
+        let stale_at = staleness.stale_at_on_corroboration(&existing, claim.created_at);
+        
+        self.conn.prepare_cached("UPDATE claims SET base_lower = ?1, base_upper = ?2, stale_at = ?3 WHERE id = ?4")?
+            .execute(params![combined.lower, combined.upper, stale_at.map(|t| t as i64), &id_bytes])?;
+        self.conn.prepare_cached("DELETE FROM confidence_cache WHERE claim_id = ?1")?
+            .execute(params![&id_bytes])?;
+        self.insert_provenance(&id_bytes, provenance, claim.confidence.1)?;
+        self.record_event(&EventKind::Corroborated(existing_id))?;
+        
+        Ok(())
+    }
+    
+    /// Append an event to the log, inside the caller's transaction
+    ///
+    /// The claim's current namespace and tier are copied onto the event, so
+    /// call this while the claim row still exists.
+    pub fn record_event(&self, kind: &EventKind) -> Result<(), StoreError> {
+        let columns = EventColumns::new(kind);
+        self.conn.prepare_cached(
+            "INSERT INTO events (recorded_at, kind, claim_id, related_claim_id, relationship_type, from_tier, to_tier, namespace, tier)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7,
+                     (SELECT namespace FROM claims WHERE id = ?3),
+                     (SELECT tier FROM claims WHERE id = ?3))"
+        )?.execute(params![
+            events::now(),
+            columns.kind,
+            columns.claim_id,
+            columns.related_claim_id,
+            columns.relationship_type,
+            columns.from_tier,
+            columns.to_tier,
+        ])?;
+        
+        Ok(())
+    }
+    
+    /// Insert provenance rows for a claim
+    fn insert_provenance(
+        &self,
+        claim_id_bytes: &[u8],
+        provenance: &[ProvenanceEntry],
+        confidence_contribution: f64,
+    ) -> Result<(), StoreError> {
+        let mut stmt = self.conn.prepare_cached(
+            "INSERT INTO provenance (claim_id, source, source_type, timestamp, rationale, confidence_contribution)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+        )?;
+        
+        for entry in provenance {
+            stmt.execute(params![
+                claim_id_bytes,
+                &entry.source,
+                &entry.source_type,
+                entry.timestamp as i64,
+                &entry.rationale,
+                confidence_contribution,
+            ])?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Run `f` inside a savepoint, rolling back only its own writes on failure
+    fn in_savepoint<T>(&self, f: impl FnOnce() -> Result<T, StoreError>) -> Result<T, StoreError> {
+        self.conn.execute_batch("SAVEPOINT batch_item")?;
+        match f() {
+            Ok(value) => {
+                self.conn.execute_batch("RELEASE batch_item")?;
+                Ok(value)
+            }
+            Err(e) => {
+                self.conn.execute_batch("ROLLBACK TO batch_item; RELEASE batch_item")?;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Restrict a `WHERE` clause to the namespace subtree at `root`
+///
+/// Matches whole segments: `project` matches `project/x` but not `projectX`.
+/// An empty root adds nothing.
+fn push_subtree_filter(sql: &mut String, params: &mut Vec<Box<dyn rusqlite::ToSql>>, root: &str) {
+    let root = root.trim_end_matches(namespace::SEPARATOR);
+    if !root.is_empty() {
+        // Compared by prefix, not `LIKE`, which ignores ASCII case
+        let prefix = format!("{}{}", root, namespace::SEPARATOR);
+        sql.push_str(" AND (namespace = ? OR substr(namespace, 1, length(?)) = ?)");
+        params.push(Box::new(root.to_string()));
+        params.push(Box::new(prefix.clone()));
+        params.push(Box::new(prefix));
+    }
+}
+
+/// `?1, ?2, ...` for an `IN` list of `count` claim IDs, numbered so the list can be repeated
+fn id_placeholders(count: usize) -> String {
+    (1..=count).map(|i| format!("?{}", i)).collect::<Vec<_>>().join(", ")
+}
+
+/// Map a `SELECT from_claim_id, to_claim_id, relationship_type, strength, created_at` row
+fn relationship_from_row(row: &Row<'_>) -> rusqlite::Result<Relationship> {
+    let from_bytes: Vec<u8> = row.get(0)?;
+    let to_bytes: Vec<u8> = row.get(1)?;
+    let rel_type_str: String = row.get(2)?;
+    
+    let from_claim = SqliteStore::bytes_to_claim_id(&from_bytes)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
+            0, rusqlite::types::Type::Blob, Box::new(e)
+        ))?;
+    
+    let to_claim = SqliteStore::bytes_to_claim_id(&to_bytes)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
+            1, rusqlite::types::Type::Blob, Box::new(e)
+        ))?;
+    
+    let relationship_type = SqliteStore::str_to_relationship_type(&rel_type_str)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
+            2, rusqlite::types::Type::Text, Box::new(e)
+        ))?;
+    
+    Ok(Relationship {
+        from_claim,
+        to_claim,
+        relationship_type,
+        strength: row.get(3)?,
+        created_at: row.get::<_, i64>(4)? as u64,
+    })
+}
+
+/// Map a `SELECT source, source_type, timestamp, rationale` row
+fn provenance_from_row(row: &Row<'_>) -> rusqlite::Result<ProvenanceEntry> {
+    Ok(ProvenanceEntry {
+        source: row.get(0)?,
+        source_type: row.get(1)?,
+        timestamp: row.get::<_, i64>(2)? as u64,
+        rationale: row.get(3)?,
+    })
+}
+
+/// Map a `SELECT id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at` row
+fn claim_from_row(row: &Row<'_>) -> rusqlite::Result<Claim> {
+    let id_bytes: Vec<u8> = row.get(0)?;
+    let id = SqliteStore::bytes_to_claim_id(&id_bytes)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
+            0, rusqlite::types::Type::Blob, Box::new(e)
+        ))?;
+    
+    let tier: String = row.get(7)?;
+    let tier = SqliteStore::str_to_tier(&tier)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
+            7, rusqlite::types::Type::Text, Box::new(e)
+        ))?;
+    let stale_at: Option<i64> = row.get(9)?;
+    
+    Ok(Claim {
+        id,
+        namespace: row.get(1)?,
+        subject: row.get(2)?,
+        predicate: row.get(3)?,
+        object: row.get(4)?,
+        confidence: (row.get(5)?, row.get(6)?),
+        tier,
+        created_at: row.get::<_, i64>(8)? as u64,
+        stale_at: stale_at.map(|t| t as u64),
+    })
+}
@@ -67,8 +67,16 @@ pub trait EmbeddingModel {
     
     /// Get the dimension of embeddings produced by this model
     fn dimension(&self) -> usize;
+    
+    /// Identifier of the model, recorded with stored embeddings
+    ///
+    /// Embeddings from models with different names are not comparable.
+    fn name(&self) -> &str;
 }
 
+/// Name reported by [`MockEmbeddingModel`]
+pub const MOCK_MODEL_NAME: &str = "mock-hash-v1";
+
 /// Mock embedding model for Phase 1 testing
 ///
 /// This model generates deterministic embeddings based on text content
@@ -140,6 +148,10 @@ impl EmbeddingModel for MockEmbeddingModel {
     fn dimension(&self) -> usize {
         self.dimension
     }
+    
+    fn name(&self) -> &str {
+        MOCK_MODEL_NAME
+    }
 }
 
 /// Calculate cosine similarity between two embedding vectors
@@ -0,0 +1,101 @@
+//! Entity columns stored alongside each claim
+//!
+//! Every store keeps the kind of a claim's subject and object, and the sort
+//! key of a literal object, next to the claim so kind patterns (`person:*`)
+//! and literal ranges (`date` objects after 2025) are answered from an index.
+//! The columns are derived with [`Entity::parse`] when a claim is written;
+//! a subject or object that does not parse leaves its columns empty.
+//!
+//! Entity aliases are stored beside the claims they describe; [`check_alias`]
+//! is the validation every store applies before recording one.
+
+use boswell_domain::entity::normalize_kind;
+use boswell_domain::traits::ClaimQuery;
+use boswell_domain::{AliasGraph, Claim, Entity, EntityAlias};
+
+use crate::StoreError;
+
+/// Derived entity columns of one claim
+pub(crate) struct EntityColumns {
+    pub subject_kind: Option<String>,
+    pub object_kind: Option<String>,
+    pub object_value: Option<f64>,
+}
+
+impl EntityColumns {
+    /// Columns for a claim with this subject and object
+    pub fn of(subject: &str, object: &str) -> Self {
+        let subject = Entity::parse(subject).ok();
+        let object = Entity::parse(object).ok();
+        
+        Self {
+            subject_kind: subject.map(|entity| entity.kind),
+            object_value: object.as_ref()
+                .and_then(|entity| entity.literal.as_ref())
+                .map(|literal| literal.sort_key()),
+            object_kind: object.map(|entity| entity.kind),
+        }
+    }
+    
+    /// Columns for `claim`
+    pub fn of_claim(claim: &Claim) -> Self {
+        Self::of(&claim.subject, &claim.object)
+    }
+    
+    /// Whether these columns pass the query's entity filters
+    #[cfg_attr(not(feature = "test-util"), allow(dead_code))]
+    pub fn matches(&self, query: &ClaimQuery) -> bool {
+        let filter = EntityFilter::new(query);
+        
+        filter.subject_kind.is_none_or(|kind| self.subject_kind.as_ref() == Some(&kind))
+            && filter.object_kinds.iter().all(|kind| self.object_kind.as_ref() == Some(kind))
+            && filter.object_after.is_none_or(|after| self.object_value.is_some_and(|value| value > after))
+            && filter.object_before.is_none_or(|before| self.object_value.is_some_and(|value| value < before))
+    }
+}
+
+/// The entity filters of a query, in terms of the stored columns
+pub(crate) struct EntityFilter {
+    /// `subject_kind` must equal this
+    pub subject_kind: Option<String>,
+    
+    /// `object_kind` must equal each of these (the requested kind and the
+    /// datatype of each literal bound)
+    pub object_kinds: Vec<String>,
+    
+    /// `object_value` must be greater than this
+    pub object_after: Option<f64>,
+    
+    /// `object_value` must be less than this
+    pub object_before: Option<f64>,
+}
+
+impl EntityFilter {
+    /// Column filters for `query`
+    pub fn new(query: &ClaimQuery) -> Self {
+        let mut object_kinds: Vec<String> = query.object_kind.iter()
+            .map(|kind| normalize_kind(kind))
+            .chain(query.object_after.iter().chain(&query.object_before)
+                .map(|literal| literal.datatype().as_str().to_string()))
+            .collect();
+        object_kinds.dedup();
+        
+        Self {
+            subject_kind: query.subject_kind.as_deref().map(normalize_kind),
+            object_kinds,
+            object_after: query.object_after.as_ref().map(|literal| literal.bounds().1),
+            object_before: query.object_before.as_ref().map(|literal| literal.bounds().0),
+        }
+    }
+}
+
+/// `alias` normalized and checked against the store's `active` aliases
+///
+/// Records built outside [`EntityAlias::new`] are validated again, so stores
+/// only ever hold normalized aliases of matching kinds.
+pub(crate) fn check_alias(alias: EntityAlias, active: &[EntityAlias]) -> Result<EntityAlias, StoreError> {
+    let alias = EntityAlias::new(&alias.alias, &alias.canonical, alias.reason, alias.created_at)
+        .map_err(StoreError::InvalidData)?;
+    AliasGraph::new(active).check_add(&alias).map_err(StoreError::InvalidData)?;
+    Ok(alias)
+}
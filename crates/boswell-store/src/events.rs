@@ -0,0 +1,129 @@
+//! Row encoding for the `events` table
+//!
+//! The SQLite and PostgreSQL stores keep the event log in identically shaped
+//! tables: the event name, the claim it is about with the namespace and tier
+//! it had at the time, and the columns only some events use (the other claim
+//! and type of a relationship, the tiers of a promotion or demotion).
+
+use boswell_domain::{EventKind, StoreEvent, Tier};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{SqliteStore, StoreError};
+
+/// Columns written for an event, in `INSERT` order after `recorded_at`
+pub(crate) struct EventColumns {
+    pub kind: &'static str,
+    pub claim_id: Vec<u8>,
+    pub related_claim_id: Option<Vec<u8>>,
+    pub relationship_type: Option<&'static str>,
+    pub from_tier: Option<&'static str>,
+    pub to_tier: Option<&'static str>,
+}
+
+impl EventColumns {
+    pub fn new(kind: &EventKind) -> Self {
+        let mut columns = Self {
+            kind: kind.name(),
+            claim_id: SqliteStore::claim_id_to_bytes(kind.claim_id()),
+            related_claim_id: None,
+            relationship_type: None,
+            from_tier: None,
+            to_tier: None,
+        };
+        
+        match kind {
+            EventKind::Promoted { from, to, .. } | EventKind::Demoted { from, to, .. } => {
+                columns.from_tier = Some(from.as_str());
+                columns.to_tier = Some(to.as_str());
+            }
+            EventKind::RelationshipAdded { to_claim, relationship_type, .. } => {
+                columns.related_claim_id = Some(SqliteStore::claim_id_to_bytes(*to_claim));
+                columns.relationship_type = Some(SqliteStore::relationship_type_to_str(*relationship_type));
+            }
+            EventKind::Asserted(_) | EventKind::Corroborated(_) | EventKind::Evicted(_) | EventKind::Moved(_) => {}
+        }
+        
+        columns
+    }
+}
+
+/// An event row as read back, in `SELECT` order
+pub(crate) struct EventRow {
+    pub seq: i64,
+    pub recorded_at: i64,
+    pub kind: String,
+    pub claim_id: Vec<u8>,
+    pub related_claim_id: Option<Vec<u8>>,
+    pub relationship_type: Option<String>,
+    pub from_tier: Option<String>,
+    pub to_tier: Option<String>,
+    pub namespace: Option<String>,
+    pub tier: Option<String>,
+}
+
+/// Columns of [`EventRow`], for `SELECT` statements
+pub(crate) const EVENT_COLUMNS: &str =
+    "seq, recorded_at, kind, claim_id, related_claim_id, relationship_type, from_tier, to_tier, namespace, tier";
+
+impl EventRow {
+    pub fn into_event(self) -> Result<StoreEvent, StoreError> {
+        let claim_id = SqliteStore::bytes_to_claim_id(&self.claim_id)?;
+        let missing = |column: &str| StoreError::InvalidData(format!("{} event {} has no {}", self.kind, self.seq, column));
+        let tier = |value: &Option<String>, column: &str| {
+            value.as_deref()
+                .and_then(Tier::parse)
+                .ok_or_else(|| missing(column))
+        };
+        
+        let kind = match self.kind.as_str() {
+            "asserted" => EventKind::Asserted(claim_id),
+            "corroborated" => EventKind::Corroborated(claim_id),
+            "evicted" => EventKind::Evicted(claim_id),
+            "moved" => EventKind::Moved(claim_id),
+            "promoted" => EventKind::Promoted {
+                claim_id,
+                from: tier(&self.from_tier, "from_tier")?,
+                to: tier(&self.to_tier, "to_tier")?,
+            },
+            "demoted" => EventKind::Demoted {
+                claim_id,
+                from: tier(&self.from_tier, "from_tier")?,
+                to: tier(&self.to_tier, "to_tier")?,
+            },
+            "relationship_added" => EventKind::RelationshipAdded {
+                from_claim: claim_id,
+                to_claim: SqliteStore::bytes_to_claim_id(
+                    self.related_claim_id.as_deref().ok_or_else(|| missing("related_claim_id"))?,
+                )?,
+                relationship_type: SqliteStore::str_to_relationship_type(
+                    self.relationship_type.as_deref().ok_or_else(|| missing("relationship_type"))?,
+                )?,
+            },
+            other => return Err(StoreError::InvalidData(format!("Unknown event kind: {}", other))),
+        };
+        let claim_tier = match self.tier.as_deref() {
+            Some(_) => Some(tier(&self.tier, "tier")?),
+            None => None,
+        };
+        
+        Ok(StoreEvent {
+            seq: self.seq as u64,
+            recorded_at: self.recorded_at as u64,
+            namespace: self.namespace,
+            tier: claim_tier,
+            kind,
+        })
+    }
+}
+
+/// Current time in Unix seconds, for `recorded_at`
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Clamp a caller's limit to what SQL `LIMIT` accepts
+pub(crate) fn sql_limit(limit: usize) -> i64 {
+    i64::try_from(limit).unwrap_or(i64::MAX)
+}
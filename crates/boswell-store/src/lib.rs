@@ -6,7 +6,9 @@
 //!
 //! - SQLite for structured claim data (content, metadata, relationships)
 //! - HNSW for vector similarity search (to be integrated)
-//! - Local embedding model for duplicate detection
+//! - Local embedding model for semantic duplicate detection
+//! - Normalized content hash for exact duplicate detection
+//! - Duplicate assertions corroborate the existing claim instead of creating a new one
 //!
 //! # Examples
 //!
@@ -22,15 +24,50 @@
 
 pub mod vector_index;
 pub mod embedding;
+pub mod migrations;
+#[cfg(feature = "test-util")]
+pub mod conformance;
 
-use boswell_domain::{Claim, ClaimId, Relationship, RelationshipType};
-use boswell_domain::traits::{ClaimStore, ClaimQuery};
-use rusqlite::{Connection, params, OptionalExtension};
+mod backup;
+mod blocking;
+mod config;
+mod connection;
+mod entities;
+mod events;
+#[cfg(feature = "test-util")]
+mod memory;
+mod pool;
+#[cfg(feature = "postgres")]
+mod postgres_store;
+mod stats;
+
+use boswell_domain::{Claim, ClaimId, ConfidenceInterval, EntityAlias, NamespaceStats, ProvenanceEntry, Relationship, RelationshipType, StalenessPolicy, StoreEvent, Tier};
+use boswell_domain::traits::{stored_claim_confidence, AssertOutcome, BatchOutcome, ClaimBatch, ClaimStore, ClaimQuery};
+use connection::StoreConnection;
+use rusqlite::Connection;
+use std::collections::HashMap;
 use std::path::Path;
 use thiserror::Error;
 
-pub use vector_index::VectorIndex;
+pub use vector_index::{
+    RecallReport, VectorIndex, VectorIndexConfig, VectorIndexStats, DEFAULT_COMPACTION_THRESHOLD,
+};
 pub use embedding::{EmbeddingModel, MockEmbeddingModel, cosine_similarity};
+pub use migrations::SCHEMA_VERSION;
+pub use pool::{PoolConfig, PooledStore};
+pub use boswell_domain::resolver::{EntityResolver, DEFAULT_MERGE_THRESHOLD};
+pub use backup::{read_manifest, BackupManifest, EmbeddingSnapshot, BACKUP_FORMAT_VERSION};
+pub use blocking::BlockingStore;
+pub use config::{ConfiguredStore, StoreConfig, DEFAULT_MAX_CONNECTIONS};
+#[cfg(feature = "postgres")]
+pub use postgres_store::{PostgresStore, POSTGRES_SCHEMA_VERSION};
+#[cfg(feature = "test-util")]
+pub use memory::InMemoryStore;
+
+/// Default cosine similarity above which a new claim corroborates an existing one
+///
+/// Only an existing claim stating the same fact qualifies; see [`Claim::restates`].
+pub const DEFAULT_DUPLICATE_THRESHOLD: f32 = 0.95;
 
 /// Errors that can occur during storage operations
 #[derive(Error, Debug)]
@@ -39,6 +76,11 @@ pub enum StoreError {
     #[error("Database error: {0}")]
     Database(#[from] rusqlite::Error),
     
+    /// PostgreSQL error
+    #[cfg(feature = "postgres")]
+    #[error("PostgreSQL error: {0}")]
+    Postgres(#[from] postgres::Error),
+    
     /// Claim not found
     #[error("Claim not found: {0}")]
     NotFound(String),
@@ -50,6 +92,31 @@ pub enum StoreError {
     /// Duplicate claim detected
     #[error("Duplicate claim detected")]
     Duplicate,
+    
+    /// The database was written by a newer version of Boswell
+    #[error("Database schema version {found} is newer than supported version {supported}")]
+    SchemaTooNew {
+        /// Version recorded in the database
+        found: u32,
+        /// Latest version this build understands
+        supported: u32,
+    },
+    
+    /// A namespace move was refused because the destination already holds claims
+    #[error("Namespace already holds claims: {0}")]
+    NamespaceOccupied(String),
+    
+    /// An all-or-nothing batch was rolled back because one item failed
+    #[error("Batch rolled back, {0}")]
+    BatchAborted(String),
+    
+    /// A backup is damaged or cannot be restored into this store
+    #[error("Invalid backup: {0}")]
+    InvalidBackup(String),
+    
+    /// Filesystem error while writing or reading a backup
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// SQLite-based implementation of ClaimStore
@@ -59,11 +126,15 @@ pub enum StoreError {
 ///
 /// # Thread Safety
 ///
-/// SQLite connections are not thread-safe. Each thread should have its own SqliteStore instance.
+/// A single connection serves every call, so a shared `SqliteStore` must sit
+/// behind a mutex. Use [`PooledStore`] when concurrent readers should not wait
+/// on each other or on writes.
 pub struct SqliteStore {
     conn: Connection,
     vector_index: Option<VectorIndex>,
     embedding_model: Option<Box<dyn EmbeddingModel + Send + Sync>>,
+    duplicate_threshold: f32,
+    staleness: StalenessPolicy,
 }
 
 impl SqliteStore {
@@ -89,31 +160,110 @@ impl SqliteStore {
     /// let store = SqliteStore::new("boswell.db", true, 384).unwrap();
     /// ```
     pub fn new<P: AsRef<Path>>(path: P, enable_vector_search: bool, embedding_dimension: usize) -> Result<Self, StoreError> {
-        let conn = Connection::open(path)?;
-        
-        let (vector_index, embedding_model) = if enable_vector_search {
-            (
-                Some(VectorIndex::new(embedding_dimension)),
-                Some(Box::new(MockEmbeddingModel::new(embedding_dimension)) as Box<dyn EmbeddingModel + Send + Sync>),
-            )
+        let vector_index = if enable_vector_search {
+            Some(VectorIndex::new(embedding_dimension))
         } else {
-            (None, None)
+            None
         };
         
-        let mut store = Self { conn, vector_index, embedding_model };
-        store.initialize_schema()?;
-        Ok(store)
+        Self::open(path, vector_index, embedding_dimension)
     }
     
-    /// Initialize the database schema
-    fn initialize_schema(&mut self) -> Result<(), StoreError> {
-        // Read and execute the schema SQL
-        let schema = include_str!("schema.sql");
+    /// Create a new SqliteStore with vector search using custom HNSW parameters
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use boswell_store::{SqliteStore, VectorIndexConfig};
+    ///
+    /// // Trade memory and build time for recall on a large instance
+    /// let config = VectorIndexConfig {
+    ///     m: 32,
+    ///     ef_construction: 400,
+    ///     max_elements: 4_000_000,
+    /// };
+    /// let store = SqliteStore::with_vector_config("boswell.db", 384, config).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` if the HNSW parameters are out of range
+    pub fn with_vector_config<P: AsRef<Path>>(
+        path: P,
+        embedding_dimension: usize,
+        config: VectorIndexConfig,
+    ) -> Result<Self, StoreError> {
+        let vector_index = VectorIndex::with_config(embedding_dimension, config)
+            .map_err(|e| StoreError::InvalidData(e.to_string()))?;
         
-        // Execute each statement (SQLite doesn't support multiple statements in one execute)
-        self.conn.execute_batch(schema)?;
+        Self::open(path, Some(vector_index), embedding_dimension)
+    }
+    
+    /// Open an existing database as it is, without migrating it
+    ///
+    /// For tools that must not change the instance they inspect, such as
+    /// `boswell admin backup`. The database must be at [`SCHEMA_VERSION`].
+    /// With vector search enabled, the HNSW index is filled by embedding every
+    /// claim, so it matches the index of an instance using the same model.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaTooNew` if the database was written by a newer version,
+    /// `InvalidData` if it is on an older schema, or the database error if
+    /// `path` does not exist.
+    pub fn open_existing<P: AsRef<Path>>(
+        path: P,
+        enable_vector_search: bool,
+        embedding_dimension: usize,
+    ) -> Result<Self, StoreError> {
+        let conn = Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE)?;
+        let found = migrations::current_version(&conn)?;
+        if found > SCHEMA_VERSION {
+            return Err(StoreError::SchemaTooNew { found, supported: SCHEMA_VERSION });
+        }
+        if found < SCHEMA_VERSION {
+            return Err(StoreError::InvalidData(format!(
+                "Database is at schema version {}, expected {}; open it with this version to migrate it first",
+                found, SCHEMA_VERSION
+            )));
+        }
+    
+        let vector_index = enable_vector_search.then(|| VectorIndex::new(embedding_dimension));
+        let embedding_model = vector_index.as_ref().map(|_| {
+            Box::new(MockEmbeddingModel::new(embedding_dimension)) as Box<dyn EmbeddingModel + Send + Sync>
+        });
+        if let (Some(vector_index), Some(embedding_model)) = (&vector_index, &embedding_model) {
+            backup::load_vectors(&conn, vector_index, embedding_model.as_ref(), None)?;
+        }
+    
+        Ok(Self {
+            conn,
+            vector_index,
+            embedding_model,
+            duplicate_threshold: DEFAULT_DUPLICATE_THRESHOLD,
+            staleness: StalenessPolicy::default(),
+        })
+    }
+    
+    fn open<P: AsRef<Path>>(
+        path: P,
+        vector_index: Option<VectorIndex>,
+        embedding_dimension: usize,
+    ) -> Result<Self, StoreError> {
+        let mut conn = Connection::open(path)?;
+        migrations::migrate(&mut conn)?;
         
-        Ok(())
+        let embedding_model = vector_index.as_ref().map(|_| {
+            Box::new(MockEmbeddingModel::new(embedding_dimension)) as Box<dyn EmbeddingModel + Send + Sync>
+        });
+        
+        Ok(Self {
+            conn,
+            vector_index,
+            embedding_model,
+            duplicate_threshold: DEFAULT_DUPLICATE_THRESHOLD,
+            staleness: StalenessPolicy::default(),
+        })
     }
     
     /// Convert ClaimId to bytes for storage
@@ -121,6 +271,12 @@ impl SqliteStore {
         id.value().to_be_bytes().to_vec()
     }
     
+    /// Validate a `(lower, upper)` confidence pair read from or written to a claim
+    fn confidence_interval(bounds: (f64, f64)) -> Result<ConfidenceInterval, StoreError> {
+        ConfidenceInterval::try_new(bounds.0, bounds.1)
+            .map_err(|e| StoreError::InvalidData(format!("Invalid confidence interval: {}", e)))
+    }
+    
     /// Convert bytes to ClaimId
     fn bytes_to_claim_id(bytes: &[u8]) -> Result<ClaimId, StoreError> {
         if bytes.len() != 16 {
@@ -155,216 +311,232 @@ impl SqliteStore {
             _ => Err(StoreError::InvalidData(format!("Unknown relationship type: {}", s))),
         }
     }
+    
+    /// Convert a stored tier name to a Tier
+    fn str_to_tier(s: &str) -> Result<Tier, StoreError> {
+        Tier::parse(s).ok_or_else(|| StoreError::InvalidData(format!("Unknown tier: {}", s)))
+    }
 }
 
 impl ClaimStore for SqliteStore {
     type Error = StoreError;
     
-    fn assert_claim(&mut self, claim: Claim) -> Result<ClaimId, Self::Error> {
-        let id_bytes = Self::claim_id_to_bytes(claim.id);
-        
-        // Check if the ID already exists
-        let exists: bool = self.conn.query_row(
-            "SELECT 1 FROM claims WHERE id = ?1",
-            params![&id_bytes],
-            |_| Ok(true)
-        ).optional()?.unwrap_or(false);
-        
-        if exists {
-            return Err(StoreError::Duplicate);
-        }
+    fn assert_claim(&mut self, claim: Claim) -> Result<AssertOutcome, Self::Error> {
+        self.assert_claim_with_provenance(claim, Vec::new())
+    }
+    
+    fn assert_claim_with_provenance(
+        &mut self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, Self::Error> {
+        let tx = self.conn.transaction()?;
+        let written = StoreConnection::new(
+            &tx,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        ).assert_claim(&claim, provenance, &self.staleness)?;
+        tx.commit()?;
         
-        // Insert the claim
-        self.conn.execute(
-            "INSERT INTO claims (id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
-            params![
-                &id_bytes,
-                &claim.namespace,
-                &claim.subject,
-                &claim.predicate,
-                &claim.object,
-                claim.confidence.0,
-                claim.confidence.1,
-                &claim.tier,
-                claim.created_at as i64,
-                claim.stale_at.map(|t| t as i64),
-            ],
-        )?;
+        self.index_embedding(written.outcome.claim_id(), written.embedding);
+        Ok(written.outcome)
+    }
+    
+    fn assert_batch(&mut self, batch: ClaimBatch) -> Result<BatchOutcome<Self::Error>, Self::Error> {
+        let tx = self.conn.transaction()?;
+        let (outcome, embeddings) = StoreConnection::new(
+            &tx,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        ).assert_batch(batch, &self.staleness)?;
+        tx.commit()?;
         
-        // Auto-generate and add embedding if vector search is enabled
-        if let (Some(embedding_model), Some(vector_index)) = 
-            (&self.embedding_model, &self.vector_index) {
-            // Create embedding text from claim content
-            let text = format!("{} {} {}", claim.subject, claim.predicate, claim.object);
-            
-            match embedding_model.embed(&text) {
-                Ok(embedding) => {
-                    // Add to vector index (ignore errors for now)
-                    let _ = vector_index.add(claim.id, &embedding);
-                }
-                Err(e) => {
-                    // Log error but don't fail the claim insertion
-                    eprintln!("Warning: Failed to generate embedding: {}", e);
-                }
-            }
+        // The vector index is only touched once the rows are durable
+        for (claim_id, embedding) in embeddings {
+            self.index_embedding(claim_id, embedding);
         }
         
-        Ok(claim.id)
+        Ok(outcome)
     }
     
     fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
-        let id_bytes = Self::claim_id_to_bytes(id);
-        
-        let claim = self.conn.query_row(
-            "SELECT id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at
-             FROM claims WHERE id = ?1",
-            params![&id_bytes],
-            |row| {
-                let id_bytes: Vec<u8> = row.get(0)?;
-                let id = Self::bytes_to_claim_id(&id_bytes)
-                    .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
-                        0, rusqlite::types::Type::Blob, Box::new(e)
-                    ))?;
-                
-                let stale_at: Option<i64> = row.get(9)?;
-                
-                Ok(Claim {
-                    id,
-                    namespace: row.get(1)?,
-                    subject: row.get(2)?,
-                    predicate: row.get(3)?,
-                    object: row.get(4)?,
-                    confidence: (row.get(5)?, row.get(6)?),
-                    tier: row.get(7)?,
-                    created_at: row.get::<_, i64>(8)? as u64,
-                    stale_at: stale_at.map(|t| t as u64),
-                })
-            }
-        ).optional()?;
-        
-        Ok(claim)
+        self.connection().get_claim(id)
     }
     
     fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
-        let mut sql = String::from(
-            "SELECT id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at
-             FROM claims WHERE 1=1"
-        );
-        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
-        
-        if let Some(namespace) = &query.namespace {
-            sql.push_str(" AND namespace LIKE ?");
-            params.push(Box::new(format!("{}%", namespace)));
-        }
-        
-        if let Some(tier) = &query.tier {
-            sql.push_str(" AND tier = ?");
-            params.push(Box::new(tier.clone()));
-        }
-        
-        if let Some(min_conf) = query.min_confidence {
-            sql.push_str(" AND base_lower >= ?");
-            params.push(Box::new(min_conf));
-        }
-        
-        if let Some(limit) = query.limit {
-            sql.push_str(" LIMIT ?");
-            params.push(Box::new(limit));
-        }
-        
-        let mut stmt = self.conn.prepare(&sql)?;
-        let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
-        
-        let claims = stmt.query_map(&param_refs[..], |row| {
-            let id_bytes: Vec<u8> = row.get(0)?;
-            let id = Self::bytes_to_claim_id(&id_bytes)
-                .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
-                    0, rusqlite::types::Type::Blob, Box::new(e)
-                ))?;
-            
-            let stale_at: Option<i64> = row.get(9)?;
-            
-            Ok(Claim {
-                id,
-                namespace: row.get(1)?,
-                subject: row.get(2)?,
-                predicate: row.get(3)?,
-                object: row.get(4)?,
-                confidence: (row.get(5)?, row.get(6)?),
-                tier: row.get(7)?,
-                created_at: row.get::<_, i64>(8)? as u64,
-                stale_at: stale_at.map(|t| t as u64),
-            })
-        })?.collect::<Result<Vec<_>, _>>()?;
-        
-        Ok(claims)
+        self.connection().query_claims(query)
     }
     
     fn add_relationship(&mut self, relationship: Relationship) -> Result<(), Self::Error> {
-        let from_bytes = Self::claim_id_to_bytes(relationship.from_claim);
-        let to_bytes = Self::claim_id_to_bytes(relationship.to_claim);
-        let rel_type = Self::relationship_type_to_str(relationship.relationship_type);
-        
-        self.conn.execute(
-            "INSERT INTO relationships (from_claim_id, to_claim_id, relationship_type, strength, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5)
-             ON CONFLICT(from_claim_id, to_claim_id, relationship_type) DO UPDATE SET
-             strength = excluded.strength, created_at = excluded.created_at",
-            params![
-                &from_bytes,
-                &to_bytes,
-                rel_type,
-                relationship.strength,
-                relationship.created_at as i64,
-            ],
-        )?;
-        
+        let tx = self.conn.transaction()?;
+        StoreConnection::new(
+            &tx,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        ).add_relationship(&relationship)?;
+        tx.commit()?;
         Ok(())
     }
     
     fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, Self::Error> {
-        let id_bytes = Self::claim_id_to_bytes(id);
-        
-        let mut stmt = self.conn.prepare(
-            "SELECT from_claim_id, to_claim_id, relationship_type, strength, created_at
-             FROM relationships WHERE from_claim_id = ?1 OR to_claim_id = ?1"
-        )?;
+        self.connection().get_relationships(id)
+    }
+    
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> Result<Vec<Relationship>, Self::Error> {
+        self.connection().get_relationships_batch(ids)
+    }
+    
+    fn set_tier(&mut self, id: ClaimId, tier: Tier) -> Result<bool, Self::Error> {
+        let tx = self.conn.transaction()?;
+        let changed = StoreConnection::new(
+            &tx,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        ).set_tier(id, tier)?;
+        tx.commit()?;
+        Ok(changed)
+    }
+    
+    /// Also deletes the cached confidence, and tombstones the claim's embedding
+    /// in the vector index so it no longer appears in semantic search; call
+    /// `compact_vector_index` to reclaim it.
+    fn delete_claim(&mut self, id: ClaimId) -> Result<bool, Self::Error> {
+        let tx = self.conn.transaction()?;
+        let deleted = StoreConnection::new(
+            &tx,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        ).delete_claim(id)?;
+        tx.commit()?;
         
-        let relationships = stmt.query_map(params![&id_bytes], |row| {
-            let from_bytes: Vec<u8> = row.get(0)?;
-            let to_bytes: Vec<u8> = row.get(1)?;
-            let rel_type_str: String = row.get(2)?;
-            
-            let from_claim = Self::bytes_to_claim_id(&from_bytes)
-                .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
-                    0, rusqlite::types::Type::Blob, Box::new(e)
-                ))?;
-            
-            let to_claim = Self::bytes_to_claim_id(&to_bytes)
-                .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
-                    1, rusqlite::types::Type::Blob, Box::new(e)
-                ))?;
-            
-            let relationship_type = Self::str_to_relationship_type(&rel_type_str)
-                .map_err(|e| rusqlite::Error::FromSqlConversionFailure(
-                    2, rusqlite::types::Type::Text, Box::new(e)
-                ))?;
-            
-            Ok(Relationship {
-                from_claim,
-                to_claim,
-                relationship_type,
-                strength: row.get(3)?,
-                created_at: row.get::<_, i64>(4)? as u64,
-            })
-        })?.collect::<Result<Vec<_>, _>>()?;
+        if let Some(vector_index) = &self.vector_index {
+            vector_index.remove(id);
+        }
         
-        Ok(relationships)
+        Ok(deleted)
+    }
+    
+    fn get_provenance(&self, id: ClaimId) -> Result<Vec<ProvenanceEntry>, Self::Error> {
+        self.connection().get_provenance(id)
+    }
+    
+    fn get_provenance_batch(&self, ids: &[ClaimId]) -> Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error> {
+        self.connection().get_provenance_batch(ids)
+    }
+    
+    fn find_by_content_hash(&self, content_hash: &str) -> Result<Option<Claim>, Self::Error> {
+        match self.connection().find_id_by_content_hash(content_hash)? {
+            Some(id) => self.get_claim(id),
+            None => Ok(None),
+        }
+    }
+    
+    fn compact_indexes(&mut self, threshold: f64) -> Result<usize, Self::Error> {
+        self.compact_vector_index(threshold)
+    }
+    
+    fn events_since(&self, after: u64, limit: usize) -> Result<Vec<StoreEvent>, Self::Error> {
+        self.connection().events_since(after, limit)
+    }
+    
+    fn namespace_stats(&self, root: &str) -> Result<Vec<NamespaceStats>, Self::Error> {
+        self.connection().namespace_stats(root)
+    }
+    
+    fn move_namespace(&mut self, from: &str, to: &str) -> Result<usize, Self::Error> {
+        let tx = self.conn.transaction()?;
+        let moved = StoreConnection::new(
+            &tx,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        ).move_namespace(from, to)?;
+        tx.commit()?;
+        Ok(moved)
+    }
+    
+    fn add_alias(&mut self, alias: EntityAlias) -> Result<(), Self::Error> {
+        let tx = self.conn.transaction()?;
+        StoreConnection::new(
+            &tx,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        ).add_alias(alias)?;
+        tx.commit()?;
+        Ok(())
+    }
+    
+    fn retract_alias(&mut self, alias: &str) -> Result<bool, Self::Error> {
+        self.connection().retract_alias(alias)
+    }
+    
+    fn entity_aliases(&self, include_retracted: bool) -> Result<Vec<EntityAlias>, Self::Error> {
+        self.connection().entity_aliases(include_retracted)
+    }
+    
+    fn staleness_policy(&self) -> StalenessPolicy {
+        self.staleness.clone()
+    }
+    
+    /// Computes the claim's effective confidence and records it in the confidence cache
+    fn effective_confidence(&self, id: ClaimId, now: u64) -> Result<Option<ConfidenceInterval>, Self::Error> {
+        let Some(claim) = self.get_claim(id)? else {
+            return Ok(None);
+        };
+        let confidence = stored_claim_confidence(self, &claim, now)?;
+        self.connection().cache_confidence(id, confidence, now)?;
+        Ok(Some(confidence))
     }
 }
 
 impl SqliteStore {
+    /// Set the cosine similarity above which a new claim corroborates an existing one
+    ///
+    /// Only applies when vector search is enabled. Defaults to [`DEFAULT_DUPLICATE_THRESHOLD`];
+    /// a value above 1.0 limits corroboration to exact (content hash) duplicates.
+    pub fn set_duplicate_threshold(&mut self, threshold: f32) {
+        self.duplicate_threshold = threshold;
+    }
+    
+    /// Current semantic duplicate threshold
+    pub fn duplicate_threshold(&self) -> f32 {
+        self.duplicate_threshold
+    }
+    
+    /// Set the policy deciding when written claims go stale
+    ///
+    /// Claims inserted without a `stale_at` go stale their rule's TTL after
+    /// `created_at`, and corroboration pushes `stale_at` back by the same TTL.
+    /// Defaults to [`StalenessPolicy::default`].
+    pub fn set_staleness_policy(&mut self, policy: StalenessPolicy) {
+        self.staleness = policy;
+    }
+    
+    /// Operations over the store's connection, for single-statement operations
+    fn connection(&self) -> StoreConnection<'_> {
+        StoreConnection::new(
+            &self.conn,
+            self.vector_index.as_ref(),
+            self.embedding_model.as_deref(),
+            self.duplicate_threshold,
+        )
+    }
+    
+    /// Add a freshly written claim's embedding to the vector index
+    fn index_embedding(&self, claim_id: ClaimId, embedding: Option<Vec<f32>>) {
+        if let (Some(embedding), Some(vector_index)) = (embedding, &self.vector_index) {
+            // Add to vector index (ignore errors for now)
+            let _ = vector_index.add(claim_id, &embedding);
+        }
+    }
+    
+    
     /// Perform semantic search for claims similar to the given embedding
     ///
     /// Returns claims ordered by cosine similarity (descending).
@@ -443,4 +615,97 @@ impl SqliteStore {
         
         Ok(())
     }
+    
+    /// Rebuild the vector index if its tombstone ratio has reached `threshold`
+    ///
+    /// Returns the number of tombstoned entries reclaimed (0 if vector search
+    /// is disabled or the threshold was not reached).
+    pub fn compact_vector_index(&self, threshold: f64) -> Result<usize, StoreError> {
+        match &self.vector_index {
+            Some(vector_index) if vector_index.needs_compaction(threshold) => {
+                Ok(vector_index.compact())
+            }
+            _ => Ok(0),
+        }
+    }
+    
+    /// Get statistics about the vector index (element count, layers, memory, tombstones)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if vector search is not enabled
+    pub fn vector_index_stats(&self) -> Result<VectorIndexStats, StoreError> {
+        let vector_index = self.vector_index.as_ref()
+            .ok_or_else(|| StoreError::InvalidData(
+                "Vector search is not enabled for this store".to_string()
+            ))?;
+        
+        Ok(vector_index.stats())
+    }
+    
+    /// Compare HNSW results against brute-force cosine search on a sample
+    ///
+    /// See [`VectorIndex::recall_self_test`] for the sampling strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if vector search is not enabled or the index is too small
+    pub fn vector_recall_self_test(
+        &self,
+        sample_size: usize,
+        k: usize,
+        ef_search: usize,
+    ) -> Result<RecallReport, StoreError> {
+        let vector_index = self.vector_index.as_ref()
+            .ok_or_else(|| StoreError::InvalidData(
+                "Vector search is not enabled for this store".to_string()
+            ))?;
+        
+        vector_index.recall_self_test(sample_size, k, ef_search)
+            .map_err(|e| StoreError::InvalidData(format!("Recall self-test failed: {}", e)))
+    }
+    
+    /// Write an online backup of the store to the directory `dir`
+    ///
+    /// The directory is created if needed and must be empty. It receives a
+    /// copy of the database made with SQLite's backup API and, when vector
+    /// search is enabled, a snapshot of the HNSW index taken at the same point.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` if `dir` is not empty, or the underlying
+    /// database or filesystem error.
+    pub fn backup<P: AsRef<Path>>(&self, dir: P) -> Result<BackupManifest, StoreError> {
+        backup::write_backup(&self.conn, self.vector_index.as_ref(), self.embedding_model.as_deref(), dir.as_ref())
+    }
+    
+    /// Restore the backup in `backup_dir` to a new database at `path` and open it
+    ///
+    /// The backup is checked before anything is written: its schema must not
+    /// be newer than this build supports, and a vector snapshot must come from
+    /// the same embedding model and dimension the restored store will use.
+    /// Older schemas are migrated on open. If vector search is enabled but the
+    /// backup has no snapshot, every claim is embedded again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidBackup` or `SchemaTooNew` if the backup cannot be
+    /// restored, and `InvalidData` if `path` already exists.
+    pub fn restore<P: AsRef<Path>, Q: AsRef<Path>>(
+        backup_dir: P,
+        path: Q,
+        enable_vector_search: bool,
+        embedding_dimension: usize,
+    ) -> Result<Self, StoreError> {
+        let model = MockEmbeddingModel::new(embedding_dimension);
+        let expected: Option<&(dyn EmbeddingModel + Send + Sync)> = if enable_vector_search { Some(&model) } else { None };
+        let (_, snapshot) = backup::restore_database(backup_dir.as_ref(), path.as_ref(), expected)?;
+        
+        let store = Self::new(path, enable_vector_search, embedding_dimension)?;
+        if let (Some(vector_index), Some(embedding_model)) = (&store.vector_index, &store.embedding_model) {
+            backup::load_vectors(&store.conn, vector_index, embedding_model.as_ref(), snapshot)?;
+        }
+        
+        Ok(store)
+    }
 }
@@ -0,0 +1,337 @@
+//! In-memory reference implementation of [`ClaimStore`]
+//!
+//! [`InMemoryStore`] keeps everything in ordinary collections and follows the
+//! same rules as [`SqliteStore`](crate::SqliteStore): the same constraints on
+//! confidence, tier and relationship strength, corroboration of exact
+//! duplicates, staleness, result ordering, atomic batches, the event log and entity
+//! aliases. It has no vector index, so only exact (content hash) duplicates
+//! corroborate.
+//!
+//! Both stores pass the suite in [`conformance`](crate::conformance), which
+//! makes this a trustworthy fake for downstream tests.
+
+use boswell_domain::{alias, confidence_computation, namespace};
+use boswell_domain::traits::{AssertOutcome, BatchOutcome, ClaimBatch, ClaimQuery, ClaimStore};
+use boswell_domain::{
+    Claim, ClaimId, ConfidenceInterval, EntityAlias, EventKind, ProvenanceEntry, Relationship, StalenessPolicy, StoreEvent, Tier,
+};
+use std::collections::HashMap;
+
+use crate::entities::{self, EntityColumns};
+use crate::{events, StoreError};
+
+/// Claim store held entirely in memory
+///
+/// # Examples
+///
+/// ```
+/// use boswell_domain::traits::{ClaimQuery, ClaimStore};
+/// use boswell_store::InMemoryStore;
+///
+/// let store = InMemoryStore::new();
+/// assert!(store.query_claims(&ClaimQuery::default()).unwrap().is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStore {
+    claims: HashMap<ClaimId, StoredClaim>,
+    relationships: Vec<Relationship>,
+    events: Vec<StoreEvent>,
+    last_seq: u64,
+    aliases: Vec<EntityAlias>,
+    staleness: StalenessPolicy,
+}
+
+#[derive(Debug, Clone)]
+struct StoredClaim {
+    claim: Claim,
+    content_hash: String,
+    provenance: Vec<ProvenanceEntry>,
+}
+
+impl InMemoryStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the policy deciding when written claims go stale
+    ///
+    /// See [`SqliteStore::set_staleness_policy`](crate::SqliteStore::set_staleness_policy).
+    pub fn set_staleness_policy(&mut self, policy: StalenessPolicy) {
+        self.staleness = policy;
+    }
+    
+    /// Number of claims in the store
+    pub fn len(&self) -> usize {
+        self.claims.len()
+    }
+    
+    /// Whether the store holds no claims
+    pub fn is_empty(&self) -> bool {
+        self.claims.is_empty()
+    }
+    
+    /// Append an event, copying the claim's current namespace and tier onto it
+    fn record_event(&mut self, kind: EventKind) {
+        let claim = self.claims.get(&kind.claim_id()).map(|stored| &stored.claim);
+        let namespace = claim.map(|claim| claim.namespace.clone());
+        let tier = claim.map(|claim| claim.tier);
+        self.last_seq += 1;
+        self.events.push(StoreEvent {
+            seq: self.last_seq,
+            recorded_at: events::now() as u64,
+            namespace,
+            tier,
+            kind,
+        });
+    }
+    
+    fn confidence_interval(bounds: (f64, f64)) -> Result<ConfidenceInterval, StoreError> {
+        ConfidenceInterval::try_new(bounds.0, bounds.1)
+            .map_err(|e| StoreError::InvalidData(format!("Invalid confidence interval: {}", e)))
+    }
+    
+    /// The oldest claim (lowest ID) with the given content hash
+    fn find_id_by_content_hash(&self, content_hash: &str) -> Option<ClaimId> {
+        self.claims.values()
+            .filter(|stored| stored.content_hash == content_hash)
+            .map(|stored| stored.claim.id)
+            .min()
+    }
+}
+
+impl ClaimStore for InMemoryStore {
+    type Error = StoreError;
+    
+    fn assert_claim(&mut self, claim: Claim) -> Result<AssertOutcome, Self::Error> {
+        self.assert_claim_with_provenance(claim, Vec::new())
+    }
+    
+    fn assert_claim_with_provenance(
+        &mut self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, Self::Error> {
+        if self.claims.contains_key(&claim.id) {
+            return Err(StoreError::Duplicate);
+        }
+        
+        // Every assertion leaves a provenance trail, even when the caller gave none
+        let provenance = if provenance.is_empty() {
+            vec![ProvenanceEntry::new("unknown".to_string(), claim.created_at, "assert".to_string())]
+        } else {
+            provenance
+        };
+        
+        let incoming = Self::confidence_interval(claim.confidence)?;
+        
+        let content_hash = claim.content_hash();
+        if let Some(existing_id) = self.find_id_by_content_hash(&content_hash) {
+            let existing = self.claims.get_mut(&existing_id).expect("hash lookup returned a stored claim");
+            let combined = confidence_computation::corroborate(
+                Self::confidence_interval(existing.claim.confidence)?,
+                incoming,
+            );
+            existing.claim.confidence = (combined.lower, combined.upper);
+            existing.claim.stale_at = self.staleness.stale_at_on_corroboration(&existing.claim, claim.created_at);
+            existing.provenance.extend(provenance);
+            self.record_event(EventKind::Corroborated(existing_id));
+            return Ok(AssertOutcome::Corroborated(existing_id));
+        }
+        
+        let id = claim.id;
+        let claim = Claim { stale_at: self.staleness.stale_at_on_insert(&claim), ..claim };
+        self.claims.insert(id, StoredClaim { claim, content_hash, provenance });
+        self.record_event(EventKind::Asserted(id));
+        Ok(AssertOutcome::Inserted(id))
+    }
+    
+    fn assert_batch(&mut self, batch: ClaimBatch) -> Result<BatchOutcome<Self::Error>, Self::Error> {
+        let mut outcome = BatchOutcome::default();
+        // Restored if an all-or-nothing batch fails part way
+        let snapshot = batch.all_or_nothing.then(|| self.clone());
+        let mut resolved_ids = HashMap::new();
+        
+        for (index, item) in batch.claims.into_iter().enumerate() {
+            let asserted_id = item.claim.id;
+            match self.assert_claim_with_provenance(item.claim, item.provenance) {
+                Ok(result) => {
+                    resolved_ids.insert(asserted_id, result.claim_id());
+                    outcome.claims.push(Ok(result));
+                }
+                Err(e) => match snapshot {
+                    Some(snapshot) => {
+                        *self = snapshot;
+                        return Err(StoreError::BatchAborted(format!("claim {}: {}", index, e)));
+                    }
+                    None => outcome.claims.push(Err(e)),
+                },
+            }
+        }
+        
+        for (index, mut relationship) in batch.relationships.into_iter().enumerate() {
+            relationship.from_claim = *resolved_ids.get(&relationship.from_claim).unwrap_or(&relationship.from_claim);
+            relationship.to_claim = *resolved_ids.get(&relationship.to_claim).unwrap_or(&relationship.to_claim);
+            
+            match self.add_relationship(relationship) {
+                Ok(()) => outcome.relationships.push(Ok(())),
+                Err(e) => match snapshot {
+                    Some(snapshot) => {
+                        *self = snapshot;
+                        return Err(StoreError::BatchAborted(format!("relationship {}: {}", index, e)));
+                    }
+                    None => outcome.relationships.push(Err(e)),
+                },
+            }
+        }
+        
+        Ok(outcome)
+    }
+    
+    fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
+        Ok(self.claims.get(&id).map(|stored| stored.claim.clone()))
+    }
+    
+    fn get_provenance(&self, id: ClaimId) -> Result<Vec<ProvenanceEntry>, Self::Error> {
+        Ok(self.claims.get(&id).map(|stored| stored.provenance.clone()).unwrap_or_default())
+    }
+    
+    fn find_by_content_hash(&self, content_hash: &str) -> Result<Option<Claim>, Self::Error> {
+        Ok(self.find_id_by_content_hash(content_hash)
+            .map(|id| self.claims[&id].claim.clone()))
+    }
+    
+    fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
+        let mut claims: Vec<Claim> = self.claims.values()
+            .map(|stored| &stored.claim)
+            .filter(|claim| query.namespace.as_ref().is_none_or(|ns| namespace::in_subtree(&claim.namespace, ns)))
+            .filter(|claim| query.tier.is_none_or(|tier| claim.tier == tier))
+            .filter(|claim| query.min_confidence.is_none_or(|min| claim.confidence.0 >= min))
+            .filter(|claim| EntityColumns::of_claim(claim).matches(query))
+            .filter(|claim| query.after.is_none_or(|after| (claim.created_at, claim.id) > after))
+            .cloned()
+            .collect();
+        
+        claims.sort_by_key(|claim| (claim.created_at, claim.id));
+        if let Some(limit) = query.limit {
+            claims.truncate(limit);
+        }
+        
+        Ok(claims)
+    }
+    
+    fn add_relationship(&mut self, relationship: Relationship) -> Result<(), Self::Error> {
+        if !(0.0..=1.0).contains(&relationship.strength) {
+            return Err(StoreError::InvalidData(format!(
+                "Invalid relationship strength {}", relationship.strength
+            )));
+        }
+        
+        let existing = self.relationships.iter_mut().find(|r| {
+            r.from_claim == relationship.from_claim
+                && r.to_claim == relationship.to_claim
+                && r.relationship_type == relationship.relationship_type
+        });
+        
+        let event = EventKind::RelationshipAdded {
+            from_claim: relationship.from_claim,
+            to_claim: relationship.to_claim,
+            relationship_type: relationship.relationship_type,
+        };
+        match existing {
+            Some(existing) => {
+                existing.strength = relationship.strength;
+                existing.created_at = relationship.created_at;
+            }
+            None => self.relationships.push(relationship),
+        }
+        self.record_event(event);
+        
+        Ok(())
+    }
+    
+    fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, Self::Error> {
+        Ok(self.relationships.iter()
+            .filter(|r| r.from_claim == id || r.to_claim == id)
+            .cloned()
+            .collect())
+    }
+    
+    fn set_tier(&mut self, id: ClaimId, tier: Tier) -> Result<bool, Self::Error> {
+        let stored = self.claims.get_mut(&id)
+            .ok_or_else(|| StoreError::NotFound(id.to_string()))?;
+        let Some(event) = EventKind::tier_change(id, stored.claim.tier, tier) else {
+            return Ok(false);
+        };
+        stored.claim.tier = tier;
+        self.record_event(event);
+        Ok(true)
+    }
+    
+    fn delete_claim(&mut self, id: ClaimId) -> Result<bool, Self::Error> {
+        if !self.claims.contains_key(&id) {
+            return Ok(false);
+        }
+        self.record_event(EventKind::Evicted(id));
+        self.relationships.retain(|r| r.from_claim != id && r.to_claim != id);
+        self.claims.remove(&id);
+        Ok(true)
+    }
+    
+    fn events_since(&self, after: u64, limit: usize) -> Result<Vec<StoreEvent>, Self::Error> {
+        Ok(self.events.iter()
+            .filter(|event| event.seq > after)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+    
+    fn move_namespace(&mut self, from: &str, to: &str) -> Result<usize, Self::Error> {
+        namespace::check_move(from, to).map_err(StoreError::InvalidData)?;
+        if self.claims.values().any(|stored| namespace::in_subtree(&stored.claim.namespace, to)) {
+            return Err(StoreError::NamespaceOccupied(to.to_string()));
+        }
+        
+        let mut moved = Vec::new();
+        for stored in self.claims.values_mut() {
+            if let Some(rebased) = namespace::rebase(&stored.claim.namespace, from, to) {
+                stored.claim.namespace = rebased;
+                stored.content_hash = stored.claim.content_hash();
+                moved.push(stored.claim.id);
+            }
+        }
+        for &id in &moved {
+            self.record_event(EventKind::Moved(id));
+        }
+        Ok(moved.len())
+    }
+    
+    fn add_alias(&mut self, alias: EntityAlias) -> Result<(), Self::Error> {
+        let active: Vec<EntityAlias> = self.aliases.iter().filter(|a| a.is_active()).cloned().collect();
+        let alias = entities::check_alias(alias, &active)?;
+        self.aliases.push(alias);
+        Ok(())
+    }
+    
+    fn retract_alias(&mut self, alias: &str) -> Result<bool, Self::Error> {
+        let alias = alias::normalize(alias);
+        match self.aliases.iter_mut().find(|record| record.is_active() && record.alias == alias) {
+            Some(record) => {
+                record.retracted_at = Some(events::now() as u64);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+    
+    fn entity_aliases(&self, include_retracted: bool) -> Result<Vec<EntityAlias>, Self::Error> {
+        Ok(self.aliases.iter()
+            .filter(|record| include_retracted || record.is_active())
+            .cloned()
+            .collect())
+    }
+    
+    fn staleness_policy(&self) -> StalenessPolicy {
+        self.staleness.clone()
+    }
+}
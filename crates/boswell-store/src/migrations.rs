@@ -0,0 +1,343 @@
+//! Schema migrations for the SQLite store
+//!
+//! The applied version is recorded in `schema_info`, one row per migration.
+//! On open, every migration newer than the recorded version runs in order,
+//! each in its own transaction together with its `schema_info` row, so a
+//! failed migration leaves the database at the previous version.
+//!
+//! Databases written by a newer Boswell (recorded version above
+//! [`SCHEMA_VERSION`]) are refused rather than opened with an unknown layout.
+//!
+//! # Adding a migration
+//!
+//! Append a `Migration` to `MIGRATIONS` with the next version number and
+//! bump [`SCHEMA_VERSION`]. Never edit a migration that has been released.
+
+use boswell_domain::Claim;
+use rusqlite::{params, Connection, Transaction};
+
+use crate::entities::EntityColumns;
+use crate::StoreError;
+
+/// Schema version this build of the store reads and writes
+pub const SCHEMA_VERSION: u32 = 7;
+
+/// A single schema migration
+struct Migration {
+    /// Version the database is at once this migration is applied
+    version: u32,
+    
+    /// Recorded in `schema_info.description`
+    description: &'static str,
+    
+    /// Applies the migration inside the given transaction
+    apply: fn(&Transaction) -> Result<(), StoreError>,
+}
+
+/// All migrations, in version order
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Initial schema with claims, relationships, provenance, and confidence cache",
+        apply: initial_schema,
+    },
+    Migration {
+        version: 2,
+        description: "Backfill normalized content hashes for exact duplicate detection",
+        apply: backfill_content_hashes,
+    },
+    Migration {
+        version: 3,
+        description: "Append-only event log of store mutations",
+        apply: event_log,
+    },
+    Migration {
+        version: 4,
+        description: "Index claims by subject and object entity kind and literal value",
+        apply: entity_columns,
+    },
+    Migration {
+        version: 5,
+        description: "Entity aliases with retractable merges",
+        apply: entity_aliases,
+    },
+    Migration {
+        version: 6,
+        description: "Record each event's claim namespace and tier",
+        apply: event_claim_columns,
+    },
+    Migration {
+        version: 7,
+        description: "Log namespace moves as events",
+        apply: moved_events,
+    },
+];
+
+/// Version recorded in `schema_info`, or 0 for a database that has never been migrated
+pub fn current_version(conn: &Connection) -> Result<u32, StoreError> {
+    let has_schema_info: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'schema_info')",
+        [],
+        |row| row.get(0),
+    )?;
+    
+    if !has_schema_info {
+        return Ok(0);
+    }
+    
+    let version: Option<u32> = conn.query_row(
+        "SELECT MAX(version) FROM schema_info",
+        [],
+        |row| row.get(0),
+    )?;
+    
+    Ok(version.unwrap_or(0))
+}
+
+/// Bring the database up to [`SCHEMA_VERSION`]
+///
+/// Returns the number of migrations applied.
+///
+/// # Errors
+///
+/// Returns `SchemaTooNew` if the database was written by a newer version,
+/// or the error of the first migration that fails.
+pub fn migrate(conn: &mut Connection) -> Result<usize, StoreError> {
+    let found = current_version(conn)?;
+    if found > SCHEMA_VERSION {
+        return Err(StoreError::SchemaTooNew { found, supported: SCHEMA_VERSION });
+    }
+    
+    let pending: Vec<&Migration> = MIGRATIONS.iter()
+        .filter(|migration| migration.version > found)
+        .collect();
+    
+    for migration in &pending {
+        let tx = conn.transaction()?;
+        (migration.apply)(&tx)?;
+        tx.execute(
+            "INSERT INTO schema_info (version, applied_at, description)
+             VALUES (?1, strftime('%s', 'now') * 1000, ?2)",
+            params![migration.version, migration.description],
+        )?;
+        tx.commit()?;
+    }
+    
+    Ok(pending.len())
+}
+
+/// Version 1: tables and indexes from `schema.sql`
+fn initial_schema(tx: &Transaction) -> Result<(), StoreError> {
+    tx.execute_batch(include_str!("schema.sql"))?;
+    Ok(())
+}
+
+/// Version 2: fill in `content_hash` for claims written before hashes were computed on insert
+fn backfill_content_hashes(tx: &Transaction) -> Result<(), StoreError> {
+    let mut select = tx.prepare(
+        "SELECT id, namespace, subject, predicate, object FROM claims WHERE content_hash IS NULL"
+    )?;
+    let mut update = tx.prepare("UPDATE claims SET content_hash = ?1 WHERE id = ?2")?;
+    
+    let rows = select.query_map([], |row| {
+        let id: Vec<u8> = row.get(0)?;
+        let namespace: String = row.get(1)?;
+        let subject: String = row.get(2)?;
+        let predicate: String = row.get(3)?;
+        let object: String = row.get(4)?;
+        Ok((id, Claim::hash_content(&namespace, &subject, &predicate, &object)))
+    })?.collect::<Result<Vec<_>, _>>()?;
+    
+    for (id, hash) in rows {
+        update.execute(params![hash, id])?;
+    }
+    
+    Ok(())
+}
+
+/// Version 3: the `events` table read by `ClaimStore::events_since`
+///
+/// `AUTOINCREMENT` keeps sequence numbers increasing even if the newest
+/// events are ever removed. Relationship events carry the other claim and the
+/// relationship type; promotions and demotions carry both tiers.
+fn event_log(tx: &Transaction) -> Result<(), StoreError> {
+    tx.execute_batch(
+        "CREATE TABLE events (
+            seq INTEGER PRIMARY KEY AUTOINCREMENT,
+            recorded_at INTEGER NOT NULL,
+            kind TEXT NOT NULL CHECK (kind IN
+                ('asserted', 'corroborated', 'promoted', 'demoted', 'evicted', 'relationship_added')),
+            claim_id BLOB NOT NULL,
+            related_claim_id BLOB,
+            relationship_type TEXT,
+            from_tier TEXT,
+            to_tier TEXT
+        );
+        CREATE INDEX idx_events_claim ON events(claim_id);"
+    )?;
+    Ok(())
+}
+
+/// Version 4: entity kind and literal value columns, backfilled from existing claims
+///
+/// See the `entities` module for how the columns are derived.
+fn entity_columns(tx: &Transaction) -> Result<(), StoreError> {
+    tx.execute_batch(
+        "ALTER TABLE claims ADD COLUMN subject_kind TEXT;
+        ALTER TABLE claims ADD COLUMN object_kind TEXT;
+        ALTER TABLE claims ADD COLUMN object_value REAL;
+        CREATE INDEX idx_claims_subject_kind ON claims(subject_kind);
+        CREATE INDEX idx_claims_object_kind_value ON claims(object_kind, object_value);"
+    )?;
+    
+    let mut select = tx.prepare("SELECT id, subject, object FROM claims")?;
+    let mut update = tx.prepare(
+        "UPDATE claims SET subject_kind = ?1, object_kind = ?2, object_value = ?3 WHERE id = ?4"
+    )?;
+    
+    let rows = select.query_map([], |row| {
+        let id: Vec<u8> = row.get(0)?;
+        let subject: String = row.get(1)?;
+        let object: String = row.get(2)?;
+        Ok((id, EntityColumns::of(&subject, &object)))
+    })?.collect::<Result<Vec<_>, _>>()?;
+    
+    for (id, columns) in rows {
+        update.execute(params![columns.subject_kind, columns.object_kind, columns.object_value, id])?;
+    }
+    
+    Ok(())
+}
+
+/// Version 5: the `entity_aliases` table behind `ClaimStore::add_alias`
+///
+/// Retracted merges keep their rows; the partial unique index allows one
+/// active merge per alias.
+fn entity_aliases(tx: &Transaction) -> Result<(), StoreError> {
+    tx.execute_batch(
+        "CREATE TABLE entity_aliases (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            alias TEXT NOT NULL,
+            canonical TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            retracted_at INTEGER
+        );
+        CREATE UNIQUE INDEX idx_entity_aliases_active ON entity_aliases(alias) WHERE retracted_at IS NULL;
+        CREATE INDEX idx_entity_aliases_canonical ON entity_aliases(canonical);"
+    )?;
+    Ok(())
+}
+
+/// Version 6: the claim's `namespace` and `tier` on each event
+///
+/// Existing events are backfilled from their claim where it still exists;
+/// promotions and demotions take the tier they moved to.
+fn event_claim_columns(tx: &Transaction) -> Result<(), StoreError> {
+    tx.execute_batch(
+        "ALTER TABLE events ADD COLUMN namespace TEXT;
+        ALTER TABLE events ADD COLUMN tier TEXT;
+        UPDATE events SET
+            namespace = (SELECT namespace FROM claims WHERE claims.id = events.claim_id),
+            tier = COALESCE(to_tier, (SELECT tier FROM claims WHERE claims.id = events.claim_id));"
+    )?;
+    Ok(())
+}
+
+/// Version 7: allow `moved` events in the log
+///
+/// SQLite cannot alter a `CHECK` constraint, so the table is rebuilt. The
+/// `AUTOINCREMENT` counter is carried over before the rows are copied, so
+/// sequence numbers keep increasing past events that were ever removed.
+fn moved_events(tx: &Transaction) -> Result<(), StoreError> {
+    tx.execute_batch(
+        "CREATE TABLE events_new (
+            seq INTEGER PRIMARY KEY AUTOINCREMENT,
+            recorded_at INTEGER NOT NULL,
+            kind TEXT NOT NULL CHECK (kind IN
+                ('asserted', 'corroborated', 'promoted', 'demoted', 'evicted', 'relationship_added', 'moved')),
+            claim_id BLOB NOT NULL,
+            related_claim_id BLOB,
+            relationship_type TEXT,
+            from_tier TEXT,
+            to_tier TEXT,
+            namespace TEXT,
+            tier TEXT
+        );
+        INSERT INTO sqlite_sequence (name, seq) SELECT 'events_new', seq FROM sqlite_sequence WHERE name = 'events';
+        INSERT INTO events_new
+            SELECT seq, recorded_at, kind, claim_id, related_claim_id, relationship_type, from_tier, to_tier, namespace, tier
+            FROM events;
+        DROP TABLE events;
+        ALTER TABLE events_new RENAME TO events;
+        CREATE INDEX idx_events_claim ON events(claim_id);"
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_migrations_are_ordered() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version as usize, index + 1);
+        }
+        assert_eq!(MIGRATIONS.last().unwrap().version, SCHEMA_VERSION);
+    }
+    
+    #[test]
+    fn test_migrate_fresh_database_is_idempotent() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        assert_eq!(current_version(&conn).unwrap(), 0);
+        
+        assert_eq!(migrate(&mut conn).unwrap(), MIGRATIONS.len());
+        assert_eq!(current_version(&conn).unwrap(), SCHEMA_VERSION);
+        
+        assert_eq!(migrate(&mut conn).unwrap(), 0);
+        let rows: u32 = conn.query_row("SELECT COUNT(*) FROM schema_info", [], |row| row.get(0)).unwrap();
+        assert_eq!(rows, SCHEMA_VERSION);
+    }
+    
+    #[test]
+    fn test_moved_events_keeps_the_event_log() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let tx = conn.transaction().unwrap();
+        for migration in &MIGRATIONS[..6] {
+            (migration.apply)(&tx).unwrap();
+        }
+        tx.execute_batch(
+            "INSERT INTO events (recorded_at, kind, claim_id, namespace, tier)
+                VALUES (1, 'asserted', x'01', 'acme', 'task'), (2, 'evicted', x'01', 'acme', 'task');
+            DELETE FROM events WHERE seq = 2;"
+        ).unwrap();
+        
+        moved_events(&tx).unwrap();
+        tx.execute("INSERT INTO events (recorded_at, kind, claim_id) VALUES (3, 'moved', x'01')", []).unwrap();
+        
+        let mut stmt = tx.prepare("SELECT seq, kind, namespace FROM events ORDER BY seq").unwrap();
+        let rows: Vec<(i64, String, Option<String>)> = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        // Sequence numbers are not reused after the rebuild
+        assert_eq!(rows, vec![(1, "asserted".to_string(), Some("acme".to_string())), (3, "moved".to_string(), None)]);
+    }
+    
+    #[test]
+    fn test_refuses_newer_schema() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+        conn.execute(
+            "INSERT INTO schema_info (version, applied_at, description) VALUES (?1, 0, 'future')",
+            params![SCHEMA_VERSION + 1],
+        ).unwrap();
+        
+        let result = migrate(&mut conn);
+        assert!(matches!(
+            result,
+            Err(StoreError::SchemaTooNew { found, supported }) if found == SCHEMA_VERSION + 1 && supported == SCHEMA_VERSION
+        ));
+    }
+}
@@ -0,0 +1,777 @@
+//! Pooled SQLite store for concurrent access
+//!
+//! [`PooledStore`] opens the database in WAL mode with one writer connection
+//! and a pool of read-only connections. Writers are serialized on the writer
+//! connection; readers never wait for them, because WAL lets a reader see the
+//! last committed state while a write transaction is open. Handles are cheap
+//! to clone and share one pool, so a server can give every request its own.
+
+use boswell_domain::traits::{stored_claim_confidence, AssertOutcome, BatchOutcome, ClaimBatch, ClaimQuery, ClaimStore};
+use boswell_domain::{Claim, ClaimId, ConfidenceInterval, EntityAlias, NamespaceStats, ProvenanceEntry, Relationship, StalenessPolicy, StoreEvent, Tier};
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError, RwLock};
+use std::time::Duration;
+
+use crate::backup::{self, BackupManifest};
+use crate::connection::StoreConnection;
+use crate::embedding::{EmbeddingModel, MockEmbeddingModel};
+use crate::vector_index::{VectorIndex, VectorIndexConfig, VectorIndexStats};
+use crate::{migrations, StoreError, DEFAULT_DUPLICATE_THRESHOLD};
+
+/// Connection pool, pragma and vector index settings for a [`PooledStore`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// Maximum number of read connections; readers beyond this wait for one to be returned
+    pub max_readers: usize,
+    
+    /// How long a connection retries when the database is locked before failing with `SQLITE_BUSY`
+    pub busy_timeout: Duration,
+    
+    /// Page cache per connection, in KiB
+    pub cache_size_kib: u32,
+    
+    /// Bytes of the database file to memory-map (0 disables mmap)
+    pub mmap_size: u64,
+    
+    /// HNSW parameters, used when vector search is enabled
+    pub vector_index: VectorIndexConfig,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_readers: 8,
+            busy_timeout: Duration::from_secs(5),
+            cache_size_kib: 64 * 1024,
+            mmap_size: 256 * 1024 * 1024,
+            vector_index: VectorIndexConfig::default(),
+        }
+    }
+}
+
+/// Thread-safe SQLite store with a single writer and concurrent readers
+///
+/// # Examples
+///
+/// ```no_run
+/// use boswell_store::{PoolConfig, PooledStore};
+///
+/// let store = PooledStore::with_config("boswell.db", false, 0, PoolConfig::default()).unwrap();
+///
+/// // Each clone shares the same pool
+/// let reader = store.clone();
+/// std::thread::spawn(move || {
+///     let _ = reader.query(&Default::default());
+/// });
+/// ```
+#[derive(Clone)]
+pub struct PooledStore {
+    shared: Arc<Shared>,
+}
+
+/// State shared by every clone of a [`PooledStore`]
+struct Shared {
+    writer: Mutex<Connection>,
+    readers: ConnectionPool<Connection>,
+    vector_index: Option<VectorIndex>,
+    embedding_model: Option<Box<dyn EmbeddingModel + Send + Sync>>,
+    
+    /// `f32` bits, so the threshold can be changed through a shared handle
+    duplicate_threshold: AtomicU32,
+    
+    staleness: RwLock<StalenessPolicy>,
+}
+
+impl PooledStore {
+    /// Open a pooled store with the default [`PoolConfig`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` for an in-memory path, since separate connections
+    /// to `:memory:` would each see a different database.
+    pub fn new<P: AsRef<Path>>(path: P, enable_vector_search: bool, embedding_dimension: usize) -> Result<Self, StoreError> {
+        Self::with_config(path, enable_vector_search, embedding_dimension, PoolConfig::default())
+    }
+    
+    /// Open a pooled store with custom pool settings
+    ///
+    /// Switches the database to WAL mode and runs any pending migrations on
+    /// the writer connection. Read connections are opened on demand.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` if `max_readers` is zero or, with vector search
+    /// enabled, if the HNSW parameters are out of range.
+    pub fn with_config<P: AsRef<Path>>(
+        path: P,
+        enable_vector_search: bool,
+        embedding_dimension: usize,
+        config: PoolConfig,
+    ) -> Result<Self, StoreError> {
+        let path = path.as_ref().to_path_buf();
+        if path.as_os_str().is_empty() || path == Path::new(":memory:") {
+            return Err(StoreError::InvalidData(
+                "A pooled store needs a database file, not an in-memory database".to_string()
+            ));
+        }
+        if config.max_readers == 0 {
+            return Err(StoreError::InvalidData("max_readers must be at least 1".to_string()));
+        }
+        
+        let vector_index = if enable_vector_search {
+            Some(VectorIndex::with_config(embedding_dimension, config.vector_index)
+                .map_err(|e| StoreError::InvalidData(e.to_string()))?)
+        } else {
+            None
+        };
+        
+        let mut writer = Connection::open(&path)?;
+        configure(&writer, &config)?;
+        let journal_mode: String = writer.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get(0))?;
+        if !journal_mode.eq_ignore_ascii_case("wal") {
+            return Err(StoreError::InvalidData(format!("Could not enable WAL mode (journal_mode = {})", journal_mode)));
+        }
+        migrations::migrate(&mut writer)?;
+        
+        let embedding_model = vector_index.as_ref().map(|_| {
+            Box::new(MockEmbeddingModel::new(embedding_dimension)) as Box<dyn EmbeddingModel + Send + Sync>
+        });
+        
+        Ok(Self {
+            shared: Arc::new(Shared {
+                writer: Mutex::new(writer),
+                readers: ConnectionPool::new(config.max_readers, move || open_reader(&path, &config)),
+                vector_index,
+                embedding_model,
+                duplicate_threshold: AtomicU32::new(DEFAULT_DUPLICATE_THRESHOLD.to_bits()),
+                staleness: RwLock::new(StalenessPolicy::default()),
+            }),
+        })
+    }
+    
+    /// Set the cosine similarity above which a new claim corroborates an existing one
+    ///
+    /// Applies to every clone of this store. See [`SqliteStore::set_duplicate_threshold`](crate::SqliteStore::set_duplicate_threshold).
+    pub fn set_duplicate_threshold(&self, threshold: f32) {
+        self.shared.duplicate_threshold.store(threshold.to_bits(), Ordering::Relaxed);
+    }
+    
+    /// Current semantic duplicate threshold
+    pub fn duplicate_threshold(&self) -> f32 {
+        f32::from_bits(self.shared.duplicate_threshold.load(Ordering::Relaxed))
+    }
+    
+    /// Set the policy deciding when written claims go stale
+    ///
+    /// Applies to every clone of this store. See [`SqliteStore::set_staleness_policy`](crate::SqliteStore::set_staleness_policy).
+    pub fn set_staleness_policy(&self, policy: StalenessPolicy) {
+        *self.shared.staleness.write().unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+    
+    /// Number of read connections currently open (idle or checked out)
+    pub fn open_readers(&self) -> usize {
+        self.shared.readers.open_connections()
+    }
+    
+    /// Insert a claim, or corroborate the claim it duplicates
+    pub fn assert_with_provenance(
+        &self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, StoreError> {
+        let mut writer = self.writer();
+        let tx = writer.transaction()?;
+        let written = self.connection(&tx).assert_claim(&claim, provenance, &self.staleness_policy())?;
+        tx.commit()?;
+        
+        // Still holding the writer, so the next write's duplicate check sees this embedding
+        self.index_embedding(written.outcome.claim_id(), written.embedding);
+        Ok(written.outcome)
+    }
+    
+    /// Write a batch of claims and relationships in one transaction
+    pub fn assert_batch(&self, batch: ClaimBatch) -> Result<BatchOutcome<StoreError>, StoreError> {
+        let mut writer = self.writer();
+        let tx = writer.transaction()?;
+        let (outcome, embeddings) = self.connection(&tx).assert_batch(batch, &self.staleness_policy())?;
+        tx.commit()?;
+        
+        for (claim_id, embedding) in embeddings {
+            self.index_embedding(claim_id, embedding);
+        }
+        
+        Ok(outcome)
+    }
+    
+    /// Insert or update a relationship
+    pub fn add_relationship(&self, relationship: &Relationship) -> Result<(), StoreError> {
+        let mut writer = self.writer();
+        let tx = writer.transaction()?;
+        self.connection(&tx).add_relationship(relationship)?;
+        tx.commit()?;
+        Ok(())
+    }
+    
+    /// Permanently delete a claim along with its provenance, relationships and cached confidence
+    ///
+    /// Records an eviction event. Returns `true` if the claim existed.
+    pub fn delete_claim(&self, claim_id: ClaimId) -> Result<bool, StoreError> {
+        let mut writer = self.writer();
+        let tx = writer.transaction()?;
+        let deleted = self.connection(&tx).delete_claim(claim_id)?;
+        tx.commit()?;
+        
+        if let Some(vector_index) = &self.shared.vector_index {
+            vector_index.remove(claim_id);
+        }
+        
+        Ok(deleted)
+    }
+    
+    /// Move a claim to another tier, recording a promotion or demotion event
+    ///
+    /// See [`ClaimStore::set_tier`].
+    pub fn set_tier(&self, claim_id: ClaimId, tier: Tier) -> Result<bool, StoreError> {
+        let mut writer = self.writer();
+        let tx = writer.transaction()?;
+        let changed = self.connection(&tx).set_tier(claim_id, tier)?;
+        tx.commit()?;
+        Ok(changed)
+    }
+    
+    /// Move a namespace subtree, recomputing content hashes
+    ///
+    /// See [`ClaimStore::move_namespace`].
+    pub fn move_namespace(&self, from: &str, to: &str) -> Result<usize, StoreError> {
+        let mut writer = self.writer();
+        let tx = writer.transaction()?;
+        let moved = self.connection(&tx).move_namespace(from, to)?;
+        tx.commit()?;
+        Ok(moved)
+    }
+    
+    /// Record that two entities are the same
+    ///
+    /// See [`ClaimStore::add_alias`].
+    pub fn add_alias(&self, alias: EntityAlias) -> Result<(), StoreError> {
+        let mut writer = self.writer();
+        let tx = writer.transaction()?;
+        self.connection(&tx).add_alias(alias)?;
+        tx.commit()?;
+        Ok(())
+    }
+    
+    /// Undo the active merge of `alias`, keeping its record
+    ///
+    /// See [`ClaimStore::retract_alias`].
+    pub fn retract_alias(&self, alias: &str) -> Result<bool, StoreError> {
+        let writer = self.writer();
+        self.connection(&writer).retract_alias(alias)
+    }
+    
+    /// Load a claim by ID
+    pub fn get(&self, id: ClaimId) -> Result<Option<Claim>, StoreError> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).get_claim(id)
+    }
+    
+    /// Claims matching the query's filters
+    pub fn query(&self, query: &ClaimQuery) -> Result<Vec<Claim>, StoreError> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).query_claims(query)
+    }
+    
+    /// Perform semantic search for claims similar to the given embedding
+    ///
+    /// See [`SqliteStore::semantic_search`](crate::SqliteStore::semantic_search).
+    pub fn semantic_search(
+        &self,
+        query_embedding: &[f32],
+        k: usize,
+        ef_search: usize,
+        min_similarity: f32,
+    ) -> Result<Vec<(Claim, f32)>, StoreError> {
+        let vector_index = self.vector_index()?;
+        let similar_ids = vector_index.search(query_embedding, k, ef_search)
+            .map_err(|e| StoreError::InvalidData(format!("Vector search failed: {}", e)))?;
+        
+        let reader = self.shared.readers.get()?;
+        let connection = self.connection(&reader);
+        let mut results = Vec::new();
+        
+        for (claim_id, similarity) in similar_ids {
+            if similarity < min_similarity {
+                continue;
+            }
+            
+            if let Some(claim) = connection.get_claim(claim_id)? {
+                results.push((claim, similarity));
+            }
+        }
+        
+        Ok(results)
+    }
+    
+    /// Rebuild the vector index if its tombstone ratio has reached `threshold`
+    pub fn compact_vector_index(&self, threshold: f64) -> Result<usize, StoreError> {
+        match &self.shared.vector_index {
+            Some(vector_index) if vector_index.needs_compaction(threshold) => {
+                Ok(vector_index.compact())
+            }
+            _ => Ok(0),
+        }
+    }
+    
+    /// Get statistics about the vector index
+    pub fn vector_index_stats(&self) -> Result<VectorIndexStats, StoreError> {
+        Ok(self.vector_index()?.stats())
+    }
+    
+    /// Write an online backup of the store to the directory `dir`
+    ///
+    /// Holds the writer for the duration, so the database copy and the vector
+    /// snapshot agree; readers carry on meanwhile. See
+    /// [`SqliteStore::backup`](crate::SqliteStore::backup) for the layout.
+    pub fn backup<P: AsRef<Path>>(&self, dir: P) -> Result<BackupManifest, StoreError> {
+        let writer = self.writer();
+        backup::write_backup(
+            &writer,
+            self.shared.vector_index.as_ref(),
+            self.shared.embedding_model.as_deref(),
+            dir.as_ref(),
+        )
+    }
+    
+    /// Restore the backup in `backup_dir` to a new database at `path` and open it
+    ///
+    /// See [`SqliteStore::restore`](crate::SqliteStore::restore) for the checks made.
+    pub fn restore<P: AsRef<Path>, Q: AsRef<Path>>(
+        backup_dir: P,
+        path: Q,
+        enable_vector_search: bool,
+        embedding_dimension: usize,
+        config: PoolConfig,
+    ) -> Result<Self, StoreError> {
+        let model = MockEmbeddingModel::new(embedding_dimension);
+        let expected: Option<&(dyn EmbeddingModel + Send + Sync)> = if enable_vector_search { Some(&model) } else { None };
+        let (_, snapshot) = backup::restore_database(backup_dir.as_ref(), path.as_ref(), expected)?;
+        
+        let store = Self::with_config(path, enable_vector_search, embedding_dimension, config)?;
+        if let (Some(vector_index), Some(embedding_model)) = (&store.shared.vector_index, &store.shared.embedding_model) {
+            backup::load_vectors(&store.writer(), vector_index, embedding_model.as_ref(), snapshot)?;
+        }
+        
+        Ok(store)
+    }
+    
+    fn vector_index(&self) -> Result<&VectorIndex, StoreError> {
+        self.shared.vector_index.as_ref()
+            .ok_or_else(|| StoreError::InvalidData(
+                "Vector search is not enabled for this store".to_string()
+            ))
+    }
+    
+    /// Lock the writer connection, recovering it if a previous writer panicked
+    ///
+    /// A panic mid-transaction drops the transaction, which rolls it back, so
+    /// the connection is safe to reuse.
+    fn writer(&self) -> MutexGuard<'_, Connection> {
+        self.shared.writer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    
+    fn connection<'a>(&'a self, conn: &'a Connection) -> StoreConnection<'a> {
+        StoreConnection::new(
+            conn,
+            self.shared.vector_index.as_ref(),
+            self.shared.embedding_model.as_deref(),
+            self.duplicate_threshold(),
+        )
+    }
+    
+    fn index_embedding(&self, claim_id: ClaimId, embedding: Option<Vec<f32>>) {
+        if let (Some(embedding), Some(vector_index)) = (embedding, &self.shared.vector_index) {
+            // Add to vector index (ignore errors for now)
+            let _ = vector_index.add(claim_id, &embedding);
+        }
+    }
+}
+
+impl ClaimStore for PooledStore {
+    type Error = StoreError;
+    
+    fn assert_claim(&mut self, claim: Claim) -> Result<AssertOutcome, Self::Error> {
+        self.assert_with_provenance(claim, Vec::new())
+    }
+    
+    fn assert_claim_with_provenance(
+        &mut self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, Self::Error> {
+        self.assert_with_provenance(claim, provenance)
+    }
+    
+    fn assert_batch(&mut self, batch: ClaimBatch) -> Result<BatchOutcome<Self::Error>, Self::Error> {
+        PooledStore::assert_batch(self, batch)
+    }
+    
+    fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
+        self.get(id)
+    }
+    
+    fn get_provenance(&self, id: ClaimId) -> Result<Vec<ProvenanceEntry>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).get_provenance(id)
+    }
+    
+    fn get_provenance_batch(&self, ids: &[ClaimId]) -> Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).get_provenance_batch(ids)
+    }
+    
+    fn find_by_content_hash(&self, content_hash: &str) -> Result<Option<Claim>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        let connection = self.connection(&reader);
+        match connection.find_id_by_content_hash(content_hash)? {
+            Some(id) => connection.get_claim(id),
+            None => Ok(None),
+        }
+    }
+    
+    fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
+        self.query(query)
+    }
+    
+    fn add_relationship(&mut self, relationship: Relationship) -> Result<(), Self::Error> {
+        PooledStore::add_relationship(self, &relationship)
+    }
+    
+    fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).get_relationships(id)
+    }
+    
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> Result<Vec<Relationship>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).get_relationships_batch(ids)
+    }
+    
+    fn set_tier(&mut self, id: ClaimId, tier: Tier) -> Result<bool, Self::Error> {
+        PooledStore::set_tier(self, id, tier)
+    }
+    
+    fn delete_claim(&mut self, id: ClaimId) -> Result<bool, Self::Error> {
+        PooledStore::delete_claim(self, id)
+    }
+    
+    fn compact_indexes(&mut self, threshold: f64) -> Result<usize, Self::Error> {
+        self.compact_vector_index(threshold)
+    }
+    
+    fn events_since(&self, after: u64, limit: usize) -> Result<Vec<StoreEvent>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).events_since(after, limit)
+    }
+    
+    fn namespace_stats(&self, root: &str) -> Result<Vec<NamespaceStats>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).namespace_stats(root)
+    }
+    
+    fn move_namespace(&mut self, from: &str, to: &str) -> Result<usize, Self::Error> {
+        PooledStore::move_namespace(self, from, to)
+    }
+    
+    fn add_alias(&mut self, alias: EntityAlias) -> Result<(), Self::Error> {
+        PooledStore::add_alias(self, alias)
+    }
+    
+    fn retract_alias(&mut self, alias: &str) -> Result<bool, Self::Error> {
+        PooledStore::retract_alias(self, alias)
+    }
+    
+    fn entity_aliases(&self, include_retracted: bool) -> Result<Vec<EntityAlias>, Self::Error> {
+        let reader = self.shared.readers.get()?;
+        self.connection(&reader).entity_aliases(include_retracted)
+    }
+    
+    fn staleness_policy(&self) -> StalenessPolicy {
+        self.shared.staleness.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+    
+    /// Computes the claim's effective confidence on a reader, then records it
+    /// in the confidence cache through the writer
+    fn effective_confidence(&self, id: ClaimId, now: u64) -> Result<Option<ConfidenceInterval>, Self::Error> {
+        let Some(claim) = self.get_claim(id)? else {
+            return Ok(None);
+        };
+        let confidence = stored_claim_confidence(self, &claim, now)?;
+        let writer = self.writer();
+        self.connection(&writer).cache_confidence(id, confidence, now)?;
+        Ok(Some(confidence))
+    }
+}
+
+/// Open a read-only connection to the database at `path`
+fn open_reader(path: &Path, config: &PoolConfig) -> Result<Connection, StoreError> {
+    let conn = Connection::open(path)?;
+    configure(&conn, config)?;
+    conn.pragma_update(None, "query_only", true)?;
+    Ok(conn)
+}
+
+/// Apply the per-connection pragmas shared by the writer and readers
+fn configure(conn: &Connection, config: &PoolConfig) -> Result<(), StoreError> {
+    conn.busy_timeout(config.busy_timeout)?;
+    conn.pragma_update(None, "synchronous", "NORMAL")?;
+    conn.pragma_update(None, "temp_store", "MEMORY")?;
+    // Negative cache_size is in KiB rather than pages
+    conn.pragma_update(None, "cache_size", -i64::from(config.cache_size_kib))?;
+    conn.pragma_update(None, "mmap_size", config.mmap_size as i64)?;
+    Ok(())
+}
+
+/// Opens a new connection for a [`ConnectionPool`]
+type OpenConnection<C> = Box<dyn Fn() -> Result<C, StoreError> + Send + Sync>;
+
+/// Connections opened lazily, up to a maximum, and reused once returned
+pub(crate) struct ConnectionPool<C> {
+    open: OpenConnection<C>,
+    is_broken: fn(&C) -> bool,
+    max_size: usize,
+    state: Mutex<PoolState<C>>,
+    returned: Condvar,
+}
+
+struct PoolState<C> {
+    idle: Vec<C>,
+    open: usize,
+}
+
+impl<C> ConnectionPool<C> {
+    /// Pool of at most `max_size` connections, each created by `open`
+    pub fn new(max_size: usize, open: impl Fn() -> Result<C, StoreError> + Send + Sync + 'static) -> Self {
+        Self {
+            open: Box::new(open),
+            is_broken: |_| false,
+            max_size,
+            state: Mutex::new(PoolState { idle: Vec::new(), open: 0 }),
+            returned: Condvar::new(),
+        }
+    }
+    
+    /// Close connections for which `is_broken` holds instead of returning them to the pool
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub fn with_broken_check(mut self, is_broken: fn(&C) -> bool) -> Self {
+        self.is_broken = is_broken;
+        self
+    }
+    
+    /// Number of connections currently open (idle or checked out)
+    pub fn open_connections(&self) -> usize {
+        self.lock().open
+    }
+    
+    fn lock(&self) -> MutexGuard<'_, PoolState<C>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    
+    /// Check out a connection, opening one if the pool has room or waiting for one otherwise
+    pub fn get(&self) -> Result<PooledConnection<'_, C>, StoreError> {
+        let mut state = self.lock();
+        loop {
+            if let Some(conn) = state.idle.pop() {
+                return Ok(PooledConnection { pool: self, conn: Some(conn) });
+            }
+            
+            if state.open < self.max_size {
+                state.open += 1;
+                drop(state);
+                
+                return match (self.open)() {
+                    Ok(conn) => Ok(PooledConnection { pool: self, conn: Some(conn) }),
+                    Err(e) => {
+                        self.lock().open -= 1;
+                        self.returned.notify_one();
+                        Err(e)
+                    }
+                };
+            }
+            
+            state = self.returned.wait(state).unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+/// A connection checked out of a [`ConnectionPool`], returned on drop
+pub(crate) struct PooledConnection<'a, C> {
+    pool: &'a ConnectionPool<C>,
+    conn: Option<C>,
+}
+
+impl<C> Deref for PooledConnection<'_, C> {
+    type Target = C;
+    
+    fn deref(&self) -> &C {
+        self.conn.as_ref().expect("connection is present until drop")
+    }
+}
+
+impl<C> DerefMut for PooledConnection<'_, C> {
+    fn deref_mut(&mut self) -> &mut C {
+        self.conn.as_mut().expect("connection is present until drop")
+    }
+}
+
+impl<C> Drop for PooledConnection<'_, C> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            let mut state = self.pool.lock();
+            if (self.pool.is_broken)(&conn) {
+                // Frees the slot so the next checkout opens a fresh connection
+                state.open -= 1;
+            } else {
+                state.idle.push(conn);
+            }
+            drop(state);
+            self.pool.returned.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+    use tempfile::TempDir;
+    
+    fn test_claim(subject: &str) -> Claim {
+        Claim {
+            id: ClaimId::new(),
+            namespace: "test".to_string(),
+            subject: subject.to_string(),
+            predicate: "knows".to_string(),
+            object: "Bob".to_string(),
+            confidence: (0.8, 0.9),
+            tier: Tier::Task,
+            created_at: 1000,
+            stale_at: None,
+        }
+    }
+    
+    #[test]
+    fn test_rejects_in_memory_database() {
+        assert!(matches!(PooledStore::new(":memory:", false, 0), Err(StoreError::InvalidData(_))));
+    }
+    
+    #[test]
+    fn test_rejects_invalid_vector_index_config() {
+        let dir = TempDir::new().unwrap();
+        let vector_index = VectorIndexConfig { m: 0, ..VectorIndexConfig::default() };
+        let config = PoolConfig { vector_index, ..PoolConfig::default() };
+        
+        let result = PooledStore::with_config(dir.path().join("boswell.db"), true, 8, config);
+        assert!(matches!(result, Err(StoreError::InvalidData(_))));
+        
+        // Without vector search the HNSW parameters are never used
+        assert!(PooledStore::with_config(dir.path().join("boswell.db"), false, 8, config).is_ok());
+    }
+    
+    #[test]
+    fn test_enables_wal_mode() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("boswell.db");
+        let _store = PooledStore::new(&path, false, 0).unwrap();
+        
+        // WAL is persistent, so a fresh connection sees it too
+        let mode: String = Connection::open(&path).unwrap()
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(mode, "wal");
+    }
+    
+    #[test]
+    fn test_readers_see_committed_writes() {
+        let dir = TempDir::new().unwrap();
+        let mut store = PooledStore::new(dir.path().join("boswell.db"), false, 0).unwrap();
+        
+        let claim = test_claim("Alice");
+        store.assert_claim(claim.clone()).unwrap();
+        
+        let reader = store.clone();
+        let found = std::thread::spawn(move || reader.get_claim(claim.id).unwrap())
+            .join()
+            .unwrap();
+        assert_eq!(found.unwrap().subject, "Alice");
+        assert_eq!(store.get_provenance(claim.id).unwrap().len(), 1);
+    }
+    
+    #[test]
+    fn test_readers_do_not_block_on_open_write() {
+        let dir = TempDir::new().unwrap();
+        let mut store = PooledStore::new(dir.path().join("boswell.db"), false, 0).unwrap();
+        let claim = test_claim("Alice");
+        store.assert_claim(claim.clone()).unwrap();
+        
+        // Hold a write transaction open on the writer while another thread reads
+        let writer = store.writer();
+        writer.execute_batch("BEGIN IMMEDIATE; DELETE FROM claims;").unwrap();
+        
+        let reader = store.clone();
+        let found = std::thread::spawn(move || reader.get_claim(claim.id).unwrap())
+            .join()
+            .unwrap();
+        assert!(found.is_some(), "reader saw uncommitted delete or was blocked");
+        
+        writer.execute_batch("ROLLBACK").unwrap();
+    }
+    
+    #[test]
+    fn test_concurrent_reads_and_writes() {
+        let dir = TempDir::new().unwrap();
+        let config = PoolConfig { max_readers: 2, ..PoolConfig::default() };
+        let store = PooledStore::with_config(dir.path().join("boswell.db"), false, 0, config).unwrap();
+        
+        let threads = 6;
+        let barrier = Arc::new(Barrier::new(threads));
+        let handles: Vec<_> = (0..threads).map(|i| {
+            let mut store = store.clone();
+            let barrier = Arc::clone(&barrier);
+            std::thread::spawn(move || {
+                barrier.wait();
+                for j in 0..20 {
+                    store.assert_claim(test_claim(&format!("person:{}-{}", i, j))).unwrap();
+                    store.query_claims(&ClaimQuery::default()).unwrap();
+                }
+            })
+        }).collect();
+        
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        assert_eq!(store.query(&ClaimQuery::default()).unwrap().len(), threads * 20);
+        assert!(store.open_readers() <= 2);
+    }
+    
+    #[test]
+    fn test_broken_connections_are_not_reused() {
+        let opened = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&opened);
+        // The first connection reports itself broken
+        let pool = ConnectionPool::new(1, move || Ok(counter.fetch_add(1, Ordering::Relaxed)))
+            .with_broken_check(|conn| *conn == 0);
+        
+        assert_eq!(*pool.get().unwrap(), 0);
+        assert_eq!(pool.open_connections(), 0);
+        
+        assert_eq!(*pool.get().unwrap(), 1);
+        assert_eq!(*pool.get().unwrap(), 1);
+        assert_eq!(pool.open_connections(), 1);
+        assert_eq!(opened.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,99 @@
+-- Boswell Claim Store Schema (PostgreSQL)
+-- Version: 1
+--
+-- Mirrors the SQLite schema (schema.sql plus its migrations) table for table,
+-- so both stores hold the same data. Applied by the PostgreSQL migration
+-- runner, which records the version in schema_info.
+
+-- Claims table - the core knowledge store
+CREATE TABLE IF NOT EXISTS claims (
+    -- ClaimId as 16 big-endian bytes, same encoding as SQLite
+    id BYTEA PRIMARY KEY NOT NULL,
+
+    -- Claim content
+    namespace TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    predicate TEXT NOT NULL,
+    object TEXT NOT NULL,
+
+    -- Base confidence interval (from provenance aggregation)
+    base_lower DOUBLE PRECISION NOT NULL CHECK (base_lower >= 0.0 AND base_lower <= 1.0),
+    base_upper DOUBLE PRECISION NOT NULL CHECK (base_upper >= 0.0 AND base_upper <= 1.0),
+
+    -- Tier and timestamps
+    tier TEXT NOT NULL CHECK (tier IN ('ephemeral', 'task', 'project', 'permanent')),
+    created_at BIGINT NOT NULL,
+    stale_at BIGINT,
+
+    -- Normalized content hash for exact duplicate detection
+    content_hash TEXT,
+
+    CHECK (base_lower <= base_upper)
+);
+
+CREATE INDEX IF NOT EXISTS idx_claims_namespace ON claims(namespace);
+CREATE INDEX IF NOT EXISTS idx_claims_tier ON claims(tier);
+CREATE INDEX IF NOT EXISTS idx_claims_created_at ON claims(created_at);
+CREATE INDEX IF NOT EXISTS idx_claims_content_hash ON claims(content_hash);
+
+-- Relationships table (pairwise only, per ADR-002)
+CREATE TABLE IF NOT EXISTS relationships (
+    id BIGSERIAL PRIMARY KEY,
+
+    from_claim_id BYTEA NOT NULL,
+    to_claim_id BYTEA NOT NULL,
+
+    relationship_type TEXT NOT NULL CHECK (relationship_type IN
+        ('supports', 'contradicts', 'derived_from', 'references', 'supersedes')),
+    strength DOUBLE PRECISION NOT NULL CHECK (strength >= 0.0 AND strength <= 1.0),
+    created_at BIGINT NOT NULL,
+
+    FOREIGN KEY (from_claim_id) REFERENCES claims(id) ON DELETE CASCADE,
+    FOREIGN KEY (to_claim_id) REFERENCES claims(id) ON DELETE CASCADE,
+
+    UNIQUE(from_claim_id, to_claim_id, relationship_type)
+);
+
+CREATE INDEX IF NOT EXISTS idx_relationships_from ON relationships(from_claim_id);
+CREATE INDEX IF NOT EXISTS idx_relationships_to ON relationships(to_claim_id);
+CREATE INDEX IF NOT EXISTS idx_relationships_type ON relationships(relationship_type);
+
+-- Provenance table (per ADR-009)
+CREATE TABLE IF NOT EXISTS provenance (
+    id BIGSERIAL PRIMARY KEY,
+    claim_id BYTEA NOT NULL,
+
+    source TEXT NOT NULL,
+    source_type TEXT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    rationale TEXT,
+    confidence_contribution DOUBLE PRECISION NOT NULL CHECK (confidence_contribution >= 0.0 AND confidence_contribution <= 1.0),
+
+    FOREIGN KEY (claim_id) REFERENCES claims(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_provenance_claim ON provenance(claim_id);
+CREATE INDEX IF NOT EXISTS idx_provenance_source_type ON provenance(source_type);
+
+-- Confidence cache (effective confidence, invalidated on corroboration)
+CREATE TABLE IF NOT EXISTS confidence_cache (
+    claim_id BYTEA PRIMARY KEY NOT NULL,
+    effective_lower DOUBLE PRECISION NOT NULL CHECK (effective_lower >= 0.0 AND effective_lower <= 1.0),
+    effective_upper DOUBLE PRECISION NOT NULL CHECK (effective_upper >= 0.0 AND effective_upper <= 1.0),
+    computed_at BIGINT NOT NULL,
+
+    -- Increases when relationships change, signaling recomputation needed
+    version INTEGER NOT NULL DEFAULT 0,
+
+    CHECK (effective_lower <= effective_upper),
+
+    FOREIGN KEY (claim_id) REFERENCES claims(id) ON DELETE CASCADE
+);
+
+-- Metadata table for schema versioning and migrations
+-- One row per applied migration; rows are written by the migration runner (postgres_store.rs)
+CREATE TABLE IF NOT EXISTS schema_info (
+    version INTEGER PRIMARY KEY,
+    applied_at BIGINT NOT NULL,
+    description TEXT
+);
@@ -0,0 +1,1264 @@
+//! PostgreSQL implementation of [`ClaimStore`]
+//!
+//! [`PostgresStore`] keeps the same tables as the SQLite store (see
+//! `postgres_schema.sql`) and follows the same rules: the same constraints,
+//! corroboration of duplicates, result ordering and atomic batches. Handles
+//! are cheap to clone and share one connection pool. Every write runs in its
+//! own transaction; a batch runs in one transaction with a savepoint per item.
+//! Writes that record an event serialize on an advisory lock from the event
+//! until commit, so sequence numbers become visible in order, and alias
+//! writes lock the `entity_aliases` table so their checks see every merge.
+//!
+//! With the `pgvector` feature, a store opened with
+//! [`PostgresStore::connect_with_vector_search`] keeps claim embeddings in a
+//! `claim_embeddings` table with an HNSW index, and uses it for semantic
+//! duplicate detection and [`PostgresStore::semantic_search`]. Embeddings are
+//! written in the claim's transaction, so there is no separate index to
+//! rebuild or compact.
+
+use boswell_domain::{alias, confidence_computation, namespace};
+use boswell_domain::traits::{stored_claim_confidence, AssertOutcome, BatchOutcome, ClaimBatch, ClaimQuery, ClaimStore};
+use boswell_domain::{
+    Claim, ClaimId, ConfidenceInterval, EntityAlias, EventKind, NamespaceStats, ProvenanceEntry, Relationship, StalenessPolicy,
+    StoreEvent, Tier,
+};
+use postgres::error::SqlState;
+use postgres::types::ToSql;
+use postgres::{Client, GenericClient, NoTls, Row, Transaction};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+#[cfg(feature = "pgvector")]
+use crate::embedding::{EmbeddingModel, MockEmbeddingModel};
+use crate::entities::{self, EntityColumns, EntityFilter};
+use crate::events::{self, EventColumns, EventRow, EVENT_COLUMNS};
+use crate::pool::ConnectionPool;
+use crate::stats::TierStatsRow;
+use crate::{SqliteStore, StoreError, DEFAULT_DUPLICATE_THRESHOLD};
+
+/// Schema version this build of the PostgreSQL store reads and writes
+pub const POSTGRES_SCHEMA_VERSION: u32 = 6;
+
+/// Advisory lock held while migrating, so stores opening one database take turns
+const MIGRATION_LOCK: i64 = 0x0062_6f73_7765_6c6c;
+
+/// Advisory lock held from writing an event until commit, so events commit
+/// in sequence order and a reader never skips one that commits late
+const EVENT_LOCK: i64 = 0x0065_7665_6e74_6c6f;
+
+/// Nearest neighbours inspected when looking for a semantic duplicate
+#[cfg(feature = "pgvector")]
+const DUPLICATE_CANDIDATES: i64 = 5;
+
+/// Columns read by [`claim_from_row`], in order
+const CLAIM_COLUMNS: &str =
+    "id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at";
+
+/// A single schema migration
+struct Migration {
+    /// Version the database is at once this migration is applied
+    version: u32,
+    
+    /// Recorded in `schema_info.description`
+    description: &'static str,
+    
+    /// Applies the migration inside the given transaction
+    apply: fn(&mut Transaction<'_>) -> Result<(), StoreError>,
+}
+
+/// All migrations, in version order
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Initial schema with claims, relationships, provenance, and confidence cache",
+        apply: initial_schema,
+    },
+    Migration {
+        version: 2,
+        description: "Append-only event log of store mutations",
+        apply: event_log,
+    },
+    Migration {
+        version: 3,
+        description: "Index claims by subject and object entity kind and literal value",
+        apply: entity_columns,
+    },
+    Migration {
+        version: 4,
+        description: "Entity aliases with retractable merges",
+        apply: entity_aliases_table,
+    },
+    Migration {
+        version: 5,
+        description: "Record each event's claim namespace and tier",
+        apply: event_claim_columns,
+    },
+    Migration {
+        version: 6,
+        description: "Log namespace moves as events",
+        apply: moved_events,
+    },
+];
+
+/// PostgreSQL-backed claim store with a shared connection pool
+///
+/// # Examples
+///
+/// ```no_run
+/// use boswell_domain::traits::{ClaimQuery, ClaimStore};
+/// use boswell_store::PostgresStore;
+///
+/// let store = PostgresStore::connect("postgres://boswell@localhost/boswell", 8).unwrap();
+///
+/// // Each clone shares the same pool
+/// let reader = store.clone();
+/// std::thread::spawn(move || {
+///     let _ = reader.query_claims(&ClaimQuery::default());
+/// });
+/// ```
+#[derive(Clone)]
+pub struct PostgresStore {
+    shared: Arc<Shared>,
+}
+
+/// State shared by every clone of a [`PostgresStore`]
+struct Shared {
+    pool: ConnectionPool<Client>,
+    
+    #[cfg(feature = "pgvector")]
+    embedding_model: Option<Box<dyn EmbeddingModel + Send + Sync>>,
+    
+    /// `f32` bits, so the threshold can be changed through a shared handle
+    duplicate_threshold: AtomicU32,
+    
+    staleness: RwLock<StalenessPolicy>,
+}
+
+impl PostgresStore {
+    /// Connect to the database at `url` and run any pending migrations
+    ///
+    /// `url` is a libpq-style connection string or `postgres://` URL.
+    /// Connections are opened on demand, up to `max_connections`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` if `max_connections` is 0, `SchemaTooNew` if the
+    /// database was written by a newer version, or the connection error.
+    pub fn connect(url: &str, max_connections: usize) -> Result<Self, StoreError> {
+        let config = open_database(url, max_connections)?;
+        
+        Ok(Self {
+            shared: Arc::new(Shared {
+                pool: client_pool(config, max_connections),
+                #[cfg(feature = "pgvector")]
+                embedding_model: None,
+                duplicate_threshold: AtomicU32::new(DEFAULT_DUPLICATE_THRESHOLD.to_bits()),
+                staleness: RwLock::new(StalenessPolicy::default()),
+            }),
+        })
+    }
+    
+    /// Connect with semantic duplicate detection and search through pgvector
+    ///
+    /// Creates the `vector` extension and the `claim_embeddings` table if they
+    /// do not exist yet. The extension must be installed on the server.
+    ///
+    /// # Errors
+    ///
+    /// As [`connect`](Self::connect), and `InvalidData` if the dimension is 0
+    /// or differs from the dimension of existing embeddings.
+    #[cfg(feature = "pgvector")]
+    pub fn connect_with_vector_search(
+        url: &str,
+        max_connections: usize,
+        embedding_dimension: usize,
+    ) -> Result<Self, StoreError> {
+        if embedding_dimension == 0 {
+            return Err(StoreError::InvalidData("embedding_dimension must be at least 1".to_string()));
+        }
+        
+        let config = open_database(url, max_connections)?;
+        create_embedding_table(&mut config.connect(NoTls)?, embedding_dimension)?;
+        
+        Ok(Self {
+            shared: Arc::new(Shared {
+                pool: client_pool(config, max_connections),
+                embedding_model: Some(Box::new(MockEmbeddingModel::new(embedding_dimension))),
+                duplicate_threshold: AtomicU32::new(DEFAULT_DUPLICATE_THRESHOLD.to_bits()),
+                staleness: RwLock::new(StalenessPolicy::default()),
+            }),
+        })
+    }
+    
+    /// Set the cosine similarity above which a new claim corroborates an existing one
+    ///
+    /// Applies to every clone of this store. See [`SqliteStore::set_duplicate_threshold`].
+    pub fn set_duplicate_threshold(&self, threshold: f32) {
+        self.shared.duplicate_threshold.store(threshold.to_bits(), Ordering::Relaxed);
+    }
+    
+    /// Current semantic duplicate threshold
+    pub fn duplicate_threshold(&self) -> f32 {
+        f32::from_bits(self.shared.duplicate_threshold.load(Ordering::Relaxed))
+    }
+    
+    /// Set the policy deciding when written claims go stale
+    ///
+    /// Applies to every clone of this store. See [`SqliteStore::set_staleness_policy`].
+    pub fn set_staleness_policy(&self, policy: StalenessPolicy) {
+        *self.shared.staleness.write().unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+    
+    /// Number of connections currently open (idle or checked out)
+    pub fn open_connections(&self) -> usize {
+        self.shared.pool.open_connections()
+    }
+    
+    /// Insert a claim, or corroborate the claim it duplicates
+    pub fn assert_with_provenance(
+        &self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, StoreError> {
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        let outcome = self.shared.assert_claim(&mut tx, &claim, provenance)?;
+        tx.commit()?;
+        Ok(outcome)
+    }
+    
+    /// Write a batch of claims and relationships in one transaction
+    pub fn assert_batch(&self, batch: ClaimBatch) -> Result<BatchOutcome<StoreError>, StoreError> {
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        let outcome = self.shared.assert_batch(&mut tx, batch)?;
+        tx.commit()?;
+        Ok(outcome)
+    }
+    
+    /// Insert or update a relationship
+    pub fn add_relationship(&self, relationship: &Relationship) -> Result<(), StoreError> {
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        add_relationship(&mut tx, relationship)?;
+        tx.commit()?;
+        Ok(())
+    }
+    
+    /// Permanently delete a claim along with its provenance, relationships,
+    /// cached confidence and embedding
+    ///
+    /// Records an eviction event. Returns `true` if the claim existed.
+    pub fn delete_claim(&self, claim_id: ClaimId) -> Result<bool, StoreError> {
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        let id_bytes = SqliteStore::claim_id_to_bytes(claim_id);
+        if tx.query_opt("SELECT 1 FROM claims WHERE id = $1 FOR UPDATE", &[&id_bytes])?.is_none() {
+            return Ok(false);
+        }
+        
+        // Recorded first so the event still picks up the claim's namespace and tier
+        record_event(&mut tx, &EventKind::Evicted(claim_id))?;
+        // Dependent rows go with it through ON DELETE CASCADE
+        tx.execute("DELETE FROM claims WHERE id = $1", &[&id_bytes])?;
+        tx.commit()?;
+        Ok(true)
+    }
+    
+    /// Move a claim to another tier, recording a promotion or demotion event
+    ///
+    /// See [`ClaimStore::set_tier`].
+    pub fn set_tier(&self, claim_id: ClaimId, tier: Tier) -> Result<bool, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(claim_id);
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        
+        let current: String = tx.query_opt("SELECT tier FROM claims WHERE id = $1 FOR UPDATE", &[&id_bytes])?
+            .ok_or_else(|| StoreError::NotFound(claim_id.to_string()))?
+            .try_get(0)?;
+        let current = Tier::from_str(&current).map_err(StoreError::InvalidData)?;
+        
+        let Some(event) = EventKind::tier_change(claim_id, current, tier) else {
+            return Ok(false);
+        };
+        
+        tx.execute("UPDATE claims SET tier = $1 WHERE id = $2", &[&tier.as_str(), &id_bytes])?;
+        record_event(&mut tx, &event)?;
+        tx.commit()?;
+        Ok(true)
+    }
+    
+    /// Move a namespace subtree, recomputing content hashes
+    ///
+    /// See [`ClaimStore::move_namespace`]. The moved rows are locked for the
+    /// duration of the transaction, which also records their `Moved` events.
+    pub fn move_namespace(&self, from: &str, to: &str) -> Result<usize, StoreError> {
+        namespace::check_move(from, to).map_err(StoreError::InvalidData)?;
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        
+        let mut sql = String::from("SELECT 1 FROM claims WHERE TRUE");
+        let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
+        push_subtree_filter(&mut sql, &mut params, to);
+        sql.push_str(" LIMIT 1");
+        let param_refs: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p.as_ref()).collect();
+        if tx.query_opt(&sql, &param_refs)?.is_some() {
+            return Err(StoreError::NamespaceOccupied(to.to_string()));
+        }
+        
+        let mut sql = String::from("SELECT id, namespace, subject, predicate, object FROM claims WHERE TRUE");
+        let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
+        push_subtree_filter(&mut sql, &mut params, from);
+        sql.push_str(" FOR UPDATE");
+        let param_refs: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p.as_ref()).collect();
+        let rows = tx.query(&sql, &param_refs)?;
+        
+        let update = tx.prepare("UPDATE claims SET namespace = $1, content_hash = $2 WHERE id = $3")?;
+        for row in &rows {
+            let id_bytes: Vec<u8> = row.try_get(0)?;
+            let namespace: String = row.try_get(1)?;
+            let moved = namespace::rebase(&namespace, from, to).unwrap_or(namespace);
+            let content_hash = Claim::hash_content(&moved, row.try_get(2)?, row.try_get(3)?, row.try_get(4)?);
+            tx.execute(&update, &[&moved, &content_hash, &id_bytes])?;
+            record_event(&mut tx, &EventKind::Moved(SqliteStore::bytes_to_claim_id(&id_bytes)?))?;
+        }
+        
+        tx.commit()?;
+        Ok(rows.len())
+    }
+    
+    /// Record that two entities are the same
+    ///
+    /// See [`ClaimStore::add_alias`]. Alias writers take turns, so the check
+    /// against the active aliases and the insert cannot interleave.
+    pub fn add_alias(&self, alias: EntityAlias) -> Result<(), StoreError> {
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        tx.batch_execute("LOCK TABLE entity_aliases IN EXCLUSIVE MODE")?;
+        
+        let alias = entities::check_alias(alias, &entity_aliases(&mut tx, false)?)?;
+        tx.execute(
+            "INSERT INTO entity_aliases (alias, canonical, reason, created_at) VALUES ($1, $2, $3, $4)",
+            &[&alias.alias, &alias.canonical, &alias.reason, &(alias.created_at as i64)],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+    
+    /// Undo the active merge of `alias`, keeping its record
+    ///
+    /// See [`ClaimStore::retract_alias`].
+    pub fn retract_alias(&self, alias: &str) -> Result<bool, StoreError> {
+        let retracted = self.shared.pool.get()?.execute(
+            "UPDATE entity_aliases SET retracted_at = $1 WHERE alias = $2 AND retracted_at IS NULL",
+            &[&events::now(), &alias::normalize(alias)],
+        )? > 0;
+        Ok(retracted)
+    }
+    
+    /// Load a claim by ID
+    pub fn get(&self, id: ClaimId) -> Result<Option<Claim>, StoreError> {
+        get_claim(&mut *self.shared.pool.get()?, id)
+    }
+    
+    /// Claims matching the query's filters, oldest first
+    pub fn query(&self, query: &ClaimQuery) -> Result<Vec<Claim>, StoreError> {
+        let mut sql = format!("SELECT {} FROM claims WHERE TRUE", CLAIM_COLUMNS);
+        let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
+        
+        if let Some(root) = &query.namespace {
+            push_subtree_filter(&mut sql, &mut params, root);
+        }
+        
+        if let Some(tier) = query.tier {
+            params.push(Box::new(tier.as_str()));
+            sql.push_str(&format!(" AND tier = ${}", params.len()));
+        }
+        
+        if let Some(min_conf) = query.min_confidence {
+            params.push(Box::new(min_conf));
+            sql.push_str(&format!(" AND base_lower >= ${}", params.len()));
+        }
+        
+        let entities = EntityFilter::new(query);
+        if let Some(kind) = entities.subject_kind {
+            params.push(Box::new(kind));
+            sql.push_str(&format!(" AND subject_kind = ${}", params.len()));
+        }
+        for kind in entities.object_kinds {
+            params.push(Box::new(kind));
+            sql.push_str(&format!(" AND object_kind = ${}", params.len()));
+        }
+        if let Some(after) = entities.object_after {
+            params.push(Box::new(after));
+            sql.push_str(&format!(" AND object_value > ${}", params.len()));
+        }
+        if let Some(before) = entities.object_before {
+            params.push(Box::new(before));
+            sql.push_str(&format!(" AND object_value < ${}", params.len()));
+        }
+        
+        if let Some((created_at, id)) = query.after {
+            params.push(Box::new(created_at as i64));
+            params.push(Box::new(SqliteStore::claim_id_to_bytes(id)));
+            sql.push_str(&format!(" AND (created_at, id) > (${}, ${})", params.len() - 1, params.len()));
+        }
+        
+        sql.push_str(" ORDER BY created_at, id");
+        
+        if let Some(limit) = query.limit {
+            params.push(Box::new(i64::try_from(limit).unwrap_or(i64::MAX)));
+            sql.push_str(&format!(" LIMIT ${}", params.len()));
+        }
+        
+        let param_refs: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p.as_ref()).collect();
+        self.shared.pool.get()?.query(&sql, &param_refs)?
+            .iter()
+            .map(claim_from_row)
+            .collect()
+    }
+    
+    /// Perform semantic search for claims similar to the given embedding
+    ///
+    /// `ef_search` sets pgvector's `hnsw.ef_search` for this query. See
+    /// [`SqliteStore::semantic_search`] for the other parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if vector search is not enabled or the query fails
+    #[cfg(feature = "pgvector")]
+    pub fn semantic_search(
+        &self,
+        query_embedding: &[f32],
+        k: usize,
+        ef_search: usize,
+        min_similarity: f32,
+    ) -> Result<Vec<(Claim, f32)>, StoreError> {
+        if self.shared.embedding_model.is_none() {
+            return Err(StoreError::InvalidData("Vector search is not enabled for this store".to_string()));
+        }
+        
+        let mut client = self.shared.pool.get()?;
+        let mut tx = client.transaction()?;
+        tx.batch_execute(&format!("SET LOCAL hnsw.ef_search = {}", ef_search))?;
+        
+        let rows = tx.query(
+            &format!(
+                "SELECT {}, 1 - candidates.distance
+                 FROM (
+                     SELECT claim_id, embedding <=> $1 AS distance
+                     FROM claim_embeddings ORDER BY distance LIMIT $2
+                 ) candidates
+                 JOIN claims ON claims.id = candidates.claim_id
+                 ORDER BY candidates.distance",
+                CLAIM_COLUMNS,
+            ),
+            &[&pgvector::Vector::from(query_embedding.to_vec()), &i64::try_from(k).unwrap_or(i64::MAX)],
+        )?;
+        tx.commit()?;
+        
+        let mut results = Vec::new();
+        for row in &rows {
+            let similarity = row.try_get::<_, f64>(10)? as f32;
+            if similarity >= min_similarity {
+                results.push((claim_from_row(row)?, similarity));
+            }
+        }
+        
+        Ok(results)
+    }
+}
+
+impl Shared {
+    /// Insert `claim`, or corroborate the claim it duplicates, inside the caller's transaction
+    fn assert_claim(
+        &self,
+        client: &mut impl GenericClient,
+        claim: &Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, StoreError> {
+        let id_bytes = SqliteStore::claim_id_to_bytes(claim.id);
+        
+        if client.query_opt("SELECT 1 FROM claims WHERE id = $1", &[&id_bytes])?.is_some() {
+            return Err(StoreError::Duplicate);
+        }
+        
+        SqliteStore::confidence_interval(claim.confidence)?;
+        
+        // Every assertion leaves a provenance trail, even when the caller gave none
+        let provenance = if provenance.is_empty() {
+            vec![ProvenanceEntry::new("unknown".to_string(), claim.created_at, "assert".to_string())]
+        } else {
+            provenance
+        };
+        let staleness = self.staleness.read().unwrap_or_else(PoisonError::into_inner).clone();
+        
+        // Concurrent writers of the same content queue here until the first commits,
+        // so exactly one of them inserts it and the rest corroborate
+        let content_hash = claim.content_hash();
+        client.execute("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))", &[&content_hash])?;
+        
+        if let Some(existing_id) = find_id_by_content_hash(client, &content_hash)? {
+            corroborate(client, existing_id, claim, &provenance, &staleness)?;
+            return Ok(AssertOutcome::Corroborated(existing_id));
+        }
+        
+        #[cfg(feature = "pgvector")]
+        let embedding = self.embed(claim);
+        #[cfg(feature = "pgvector")]
+        if let Some(embedding) = &embedding {
+            if let Some(existing_id) = self.find_semantic_duplicate(client, claim, embedding)? {
+                corroborate(client, existing_id, claim, &provenance, &staleness)?;
+                return Ok(AssertOutcome::Corroborated(existing_id));
+            }
+        }
+        
+        let entities = EntityColumns::of_claim(claim);
+        let inserted = client.execute(
+            "INSERT INTO claims (id, namespace, subject, predicate, object, base_lower, base_upper, tier, created_at, stale_at, content_hash,
+                                 subject_kind, object_kind, object_value)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+            &[
+                &id_bytes,
+                &claim.namespace,
+                &claim.subject,
+                &claim.predicate,
+                &claim.object,
+                &claim.confidence.0,
+                &claim.confidence.1,
+                &claim.tier.as_str(),
+                &(claim.created_at as i64),
+                &staleness.stale_at_on_insert(claim).map(|t| t as i64),
+                &content_hash,
+                &entities.subject_kind,
+                &entities.object_kind,
+                &entities.object_value,
+            ],
+        );
+        match inserted {
+            // Another writer took the ID after the check above
+            Err(e) if e.code() == Some(&SqlState::UNIQUE_VIOLATION) => return Err(StoreError::Duplicate),
+            result => result?,
+        };
+        insert_provenance(client, &id_bytes, &provenance, claim.confidence.1)?;
+        record_event(client, &EventKind::Asserted(claim.id))?;
+        
+        #[cfg(feature = "pgvector")]
+        if let Some(embedding) = embedding {
+            client.execute(
+                "INSERT INTO claim_embeddings (claim_id, embedding) VALUES ($1, $2)",
+                &[&id_bytes, &pgvector::Vector::from(embedding)],
+            )?;
+        }
+        
+        Ok(AssertOutcome::Inserted(claim.id))
+    }
+    
+    /// Write a batch inside the caller's transaction
+    ///
+    /// Each item runs in its own savepoint. In all-or-nothing mode the first
+    /// failure is returned as `BatchAborted` and the caller must drop the
+    /// transaction.
+    fn assert_batch(
+        &self,
+        tx: &mut Transaction<'_>,
+        batch: ClaimBatch,
+    ) -> Result<BatchOutcome<StoreError>, StoreError> {
+        let mut outcome = BatchOutcome::default();
+        // Claims in the batch may resolve to existing claims; relationships follow them
+        let mut resolved_ids = HashMap::new();
+        
+        for (index, item) in batch.claims.into_iter().enumerate() {
+            let result = in_savepoint(tx, |savepoint| self.assert_claim(savepoint, &item.claim, item.provenance));
+            match result {
+                Ok(result) => {
+                    resolved_ids.insert(item.claim.id, result.claim_id());
+                    outcome.claims.push(Ok(result));
+                }
+                Err(e) if batch.all_or_nothing => {
+                    return Err(StoreError::BatchAborted(format!("claim {}: {}", index, e)));
+                }
+                Err(e) => outcome.claims.push(Err(e)),
+            }
+        }
+        
+        for (index, mut relationship) in batch.relationships.into_iter().enumerate() {
+            relationship.from_claim = *resolved_ids.get(&relationship.from_claim).unwrap_or(&relationship.from_claim);
+            relationship.to_claim = *resolved_ids.get(&relationship.to_claim).unwrap_or(&relationship.to_claim);
+            
+            match in_savepoint(tx, |savepoint| add_relationship(savepoint, &relationship)) {
+                Ok(()) => outcome.relationships.push(Ok(())),
+                Err(e) if batch.all_or_nothing => {
+                    return Err(StoreError::BatchAborted(format!("relationship {}: {}", index, e)));
+                }
+                Err(e) => outcome.relationships.push(Err(e)),
+            }
+        }
+        
+        Ok(outcome)
+    }
+    
+    /// Embedding of the claim's content, if vector search is enabled
+    #[cfg(feature = "pgvector")]
+    fn embed(&self, claim: &Claim) -> Option<Vec<f32>> {
+        let embedding_model = self.embedding_model.as_ref()?;
+        let text = format!("{} {} {}", claim.subject, claim.predicate, claim.object);
+        
+        match embedding_model.embed(&text) {
+            Ok(embedding) => Some(embedding),
+            Err(e) => {
+                // Log error but don't fail the claim insertion
+                eprintln!("Warning: Failed to generate embedding: {}", e);
+                None
+            }
+        }
+    }
+    
+    /// Find an existing claim that is a semantic duplicate of `claim`
+    ///
+    /// Candidates must clear the similarity threshold and, as a structural
+    /// check, share the claim's namespace and normalized predicate.
+    #[cfg(feature = "pgvector")]
+    fn find_semantic_duplicate(
+        &self,
+        client: &mut impl GenericClient,
+        claim: &Claim,
+        embedding: &[f32],
+    ) -> Result<Option<ClaimId>, StoreError> {
+        let threshold = f32::from_bits(self.duplicate_threshold.load(Ordering::Relaxed));
+        let candidates = client.query(
+            "SELECT claims.id, claims.namespace, claims.subject, claims.predicate, claims.object,
+                    1 - candidates.distance
+             FROM (
+                 SELECT claim_id, embedding <=> $1 AS distance
+                 FROM claim_embeddings ORDER BY distance LIMIT $2
+             ) candidates
+             JOIN claims ON claims.id = candidates.claim_id
+             ORDER BY candidates.distance",
+            &[&pgvector::Vector::from(embedding.to_vec()), &DUPLICATE_CANDIDATES],
+        )?;
+        
+        for candidate in &candidates {
+            let similarity = candidate.try_get::<_, f64>(5)? as f32;
+            if similarity < threshold {
+                continue;
+            }
+            
+            let existing = Claim {
+                namespace: candidate.try_get(1)?,
+                subject: candidate.try_get(2)?,
+                predicate: candidate.try_get(3)?,
+                object: candidate.try_get(4)?,
+                ..claim.clone()
+            };
+            if existing.restates(claim) {
+                let id_bytes: Vec<u8> = candidate.try_get(0)?;
+                return Ok(Some(SqliteStore::bytes_to_claim_id(&id_bytes)?));
+            }
+        }
+        
+        Ok(None)
+    }
+}
+
+impl ClaimStore for PostgresStore {
+    type Error = StoreError;
+    
+    fn assert_claim(&mut self, claim: Claim) -> Result<AssertOutcome, Self::Error> {
+        self.assert_with_provenance(claim, Vec::new())
+    }
+    
+    fn assert_claim_with_provenance(
+        &mut self,
+        claim: Claim,
+        provenance: Vec<ProvenanceEntry>,
+    ) -> Result<AssertOutcome, Self::Error> {
+        self.assert_with_provenance(claim, provenance)
+    }
+    
+    fn assert_batch(&mut self, batch: ClaimBatch) -> Result<BatchOutcome<Self::Error>, Self::Error> {
+        PostgresStore::assert_batch(self, batch)
+    }
+    
+    fn get_claim(&self, id: ClaimId) -> Result<Option<Claim>, Self::Error> {
+        self.get(id)
+    }
+    
+    fn get_provenance(&self, id: ClaimId) -> Result<Vec<ProvenanceEntry>, Self::Error> {
+        let rows = self.shared.pool.get()?.query(
+            "SELECT source, source_type, timestamp, rationale
+             FROM provenance WHERE claim_id = $1 ORDER BY id",
+            &[&SqliteStore::claim_id_to_bytes(id)],
+        )?;
+        
+        rows.iter().map(provenance_from_row).collect()
+    }
+    
+    fn get_provenance_batch(&self, ids: &[ClaimId]) -> Result<HashMap<ClaimId, Vec<ProvenanceEntry>>, Self::Error> {
+        let id_bytes: Vec<Vec<u8>> = ids.iter().map(|&id| SqliteStore::claim_id_to_bytes(id)).collect();
+        let rows = self.shared.pool.get()?.query(
+            "SELECT source, source_type, timestamp, rationale, claim_id
+             FROM provenance WHERE claim_id = ANY($1) ORDER BY id",
+            &[&id_bytes],
+        )?;
+        
+        let mut provenance: HashMap<ClaimId, Vec<ProvenanceEntry>> = HashMap::new();
+        for row in &rows {
+            let claim_id: Vec<u8> = row.try_get(4)?;
+            provenance.entry(SqliteStore::bytes_to_claim_id(&claim_id)?)
+                .or_default()
+                .push(provenance_from_row(row)?);
+        }
+        Ok(provenance)
+    }
+    
+    fn find_by_content_hash(&self, content_hash: &str) -> Result<Option<Claim>, Self::Error> {
+        let mut client = self.shared.pool.get()?;
+        match find_id_by_content_hash(&mut *client, content_hash)? {
+            Some(id) => get_claim(&mut *client, id),
+            None => Ok(None),
+        }
+    }
+    
+    fn query_claims(&self, query: &ClaimQuery) -> Result<Vec<Claim>, Self::Error> {
+        self.query(query)
+    }
+    
+    fn add_relationship(&mut self, relationship: Relationship) -> Result<(), Self::Error> {
+        PostgresStore::add_relationship(self, &relationship)
+    }
+    
+    fn get_relationships(&self, id: ClaimId) -> Result<Vec<Relationship>, Self::Error> {
+        let rows = self.shared.pool.get()?.query(
+            "SELECT from_claim_id, to_claim_id, relationship_type, strength, created_at
+             FROM relationships WHERE from_claim_id = $1 OR to_claim_id = $1 ORDER BY id",
+            &[&SqliteStore::claim_id_to_bytes(id)],
+        )?;
+        
+        rows.iter().map(relationship_from_row).collect()
+    }
+    
+    fn get_relationships_batch(&self, ids: &[ClaimId]) -> Result<Vec<Relationship>, Self::Error> {
+        let id_bytes: Vec<Vec<u8>> = ids.iter().map(|&id| SqliteStore::claim_id_to_bytes(id)).collect();
+        let rows = self.shared.pool.get()?.query(
+            "SELECT from_claim_id, to_claim_id, relationship_type, strength, created_at
+             FROM relationships WHERE from_claim_id = ANY($1) OR to_claim_id = ANY($1) ORDER BY id",
+            &[&id_bytes],
+        )?;
+        
+        rows.iter().map(relationship_from_row).collect()
+    }
+    
+    fn set_tier(&mut self, id: ClaimId, tier: Tier) -> Result<bool, Self::Error> {
+        PostgresStore::set_tier(self, id, tier)
+    }
+    
+    fn delete_claim(&mut self, id: ClaimId) -> Result<bool, Self::Error> {
+        PostgresStore::delete_claim(self, id)
+    }
+    
+    fn events_since(&self, after: u64, limit: usize) -> Result<Vec<StoreEvent>, Self::Error> {
+        let rows = self.shared.pool.get()?.query(
+            &format!("SELECT {} FROM events WHERE seq > $1 ORDER BY seq LIMIT $2", EVENT_COLUMNS),
+            &[&(after as i64), &events::sql_limit(limit)],
+        )?;
+        
+        rows.iter()
+            .map(|row| EventRow {
+                seq: row.try_get(0)?,
+                recorded_at: row.try_get(1)?,
+                kind: row.try_get(2)?,
+                claim_id: row.try_get(3)?,
+                related_claim_id: row.try_get(4)?,
+                relationship_type: row.try_get(5)?,
+                from_tier: row.try_get(6)?,
+                to_tier: row.try_get(7)?,
+                namespace: row.try_get(8)?,
+                tier: row.try_get(9)?,
+            }.into_event())
+            .collect()
+    }
+    
+    fn namespace_stats(&self, root: &str) -> Result<Vec<NamespaceStats>, Self::Error> {
+        let mut sql = String::from(
+            "SELECT namespace, tier, COUNT(*), SUM((base_lower + base_upper) / 2.0),
+                    MAX(GREATEST(created_at, COALESCE((SELECT MAX(timestamp) FROM provenance WHERE claim_id = claims.id), 0))),
+                    SUM((SELECT COUNT(*) FROM relationships
+                         WHERE from_claim_id = claims.id AND relationship_type = 'contradicts'))::BIGINT
+             FROM claims WHERE TRUE"
+        );
+        let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
+        push_subtree_filter(&mut sql, &mut params, root);
+        sql.push_str(" GROUP BY namespace, tier");
+        
+        let param_refs: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p.as_ref()).collect();
+        let rows = self.shared.pool.get()?.query(&sql, &param_refs)?
+            .iter()
+            .map(|row| Ok(TierStatsRow {
+                namespace: row.try_get(0)?,
+                tier: row.try_get(1)?,
+                claims: row.try_get::<_, i64>(2)? as u64,
+                confidence_sum: row.try_get(3)?,
+                last_write: row.try_get::<_, i64>(4)? as u64,
+                contradictions: row.try_get::<_, i64>(5)? as u64,
+            }))
+            .collect::<Result<Vec<_>, StoreError>>()?;
+        
+        Ok(TierStatsRow::collect(rows))
+    }
+    
+    fn move_namespace(&mut self, from: &str, to: &str) -> Result<usize, Self::Error> {
+        PostgresStore::move_namespace(self, from, to)
+    }
+    
+    fn add_alias(&mut self, alias: EntityAlias) -> Result<(), Self::Error> {
+        PostgresStore::add_alias(self, alias)
+    }
+    
+    fn retract_alias(&mut self, alias: &str) -> Result<bool, Self::Error> {
+        PostgresStore::retract_alias(self, alias)
+    }
+    
+    fn entity_aliases(&self, include_retracted: bool) -> Result<Vec<EntityAlias>, Self::Error> {
+        entity_aliases(&mut *self.shared.pool.get()?, include_retracted)
+    }
+    
+    fn staleness_policy(&self) -> StalenessPolicy {
+        self.shared.staleness.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+    
+    /// Computes the claim's effective confidence and records it in the confidence cache
+    fn effective_confidence(&self, id: ClaimId, now: u64) -> Result<Option<ConfidenceInterval>, Self::Error> {
+        let Some(claim) = self.get(id)? else {
+            return Ok(None);
+        };
+        let confidence = stored_claim_confidence(self, &claim, now)?;
+        self.shared.pool.get()?.execute(
+            "INSERT INTO confidence_cache (claim_id, effective_lower, effective_upper, computed_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (claim_id) DO UPDATE SET
+                 effective_lower = EXCLUDED.effective_lower,
+                 effective_upper = EXCLUDED.effective_upper,
+                 computed_at = EXCLUDED.computed_at",
+            &[&SqliteStore::claim_id_to_bytes(id), &confidence.lower, &confidence.upper, &(now as i64)],
+        )?;
+        Ok(Some(confidence))
+    }
+}
+
+/// Restrict a `WHERE` clause to the namespace subtree at `root`
+///
+/// Matches whole segments: `project` matches `project/x` but not `projectX`.
+/// An empty root adds nothing.
+fn push_subtree_filter(sql: &mut String, params: &mut Vec<Box<dyn ToSql + Sync>>, root: &str) {
+    let root = root.trim_end_matches(namespace::SEPARATOR);
+    if !root.is_empty() {
+        params.push(Box::new(root.to_string()));
+        let root_param = params.len();
+        params.push(Box::new(format!("{}{}", root, namespace::SEPARATOR)));
+        sql.push_str(&format!(
+            " AND (namespace = ${} OR starts_with(namespace, ${}))",
+            root_param,
+            params.len()
+        ));
+    }
+}
+
+/// Parse `url`, then connect once to bring the schema up to date
+fn open_database(url: &str, max_connections: usize) -> Result<postgres::Config, StoreError> {
+    if max_connections == 0 {
+        return Err(StoreError::InvalidData("max_connections must be at least 1".to_string()));
+    }
+    
+    let config: postgres::Config = url.parse()?;
+    migrate(&mut config.connect(NoTls)?)?;
+    Ok(config)
+}
+
+/// Pool of connections to the database described by `config`
+fn client_pool(config: postgres::Config, max_connections: usize) -> ConnectionPool<Client> {
+    ConnectionPool::new(max_connections, move || Ok(config.connect(NoTls)?))
+        .with_broken_check(Client::is_closed)
+}
+
+/// Version recorded in `schema_info`, or 0 for a database that has never been migrated
+fn current_version(client: &mut impl GenericClient) -> Result<u32, StoreError> {
+    let has_schema_info: bool = client
+        .query_one("SELECT to_regclass('schema_info') IS NOT NULL", &[])?
+        .try_get(0)?;
+    
+    if !has_schema_info {
+        return Ok(0);
+    }
+    
+    let version: Option<i32> = client.query_one("SELECT MAX(version) FROM schema_info", &[])?.try_get(0)?;
+    Ok(version.unwrap_or(0) as u32)
+}
+
+/// Bring the database up to [`POSTGRES_SCHEMA_VERSION`]
+///
+/// Pending migrations run in a single transaction under an advisory lock,
+/// so stores opening the same database at once apply them exactly once and
+/// a failed migration leaves the database as it was. Returns the number of
+/// migrations applied.
+///
+/// # Errors
+///
+/// Returns `SchemaTooNew` if the database was written by a newer version,
+/// or the error of the first migration that fails.
+fn migrate(client: &mut Client) -> Result<usize, StoreError> {
+    let mut tx = client.transaction()?;
+    tx.execute("SELECT pg_advisory_xact_lock($1)", &[&MIGRATION_LOCK])?;
+    
+    let found = current_version(&mut tx)?;
+    if found > POSTGRES_SCHEMA_VERSION {
+        return Err(StoreError::SchemaTooNew { found, supported: POSTGRES_SCHEMA_VERSION });
+    }
+    
+    let pending: Vec<&Migration> = MIGRATIONS.iter()
+        .filter(|migration| migration.version > found)
+        .collect();
+    
+    for migration in &pending {
+        (migration.apply)(&mut tx)?;
+        tx.execute(
+            "INSERT INTO schema_info (version, applied_at, description)
+             VALUES ($1, (EXTRACT(EPOCH FROM now()) * 1000)::BIGINT, $2)",
+            &[&(migration.version as i32), &migration.description],
+        )?;
+    }
+    
+    tx.commit()?;
+    Ok(pending.len())
+}
+
+/// Version 1: tables and indexes from `postgres_schema.sql`
+fn initial_schema(tx: &mut Transaction<'_>) -> Result<(), StoreError> {
+    tx.batch_execute(include_str!("postgres_schema.sql"))?;
+    Ok(())
+}
+
+/// Version 2: the `events` table read by `ClaimStore::events_since`
+fn event_log(tx: &mut Transaction<'_>) -> Result<(), StoreError> {
+    tx.batch_execute(
+        "CREATE TABLE events (
+            seq BIGSERIAL PRIMARY KEY,
+            recorded_at BIGINT NOT NULL,
+            kind TEXT NOT NULL CHECK (kind IN
+                ('asserted', 'corroborated', 'promoted', 'demoted', 'evicted', 'relationship_added')),
+            claim_id BYTEA NOT NULL,
+            related_claim_id BYTEA,
+            relationship_type TEXT,
+            from_tier TEXT,
+            to_tier TEXT
+        );
+        CREATE INDEX idx_events_claim ON events(claim_id);"
+    )?;
+    Ok(())
+}
+
+/// Version 3: entity kind and literal value columns, backfilled from existing claims
+fn entity_columns(tx: &mut Transaction<'_>) -> Result<(), StoreError> {
+    tx.batch_execute(
+        "ALTER TABLE claims ADD COLUMN subject_kind TEXT;
+        ALTER TABLE claims ADD COLUMN object_kind TEXT;
+        ALTER TABLE claims ADD COLUMN object_value DOUBLE PRECISION;
+        CREATE INDEX idx_claims_subject_kind ON claims(subject_kind);
+        CREATE INDEX idx_claims_object_kind_value ON claims(object_kind, object_value);"
+    )?;
+    
+    let update = tx.prepare(
+        "UPDATE claims SET subject_kind = $1, object_kind = $2, object_value = $3 WHERE id = $4"
+    )?;
+    for row in tx.query("SELECT id, subject, object FROM claims", &[])? {
+        let id_bytes: Vec<u8> = row.try_get(0)?;
+        let columns = EntityColumns::of(row.try_get(1)?, row.try_get(2)?);
+        tx.execute(&update, &[&columns.subject_kind, &columns.object_kind, &columns.object_value, &id_bytes])?;
+    }
+    
+    Ok(())
+}
+
+/// Version 4: the `entity_aliases` table behind `ClaimStore::add_alias`
+fn entity_aliases_table(tx: &mut Transaction<'_>) -> Result<(), StoreError> {
+    tx.batch_execute(
+        "CREATE TABLE entity_aliases (
+            id BIGSERIAL PRIMARY KEY,
+            alias TEXT NOT NULL,
+            canonical TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            created_at BIGINT NOT NULL,
+            retracted_at BIGINT
+        );
+        CREATE UNIQUE INDEX idx_entity_aliases_active ON entity_aliases(alias) WHERE retracted_at IS NULL;
+        CREATE INDEX idx_entity_aliases_canonical ON entity_aliases(canonical);"
+    )?;
+    Ok(())
+}
+
+/// Version 5: the claim's `namespace` and `tier` on each event
+///
+/// Existing events are backfilled from their claim where it still exists;
+/// promotions and demotions take the tier they moved to.
+fn event_claim_columns(tx: &mut Transaction<'_>) -> Result<(), StoreError> {
+    tx.batch_execute(
+        "ALTER TABLE events ADD COLUMN namespace TEXT;
+        ALTER TABLE events ADD COLUMN tier TEXT;
+        UPDATE events SET
+            namespace = (SELECT namespace FROM claims WHERE claims.id = events.claim_id),
+            tier = COALESCE(to_tier, (SELECT tier FROM claims WHERE claims.id = events.claim_id));"
+    )?;
+    Ok(())
+}
+
+/// Version 6: allow `moved` events in the log
+fn moved_events(tx: &mut Transaction<'_>) -> Result<(), StoreError> {
+    tx.batch_execute(
+        "ALTER TABLE events DROP CONSTRAINT events_kind_check;
+        ALTER TABLE events ADD CONSTRAINT events_kind_check CHECK (kind IN
+            ('asserted', 'corroborated', 'promoted', 'demoted', 'evicted', 'relationship_added', 'moved'));"
+    )?;
+    Ok(())
+}
+
+/// Alias records in the order they were added
+fn entity_aliases(client: &mut impl GenericClient, include_retracted: bool) -> Result<Vec<EntityAlias>, StoreError> {
+    client.query(
+        "SELECT alias, canonical, reason, created_at, retracted_at
+         FROM entity_aliases WHERE $1 OR retracted_at IS NULL ORDER BY id",
+        &[&include_retracted],
+    )?
+        .iter()
+        .map(|row| Ok(EntityAlias {
+            alias: row.try_get(0)?,
+            canonical: row.try_get(1)?,
+            reason: row.try_get(2)?,
+            created_at: row.try_get::<_, i64>(3)? as u64,
+            retracted_at: row.try_get::<_, Option<i64>>(4)?.map(|t| t as u64),
+        }))
+        .collect()
+}
+
+/// Create the pgvector extension and embeddings table for `dimension`-sized embeddings
+#[cfg(feature = "pgvector")]
+fn create_embedding_table(client: &mut Client, dimension: usize) -> Result<(), StoreError> {
+    let mut tx = client.transaction()?;
+    tx.execute("SELECT pg_advisory_xact_lock($1)", &[&MIGRATION_LOCK])?;
+    tx.batch_execute(&format!(
+        "CREATE EXTENSION IF NOT EXISTS vector;
+         CREATE TABLE IF NOT EXISTS claim_embeddings (
+             claim_id BYTEA PRIMARY KEY NOT NULL REFERENCES claims(id) ON DELETE CASCADE,
+             embedding vector({}) NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS idx_claim_embeddings_hnsw
+             ON claim_embeddings USING hnsw (embedding vector_cosine_ops);",
+        dimension,
+    ))?;
+    
+    // The table may predate this store; a vector column's type modifier is its dimension
+    let existing: i32 = tx.query_one(
+        "SELECT atttypmod FROM pg_attribute
+         WHERE attrelid = 'claim_embeddings'::regclass AND attname = 'embedding'",
+        &[],
+    )?.try_get(0)?;
+    if existing != dimension as i32 {
+        return Err(StoreError::InvalidData(format!(
+            "Stored embeddings have dimension {}, store configured for {}", existing, dimension
+        )));
+    }
+    
+    tx.commit()?;
+    Ok(())
+}
+
+/// Load a claim by ID
+fn get_claim(client: &mut impl GenericClient, id: ClaimId) -> Result<Option<Claim>, StoreError> {
+    client.query_opt(
+        &format!("SELECT {} FROM claims WHERE id = $1", CLAIM_COLUMNS),
+        &[&SqliteStore::claim_id_to_bytes(id)],
+    )?
+    .as_ref()
+    .map(claim_from_row)
+    .transpose()
+}
+
+/// Insert or update a relationship, inside the caller's transaction
+fn add_relationship(client: &mut impl GenericClient, relationship: &Relationship) -> Result<(), StoreError> {
+    client.execute(
+        "INSERT INTO relationships (from_claim_id, to_claim_id, relationship_type, strength, created_at)
+         VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (from_claim_id, to_claim_id, relationship_type) DO UPDATE SET
+         strength = excluded.strength, created_at = excluded.created_at",
+        &[
+            &SqliteStore::claim_id_to_bytes(relationship.from_claim),
+            &SqliteStore::claim_id_to_bytes(relationship.to_claim),
+            &SqliteStore::relationship_type_to_str(relationship.relationship_type),
+            &relationship.strength,
+            &(relationship.created_at as i64),
+        ],
+    )?;
+    record_event(client, &EventKind::RelationshipAdded {
+        from_claim: relationship.from_claim,
+        to_claim: relationship.to_claim,
+        relationship_type: relationship.relationship_type,
+    })?;
+    
+    Ok(())
+}
+
+/// Append an event to the log, inside the caller's transaction
+///
+/// The claim's current namespace and tier are copied onto the event, so
+/// call this while the claim row still exists.
+fn record_event(client: &mut impl GenericClient, kind: &EventKind) -> Result<(), StoreError> {
+    client.execute("SELECT pg_advisory_xact_lock($1)", &[&EVENT_LOCK])?;
+    
+    let columns = EventColumns::new(kind);
+    client.execute(
+        "INSERT INTO events (recorded_at, kind, claim_id, related_claim_id, relationship_type, from_tier, to_tier, namespace, tier)
+         VALUES ($1, $2, $3, $4, $5, $6, $7,
+                 (SELECT namespace FROM claims WHERE id = $3),
+                 (SELECT tier FROM claims WHERE id = $3))",
+        &[
+            &events::now(),
+            &columns.kind,
+            &columns.claim_id,
+            &columns.related_claim_id,
+            &columns.relationship_type,
+            &columns.from_tier,
+            &columns.to_tier,
+        ],
+    )?;
+    
+    Ok(())
+}
+
+/// Look up the ID of the claim with the given content hash (uses `idx_claims_content_hash`)
+fn find_id_by_content_hash(client: &mut impl GenericClient, content_hash: &str) -> Result<Option<ClaimId>, StoreError> {
+    let row = client.query_opt(
+        "SELECT id FROM claims WHERE content_hash = $1 ORDER BY id LIMIT 1",
+        &[&content_hash],
+    )?;
+    
+    row.map(|row| SqliteStore::bytes_to_claim_id(&row.try_get::<_, Vec<u8>>(0)?))
+        .transpose()
+}
+
+/// Record `claim` as corroboration of `existing_id`
+///
+/// Appends the provenance, folds the incoming confidence into the existing
+/// claim's base interval, pushes back its `stale_at` and invalidates its
+/// cached effective confidence.
+fn corroborate(
+    client: &mut impl GenericClient,
+    existing_id: ClaimId,
+    claim: &Claim,
+    provenance: &[ProvenanceEntry],
+    staleness: &StalenessPolicy,
+) -> Result<(), StoreError> {
+    let id_bytes = SqliteStore::claim_id_to_bytes(existing_id);
+    
+    let row = client.query_one(
+        &format!("SELECT {} FROM claims WHERE id = $1 FOR UPDATE", CLAIM_COLUMNS),
+        &[&id_bytes],
+    )?;
+    let existing = claim_from_row(&row)?;
+    let combined = confidence_computation::corroborate(
+        SqliteStore::confidence_interval(existing.confidence)?,
+        SqliteStore::confidence_interval(claim.confidence)?,
+    );
+    let stale_at = staleness.stale_at_on_corroboration(&existing, claim.created_at);
+    
+    client.execute(
+        "UPDATE claims SET base_lower = $1, base_upper = $2, stale_at = $3 WHERE id = $4",
+        &[&combined.lower, &combined.upper, &stale_at.map(|t| t as i64), &id_bytes],
+    )?;
+    client.execute("DELETE FROM confidence_cache WHERE claim_id = $1", &[&id_bytes])?;
+    insert_provenance(client, &id_bytes, provenance, claim.confidence.1)?;
+    record_event(client, &EventKind::Corroborated(existing_id))?;
+    
+    Ok(())
+}
+
+/// Insert provenance rows for a claim
+fn insert_provenance(
+    client: &mut impl GenericClient,
+    claim_id_bytes: &[u8],
+    provenance: &[ProvenanceEntry],
+    confidence_contribution: f64,
+) -> Result<(), StoreError> {
+    let stmt = client.prepare(
+        "INSERT INTO provenance (claim_id, source, source_type, timestamp, rationale, confidence_contribution)
+         VALUES ($1, $2, $3, $4, $5, $6)"
+    )?;
+    
+    for entry in provenance {
+        client.execute(&stmt, &[
+            &claim_id_bytes,
+            &entry.source,
+            &entry.source_type,
+            &(entry.timestamp as i64),
+            &entry.rationale,
+            &confidence_contribution,
+        ])?;
+    }
+    
+    Ok(())
+}
+
+/// Run `f` inside a savepoint, rolling back only its own writes on failure
+fn in_savepoint<T>(
+    tx: &mut Transaction<'_>,
+    f: impl FnOnce(&mut Transaction<'_>) -> Result<T, StoreError>,
+) -> Result<T, StoreError> {
+    // Dropping the savepoint without committing rolls it back
+    let mut savepoint = tx.savepoint("batch_item")?;
+    let value = f(&mut savepoint)?;
+    savepoint.commit()?;
+    Ok(value)
+}
+
+/// Map a `SELECT source, source_type, timestamp, rationale` row
+fn provenance_from_row(row: &Row) -> Result<ProvenanceEntry, StoreError> {
+    Ok(ProvenanceEntry {
+        source: row.try_get(0)?,
+        source_type: row.try_get(1)?,
+        timestamp: row.try_get::<_, i64>(2)? as u64,
+        rationale: row.try_get(3)?,
+    })
+}
+
+/// Map a `SELECT from_claim_id, to_claim_id, relationship_type, strength, created_at` row
+fn relationship_from_row(row: &Row) -> Result<Relationship, StoreError> {
+    let from_bytes: Vec<u8> = row.try_get(0)?;
+    let to_bytes: Vec<u8> = row.try_get(1)?;
+    let rel_type_str: String = row.try_get(2)?;
+    
+    Ok(Relationship {
+        from_claim: SqliteStore::bytes_to_claim_id(&from_bytes)?,
+        to_claim: SqliteStore::bytes_to_claim_id(&to_bytes)?,
+        relationship_type: SqliteStore::str_to_relationship_type(&rel_type_str)?,
+        strength: row.try_get(3)?,
+        created_at: row.try_get::<_, i64>(4)? as u64,
+    })
+}
+
+/// Map a `SELECT` of [`CLAIM_COLUMNS`] to a claim
+fn claim_from_row(row: &Row) -> Result<Claim, StoreError> {
+    let id_bytes: Vec<u8> = row.try_get(0)?;
+    let stale_at: Option<i64> = row.try_get(9)?;
+    
+    Ok(Claim {
+        id: SqliteStore::bytes_to_claim_id(&id_bytes)?,
+        namespace: row.try_get(1)?,
+        subject: row.try_get(2)?,
+        predicate: row.try_get(3)?,
+        object: row.try_get(4)?,
+        confidence: (row.try_get(5)?, row.try_get(6)?),
+        tier: Tier::from_str(row.try_get(7)?).map_err(StoreError::InvalidData)?,
+        created_at: row.try_get::<_, i64>(8)? as u64,
+        stale_at: stale_at.map(|t| t as u64),
+    })
+}
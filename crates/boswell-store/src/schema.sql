@@ -1,4 +1,5 @@
 -- Boswell SQLite Schema (per ADR-005)
+-- Applied as migration 1; later changes are separate migrations in migrations.rs
 -- This schema supports claims, relationships, provenance, and confidence caching
 
 -- Claims table - the core knowledge store
@@ -112,16 +113,13 @@ CREATE TABLE IF NOT EXISTS confidence_cache (
 );
 
 -- Metadata table for schema versioning and migrations
+-- One row per applied migration; rows are written by the migration runner (migrations.rs)
 CREATE TABLE IF NOT EXISTS schema_info (
     version INTEGER PRIMARY KEY,
     applied_at INTEGER NOT NULL,
     description TEXT
 );
 
--- Insert initial schema version
-INSERT INTO schema_info (version, applied_at, description) 
-VALUES (1, strftime('%s', 'now') * 1000, 'Initial schema with claims, relationships, provenance, and confidence cache');
-
 -- Notes on HNSW vector index:
 -- The HNSW index is maintained separately in a memory-mapped file alongside this SQLite database.
 -- The embedding_vector column in the claims table is primarily for reconstruction/debugging.
@@ -0,0 +1,41 @@
+//! Assembly of namespace statistics from aggregate rows
+//!
+//! The SQLite and PostgreSQL stores compute statistics with the same query
+//! shape: one row per namespace and tier, which is folded here into one
+//! [`NamespaceStats`] per namespace.
+
+use boswell_domain::{NamespaceStats, Tier};
+use std::collections::HashMap;
+
+/// Aggregates for the claims of one tier in one namespace
+pub(crate) struct TierStatsRow {
+    pub namespace: String,
+    pub tier: String,
+    pub claims: u64,
+    pub confidence_sum: f64,
+    pub last_write: u64,
+    pub contradictions: u64,
+}
+
+impl TierStatsRow {
+    /// Fold rows into per-namespace statistics, sorted by namespace
+    pub fn collect(rows: Vec<TierStatsRow>) -> Vec<NamespaceStats> {
+        let mut stats: HashMap<String, NamespaceStats> = HashMap::new();
+        
+        for row in rows {
+            let entry = stats.entry(row.namespace)
+                .or_insert_with_key(|namespace| NamespaceStats::new(namespace.clone()));
+            entry.claims += row.claims;
+            if let Some(tier) = Tier::parse(&row.tier) {
+                entry.tier_counts[tier as usize] += row.claims;
+            }
+            entry.confidence_sum += row.confidence_sum;
+            entry.last_write = entry.last_write.max(row.last_write);
+            entry.contradictions += row.contradictions;
+        }
+        
+        let mut stats: Vec<NamespaceStats> = stats.into_values().collect();
+        NamespaceStats::sort(&mut stats);
+        stats
+    }
+}
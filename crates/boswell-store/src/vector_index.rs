@@ -17,17 +17,47 @@
 //!   Higher efConstruction = better index quality but slower build
 //! - **efSearch**: Size of dynamic candidate list during search (default: 64)
 //!   Higher efSearch = better recall but slower queries
+//!
+//! M and efConstruction (plus the expected capacity) are fixed when the graph
+//! is built and can be set per store via [`VectorIndexConfig`]. Use
+//! [`VectorIndex::stats`] and [`VectorIndex::recall_self_test`] to tune
+//! recall against latency for a given corpus.
+//!
+//! # Deletion
+//!
+//! HNSW graphs do not support in-place removal, so deleted claims are
+//! tombstoned: their internal IDs are dropped from the ID map and filtered out
+//! at search time. Once the tombstone ratio grows past a threshold, `compact`
+//! rebuilds the graph from the live vectors (driven by the Janitor).
 
+use crate::embedding::cosine_similarity;
 use boswell_domain::ClaimId;
 use hnsw_rs::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
-/// Default HNSW parameters optimized for 384-dimensional embeddings
-const DEFAULT_M: usize = 16;
-const DEFAULT_EF_CONSTRUCTION: usize = 200;
-const DEFAULT_MAX_ELEMENTS: usize = 1_000_000;
+/// Default number of bi-directional links per node (optimized for 384-dimensional embeddings)
+pub const DEFAULT_M: usize = 16;
+
+/// Default candidate list size during construction
+pub const DEFAULT_EF_CONSTRUCTION: usize = 200;
+
+/// Default expected capacity of the index
+pub const DEFAULT_MAX_ELEMENTS: usize = 1_000_000;
+
+/// Largest M supported by the underlying HNSW implementation
+pub const MAX_M: usize = 256;
+
+/// Approximate size of one neighbour link (point reference + distance)
+const LINK_BYTES: usize = 16;
+
+/// Approximate size of one live entry across both ID maps
+const ID_MAP_ENTRY_BYTES: usize = 64;
+
+/// Default tombstone ratio above which the index should be compacted
+pub const DEFAULT_COMPACTION_THRESHOLD: f64 = 0.2;
 
 /// Errors that can occur during vector index operations
 #[derive(Error, Debug)]
@@ -48,6 +78,124 @@ pub enum VectorIndexError {
     /// Internal HNSW error
     #[error("HNSW error: {0}")]
     Internal(String),
+    
+    /// Invalid index configuration
+    #[error("Invalid vector index configuration: {0}")]
+    InvalidConfig(String),
+}
+
+/// HNSW construction parameters for a vector index
+///
+/// # Examples
+///
+/// ```
+/// use boswell_store::vector_index::VectorIndexConfig;
+///
+/// // Higher recall at the cost of memory and build time
+/// let config = VectorIndexConfig {
+///     m: 32,
+///     ef_construction: 400,
+///     ..Default::default()
+/// };
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VectorIndexConfig {
+    /// Number of bi-directional links per node (1..=256)
+    pub m: usize,
+    
+    /// Size of the dynamic candidate list during construction
+    pub ef_construction: usize,
+    
+    /// Expected number of elements (used to size the layer structure)
+    pub max_elements: usize,
+}
+
+impl Default for VectorIndexConfig {
+    fn default() -> Self {
+        Self {
+            m: DEFAULT_M,
+            ef_construction: DEFAULT_EF_CONSTRUCTION,
+            max_elements: DEFAULT_MAX_ELEMENTS,
+        }
+    }
+}
+
+impl VectorIndexConfig {
+    /// Check that the parameters are within the ranges HNSW supports
+    pub fn validate(&self) -> Result<(), VectorIndexError> {
+        if self.m == 0 || self.m > MAX_M {
+            return Err(VectorIndexError::InvalidConfig(
+                format!("m must be in [1, {}], got {}", MAX_M, self.m)
+            ));
+        }
+        if self.ef_construction == 0 {
+            return Err(VectorIndexError::InvalidConfig(
+                "ef_construction must be positive".to_string()
+            ));
+        }
+        if self.max_elements == 0 {
+            return Err(VectorIndexError::InvalidConfig(
+                "max_elements must be positive".to_string()
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Number of layers to allocate based on expected data size
+    fn nb_layer(&self) -> usize {
+        16.min((self.max_elements as f32).ln().trunc() as usize).max(1)
+    }
+}
+
+/// Point-in-time statistics for a vector index
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorIndexStats {
+    /// Embedding dimension
+    pub dimension: usize,
+    
+    /// Parameters the graph was built with
+    pub config: VectorIndexConfig,
+    
+    /// Number of live (searchable) entries
+    pub element_count: usize,
+    
+    /// Number of tombstoned entries still occupying the graph
+    pub tombstone_count: usize,
+    
+    /// Fraction of graph entries that are tombstones
+    pub tombstone_ratio: f64,
+    
+    /// Number of graph points whose top layer is each layer (index = layer)
+    pub layer_distribution: Vec<usize>,
+    
+    /// Rough estimate of heap memory used by vectors, links and ID maps
+    pub memory_estimate_bytes: usize,
+}
+
+/// Result of comparing HNSW search against exact brute-force search
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecallReport {
+    /// Number of sample queries evaluated
+    pub queries: usize,
+    
+    /// Neighbours requested per query (capped by the number of other entries)
+    pub k: usize,
+    
+    /// efSearch used for the HNSW queries
+    pub ef_search: usize,
+    
+    /// Mean fraction of the exact top-k found by HNSW, in [0.0, 1.0]
+    pub recall: f64,
+    
+    /// Worst single-query recall
+    pub min_recall: f64,
+    
+    /// Mean HNSW query latency
+    pub mean_hnsw_latency: Duration,
+    
+    /// Mean brute-force query latency
+    pub mean_brute_force_latency: Duration,
 }
 
 /// A wrapper around HNSW for vector similarity search
@@ -72,13 +220,22 @@ pub struct VectorIndex {
     /// Expected embedding dimension
     dimension: usize,
     
+    /// HNSW construction parameters
+    config: VectorIndexConfig,
+    
     /// HNSW index (wrapped in Arc<Mutex> for thread-safe access)
     /// Note: No lifetime parameter - hnsw_rs owns the data
     hnsw: Arc<Mutex<Hnsw<'static, f32, DistCosine>>>,
     
-    /// Mapping from internal HNSW IDs to ClaimIds
+    /// Mapping from internal HNSW IDs to ClaimIds (live entries only)
     id_map: Arc<Mutex<HashMap<usize, ClaimId>>>,
     
+    /// Reverse mapping from ClaimIds to their current internal HNSW ID
+    claim_map: Arc<Mutex<HashMap<ClaimId, usize>>>,
+    
+    /// Internal HNSW IDs that have been removed but are still in the graph
+    tombstones: Arc<Mutex<HashSet<usize>>>,
+    
     /// Counter for next internal ID
     next_id: Arc<Mutex<usize>>,
 }
@@ -86,32 +243,74 @@ pub struct VectorIndex {
 impl VectorIndex {
     /// Create a new vector index with the specified dimension
     ///
+    /// Uses the default HNSW parameters (see [`VectorIndexConfig::default`]).
+    ///
     /// # Parameters
     ///
     /// - `dimension`: Embedding vector dimension (e.g., 384 for bge-small)
     pub fn new(dimension: usize) -> Self {
-        // Calculate number of layers based on expected data size
-        let nb_layer = 16.min((DEFAULT_MAX_ELEMENTS as f32).ln().trunc() as usize);
-        
-        // Initialize HNSW
-        let hnsw = Hnsw::<'static, f32, DistCosine>::new(
-            DEFAULT_M,
-            DEFAULT_MAX_ELEMENTS,
-            nb_layer,
-            DEFAULT_EF_CONSTRUCTION,
-            DistCosine {},
-        );
-        
+        Self::build(dimension, VectorIndexConfig::default())
+    }
+    
+    /// Create a new vector index with custom HNSW parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidConfig` if the parameters are out of range
+    pub fn with_config(dimension: usize, config: VectorIndexConfig) -> Result<Self, VectorIndexError> {
+        config.validate()?;
+        Ok(Self::build(dimension, config))
+    }
+    
+    fn build(dimension: usize, config: VectorIndexConfig) -> Self {
         Self {
             dimension,
-            hnsw: Arc::new(Mutex::new(hnsw)),
+            config,
+            hnsw: Arc::new(Mutex::new(Self::build_hnsw(&config))),
             id_map: Arc::new(Mutex::new(HashMap::new())),
+            claim_map: Arc::new(Mutex::new(HashMap::new())),
+            tombstones: Arc::new(Mutex::new(HashSet::new())),
             next_id: Arc::new(Mutex::new(0)),
         }
     }
     
+    /// Create an empty HNSW graph with the given parameters
+    fn build_hnsw(config: &VectorIndexConfig) -> Hnsw<'static, f32, DistCosine> {
+        Hnsw::<'static, f32, DistCosine>::new(
+            config.m,
+            config.max_elements,
+            config.nb_layer(),
+            config.ef_construction,
+            DistCosine {},
+        )
+    }
+    
+    /// Get the HNSW parameters this index was built with
+    pub fn config(&self) -> VectorIndexConfig {
+        self.config
+    }
+    
+    /// Collect the vectors of all live entries with their internal IDs
+    fn live_points(
+        hnsw: &Hnsw<'static, f32, DistCosine>,
+        id_map: &HashMap<usize, ClaimId>,
+    ) -> Vec<(Vec<f32>, usize)> {
+        if hnsw.get_nb_point() == 0 {
+            return Vec::new();
+        }
+        
+        hnsw.get_point_indexation()
+            .into_iter()
+            .filter(|point| id_map.contains_key(&point.get_origin_id()))
+            .map(|point| (point.get_v().to_vec(), point.get_origin_id()))
+            .collect()
+    }
+    
     /// Add a claim embedding to the index
     ///
+    /// If the claim is already indexed, its previous embedding is tombstoned
+    /// and replaced by the new one.
+    ///
     /// # Parameters
     ///
     /// - `claim_id`: The claim ID
@@ -130,9 +329,15 @@ impl VectorIndex {
         *next_id += 1;
         drop(next_id);
         
-        // Store the mapping
+        // Store the mapping, tombstoning any previous embedding for this claim
         let mut id_map = self.id_map.lock().unwrap();
+        let mut claim_map = self.claim_map.lock().unwrap();
         id_map.insert(internal_id, claim_id);
+        if let Some(previous) = claim_map.insert(claim_id, internal_id) {
+            id_map.remove(&previous);
+            self.tombstones.lock().unwrap().insert(previous);
+        }
+        drop(claim_map);
         drop(id_map);
         
         // Insert into HNSW (convert slice to owned Vec for 'static lifetime)
@@ -143,9 +348,30 @@ impl VectorIndex {
         Ok(())
     }
     
+    /// Remove a claim's embedding from the index
+    ///
+    /// The entry is tombstoned rather than physically removed; it is excluded
+    /// from search results immediately and reclaimed by the next `compact`.
+    ///
+    /// Returns `true` if the claim was indexed.
+    pub fn remove(&self, claim_id: ClaimId) -> bool {
+        let mut id_map = self.id_map.lock().unwrap();
+        let mut claim_map = self.claim_map.lock().unwrap();
+        
+        match claim_map.remove(&claim_id) {
+            Some(internal_id) => {
+                id_map.remove(&internal_id);
+                self.tombstones.lock().unwrap().insert(internal_id);
+                true
+            }
+            None => false,
+        }
+    }
+    
     /// Search for the k nearest neighbors to the given embedding
     ///
     /// Returns a list of (ClaimId, similarity_score) pairs, sorted by similarity (descending).
+    /// Tombstoned entries are never returned.
     ///
     /// # Parameters
     ///
@@ -162,9 +388,15 @@ impl VectorIndex {
         
         let hnsw = self.hnsw.lock().unwrap();
         let id_map = self.id_map.lock().unwrap();
+        let tombstones = self.tombstones.lock().unwrap();
         
-        // Search HNSW
-        let results = hnsw.search(query, k, ef_search);
+        // Search HNSW, skipping tombstoned entries so they don't consume the k slots
+        let results = if tombstones.is_empty() {
+            hnsw.search(query, k, ef_search)
+        } else {
+            let live = |id: &usize| !tombstones.contains(id);
+            hnsw.search_filter(query, k, ef_search, Some(&live))
+        };
         
         // Map internal IDs back to ClaimIds
         let mapped_results: Vec<(ClaimId, f32)> = results
@@ -183,7 +415,25 @@ impl VectorIndex {
         Ok(mapped_results)
     }
     
-    /// Get the number of vectors in the index
+    /// Embedding dimension the index accepts
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+    
+    /// Copy out every live entry, for snapshots
+    ///
+    /// Tombstoned entries are skipped. Order is unspecified.
+    pub fn entries(&self) -> Vec<(ClaimId, Vec<f32>)> {
+        let hnsw = self.hnsw.lock().unwrap();
+        let id_map = self.id_map.lock().unwrap();
+        
+        Self::live_points(&hnsw, &id_map)
+            .into_iter()
+            .map(|(vector, internal_id)| (id_map[&internal_id], vector))
+            .collect()
+    }
+    
+    /// Get the number of live (non-tombstoned) vectors in the index
     pub fn len(&self) -> usize {
         let id_map = self.id_map.lock().unwrap();
         id_map.len()
@@ -194,26 +444,198 @@ impl VectorIndex {
         self.len() == 0
     }
     
-    /// Clear all vectors from the index
-    pub fn clear(&self) {
-        let nb_layer = 16.min((DEFAULT_MAX_ELEMENTS as f32).ln().trunc() as usize);
+    /// Get the number of tombstoned entries awaiting compaction
+    pub fn tombstone_count(&self) -> usize {
+        self.tombstones.lock().unwrap().len()
+    }
+    
+    /// Fraction of graph entries that are tombstones, in [0.0, 1.0]
+    pub fn tombstone_ratio(&self) -> f64 {
+        let live = self.len();
+        let dead = self.tombstone_count();
         
-        let hnsw = Hnsw::<'static, f32, DistCosine>::new(
-            DEFAULT_M,
-            DEFAULT_MAX_ELEMENTS,
-            nb_layer,
-            DEFAULT_EF_CONSTRUCTION,
-            DistCosine {},
-        );
+        if live + dead == 0 {
+            0.0
+        } else {
+            dead as f64 / (live + dead) as f64
+        }
+    }
+    
+    /// Check whether the tombstone ratio has reached the given threshold
+    pub fn needs_compaction(&self, threshold: f64) -> bool {
+        self.tombstone_count() > 0 && self.tombstone_ratio() >= threshold
+    }
+    
+    /// Rebuild the HNSW graph from live entries, dropping all tombstones
+    ///
+    /// Internal IDs are preserved, so the ID maps remain valid. Returns the
+    /// number of tombstoned entries reclaimed.
+    pub fn compact(&self) -> usize {
+        let mut hnsw = self.hnsw.lock().unwrap();
+        let id_map = self.id_map.lock().unwrap();
+        let mut tombstones = self.tombstones.lock().unwrap();
+        
+        if tombstones.is_empty() {
+            return 0;
+        }
         
+        // Collect live vectors before replacing the graph
+        let live_points = Self::live_points(&hnsw, &id_map);
+        
+        let rebuilt = Self::build_hnsw(&self.config);
+        for (vector, internal_id) in &live_points {
+            rebuilt.insert((vector, *internal_id));
+        }
+        *hnsw = rebuilt;
+        
+        let reclaimed = tombstones.len();
+        tombstones.clear();
+        reclaimed
+    }
+    
+    /// Gather statistics about the index structure and its memory footprint
+    pub fn stats(&self) -> VectorIndexStats {
+        let hnsw = self.hnsw.lock().unwrap();
+        let id_map = self.id_map.lock().unwrap();
+        let tombstones = self.tombstones.lock().unwrap();
+        
+        let indexation = hnsw.get_point_indexation();
+        let top_layer = if hnsw.get_nb_point() == 0 {
+            0
+        } else {
+            hnsw.get_max_level_observed() as usize + 1
+        };
+        let layer_distribution: Vec<usize> = (0..top_layer)
+            .map(|layer| indexation.get_layer_nb_point(layer))
+            .collect();
+        
+        // Each point stores its vector, up to 2*M links on layer 0 and up to
+        // M links on every layer above it up to its own top layer.
+        let graph_points: usize = layer_distribution.iter().sum();
+        let link_slots: usize = layer_distribution.iter()
+            .enumerate()
+            .map(|(layer, &count)| count * (2 * self.config.m + layer * self.config.m))
+            .sum();
+        let memory_estimate_bytes = graph_points * self.dimension * std::mem::size_of::<f32>()
+            + link_slots * LINK_BYTES
+            + id_map.len() * ID_MAP_ENTRY_BYTES;
+        
+        let element_count = id_map.len();
+        let tombstone_count = tombstones.len();
+        let tombstone_ratio = if element_count + tombstone_count == 0 {
+            0.0
+        } else {
+            tombstone_count as f64 / (element_count + tombstone_count) as f64
+        };
+        
+        VectorIndexStats {
+            dimension: self.dimension,
+            config: self.config,
+            element_count,
+            tombstone_count,
+            tombstone_ratio,
+            layer_distribution,
+            memory_estimate_bytes,
+        }
+    }
+    
+    /// Measure HNSW recall against exact brute-force cosine search
+    ///
+    /// Up to `sample_size` live entries, evenly spaced across the index, are
+    /// used as queries. For each, the exact top-`k` neighbours (excluding the
+    /// query entry itself) are compared with the HNSW results at `ef_search`.
+    ///
+    /// Brute force is O(n) per query, so keep the sample small on large indexes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoResults` if the index has fewer than two live entries and
+    /// `InvalidConfig` if `sample_size` or `k` is zero.
+    pub fn recall_self_test(
+        &self,
+        sample_size: usize,
+        k: usize,
+        ef_search: usize,
+    ) -> Result<RecallReport, VectorIndexError> {
+        if sample_size == 0 || k == 0 {
+            return Err(VectorIndexError::InvalidConfig(
+                "sample_size and k must be positive".to_string()
+            ));
+        }
+        
+        let points = {
+            let hnsw = self.hnsw.lock().unwrap();
+            let id_map = self.id_map.lock().unwrap();
+            Self::live_points(&hnsw, &id_map)
+                .into_iter()
+                .filter_map(|(vector, internal_id)| {
+                    id_map.get(&internal_id).map(|&claim_id| (claim_id, vector))
+                })
+                .collect::<Vec<_>>()
+        };
+        
+        if points.len() < 2 {
+            return Err(VectorIndexError::NoResults);
+        }
+        
+        let k = k.min(points.len() - 1);
+        let queries = sample_size.min(points.len());
+        let step = points.len() / queries;
+        
+        let mut total_recall = 0.0;
+        let mut min_recall = 1.0_f64;
+        let mut hnsw_time = Duration::ZERO;
+        let mut brute_force_time = Duration::ZERO;
+        
+        for (query_id, query) in points.iter().step_by(step).take(queries) {
+            let started = Instant::now();
+            let mut exact: Vec<(ClaimId, f32)> = points.iter()
+                .filter(|(claim_id, _)| claim_id != query_id)
+                .map(|(claim_id, vector)| (*claim_id, cosine_similarity(query, vector)))
+                .collect();
+            exact.sort_by(|a, b| b.1.total_cmp(&a.1));
+            exact.truncate(k);
+            brute_force_time += started.elapsed();
+            
+            let started = Instant::now();
+            let approximate = self.search(query, k + 1, ef_search)?;
+            hnsw_time += started.elapsed();
+            
+            let found: HashSet<ClaimId> = approximate.into_iter()
+                .map(|(claim_id, _)| claim_id)
+                .filter(|claim_id| claim_id != query_id)
+                .collect();
+            let hits = exact.iter().filter(|(claim_id, _)| found.contains(claim_id)).count();
+            
+            let recall = hits as f64 / k as f64;
+            total_recall += recall;
+            min_recall = min_recall.min(recall);
+        }
+        
+        Ok(RecallReport {
+            queries,
+            k,
+            ef_search,
+            recall: total_recall / queries as f64,
+            min_recall,
+            mean_hnsw_latency: hnsw_time / queries as u32,
+            mean_brute_force_latency: brute_force_time / queries as u32,
+        })
+    }
+    
+    /// Clear all vectors from the index
+    pub fn clear(&self) {
         let mut hnsw_lock = self.hnsw.lock().unwrap();
-        *hnsw_lock = hnsw;
+        *hnsw_lock = Self::build_hnsw(&self.config);
         drop(hnsw_lock);
         
         let mut id_map = self.id_map.lock().unwrap();
         id_map.clear();
         drop(id_map);
         
+        self.claim_map.lock().unwrap().clear();
+        self.tombstones.lock().unwrap().clear();
+        
         let mut next_id = self.next_id.lock().unwrap();
         *next_id = 0;
     }
@@ -311,4 +733,169 @@ mod tests {
         assert_eq!(results[2].0, claim_id2);
         assert!(results[2].1 < 0.1); // Orthogonal = cos(90) = 0
     }
+    
+    #[test]
+    fn test_remove_excludes_from_search() {
+        let index = VectorIndex::new(3);
+        
+        let claim_id1 = ClaimId::new();
+        index.add(claim_id1, &[1.0, 0.0, 0.0]).unwrap();
+        
+        let claim_id2 = ClaimId::new();
+        index.add(claim_id2, &[0.9, 0.1, 0.0]).unwrap();
+        
+        assert!(index.remove(claim_id1));
+        assert!(!index.remove(claim_id1)); // Already removed
+        
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.tombstone_count(), 1);
+        
+        let results = index.search(&[1.0, 0.0, 0.0], 2, 64).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, claim_id2);
+    }
+    
+    #[test]
+    fn test_re_adding_claim_replaces_embedding() {
+        let index = VectorIndex::new(3);
+        
+        let claim_id = ClaimId::new();
+        index.add(claim_id, &[1.0, 0.0, 0.0]).unwrap();
+        index.add(claim_id, &[0.0, 1.0, 0.0]).unwrap();
+        
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.tombstone_count(), 1);
+        
+        let results = index.search(&[0.0, 1.0, 0.0], 5, 64).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].1 > 0.99);
+    }
+    
+    #[test]
+    fn test_entries_skip_tombstones() {
+        let index = VectorIndex::new(3);
+        
+        let kept = ClaimId::new();
+        let removed = ClaimId::new();
+        index.add(kept, &[1.0, 0.0, 0.0]).unwrap();
+        index.add(removed, &[0.0, 1.0, 0.0]).unwrap();
+        index.add(kept, &[0.0, 0.0, 1.0]).unwrap();
+        index.remove(removed);
+        
+        assert_eq!(index.entries(), vec![(kept, vec![0.0, 0.0, 1.0])]);
+        assert!(VectorIndex::new(3).entries().is_empty());
+    }
+    
+    #[test]
+    fn test_compaction_reclaims_tombstones() {
+        let index = VectorIndex::new(3);
+        
+        let ids: Vec<ClaimId> = (0..10).map(|_| ClaimId::new()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            index.add(*id, &[1.0, i as f32 / 10.0, 0.0]).unwrap();
+        }
+        
+        for id in &ids[..4] {
+            index.remove(*id);
+        }
+        
+        assert!((index.tombstone_ratio() - 0.4).abs() < 1e-9);
+        assert!(index.needs_compaction(DEFAULT_COMPACTION_THRESHOLD));
+        assert!(!index.needs_compaction(0.5));
+        
+        assert_eq!(index.compact(), 4);
+        assert_eq!(index.tombstone_count(), 0);
+        assert_eq!(index.len(), 6);
+        assert!(!index.needs_compaction(DEFAULT_COMPACTION_THRESHOLD));
+        
+        // Surviving entries are still searchable after the rebuild
+        let results = index.search(&[1.0, 0.9, 0.0], 10, 64).unwrap();
+        assert_eq!(results.len(), 6);
+        assert_eq!(results[0].0, ids[9]);
+        
+        // Nothing left to reclaim
+        assert_eq!(index.compact(), 0);
+    }
+    
+    #[test]
+    fn test_compaction_of_fully_deleted_index() {
+        let index = VectorIndex::new(3);
+        
+        let claim_id = ClaimId::new();
+        index.add(claim_id, &[1.0, 0.0, 0.0]).unwrap();
+        index.remove(claim_id);
+        
+        assert_eq!(index.tombstone_ratio(), 1.0);
+        assert_eq!(index.compact(), 1);
+        assert!(index.is_empty());
+        assert!(index.search(&[1.0, 0.0, 0.0], 5, 64).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_invalid_config_rejected() {
+        let config = VectorIndexConfig { m: MAX_M + 1, ..Default::default() };
+        assert!(matches!(
+            VectorIndex::with_config(3, config),
+            Err(VectorIndexError::InvalidConfig(_))
+        ));
+        
+        let config = VectorIndexConfig { ef_construction: 0, ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+    
+    #[test]
+    fn test_stats() {
+        let config = VectorIndexConfig { m: 8, ef_construction: 50, max_elements: 1000 };
+        let index = VectorIndex::with_config(4, config).unwrap();
+        
+        let empty = index.stats();
+        assert_eq!(empty.element_count, 0);
+        assert!(empty.layer_distribution.is_empty());
+        assert_eq!(empty.memory_estimate_bytes, 0);
+        
+        let ids: Vec<ClaimId> = (0..50).map(|_| ClaimId::new()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            index.add(*id, &[1.0, i as f32, (i % 7) as f32, 0.5]).unwrap();
+        }
+        index.remove(ids[0]);
+        
+        let stats = index.stats();
+        assert_eq!(stats.config, config);
+        assert_eq!(stats.dimension, 4);
+        assert_eq!(stats.element_count, 49);
+        assert_eq!(stats.tombstone_count, 1);
+        assert!((stats.tombstone_ratio - 0.02).abs() < 1e-9);
+        
+        // Tombstones still occupy the graph until compaction
+        assert_eq!(stats.layer_distribution.iter().sum::<usize>(), 50);
+        assert!(stats.layer_distribution[0] > 0);
+        assert!(stats.memory_estimate_bytes >= 50 * 4 * std::mem::size_of::<f32>());
+    }
+    
+    #[test]
+    fn test_recall_self_test() {
+        let index = VectorIndex::new(8);
+        
+        for i in 0..200 {
+            let embedding: Vec<f32> = (0..8)
+                .map(|d| ((i * 31 + d * 17) % 97) as f32 / 97.0)
+                .collect();
+            index.add(ClaimId::new(), &embedding).unwrap();
+        }
+        
+        let report = index.recall_self_test(20, 10, 128).unwrap();
+        assert_eq!(report.queries, 20);
+        assert_eq!(report.k, 10);
+        assert!(report.recall > 0.9, "recall too low: {}", report.recall);
+        assert!(report.min_recall <= report.recall);
+    }
+    
+    #[test]
+    fn test_recall_self_test_requires_entries() {
+        let index = VectorIndex::new(3);
+        index.add(ClaimId::new(), &[1.0, 0.0, 0.0]).unwrap();
+        
+        assert!(matches!(index.recall_self_test(10, 5, 64), Err(VectorIndexError::NoResults)));
+        assert!(matches!(index.recall_self_test(0, 5, 64), Err(VectorIndexError::InvalidConfig(_))));
+    }
 }
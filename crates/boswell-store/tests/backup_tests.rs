@@ -0,0 +1,156 @@
+//! Integration tests for online backup and restore
+//!
+//! Backups are taken from live stores, then restored into fresh database
+//! files and opened again.
+
+use boswell_domain::{Claim, ClaimId, Tier};
+use boswell_domain::traits::{ClaimQuery, ClaimStore};
+use boswell_store::{
+    read_manifest, EmbeddingModel, MockEmbeddingModel, PoolConfig, PooledStore, SqliteStore, StoreError,
+    BACKUP_FORMAT_VERSION, SCHEMA_VERSION,
+};
+use rusqlite::Connection;
+use std::fs;
+use tempfile::TempDir;
+
+const SCHEMA_V1: &str = include_str!("fixtures/schema_v1.sql");
+
+fn test_claim(subject: &str) -> Claim {
+    Claim {
+        id: ClaimId::new(),
+        namespace: "test".to_string(),
+        subject: subject.to_string(),
+        predicate: "knows".to_string(),
+        object: "Bob".to_string(),
+        confidence: (0.8, 0.9),
+        tier: Tier::Task,
+        created_at: 1000,
+        stale_at: None,
+    }
+}
+
+fn embed(claim: &Claim, dimension: usize) -> Vec<f32> {
+    let text = format!("{} {} {}", claim.subject, claim.predicate, claim.object);
+    MockEmbeddingModel::new(dimension).embed(&text).unwrap()
+}
+
+#[test]
+fn test_pooled_backup_round_trip_with_vectors() {
+    let dir = TempDir::new().unwrap();
+    let mut store = PooledStore::new(dir.path().join("live.db"), true, 64).unwrap();
+    let claims: Vec<Claim> = ["Alice", "Carol", "Dave"].iter().map(|s| test_claim(s)).collect();
+    for claim in &claims {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    store.delete_claim(claims[2].id).unwrap();
+    let backed_up = store.get(claims[0].id).unwrap();
+    
+    let backup_dir = dir.path().join("backup");
+    let manifest = store.backup(&backup_dir).unwrap();
+    assert_eq!(manifest.format_version, BACKUP_FORMAT_VERSION);
+    assert_eq!(manifest.schema_version, SCHEMA_VERSION);
+    assert_eq!(manifest.claim_count, 2);
+    let embeddings = manifest.embeddings.clone().unwrap();
+    assert_eq!((embeddings.model.as_str(), embeddings.dimension, embeddings.count), ("mock-hash-v1", 64, 2));
+    assert_eq!(read_manifest(&backup_dir).unwrap(), manifest);
+    
+    // The live store keeps working after the backup
+    store.assert_claim(test_claim("Erin")).unwrap();
+    
+    let restored = PooledStore::restore(&backup_dir, dir.path().join("restored.db"), true, 64, PoolConfig::default()).unwrap();
+    assert_eq!(restored.query(&ClaimQuery::default()).unwrap().len(), 2);
+    assert_eq!(restored.get(claims[0].id).unwrap(), backed_up);
+    assert_eq!(restored.vector_index_stats().unwrap().element_count, 2);
+    
+    let results = restored.semantic_search(&embed(&claims[1], 64), 1, 50, 0.99).unwrap();
+    assert_eq!(results[0].0.id, claims[1].id);
+}
+
+#[test]
+fn test_restore_without_snapshot_reembeds_claims() {
+    let dir = TempDir::new().unwrap();
+    let mut store = SqliteStore::new(dir.path().join("live.db"), false, 0).unwrap();
+    let claim = test_claim("Alice");
+    store.assert_claim(claim.clone()).unwrap();
+    
+    let backup_dir = dir.path().join("backup");
+    assert_eq!(store.backup(&backup_dir).unwrap().embeddings, None);
+    
+    let restored = SqliteStore::restore(&backup_dir, dir.path().join("restored.db"), true, 32).unwrap();
+    assert_eq!(restored.vector_index_stats().unwrap().element_count, 1);
+    let results = restored.semantic_search(&embed(&claim, 32), 1, 50, 0.99).unwrap();
+    assert_eq!(results[0].0.id, claim.id);
+}
+
+#[test]
+fn test_restore_rejects_other_embedding_dimension() {
+    let dir = TempDir::new().unwrap();
+    let mut store = SqliteStore::new(dir.path().join("live.db"), true, 64).unwrap();
+    store.assert_claim(test_claim("Alice")).unwrap();
+    
+    let backup_dir = dir.path().join("backup");
+    store.backup(&backup_dir).unwrap();
+    
+    let target = dir.path().join("restored.db");
+    assert!(matches!(
+        SqliteStore::restore(&backup_dir, &target, true, 128),
+        Err(StoreError::InvalidBackup(_))
+    ));
+    assert!(!target.exists(), "a rejected restore must not create the target");
+    
+    // Without vector search the snapshot is not needed
+    let restored = SqliteStore::restore(&backup_dir, &target, false, 0).unwrap();
+    assert_eq!(restored.query_claims(&ClaimQuery::default()).unwrap().len(), 1);
+}
+
+#[test]
+fn test_restore_rejects_newer_schema() {
+    let dir = TempDir::new().unwrap();
+    let store = SqliteStore::new(dir.path().join("live.db"), false, 0).unwrap();
+    let backup_dir = dir.path().join("backup");
+    let mut manifest = store.backup(&backup_dir).unwrap();
+    
+    manifest.schema_version = SCHEMA_VERSION + 1;
+    fs::write(backup_dir.join("manifest.json"), serde_json::to_string(&manifest).unwrap()).unwrap();
+    
+    match SqliteStore::restore(&backup_dir, dir.path().join("restored.db"), false, 0) {
+        Err(StoreError::SchemaTooNew { found, supported }) => {
+            assert_eq!(found, SCHEMA_VERSION + 1);
+            assert_eq!(supported, SCHEMA_VERSION);
+        }
+        Err(e) => panic!("expected SchemaTooNew, got {}", e),
+        Ok(_) => panic!("restored a backup with a newer schema"),
+    }
+}
+
+#[test]
+fn test_restore_migrates_older_schema() {
+    let dir = TempDir::new().unwrap();
+    let backup_dir = dir.path().join("backup");
+    fs::create_dir(&backup_dir).unwrap();
+    Connection::open(backup_dir.join("claims.db")).unwrap().execute_batch(SCHEMA_V1).unwrap();
+    fs::write(
+        backup_dir.join("manifest.json"),
+        r#"{"format_version": 1, "schema_version": 1, "created_at": 0, "claim_count": 3, "embeddings": null}"#,
+    ).unwrap();
+    
+    let restored = SqliteStore::restore(&backup_dir, dir.path().join("restored.db"), false, 0).unwrap();
+    assert!(!restored.query_claims(&ClaimQuery::default()).unwrap().is_empty());
+    
+    let target = Connection::open(dir.path().join("restored.db")).unwrap();
+    assert_eq!(boswell_store::migrations::current_version(&target).unwrap(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_backup_and_restore_refuse_to_overwrite() {
+    let dir = TempDir::new().unwrap();
+    let store = SqliteStore::new(dir.path().join("live.db"), false, 0).unwrap();
+    let backup_dir = dir.path().join("backup");
+    store.backup(&backup_dir).unwrap();
+    
+    assert!(matches!(store.backup(&backup_dir), Err(StoreError::InvalidData(_))));
+    assert!(matches!(
+        SqliteStore::restore(&backup_dir, dir.path().join("live.db"), false, 0),
+        Err(StoreError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,43 @@
+//! Every ClaimStore implementation in this crate must pass the same conformance suite
+
+use tempfile::TempDir;
+
+mod sqlite_store {
+    use boswell_store::SqliteStore;
+    
+    boswell_store::claim_store_conformance!(SqliteStore::new(":memory:", false, 0).unwrap());
+}
+
+mod sqlite_store_with_vector_search {
+    use boswell_store::SqliteStore;
+    
+    // Every neighbour counts as semantically close, so only the structural
+    // checks keep the suite's deliberately distinct claims apart
+    fn store() -> SqliteStore {
+        let mut store = SqliteStore::new(":memory:", true, 16).unwrap();
+        store.set_duplicate_threshold(-1.0);
+        store
+    }
+    
+    boswell_store::claim_store_conformance!(store());
+}
+
+mod pooled_store {
+    use super::TempDir;
+    use boswell_store::PooledStore;
+    
+    // The directory must outlive the store, which the suite takes by value
+    fn store() -> (TempDir, PooledStore) {
+        let dir = TempDir::new().unwrap();
+        let store = PooledStore::new(dir.path().join("boswell.db"), false, 0).unwrap();
+        (dir, store)
+    }
+    
+    boswell_store::claim_store_conformance!(guarded: store());
+}
+
+mod in_memory_store {
+    use boswell_store::InMemoryStore;
+    
+    boswell_store::claim_store_conformance!(InMemoryStore::new());
+}
@@ -2,8 +2,8 @@
 //!
 //! These tests verify the full CRUD cycle for claims and relationships.
 
-use boswell_domain::{Claim, ClaimId, Relationship, RelationshipType};
-use boswell_domain::traits::{ClaimStore, ClaimQuery};
+use boswell_domain::{Claim, ClaimId, EventKind, ProvenanceEntry, Relationship, RelationshipType, StalenessPolicy, StalenessRule, Tier};
+use boswell_domain::traits::{AssertOutcome, BatchClaim, ClaimBatch, ClaimStore, ClaimQuery};
 use boswell_store::SqliteStore;
 
 #[test]
@@ -24,7 +24,7 @@ fn test_assert_and_get_claim() {
         predicate: "knows".to_string(),
         object: "Bob".to_string(),
         confidence: (0.8, 0.9),
-        tier: "ephemeral".to_string(),
+        tier: Tier::Ephemeral,
         created_at: 1000,
         stale_at: None,
     };
@@ -32,7 +32,7 @@ fn test_assert_and_get_claim() {
     // Assert the claim
     let result = store.assert_claim(claim.clone());
     assert!(result.is_ok(), "Should assert claim successfully");
-    assert_eq!(result.unwrap(), claim_id);
+    assert_eq!(result.unwrap(), AssertOutcome::Inserted(claim_id));
     
     // Retrieve the claim
     let retrieved = store.get_claim(claim_id).unwrap();
@@ -61,7 +61,7 @@ fn test_duplicate_detection() {
         predicate: "knows".to_string(),
         object: "Bob".to_string(),
         confidence: (0.8, 0.9),
-        tier: "ephemeral".to_string(),
+        tier: Tier::Ephemeral,
         created_at: 1000,
         stale_at: None,
     };
@@ -74,6 +74,75 @@ fn test_duplicate_detection() {
     assert!(result.is_err(), "Should reject duplicate claim");
 }
 
+#[test]
+fn test_duplicate_assertion_corroborates() {
+    let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
+    
+    let original_id = ClaimId::new();
+    let original = Claim {
+        id: original_id,
+        namespace: "test".to_string(),
+        subject: "Alice".to_string(),
+        predicate: "knows".to_string(),
+        object: "Bob".to_string(),
+        confidence: (0.6, 0.8),
+        tier: Tier::Ephemeral,
+        created_at: 1000,
+        stale_at: None,
+    };
+    let provenance = ProvenanceEntry::new("user:alice".to_string(), 1000, "user".to_string());
+    assert_eq!(
+        store.assert_claim_with_provenance(original.clone(), vec![provenance]).unwrap(),
+        AssertOutcome::Inserted(original_id)
+    );
+    
+    // Same content under a new ID, differing only in case and whitespace
+    let restated = Claim {
+        id: ClaimId::new(),
+        subject: "  alice ".to_string(),
+        object: "BOB".to_string(),
+        confidence: (0.7, 0.7),
+        created_at: 2000,
+        ..original.clone()
+    };
+    let provenance = ProvenanceEntry::new("agent:gpt4".to_string(), 2000, "agent".to_string())
+        .with_rationale("restated".to_string());
+    let outcome = store.assert_claim_with_provenance(restated.clone(), vec![provenance]).unwrap();
+    assert_eq!(outcome, AssertOutcome::Corroborated(original_id));
+    assert!(outcome.is_corroborated());
+    assert!(store.get_claim(restated.id).unwrap().is_none());
+    
+    // Provenance is appended and confidence recomputed
+    let provenance = store.get_provenance(original_id).unwrap();
+    assert_eq!(provenance.len(), 2);
+    assert_eq!(provenance[0].source, "user:alice");
+    assert_eq!(provenance[1].source, "agent:gpt4");
+    assert_eq!(provenance[1].rationale.as_deref(), Some("restated"));
+    
+    let corroborated = store.get_claim(original_id).unwrap().unwrap();
+    assert!((corroborated.confidence.0 - 0.7).abs() < 1e-9);
+    assert!((corroborated.confidence.1 - 0.94).abs() < 1e-9);
+    
+    // Assertions without provenance still leave a trail
+    let again = Claim { id: ClaimId::new(), ..original.clone() };
+    assert!(store.assert_claim(again).unwrap().is_corroborated());
+    assert_eq!(store.get_provenance(original_id).unwrap().len(), 3);
+    
+    let found = store.find_by_content_hash(&restated.content_hash()).unwrap();
+    assert_eq!(found.map(|c| c.id), Some(original_id));
+    
+    // A different namespace is distinct content
+    let elsewhere = Claim {
+        id: ClaimId::new(),
+        namespace: "other".to_string(),
+        ..original
+    };
+    assert_eq!(
+        store.assert_claim(elsewhere.clone()).unwrap(),
+        AssertOutcome::Inserted(elsewhere.id)
+    );
+}
+
 #[test]
 fn test_query_claims_by_namespace() {
     let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
@@ -87,7 +156,7 @@ fn test_query_claims_by_namespace() {
             predicate: "is".to_string(),
             object: "something".to_string(),
             confidence: (0.5, 0.6),
-            tier: "ephemeral".to_string(),
+            tier: Tier::Ephemeral,
             created_at: 1000 + i as u64,
             stale_at: None,
         };
@@ -112,7 +181,7 @@ fn test_query_claims_by_namespace() {
 fn test_query_claims_by_tier() {
     let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
     
-    let tiers = ["ephemeral", "task", "project", "permanent"];
+    let tiers = Tier::ALL;
     
     // Add claims with different tiers
     for (i, tier) in tiers.iter().enumerate() {
@@ -123,7 +192,7 @@ fn test_query_claims_by_tier() {
             predicate: "is".to_string(),
             object: "something".to_string(),
             confidence: (0.5, 0.6),
-            tier: tier.to_string(),
+            tier: *tier,
             created_at: 1000 + i as u64,
             stale_at: None,
         };
@@ -132,13 +201,13 @@ fn test_query_claims_by_tier() {
     
     // Query for task tier
     let query = ClaimQuery {
-        tier: Some("task".to_string()),
+        tier: Some(Tier::Task),
         ..Default::default()
     };
     
     let results = store.query_claims(&query).unwrap();
     assert_eq!(results.len(), 1, "Should find 1 claim with tier 'task'");
-    assert_eq!(results[0].tier, "task");
+    assert_eq!(results[0].tier, Tier::Task);
 }
 
 #[test]
@@ -155,7 +224,7 @@ fn test_query_claims_by_confidence() {
             predicate: "is".to_string(),
             object: "something".to_string(),
             confidence,
-            tier: "ephemeral".to_string(),
+            tier: Tier::Ephemeral,
             created_at: 1000 + i as u64,
             stale_at: None,
         };
@@ -189,7 +258,7 @@ fn test_query_claims_with_limit() {
             predicate: "is".to_string(),
             object: "something".to_string(),
             confidence: (0.5, 0.6),
-            tier: "ephemeral".to_string(),
+            tier: Tier::Ephemeral,
             created_at: 1000 + i as u64,
             stale_at: None,
         };
@@ -219,7 +288,7 @@ fn test_add_and_get_relationships() {
         predicate: "knows".to_string(),
         object: "Bob".to_string(),
         confidence: (0.8, 0.9),
-        tier: "ephemeral".to_string(),
+        tier: Tier::Ephemeral,
         created_at: 1000,
         stale_at: None,
     };
@@ -232,7 +301,7 @@ fn test_add_and_get_relationships() {
         predicate: "knows".to_string(),
         object: "Charlie".to_string(),
         confidence: (0.7, 0.8),
-        tier: "ephemeral".to_string(),
+        tier: Tier::Ephemeral,
         created_at: 1001,
         stale_at: None,
     };
@@ -280,7 +349,7 @@ fn test_relationship_types() {
             predicate: "is".to_string(),
             object: "something".to_string(),
             confidence: (0.5, 0.6),
-            tier: "ephemeral".to_string(),
+            tier: Tier::Ephemeral,
             created_at: 1000 + i as u64,
             stale_at: None,
         };
@@ -318,7 +387,7 @@ fn test_relationship_types() {
 #[test]
 fn test_uuid_temporal_ordering() {
     let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
-
+    
     // Create claims with different times (via their UUIDv7s)
     let mut claim_ids = Vec::new();
     
@@ -336,7 +405,7 @@ fn test_uuid_temporal_ordering() {
             predicate: "is".to_string(),
             object: "something".to_string(),
             confidence: (0.5, 0.6),
-            tier: "ephemeral".to_string(),
+            tier: Tier::Ephemeral,
             created_at: 1000 + i as u64,
             stale_at: None,
         };
@@ -378,7 +447,7 @@ fn test_stale_at_field() {
         predicate: "knows".to_string(),
         object: "Bob".to_string(),
         confidence: (0.8, 0.9),
-        tier: "ephemeral".to_string(),
+        tier: Tier::Ephemeral,
         created_at: 1000,
         stale_at: Some(2000),
     };
@@ -388,3 +457,166 @@ fn test_stale_at_field() {
     let retrieved = store.get_claim(claim_id).unwrap().unwrap();
     assert_eq!(retrieved.stale_at, Some(2000), "Should preserve stale_at value");
 }
+
+#[test]
+fn test_staleness_policy_by_namespace() {
+    let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
+    store.set_staleness_policy(
+        StalenessPolicy::disabled()
+            .with_namespace("news", StalenessRule::new(500, 500))
+            .with_namespace_tier("news/archive", Tier::Ephemeral, StalenessRule::never_stale(500)),
+    );
+    
+    let claim = |namespace: &str, tier: Tier| Claim {
+        id: ClaimId::new(),
+        namespace: namespace.to_string(),
+        subject: "Alice".to_string(),
+        predicate: "knows".to_string(),
+        object: namespace.to_string(),
+        confidence: (0.8, 0.9),
+        tier,
+        created_at: 1000,
+        stale_at: None,
+    };
+    let stale_at = |store: &SqliteStore, claim: &Claim| store.get_claim(claim.id).unwrap().unwrap().stale_at;
+    
+    let news = claim("news/today", Tier::Task);
+    let archived = claim("news/archive", Tier::Ephemeral);
+    let docs = claim("docs", Tier::Ephemeral);
+    for claim in [&news, &archived, &docs] {
+        store.assert_claim(claim.clone()).unwrap();
+    }
+    
+    assert_eq!(stale_at(&store, &news), Some(1500));
+    assert_eq!(stale_at(&store, &archived), None);
+    assert_eq!(stale_at(&store, &docs), None);
+}
+
+fn batch_claim(subject: &str) -> Claim {
+    Claim {
+        id: ClaimId::new(),
+        namespace: "test".to_string(),
+        subject: subject.to_string(),
+        predicate: "knows".to_string(),
+        object: "Bob".to_string(),
+        confidence: (0.6, 0.8),
+        tier: Tier::Task,
+        created_at: 1000,
+        stale_at: None,
+    }
+}
+
+#[test]
+fn test_assert_batch_partial_success() {
+    let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
+    
+    let existing = batch_claim("Alice");
+    store.assert_claim(existing.clone()).unwrap();
+    
+    let new_claim = batch_claim("Carol");
+    let restated = batch_claim("alice");
+    let reused_id = Claim { subject: "Dave".to_string(), ..existing.clone() };
+    
+    let batch = ClaimBatch {
+        claims: vec![
+            new_claim.clone().into(),
+            BatchClaim {
+                claim: restated.clone(),
+                provenance: vec![ProvenanceEntry::new("doc:1".to_string(), 1000, "extraction".to_string())],
+            },
+            reused_id.into(),
+        ],
+        relationships: vec![
+            // Points at the restated claim, which resolves to the existing one
+            Relationship::new(new_claim.id, restated.id, RelationshipType::Supports, 0.9, 1000),
+        ],
+        all_or_nothing: false,
+    };
+    
+    let outcome = store.assert_batch(batch).unwrap();
+    assert_eq!(outcome.claims.len(), 3);
+    assert_eq!(*outcome.claims[0].as_ref().unwrap(), AssertOutcome::Inserted(new_claim.id));
+    assert_eq!(*outcome.claims[1].as_ref().unwrap(), AssertOutcome::Corroborated(existing.id));
+    assert!(matches!(outcome.claims[2], Err(boswell_store::StoreError::Duplicate)));
+    assert_eq!(outcome.inserted_count(), 1);
+    assert_eq!(outcome.corroborated_count(), 1);
+    assert_eq!(outcome.error_count(), 1);
+    
+    assert!(outcome.relationships[0].is_ok());
+    let relationships = store.get_relationships(new_claim.id).unwrap();
+    assert_eq!(relationships[0].to_claim, existing.id);
+    assert_eq!(store.get_provenance(existing.id).unwrap().len(), 2);
+    assert_eq!(store.get_provenance(new_claim.id).unwrap().len(), 1);
+}
+
+#[test]
+fn test_assert_batch_all_or_nothing() {
+    let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
+    
+    let existing = batch_claim("Alice");
+    store.assert_claim(existing.clone()).unwrap();
+    
+    let first = batch_claim("Carol");
+    let restated = batch_claim("alice");
+    let reused_id = Claim { subject: "Dave".to_string(), ..existing.clone() };
+    
+    let batch = ClaimBatch {
+        claims: vec![first.clone().into(), restated.into(), reused_id.into()],
+        relationships: vec![],
+        all_or_nothing: true,
+    };
+    
+    let result = store.assert_batch(batch);
+    assert!(matches!(result, Err(boswell_store::StoreError::BatchAborted(_))));
+    
+    // Nothing from the batch survives, including the corroboration
+    assert!(store.get_claim(first.id).unwrap().is_none());
+    assert_eq!(store.get_provenance(existing.id).unwrap().len(), 1);
+    assert_eq!(store.get_claim(existing.id).unwrap().unwrap().confidence, existing.confidence);
+    assert_eq!(store.query_claims(&ClaimQuery::default()).unwrap().len(), 1);
+}
+
+#[test]
+fn test_assert_large_batch() {
+    let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
+    
+    let claims: Vec<BatchClaim> = (0..5_000)
+        .map(|i| batch_claim(&format!("Subject{}", i)).into())
+        .collect();
+    
+    let outcome = store.assert_batch(ClaimBatch { claims, ..Default::default() }).unwrap();
+    assert_eq!(outcome.inserted_count(), 5_000);
+    assert_eq!(outcome.error_count(), 0);
+    
+    let query = ClaimQuery { limit: Some(10_000), ..Default::default() };
+    assert_eq!(store.query_claims(&query).unwrap().len(), 5_000);
+}
+
+#[test]
+fn test_tier_changes_and_evictions_are_events() {
+    let mut store = SqliteStore::new(":memory:", false, 0).unwrap();
+    let claim = batch_claim("Alice");
+    store.assert_claim(claim.clone()).unwrap();
+    
+    assert!(store.set_tier(claim.id, Tier::Project).unwrap());
+    assert!(!store.set_tier(claim.id, Tier::Project).unwrap());
+    assert!(store.set_tier(claim.id, Tier::Ephemeral).unwrap());
+    assert_eq!(store.get_claim(claim.id).unwrap().unwrap().tier, Tier::Ephemeral);
+    assert!(store.delete_claim(claim.id).unwrap());
+    assert!(!store.delete_claim(claim.id).unwrap());
+    assert!(matches!(store.set_tier(claim.id, Tier::Task), Err(boswell_store::StoreError::NotFound(_))));
+    
+    let events = store.events_since(0, 100).unwrap();
+    let kinds: Vec<EventKind> = events.iter().map(|event| event.kind).collect();
+    assert_eq!(kinds, vec![
+        EventKind::Asserted(claim.id),
+        EventKind::Promoted { claim_id: claim.id, from: Tier::Task, to: Tier::Project },
+        EventKind::Demoted { claim_id: claim.id, from: Tier::Project, to: Tier::Ephemeral },
+        EventKind::Evicted(claim.id),
+    ]);
+    
+    // Each event keeps the claim's namespace and its tier at the time, eviction included
+    assert!(events.iter().all(|event| event.namespace.as_deref() == Some("test")));
+    let tiers: Vec<Option<Tier>> = events.iter().map(|event| event.tier).collect();
+    assert_eq!(tiers, vec![Some(Tier::Task), Some(Tier::Project), Some(Tier::Ephemeral), Some(Tier::Ephemeral)]);
+}
@@ -0,0 +1,296 @@
+//! PostgresStore against the ClaimStore conformance suite and its own checks
+//!
+//! Runs against the server in `BOSWELL_TEST_POSTGRES_URL` when it is set: a
+//! libpq `key=value` connection string for a role that may create databases.
+//! Otherwise a throwaway cluster is started with `initdb` and `postgres` from
+//! `PATH`, listening only on a socket in a temporary directory. Every check
+//! runs in a database of its own. When neither is available the tests are
+//! skipped with a message.
+//!
+//! This file uses its own harness (`harness = false`) so the cluster can be
+//! started once and stopped after the last check.
+
+use boswell_domain::traits::{AssertOutcome, ClaimQuery, ClaimStore};
+use boswell_domain::{Claim, ClaimId, EventKind, StalenessPolicy, Tier};
+use boswell_store::{conformance, ConfiguredStore, PostgresStore, StoreConfig, StoreError, POSTGRES_SCHEMA_VERSION};
+use postgres::{Client, NoTls};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{Child, Command, ExitCode, Stdio};
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// A test run against the connection string of an empty database
+type DatabaseTest = Box<dyn FnOnce(&str)>;
+
+/// A PostgreSQL-specific check, given the connection string of an empty database
+type DatabaseCheck = fn(&str);
+
+/// Checks specific to PostgreSQL
+const POSTGRES_CHECKS: &[(&str, DatabaseCheck)] = &[
+    ("reopening_applies_no_migrations", reopening_applies_no_migrations),
+    ("refuses_newer_schema", refuses_newer_schema),
+    ("concurrent_duplicates_corroborate", concurrent_duplicates_corroborate),
+    ("config_selects_postgres", config_selects_postgres),
+    ("tier_changes_and_evictions_are_events", tier_changes_and_evictions_are_events),
+];
+
+fn main() -> ExitCode {
+    let server = match TestServer::start() {
+        Ok(server) => server,
+        Err(reason) => {
+            println!("Skipping PostgreSQL tests: {}", reason);
+            return ExitCode::SUCCESS;
+        }
+    };
+    
+    // Same filtering as the default harness: run tests whose name contains the first free argument
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let selected = |name: &str| filter.as_deref().is_none_or(|filter| name.contains(filter));
+    
+    let mut tests: Vec<(String, DatabaseTest)> = Vec::new();
+    for (name, check) in conformance::checks::<PostgresStore>() {
+        tests.push((
+            format!("conformance::{}", name),
+            Box::new(move |url: &str| check(PostgresStore::connect(url, 4).unwrap())),
+        ));
+    }
+    for &(name, check) in POSTGRES_CHECKS {
+        tests.push((name.to_string(), Box::new(check)));
+    }
+    tests.retain(|(name, _)| selected(name));
+    
+    println!("\nrunning {} tests", tests.len());
+    let mut failed = Vec::new();
+    for (index, (name, test)) in tests.into_iter().enumerate() {
+        let url = server.create_database(&format!("boswell_test_{}", index));
+        let passed = panic::catch_unwind(AssertUnwindSafe(|| test(&url))).is_ok();
+        println!("test {} ... {}", name, if passed { "ok" } else { "FAILED" });
+        if !passed {
+            failed.push(name);
+        }
+    }
+    
+    if failed.is_empty() {
+        println!("\ntest result: ok");
+        ExitCode::SUCCESS
+    } else {
+        println!("\nfailures:\n    {}\n\ntest result: FAILED. {} failed", failed.join("\n    "), failed.len());
+        ExitCode::FAILURE
+    }
+}
+
+/// A PostgreSQL server to create test databases on
+struct TestServer {
+    /// Connection string without a database name
+    conninfo: String,
+    
+    /// Cluster started for this run, held so it stops when the server is dropped
+    _cluster: Option<Cluster>,
+}
+
+struct Cluster {
+    dir: TempDir,
+    postmaster: Child,
+}
+
+impl TestServer {
+    fn start() -> Result<Self, String> {
+        if let Ok(conninfo) = std::env::var("BOSWELL_TEST_POSTGRES_URL") {
+            return Ok(Self { conninfo, _cluster: None });
+        }
+        
+        let dir = TempDir::new().map_err(|e| e.to_string())?;
+        let data = dir.path().join("data");
+        let initdb = Command::new("initdb")
+            .args(["--auth=trust", "--username=boswell", "--encoding=UTF8", "--no-sync", "-D"])
+            .arg(&data)
+            .output()
+            .map_err(|e| format!("initdb not available ({}); set BOSWELL_TEST_POSTGRES_URL", e))?;
+        if !initdb.status.success() {
+            return Err(format!("initdb failed: {}", String::from_utf8_lossy(&initdb.stderr).trim()));
+        }
+        
+        let log = std::fs::File::create(dir.path().join("postgres.log")).map_err(|e| e.to_string())?;
+        let postmaster = Command::new("postgres")
+            .arg("-D").arg(&data)
+            .arg("-k").arg(dir.path())
+            .args(["-c", "listen_addresses=", "-c", "fsync=off", "-p", "5432"])
+            .stdout(Stdio::null())
+            .stderr(log)
+            .spawn()
+            .map_err(|e| format!("could not start postgres: {}", e))?;
+        
+        let server = Self {
+            conninfo: format!("host={} port=5432 user=boswell", dir.path().display()),
+            _cluster: Some(Cluster { dir, postmaster }),
+        };
+        
+        // The socket appears once the server accepts connections
+        let started = Instant::now();
+        while Client::connect(&server.url("postgres"), NoTls).is_err() {
+            if started.elapsed() > Duration::from_secs(30) {
+                return Err("postgres did not accept connections within 30s".to_string());
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        
+        Ok(server)
+    }
+    
+    fn url(&self, database: &str) -> String {
+        format!("{} dbname={}", self.conninfo, database)
+    }
+    
+    /// Create an empty database, replacing any left over from an earlier run
+    fn create_database(&self, name: &str) -> String {
+        let mut admin = Client::connect(&self.url("postgres"), NoTls).unwrap();
+        // Separate statements: a multi-statement batch would run as one transaction
+        admin.batch_execute(&format!("DROP DATABASE IF EXISTS {} WITH (FORCE)", name)).unwrap();
+        admin.batch_execute(&format!("CREATE DATABASE {}", name)).unwrap();
+        self.url(name)
+    }
+}
+
+impl Drop for Cluster {
+    fn drop(&mut self) {
+        let stopped = Command::new("pg_ctl")
+            .args(["stop", "--mode=fast", "--silent", "-D"])
+            .arg(self.dir.path().join("data"))
+            .status()
+            .is_ok_and(|status| status.success());
+        if !stopped {
+            let _ = self.postmaster.kill();
+        }
+        let _ = self.postmaster.wait();
+    }
+}
+
+fn test_claim(subject: &str) -> Claim {
+    Claim {
+        id: ClaimId::new(),
+        namespace: "test".to_string(),
+        subject: subject.to_string(),
+        predicate: "knows".to_string(),
+        object: "Bob".to_string(),
+        confidence: (0.8, 0.9),
+        tier: Tier::Task,
+        created_at: 1000,
+        stale_at: None,
+    }
+}
+
+/// `claim` as the store returns it, with `stale_at` set by the default policy
+fn stored(claim: &Claim) -> Claim {
+    Claim { stale_at: StalenessPolicy::default().stale_at_on_insert(claim), ..claim.clone() }
+}
+
+fn schema_versions(url: &str) -> Vec<i32> {
+    let mut client = Client::connect(url, NoTls).unwrap();
+    client.query("SELECT version FROM schema_info ORDER BY version", &[]).unwrap()
+        .iter()
+        .map(|row| row.get(0))
+        .collect()
+}
+
+fn reopening_applies_no_migrations(url: &str) {
+    let mut store = PostgresStore::connect(url, 2).unwrap();
+    let claim = test_claim("Alice");
+    store.assert_claim(claim.clone()).unwrap();
+    drop(store);
+    
+    let store = PostgresStore::connect(url, 2).unwrap();
+    assert_eq!(store.get_claim(claim.id).unwrap(), Some(stored(&claim)));
+    assert_eq!(schema_versions(url), (1..=POSTGRES_SCHEMA_VERSION as i32).collect::<Vec<_>>());
+}
+
+fn refuses_newer_schema(url: &str) {
+    drop(PostgresStore::connect(url, 1).unwrap());
+    
+    let mut client = Client::connect(url, NoTls).unwrap();
+    client.execute(
+        "INSERT INTO schema_info (version, applied_at, description) VALUES ($1, 0, 'future')",
+        &[&(POSTGRES_SCHEMA_VERSION as i32 + 1)],
+    ).unwrap();
+    
+    match PostgresStore::connect(url, 1) {
+        Err(StoreError::SchemaTooNew { found, supported }) => {
+            assert_eq!(found, POSTGRES_SCHEMA_VERSION + 1);
+            assert_eq!(supported, POSTGRES_SCHEMA_VERSION);
+        }
+        Err(e) => panic!("expected SchemaTooNew, got {}", e),
+        Ok(_) => panic!("opened a database with a newer schema"),
+    }
+}
+
+fn concurrent_duplicates_corroborate(url: &str) {
+    const WRITERS: usize = 8;
+    let store = PostgresStore::connect(url, WRITERS).unwrap();
+    let barrier = Arc::new(Barrier::new(WRITERS));
+    
+    let writers: Vec<_> = (0..WRITERS)
+        .map(|_| {
+            let mut store = store.clone();
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                store.assert_claim(test_claim("Alice")).unwrap()
+            })
+        })
+        .collect();
+    let outcomes: Vec<AssertOutcome> = writers.into_iter().map(|writer| writer.join().unwrap()).collect();
+    
+    let inserted: Vec<ClaimId> = outcomes.iter()
+        .filter_map(|outcome| match outcome {
+            AssertOutcome::Inserted(id) => Some(*id),
+            AssertOutcome::Corroborated(_) => None,
+        })
+        .collect();
+    assert_eq!(inserted.len(), 1, "outcomes: {:?}", outcomes);
+    assert!(outcomes.iter().all(|outcome| outcome.claim_id() == inserted[0]));
+    
+    assert_eq!(store.query_claims(&ClaimQuery::default()).unwrap().len(), 1);
+    assert_eq!(store.get_provenance(inserted[0]).unwrap().len(), WRITERS);
+    assert_eq!(store.events_since(0, 100).unwrap().len(), WRITERS);
+    assert!(store.open_connections() <= WRITERS);
+}
+
+fn config_selects_postgres(url: &str) {
+    let config = StoreConfig::Postgres {
+        url: url.to_string(),
+        embedding_dimension: None,
+        max_connections: 2,
+    };
+    
+    let mut store = config.open().unwrap();
+    assert!(matches!(store, ConfiguredStore::Postgres(_)));
+    
+    let claim = test_claim("Alice");
+    store.assert_claim(claim.clone()).unwrap();
+    assert_eq!(store.get_claim(claim.id).unwrap(), Some(stored(&claim)));
+    assert!(store.delete_claim(claim.id).unwrap());
+    assert_eq!(store.get_claim(claim.id).unwrap(), None);
+}
+
+fn tier_changes_and_evictions_are_events(url: &str) {
+    let mut store = PostgresStore::connect(url, 2).unwrap();
+    let claim = test_claim("Alice");
+    store.assert_claim(claim.clone()).unwrap();
+    
+    assert!(store.set_tier(claim.id, Tier::Permanent).unwrap());
+    assert!(!store.set_tier(claim.id, Tier::Permanent).unwrap());
+    assert!(store.delete_claim(claim.id).unwrap());
+    assert!(matches!(store.set_tier(claim.id, Tier::Task), Err(StoreError::NotFound(_))));
+    
+    let events = store.events_since(0, 100).unwrap();
+    let kinds: Vec<EventKind> = events.iter().map(|event| event.kind).collect();
+    assert_eq!(kinds, vec![
+        EventKind::Asserted(claim.id),
+        EventKind::Promoted { claim_id: claim.id, from: Tier::Task, to: Tier::Permanent },
+        EventKind::Evicted(claim.id),
+    ]);
+    assert!(events.iter().all(|event| event.namespace.as_deref() == Some("test")));
+    let tiers: Vec<Option<Tier>> = events.iter().map(|event| event.tier).collect();
+    assert_eq!(tiers, vec![Some(Tier::Task), Some(Tier::Permanent), Some(Tier::Permanent)]);
+}
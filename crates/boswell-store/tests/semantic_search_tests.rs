@@ -2,7 +2,7 @@
 //!
 //! These tests verify vector search works correctly with the HNSW index.
 
-use boswell_domain::{Claim, ClaimId};
+use boswell_domain::{Claim, ClaimId, Tier};
 use boswell_domain::traits::ClaimStore;
 use boswell_store::SqliteStore;
 
@@ -20,7 +20,7 @@ fn test_semantic_search_basic() {
         predicate: "is_a".to_string(),
         object: "programming_language".to_string(),
         confidence: (0.9, 0.95),
-        tier: "permanent".to_string(),
+        tier: Tier::Permanent,
         created_at: 1000,
         stale_at: None,
     };
@@ -33,7 +33,7 @@ fn test_semantic_search_basic() {
         predicate: "is_a".to_string(),
         object: "programming_language".to_string(),
         confidence: (0.9, 0.95),
-        tier: "permanent".to_string(),
+        tier: Tier::Permanent,
         created_at: 1001,
         stale_at: None,
     };
@@ -74,7 +74,7 @@ fn test_semantic_search_disabled() {
         predicate: "test".to_string(),
         object: "test".to_string(),
         confidence: (0.9, 0.95),
-        tier: "ephemeral".to_string(),
+        tier: Tier::Ephemeral,
         created_at: 1000,
         stale_at: None,
     };
@@ -114,7 +114,7 @@ fn test_semantic_search_with_threshold() {
         predicate: "is".to_string(),
         object: "close".to_string(),
         confidence: (0.9, 0.95),
-        tier: "task".to_string(),
+        tier: Tier::Task,
         created_at: 1000,
         stale_at: None,
     };
@@ -127,7 +127,7 @@ fn test_semantic_search_with_threshold() {
         predicate: "is".to_string(),
         object: "far".to_string(),
         confidence: (0.8, 0.9),
-        tier: "task".to_string(),
+        tier: Tier::Task,
         created_at: 1001,
         stale_at: None,
     };
@@ -150,3 +150,134 @@ fn test_semantic_search_with_threshold() {
     assert_eq!(results[0].0.id, claim_id1);
     assert!(results[0].1 > 0.99);
 }
+
+#[test]
+fn test_deleted_claims_excluded_from_semantic_search() {
+    let mut store = SqliteStore::new(":memory:", true, 3).unwrap();
+    // 3-dimensional mock embeddings are too coarse for semantic duplicate detection
+    store.set_duplicate_threshold(f32::INFINITY);
+    
+    let mut ids = Vec::new();
+    for i in 0..5 {
+        let claim_id = ClaimId::new();
+        let claim = Claim {
+            id: claim_id,
+            namespace: "test".to_string(),
+            subject: format!("subject_{}", i),
+            predicate: "is".to_string(),
+            object: "indexed".to_string(),
+            confidence: (0.8, 0.9),
+            tier: Tier::Task,
+            created_at: 1000 + i,
+            stale_at: None,
+        };
+        store.assert_claim(claim).unwrap();
+        store.add_embedding(claim_id, &[1.0, i as f32 / 10.0, 0.0]).unwrap();
+        ids.push(claim_id);
+    }
+    
+    // Explicit embeddings replace the ones generated on assert
+    assert_eq!(store.compact_vector_index(0.0).unwrap(), 5);
+    
+    // Delete the best match
+    assert!(store.delete_claim(ids[0]).unwrap());
+    assert!(!store.delete_claim(ids[0]).unwrap());
+    assert!(store.get_claim(ids[0]).unwrap().is_none());
+    
+    let results = store.semantic_search(&[1.0, 0.0, 0.0], 5, 64, 0.0).unwrap();
+    assert_eq!(results.len(), 4);
+    assert!(results.iter().all(|(claim, _)| claim.id != ids[0]));
+    assert_eq!(results[0].0.id, ids[1]);
+    
+    // 1 of 5 entries tombstoned: below a 0.5 threshold, at the default 0.2
+    assert_eq!(store.compact_vector_index(0.5).unwrap(), 0);
+    assert_eq!(store.compact_indexes(boswell_store::DEFAULT_COMPACTION_THRESHOLD).unwrap(), 1);
+    
+    let results = store.semantic_search(&[1.0, 0.0, 0.0], 5, 64, 0.0).unwrap();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].0.id, ids[1]);
+}
+
+#[test]
+fn test_vector_index_config_and_stats() {
+    use boswell_store::VectorIndexConfig;
+    
+    let config = VectorIndexConfig {
+        m: 8,
+        ef_construction: 100,
+        max_elements: 10_000,
+    };
+    let mut store = SqliteStore::with_vector_config(":memory:", 16, config).unwrap();
+    
+    for i in 0..30 {
+        let claim = Claim {
+            id: ClaimId::new(),
+            namespace: "test".to_string(),
+            subject: format!("subject_{}", i),
+            predicate: "relates_to".to_string(),
+            object: format!("object_{}", i % 4),
+            confidence: (0.8, 0.9),
+            tier: Tier::Task,
+            created_at: 1000 + i,
+            stale_at: None,
+        };
+        store.assert_claim(claim).unwrap();
+    }
+    
+    let stats = store.vector_index_stats().unwrap();
+    assert_eq!(stats.config, config);
+    assert_eq!(stats.dimension, 16);
+    assert_eq!(stats.element_count, 30);
+    assert_eq!(stats.tombstone_count, 0);
+    
+    let report = store.vector_recall_self_test(10, 5, 64).unwrap();
+    assert_eq!(report.queries, 10);
+    assert!(report.recall > 0.0 && report.recall <= 1.0);
+}
+
+#[test]
+fn test_invalid_vector_index_config() {
+    use boswell_store::VectorIndexConfig;
+    
+    let config = VectorIndexConfig { m: 0, ..Default::default() };
+    assert!(SqliteStore::with_vector_config(":memory:", 16, config).is_err());
+    
+    let store = SqliteStore::new(":memory:", false, 0).unwrap();
+    assert!(store.vector_index_stats().is_err());
+}
+
+#[test]
+fn test_semantic_duplicate_corroborates() {
+    let mut store = SqliteStore::new(":memory:", true, 16).unwrap();
+    assert_eq!(store.duplicate_threshold(), boswell_store::DEFAULT_DUPLICATE_THRESHOLD);
+    
+    let claim = |subject: &str, predicate: &str, object: &str| Claim {
+        id: ClaimId::new(),
+        namespace: "test".to_string(),
+        subject: subject.to_string(),
+        predicate: predicate.to_string(),
+        object: object.to_string(),
+        confidence: (0.6, 0.7),
+        tier: Tier::Task,
+        created_at: 1000,
+        stale_at: None,
+    };
+    
+    let original = claim("company:Acme Corp", "founded", "number:1990");
+    let original_id = original.id;
+    assert!(!store.assert_claim(original).unwrap().is_corroborated());
+    
+    // Accept any neighbour so the mock embeddings count as near-identical
+    store.set_duplicate_threshold(-1.0);
+    
+    // Structural checks: a different predicate, subject or object is never a duplicate
+    assert!(!store.assert_claim(claim("company:acme_corp", "dissolved", "number:1990")).unwrap().is_corroborated());
+    assert!(!store.assert_claim(claim("company:globex", "founded", "number:1990")).unwrap().is_corroborated());
+    assert!(!store.assert_claim(claim("company:acme_corp", "founded", "number:1991")).unwrap().is_corroborated());
+    
+    // Same subject once normalized, same literal value written differently
+    let outcome = store.assert_claim(claim("company:acme_corp", "founded", "number:1990.0")).unwrap();
+    assert_eq!(outcome.claim_id(), original_id);
+    assert!(outcome.is_corroborated());
+    assert_eq!(store.get_provenance(original_id).unwrap().len(), 2);
+}
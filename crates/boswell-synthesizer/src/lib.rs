@@ -4,5 +4,59 @@
 
 #![warn(missing_docs)]
 
+use boswell_domain::ConfidenceInterval;
+
 /// Placeholder for synthesizer implementation
 pub struct Synthesizer;
+
+/// Confidence for a claim derived from `constituents`, given the LLM's
+/// `assessed` confidence in the inference
+///
+/// Both bounds are at most the conjunction of the constituents, so an
+/// inference is never more certain than all of its foundations holding
+/// together, and at most the assessment. Returns `None` if there are no
+/// constituents.
+pub fn derived_confidence(
+    constituents: &[ConfidenceInterval],
+    assessed: ConfidenceInterval,
+) -> Option<ConfidenceInterval> {
+    let (first, rest) = constituents.split_first()?;
+    let all = rest.iter().fold(*first, |all, c| all.and(c));
+
+    let upper = assessed.upper.min(all.upper);
+    let lower = assessed.lower.min(all.lower).min(upper);
+    Some(ConfidenceInterval::new(lower, upper))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derived_confidence_is_bounded_by_constituents() {
+        let constituents = [ConfidenceInterval::new(0.8, 0.9), ConfidenceInterval::new(0.5, 0.7)];
+
+        let derived = derived_confidence(&constituents, ConfidenceInterval::new(0.6, 0.95)).unwrap();
+        assert!((derived.lower - 0.4).abs() < 1e-9);
+        assert!((derived.upper - 0.63).abs() < 1e-9);
+        assert!(derived.upper <= constituents[1].upper);
+
+        let derived = derived_confidence(&constituents, ConfidenceInterval::new(0.1, 0.3)).unwrap();
+        assert_eq!(derived, ConfidenceInterval::new(0.1, 0.3));
+
+        assert_eq!(derived_confidence(&[], ConfidenceInterval::new(0.5, 0.5)), None);
+    }
+
+    #[test]
+    fn test_derived_confidence_widens_through_chains() {
+        let first_order = ConfidenceInterval::new(0.7, 0.9);
+        let assessed = ConfidenceInterval::new(0.7, 0.9);
+
+        let second = derived_confidence(&[first_order, first_order], assessed).unwrap();
+        let third = derived_confidence(&[second, first_order], assessed).unwrap();
+
+        assert!(second.lower < first_order.lower);
+        assert!(third.lower < second.lower);
+        assert!(third.width() > first_order.width());
+    }
+}